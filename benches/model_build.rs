@@ -0,0 +1,92 @@
+// SPDX-License-Identifier: Apache-2.0
+//! Allocation benchmark for building a large ConfigModel.
+//!
+//! Run with `cargo bench --bench model_build`. Compares growing the node storage on demand
+//! (`ConfigModel::new`) with preallocating it (`ConfigModel::with_capacity`), as
+//! `ModelBuilder` does from its node count estimate.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+use colap::config_model::{ConfigModel, ConfigNode, ConfigValue};
+use colap::model_builder::ModelBuilder;
+use colap::cola::ColaParser;
+use rustemo::Parser;
+
+/// Counts allocations and reallocations made through the global allocator
+struct CountingAlloc;
+
+static ALLOCS: AtomicUsize = AtomicUsize::new(0);
+static REALLOCS: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        REALLOCS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+const ENTITIES: usize = 20_000;
+const FIELDS_PER_ENTITY: usize = 4;
+
+/// Run `f` and report its allocation counts and duration
+fn measure(label: &str, f: impl FnOnce() -> ConfigModel) {
+    let allocs = ALLOCS.load(Ordering::Relaxed);
+    let reallocs = REALLOCS.load(Ordering::Relaxed);
+    let start = Instant::now();
+    let model = f();
+    let elapsed = start.elapsed();
+    println!(
+        "{:<28} {:>8} nodes {:>9} allocs {:>7} reallocs {:>10.2?}",
+        label,
+        model.node_count(),
+        ALLOCS.load(Ordering::Relaxed) - allocs,
+        REALLOCS.load(Ordering::Relaxed) - reallocs,
+        elapsed
+    );
+}
+
+/// Fill a model with flat entities, each with a few fields
+fn populate(mut model: ConfigModel) -> ConfigModel {
+    let root_id = model.root_id();
+    for i in 0..ENTITIES {
+        let entity = ConfigNode::new_entity(&format!("entity{}", i), None, Some(root_id), None);
+        let entity_id = model.add_node(entity);
+        model.add_child(root_id, entity_id).unwrap();
+        for j in 0..FIELDS_PER_ENTITY {
+            model
+                .add_field_with_location(entity_id, &format!("field{}", j), ConfigValue::Integer(j as i64), None)
+                .unwrap();
+        }
+    }
+    model
+}
+
+fn main() {
+    let node_count = 1 + ENTITIES * (1 + FIELDS_PER_ENTITY);
+
+    measure("ConfigModel::new", || populate(ConfigModel::new()));
+    measure("ConfigModel::with_capacity", || populate(ConfigModel::with_capacity(node_count)));
+
+    // End to end through the builder, which preallocates from the AST
+    let mut source = String::from("```cola\nitem plural items:\n");
+    for i in 0..ENTITIES {
+        source.push_str(&format!("    item{}:\n        name: \"item{}\",\n        size: {}\n    ;\n", i, i, i));
+    }
+    source.push_str(";\n```\n");
+    let parser = ColaParser::new();
+    let ast = parser.parse(&source).expect("Failed to parse benchmark input");
+    measure("ModelBuilder::build", || ModelBuilder::new().build(&ast).unwrap());
+}
@@ -20,22 +20,23 @@ fn main() {
     
     // Clean up any existing files
     for path in actions_paths.iter().chain(cola_paths.iter()) {
-        if path.exists() {
-            if let Err(e) = fs::remove_file(path) {
-                eprintln!("Failed to delete {}: {e}", path.display());
-                exit(1);
-            }
+        if path.exists()
+            && let Err(e) = fs::remove_file(path)
+        {
+            eprintln!("Failed to delete {}: {e}", path.display());
+            exit(1);
         }
     }
     
     // Make sure src/cola.rustemo exists by copying from src/grammar if needed
     let grammar_src = Path::new("src/grammar/cola.rustemo");
     let grammar_dest = Path::new("src/cola.rustemo");
-    if !grammar_dest.exists() && grammar_src.exists() {
-        if let Err(e) = fs::copy(grammar_src, grammar_dest) {
-            eprintln!("Failed to copy grammar file: {e}");
-            exit(1);
-        }
+    if !grammar_dest.exists()
+        && grammar_src.exists()
+        && let Err(e) = fs::copy(grammar_src, grammar_dest)
+    {
+        eprintln!("Failed to copy grammar file: {e}");
+        exit(1);
     }
 
     let mut settings = rustemo_compiler::Settings::new();
@@ -1,15 +1,20 @@
 // SPDX-License-Identifier: Apache-2.0
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::ffi::OsString;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Component, Path, PathBuf};
 
 use anyhow::Result;
-use heck::{ToPascalCase, ToSnakeCase};
+use heck::{ToKebabCase, ToPascalCase, ToSnakeCase};
 use handlebars::Handlebars;
 use chrono::Local;
 use serde_json::json;
 
-use crate::model::config_model::{ConfigModel, ConfigNode, ConfigValue, EntityNode};
+use crate::model::config_model::{ConfigModel, ConfigNode, ConfigValue, EntityNode, NodeRef, ENUM_DISCRIMINATOR};
+use super::type_map::TypeMap;
+
+/// Rust type of the fields declared as a `Duration`, whose values are in milliseconds
+const DURATION_TYPE: &str = "std::time::Duration";
 
 /// Generation mode for the code generator
 #[derive(Debug, Clone)]
@@ -23,8 +28,85 @@ pub enum GenerationMode {
         output_dir: PathBuf,
         crate_name: String,
     },
+    /// Dump the configuration model as YAML instead of generating code
+    Yaml {
+        output_file: PathBuf,
+    },
+    /// Dump the configuration model as TOML instead of generating code
+    Toml {
+        output_file: PathBuf,
+    },
+}
+
+/// Optional features of the generated code
+#[derive(Debug, Clone, Default)]
+pub struct GeneratorOptions {
+    /// Emit a clap-derive `RootArgs` struct exposing every scalar field as an optional flag
+    pub emit_clap: bool,
+    /// Keep fields without a matching struct field in an `extras` map on each struct
+    pub keep_extras: bool,
+    /// Emit a `ConfigHandle` type and `Index<&str>` impls for `config["llm"]["openai"]` access
+    pub emit_index: bool,
+    /// Derive serde `Serialize` and `Deserialize` on every generated struct
+    pub derive_serde: bool,
+    /// Parse the generated code with `syn` before anything is written, failing on invalid Rust
+    pub verify_syntax: bool,
+    /// Keep the fields of entity structs private and emit a `<Struct>Builder` for each
+    pub builder_api: bool,
+    /// Write a `SCHEMA.md` documenting every entity and its fields in crate mode
+    pub emit_schema_doc: bool,
+    /// Store plural collections whose entries are known at generation time in a fixed-size
+    /// `[Singular; N]` array rather than a map (see `fixed_array_keys`)
+    pub fixed_arrays: bool,
+    /// Derive `derive_builder::Builder` on every generated struct and add the dependency to the
+    /// generated crate. Not combined with `builder_api`, whose builders have the same names.
+    pub derive_builder: bool,
+    /// Type arrays whose elements have different types as `Vec<ConfigScalar>`, an enum of the
+    /// scalar types, instead of keeping only their string elements
+    pub heterogeneous_arrays: bool,
+    /// Return scalar, string and array fields from getters as a `Cow` borrowed from the struct,
+    /// which callers can turn into an owned value with `into_owned`
+    pub cow_getters: bool,
+    /// With `derive_serde`, give every field that has a value in the configuration a
+    /// `#[serde(default)]` provider returning that value, so input that omits the field
+    /// deserializes to the configured value rather than the type's zero value
+    pub serde_defaults: bool,
+    /// Derive `PartialEq` on every generated struct so configs can be compared with
+    /// `assert_eq!`. Never `Eq`, which float fields rule out.
+    pub derive_partialeq: bool,
+    /// Implement `Display` on every generated struct, rendering the configuration as a tree in
+    /// the shape of `ConfigModel::pretty_display`
+    pub impl_display: bool,
+    /// The model was built with `ModelBuilder::unit_suffixes`, so the generated loaders and
+    /// tests build theirs the same way to read the configuration
+    pub unit_suffixes: bool,
+    /// The model was built with `ModelBuilder::repeatable_fields`, which the generated loaders
+    /// and tests use as well
+    pub repeatable_fields: bool,
+    /// Type a plural entity with a single instance as the struct of that instance instead of a
+    /// collection (see `flattens`)
+    pub flatten_singletons: bool,
+}
+
+/// How a generated crate depends on colap in its `Cargo.toml`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColapDependency {
+    /// A path dependency, relative to the generated crate directory or absolute
+    Path(String),
+    /// A crates.io dependency on a version requirement such as `0.1`
+    Version(String),
+}
+
+impl Default for ColapDependency {
+    /// A colap checkout next to the generated crate
+    fn default() -> Self {
+        ColapDependency::Path("../colap".to_string())
+    }
 }
 
+/// A transformation applied to generated Rust source before it is written
+pub type PostProcessor = Box<dyn Fn(&str) -> String>;
+
 /// Code generator that traverses a ConfigModel and emits Rust structs & helper methods.
 /// Supports generating either a single module file or a complete library crate.
 /// Produces a library-like API with proper encapsulation and collection handling.
@@ -37,6 +119,22 @@ pub struct CodeGenerator {
     plural_instances: HashSet<usize>,
     // Handlebars registry for template rendering
     handlebars: Handlebars<'static>,
+    // User hooks run over each generated Rust file, in registration order
+    post_processors: Vec<PostProcessor>,
+    // Optional features of the generated code
+    options: GeneratorOptions,
+    // Entity path the model was narrowed to with `with_only`
+    only_path: Option<String>,
+    // The whole configuration as cola, kept by `with_only` for tests generated without a source file
+    unnarrowed_cola: Option<String>,
+    // Render everything but leave the filesystem untouched
+    dry_run: bool,
+    // Files written, or in a dry run the files that would be, with their sizes in bytes
+    written_files: Vec<(PathBuf, usize)>,
+    // The colap dependency of a generated crate
+    colap_dependency: ColapDependency,
+    // Field type overrides set with `with_type_map`
+    type_map: TypeMap,
 }
 
 impl CodeGenerator {
@@ -52,14 +150,33 @@ impl CodeGenerator {
         handlebars.register_template_string("file_header", include_str!("templates/file_header.hbs"))?;
         handlebars.register_template_string("singular_struct", include_str!("templates/singular_struct.hbs"))?;
         handlebars.register_template_string("plural_struct", include_str!("templates/plural_struct.hbs"))?;
+        handlebars.register_template_string("fixed_plural_struct", include_str!("templates/fixed_plural_struct.hbs"))?;
+        handlebars.register_template_string("enum_struct", include_str!("templates/enum_struct.hbs"))?;
         handlebars.register_template_string("api_struct", include_str!("templates/api_struct.hbs"))?;
         handlebars.register_template_string("entity_struct", include_str!("templates/entity_struct.hbs"))?;
+        handlebars.register_template_string("builder_struct", include_str!("templates/builder_struct.hbs"))?;
         handlebars.register_template_string("integration_test", include_str!("templates/integration_test.hbs"))?;
         handlebars.register_template_string("cargo_toml", include_str!("templates/cargo_toml.hbs"))?;
         handlebars.register_template_string("readme", include_str!("templates/readme.hbs"))?;
+        handlebars.register_template_string("schema_doc", include_str!("templates/schema_doc.hbs"))?;
+        handlebars.register_template_string("clap_args", include_str!("templates/clap_args.hbs"))?;
+        handlebars.register_template_string("config_error", include_str!("templates/config_error.hbs"))?;
+        handlebars.register_template_string("config_scalar", include_str!("templates/config_scalar.hbs"))?;
+        handlebars.register_template_string("config_handle", include_str!("templates/config_handle.hbs"))?;
+        handlebars.register_template_string("visitor", include_str!("templates/visitor.hbs"))?;
+        handlebars.register_template_string("display_tree", include_str!("templates/display_tree.hbs"))?;
+        handlebars.register_partial("model_value", include_str!("templates/model_value.hbs"))?;
+        handlebars.register_partial("validate", include_str!("templates/validate.hbs"))?;
+        handlebars.register_partial("accept", include_str!("templates/accept.hbs"))?;
+        handlebars.register_partial("try_from_entity", include_str!("templates/try_from_entity.hbs"))?;
+        handlebars.register_partial("tree", include_str!("templates/tree.hbs"))?;
+        handlebars.register_partial("display", include_str!("templates/display.hbs"))?;
         
         // Enable built-in helpers
         handlebars.set_strict_mode(false);
+
+        // Templates emit Rust source, so values like `Vec<String>` must not be HTML-escaped
+        handlebars.register_escape_fn(handlebars::no_escape);
         
         Ok(Self {
             model,
@@ -68,9 +185,114 @@ impl CodeGenerator {
             emitted_structs: HashSet::new(),
             plural_instances: HashSet::new(),
             handlebars,
+            post_processors: Vec::new(),
+            options: GeneratorOptions::default(),
+            only_path: None,
+            unnarrowed_cola: None,
+            dry_run: false,
+            written_files: Vec::new(),
+            colap_dependency: ColapDependency::default(),
+            type_map: TypeMap::default(),
         })
     }
 
+    /// Enable optional features of the generated code
+    pub fn with_options(mut self, options: GeneratorOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    /// Generate code for the subtree at `path` (e.g. `llm/openai`) only, with that entity as
+    /// the root. The generated tests extract the same subtree from the source configuration.
+    pub fn with_only(mut self, path: &str) -> Result<Self> {
+        let id = self
+            .model
+            .find_entity_by_path(path)
+            .ok_or_else(|| anyhow::anyhow!("Entity path '{}' not found in the configuration", path))?;
+        let subtree = self
+            .model
+            .subtree_to_model(id)
+            .ok_or_else(|| anyhow::anyhow!("'{}' is not an entity", path))?;
+        self.unnarrowed_cola = Some(self.model.to_cola());
+        self.model = subtree;
+        self.only_path = Some(path.to_string());
+        Ok(self)
+    }
+
+    /// Override field types with the entries of a type map. Every field an entry matches must
+    /// hold a value of the mapped type, or a string for a type that is not built in. Apply it
+    /// after `with_only`, as the config paths of the entries are those of the generated structs.
+    pub fn with_type_map(mut self, type_map: TypeMap) -> Result<Self> {
+        self.type_map = type_map;
+        let mut used = HashSet::new();
+        let root_id = self.model.root_id();
+        for id in std::iter::once(root_id).chain(self.model.iter_entities().map(|(id, _, _)| id)) {
+            let Some(node) = self.model.get_node(id) else {
+                continue;
+            };
+            let node_b = node.borrow();
+            let ConfigNode::Entity(ent) = &*node_b else {
+                continue;
+            };
+            for (name, value) in &ent.fields {
+                let Some((pattern, rust_type)) = self.mapped_type(id, ent, name)? else {
+                    continue;
+                };
+                used.insert(pattern.to_string());
+                let fits = match (rust_type, value) {
+                    (_, ConfigValue::Null) => true,
+                    ("i64" | "i32" | "i16" | "i8" | "u32" | "u16" | "u8" | "Duration", ConfigValue::Integer(_)) => true,
+                    ("f64", ConfigValue::Float(_)) | ("bool", ConfigValue::Boolean(_)) | ("String", ConfigValue::String(_)) => true,
+                    (_, ConfigValue::String(_)) => self.type_map.is_custom(rust_type),
+                    _ => false,
+                };
+                if !fits {
+                    anyhow::bail!(
+                        "Type map entry '{}' maps field '{}' to {}, which does not fit its value {}",
+                        pattern,
+                        self.config_path(id, name),
+                        rust_type,
+                        value
+                    );
+                }
+            }
+        }
+        for (pattern, _) in self.type_map.entries() {
+            if !used.contains(pattern) {
+                log::warn!("Type map entry '{}' matches no field", pattern);
+            }
+        }
+        Ok(self)
+    }
+
+    /// Register a hook that transforms each generated Rust file (`lib.rs`, the module file and
+    /// the integration tests) before it is written. Hooks run in registration order on the
+    /// rendered template output; colap does not run rustfmt itself, so formatting the written
+    /// files afterwards sees the post-processed code.
+    pub fn with_post_processor(mut self, processor: PostProcessor) -> Self {
+        self.post_processors.push(processor);
+        self
+    }
+
+    /// Render all output but create no directories and write no files. `written_files` still
+    /// lists every file with the size it would have, and rendering errors are reported as usual.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// The files written by `generate` in order, with their sizes in bytes. After a dry run these
+    /// are the files that would have been written.
+    pub fn written_files(&self) -> &[(PathBuf, usize)] {
+        &self.written_files
+    }
+
+    /// Set how a generated crate depends on colap, by default the path `../colap`
+    pub fn with_colap_dependency(mut self, dependency: ColapDependency) -> Self {
+        self.colap_dependency = dependency;
+        self
+    }
+
     /// Entry point – generate code based on the configured mode.
     pub fn generate(&mut self) -> Result<()> {
         match &self.mode {
@@ -80,6 +302,165 @@ impl CodeGenerator {
             GenerationMode::Crate { output_dir, crate_name } => {
                 self.generate_crate(output_dir.clone(), crate_name.clone())
             }
+            GenerationMode::Yaml { output_file } => self.generate_yaml(output_file.clone()),
+            GenerationMode::Toml { output_file } => self.generate_toml(output_file.clone()),
+        }
+    }
+
+    /// Write the model as YAML: entities become nested mappings, plural entities sequences of
+    /// single-key mappings named after their instances. Fields come first, sorted by name, then
+    /// child entities in document order, so the output is stable enough to diff.
+    fn generate_yaml(&mut self, output_file: PathBuf) -> Result<()> {
+        if let Some(parent) = output_file.parent() {
+            self.create_dir(parent)?;
+        }
+
+        let mut out = String::new();
+        self.write_yaml_entity(self.model.root_id(), 0, &mut out);
+        if out.is_empty() {
+            out.push_str("{}\n");
+        }
+        self.write_file(&output_file, out)
+    }
+
+    /// Write the fields and child entities of an entity as mapping entries at `indent` levels
+    fn write_yaml_entity(&self, id: usize, indent: usize, out: &mut String) {
+        let Some(node) = self.model.get_node(id) else {
+            return;
+        };
+        let node_b = node.borrow();
+        let ConfigNode::Entity(ent) = &*node_b else {
+            return;
+        };
+        let pad = "  ".repeat(indent);
+
+        let mut names: Vec<&String> = ent.fields.keys().collect();
+        names.sort();
+        for name in names {
+            out.push_str(&format!("{}{}: {}\n", pad, yaml_key(name), yaml_value(&ent.fields[name])));
+        }
+
+        for &child_id in &ent.children {
+            let Some(child) = self.model.get_node(child_id) else {
+                continue;
+            };
+            let ConfigNode::Entity(child_ent) = &*child.borrow() else {
+                continue;
+            };
+            match &child_ent.plural_name {
+                Some(plural) => {
+                    let instances: Vec<usize> = child_ent
+                        .children
+                        .iter()
+                        .copied()
+                        .filter(|&id| self.model.get_node(id).is_some_and(|n| n.borrow().is_entity()))
+                        .collect();
+                    if instances.is_empty() {
+                        out.push_str(&format!("{}{}: []\n", pad, yaml_key(plural)));
+                        continue;
+                    }
+                    out.push_str(&format!("{}{}:\n", pad, yaml_key(plural)));
+                    for instance_id in instances {
+                        let name = self.model.get_node(instance_id).map(|n| n.borrow().name().to_string());
+                        let name = yaml_key(&name.unwrap_or_default());
+                        self.write_yaml_child(&format!("{}  - {}", pad, name), instance_id, indent + 3, out);
+                    }
+                }
+                None => self.write_yaml_child(&format!("{}{}", pad, yaml_key(&child_ent.name)), child_id, indent + 1, out),
+            }
+        }
+    }
+
+    /// Write `key:` followed by the entity's entries, or `key: {}` for an empty entity
+    fn write_yaml_child(&self, key: &str, id: usize, indent: usize, out: &mut String) {
+        let mut body = String::new();
+        self.write_yaml_entity(id, indent, &mut body);
+        if body.is_empty() {
+            out.push_str(&format!("{}: {{}}\n", key));
+        } else {
+            out.push_str(&format!("{}:\n{}", key, body));
+        }
+    }
+
+    /// Write the model as TOML: entities become `[table]` headers named by their path, plural
+    /// entities arrays of tables (`[[llms]]`) whose elements hold one table named after the
+    /// instance. An entity's fields, sorted by name, precede its tables as TOML requires, and
+    /// child entities keep their document order. TOML has no null, so null fields are left out.
+    fn generate_toml(&mut self, output_file: PathBuf) -> Result<()> {
+        if let Some(parent) = output_file.parent() {
+            self.create_dir(parent)?;
+        }
+
+        let mut out = String::new();
+        self.write_toml_entity(self.model.root_id(), &[], &mut out);
+        let out = out.trim_start().to_string();
+        self.write_file(&output_file, out)
+    }
+
+    /// Write the fields of an entity, then its child entities as tables below the key path `path`
+    fn write_toml_entity(&self, id: usize, path: &[String], out: &mut String) {
+        let Some(node) = self.model.get_node(id) else {
+            return;
+        };
+        let node_b = node.borrow();
+        let ConfigNode::Entity(ent) = &*node_b else {
+            return;
+        };
+
+        let mut names: Vec<&String> = ent.fields.keys().collect();
+        names.sort();
+        for name in names {
+            if let Some(value) = toml_value(&ent.fields[name]) {
+                out.push_str(&format!("{} = {}\n", toml_key(name), value));
+            }
+        }
+
+        // A plural entity's instances; one without any is an empty array, which is a key/value
+        // line and must come before the tables too
+        let mut children: Vec<(usize, String, Option<Vec<usize>>)> = Vec::new();
+        for &child_id in &ent.children {
+            let Some(child) = self.model.get_node(child_id) else {
+                continue;
+            };
+            let ConfigNode::Entity(child_ent) = &*child.borrow() else {
+                continue;
+            };
+            match &child_ent.plural_name {
+                Some(plural) => {
+                    let instances: Vec<usize> = child_ent
+                        .children
+                        .iter()
+                        .copied()
+                        .filter(|&id| self.model.get_node(id).is_some_and(|n| n.borrow().is_entity()))
+                        .collect();
+                    if instances.is_empty() {
+                        out.push_str(&format!("{} = []\n", toml_key(plural)));
+                    } else {
+                        children.push((child_id, plural.clone(), Some(instances)));
+                    }
+                }
+                None => children.push((child_id, child_ent.name.clone(), None)),
+            }
+        }
+
+        for (child_id, name, instances) in children {
+            let mut child_path = path.to_vec();
+            child_path.push(name);
+            match instances {
+                Some(instances) => {
+                    for instance_id in instances {
+                        let name = self.model.get_node(instance_id).map(|n| n.borrow().name().to_string());
+                        let mut instance_path = child_path.clone();
+                        instance_path.push(name.unwrap_or_default());
+                        out.push_str(&format!("\n[[{}]]\n[{}]\n", toml_path(&child_path), toml_path(&instance_path)));
+                        self.write_toml_entity(instance_id, &instance_path, out);
+                    }
+                }
+                None => {
+                    out.push_str(&format!("\n[{}]\n", toml_path(&child_path)));
+                    self.write_toml_entity(child_id, &child_path, out);
+                }
+            }
         }
     }
 
@@ -87,19 +468,17 @@ impl CodeGenerator {
     fn generate_module(&mut self, output_file: PathBuf) -> Result<()> {
         // Create the output directory if it doesn't exist
         if let Some(parent) = output_file.parent() {
-            if !parent.exists() {
-                fs::create_dir_all(parent)?
-            }
+            self.create_dir(parent)?;
         }
 
         let mut out = String::new();
-        self.generate_code_content(&mut out)?;
+        self.generate_code_content(&mut out, false)?;
         
         // Add module-level tests
         self.generate_module_tests(&mut out)?;
 
         // Write the output to the file
-        fs::write(&output_file, out)?;
+        self.write_rust_file(&output_file, out)?;
         
         Ok(())
     }
@@ -107,35 +486,43 @@ impl CodeGenerator {
     /// Generate a complete library crate
     fn generate_crate(&mut self, output_dir: PathBuf, crate_name: String) -> Result<()> {
         // Create crate directory structure
-        fs::create_dir_all(output_dir.join("src"))?;
+        self.create_dir(&output_dir.join("src"))?;
         
         // Generate Cargo.toml
         self.generate_cargo_toml(&output_dir, &crate_name)?;
         
         // Generate src/lib.rs
         let mut lib_content = String::new();
-        self.generate_code_content(&mut lib_content)?;
-        fs::write(output_dir.join("src").join("lib.rs"), lib_content)?;
+        self.generate_code_content(&mut lib_content, true)?;
+        self.write_rust_file(&output_dir.join("src").join("lib.rs"), lib_content)?;
         
         // Generate tests in tests/ directory
-        fs::create_dir_all(output_dir.join("tests"))?;
+        self.create_dir(&output_dir.join("tests"))?;
         self.generate_crate_tests(&output_dir)?;
         
         // Generate README.md
         self.generate_readme(&output_dir, &crate_name)?;
+
+        if self.options.emit_schema_doc {
+            self.generate_schema_doc(&output_dir)?;
+        }
         
         Ok(())
     }
 
-    /// Generate the core code content (structs and implementations)
-    fn generate_code_content(&mut self, out: &mut String) -> Result<()> {
+    /// Generate the core code content (structs and implementations), with the helpers that need
+    /// the dependencies of a generated crate when `is_crate` is set
+    fn generate_code_content(&mut self, out: &mut String, is_crate: bool) -> Result<()> {
         // Determine if we need HashMap
         let uses_hashmap = true; // In the future, we could analyze the model to determine this
         
         // Create the template data for file header
         let header_data = json!({
             "include_imports": true,
-            "uses_hashmap": uses_hashmap
+            "uses_hashmap": uses_hashmap,
+            "schema_hash": self.model.fingerprint(),
+            "paths": self.config_paths(),
+            "type_imports": self.type_map.imports()
         });
         
         // Render the file header
@@ -157,31 +544,93 @@ impl CodeGenerator {
 
         // Generate all entity definitions recursively
         self.emit_all_entities(self.model.root_id(), &struct_names, out);
-        
+
+        // Generate the error type and fallible loaders
+        let error_content = self
+            .handlebars
+            .render("config_error", &json!({
+                "builder_options": self.builder_options(),
+                "parsed_types": self.uses_parsed_types(),
+                "flattened": self.uses_flattening(),
+                "is_crate": is_crate
+            }))?;
+        out.push_str(&error_content);
+
+        // Generate the element type of mixed arrays
+        if self.options.heterogeneous_arrays && self.has_mixed_arrays() {
+            let scalar_content = self
+                .handlebars
+                .render("config_scalar", &json!({ "derive_serde": self.options.derive_serde }))?;
+            out.push_str(&scalar_content);
+        }
+
+        // Generate the visitor trait walked by every struct's `accept`
+        let visitor_content = self.handlebars.render("visitor", &json!({}))?;
+        out.push_str(&visitor_content);
+
+        // Generate the line helpers of the `Display` trees
+        if self.options.impl_display {
+            let display_content = self.handlebars.render("display_tree", &json!({}))?;
+            out.push_str(&display_content);
+        }
+
+        // Generate the dynamic handle for path-style indexing
+        if self.options.emit_index {
+            let handle_content = self.handlebars.render("config_handle", &json!({ "derive_partialeq": self.options.derive_partialeq }))?;
+            out.push_str(&handle_content);
+        }
+
+        // Generate command line overrides for the scalar fields
+        if self.options.emit_clap {
+            let mut flags = Vec::new();
+            self.collect_cli_flags(self.model.root_id(), "", "", &mut flags);
+            let args_content = self.handlebars.render("clap_args", &json!({ "flags": flags }))?;
+            out.push_str(&args_content);
+        }
+
+        if self.options.verify_syntax {
+            syn::parse_file(out).map_err(|e| {
+                let line = e.span().start().line;
+                anyhow::anyhow!(
+                    "Generated code is not valid Rust: {} at line {}: {}",
+                    e,
+                    line,
+                    out.lines().nth(line.saturating_sub(1)).unwrap_or_default().trim()
+                )
+            })?;
+        }
+
         Ok(())
     }
 
     /// Generate module-level tests (inline with the module)
     fn generate_module_tests(&self, out: &mut String) -> Result<()> {
-        // Create a list of plural entity types for assertions
-        let mut plural_entity_types = Vec::new();
-        let mut plural_entity_assertions = Vec::new();
-        
-        // Add basic placeholders for entities to test
-        // In a real implementation, we would gather these from the model
-        plural_entity_types.push("Llms".to_string());
-        plural_entity_assertions.push(json!({
-            "plural": "llms",
-            "singular": "llm"
-        }));
-        
         // Prepare the template data
         let test_data = json!({
+            "keep_extras": self.options.keep_extras,
             "crate_name": "", // Empty for modules as they use relative paths
             "is_crate": false,
             "test_file_path": self.relative_source_path(),
-            "plural_entity_types": plural_entity_types,
-            "plural_entity_assertions": plural_entity_assertions
+            // Without a source file to include on its own, the configuration is embedded
+            "config_content": (!self.source_is_file() || self.source_includes_files())
+                .then(|| format!("{:?}", self.source_cola())),
+            "plural_entity_assertions": self.root_plural_assertions(),
+            "clap_override": self.clap_override_sample(),
+            "root_has_content": self.root_has_content(),
+            "index_sample": self.index_sample(),
+            "range_sample": self.range_sample(),
+            "builder_sample": self.builder_sample(),
+            "plural_sample": self.plural_sample(),
+            "from_path_sample": self.path_constructor_sample(),
+            "missing_required_sample": self.missing_required_sample(),
+            "cow_getter_sample": self.cow_getter_sample(),
+            "sample_path": self.config_paths().first().map(|path| path["path"].clone()),
+            "builder_api": self.options.builder_api,
+            "derive_builder": self.options.derive_builder,
+            "derive_partialeq": self.options.derive_partialeq,
+            "impl_display": self.options.impl_display,
+            "builder_options": self.builder_options(),
+            "only_path": self.only_path
         });
         
         // Render the test template
@@ -197,7 +646,7 @@ impl CodeGenerator {
             if !line.trim().is_empty() {
                 out.push_str("    ");
                 out.push_str(line);
-                out.push_str("\n");
+                out.push('\n');
             }
         }
         
@@ -206,33 +655,45 @@ impl CodeGenerator {
     }
 
     /// Generate Cargo.toml for the crate
-    fn generate_cargo_toml(&self, output_dir: &PathBuf, crate_name: &str) -> Result<()> {
-        // Figure out the relative path to colap crate from the output directory
-        // This is a simplified approach; in a real-world scenario, you might need a more robust solution
-        let colap_path = "../colap".to_string();
+    fn generate_cargo_toml(&mut self, output_dir: &Path, crate_name: &str) -> Result<()> {
+        let (colap_path, colap_version) = match &self.colap_dependency {
+            ColapDependency::Path(path) => (Some(path), None),
+            ColapDependency::Version(version) => (None, Some(version)),
+        };
         
         // Create the template data
         let cargo_data = json!({
             "crate_name": crate_name,
-            "colap_path": colap_path,
+            // Quoted as TOML basic strings, whose escapes are those of JSON
+            "colap_path": colap_path.map(|path| serde_json::to_string(path).unwrap_or_default()),
+            "colap_version": colap_version.map(|version| serde_json::to_string(version).unwrap_or_default()),
+            "emit_clap": self.options.emit_clap,
+            "derive_serde": self.options.derive_serde,
+            "derive_builder": self.options.derive_builder,
+            "extra_dependencies": self
+                .type_map
+                .dependencies()
+                .iter()
+                .map(|(name, value)| json!({ "name": name, "value": value }))
+                .collect::<Vec<_>>(),
         });
         
         // Render the Cargo.toml using the Handlebars template
         let cargo_content = self.handlebars.render("cargo_toml", &cargo_data)?;
         
         // Write the Cargo.toml file
-        fs::write(output_dir.join("Cargo.toml"), cargo_content)?;
+        self.write_file(&output_dir.join("Cargo.toml"), cargo_content)?;
         
         log::info!("Generated Cargo.toml for {}", crate_name);
         Ok(())
     }
 
     /// Generate integration tests for the crate
-    fn generate_crate_tests(&self, output_dir: &PathBuf) -> Result<()> {
+    fn generate_crate_tests(&mut self, output_dir: &Path) -> Result<()> {
         let tests_dir = output_dir.join("tests");
         
         // Create tests directory if it doesn't exist
-        fs::create_dir_all(&tests_dir)?;
+        self.create_dir(&tests_dir)?;
         
         // Create tests/data directory and copy input configuration file
         self.copy_config_to_tests_data(output_dir)?;
@@ -243,39 +704,45 @@ impl CodeGenerator {
         // Create a sanitized crate name for Rust imports (replace hyphens with underscores)
         let sanitized_crate_name = crate_name.replace('-', "_");
         
-        // Generate a list of plural entity types for assertions
-        let mut plural_entity_types = Vec::new();
-        let mut plural_entity_assertions = Vec::new();
-        
-        // [This would be replaced with actual code to gather plural entities]
-        // For now we're just adding basic placeholders
-        plural_entity_types.push("Llms".to_string());
-        plural_entity_assertions.push(json!({
-            "plural": "llms",
-            "singular": "llm"
-        }));
-        
         // Use the Handlebars template for integration tests
         let test_data = json!({
+            "keep_extras": self.options.keep_extras,
+            "derive_serde": self.options.derive_serde,
+            "derive_partialeq": self.options.derive_partialeq,
+            "impl_display": self.options.impl_display,
+            "builder_options": self.builder_options(),
             "crate_name": crate_name,
             "sanitized_crate_name": sanitized_crate_name,
             "is_crate": true,
             "test_file_path": "tests/data/config.md",
-            "plural_entity_types": plural_entity_types,
-            "plural_entity_assertions": plural_entity_assertions
+            "plural_entity_assertions": self.root_plural_assertions(),
+            "clap_override": self.clap_override_sample(),
+            "root_has_content": self.root_has_content(),
+            "index_sample": self.index_sample(),
+            "range_sample": self.range_sample(),
+            "builder_sample": self.builder_sample(),
+            "plural_sample": self.plural_sample(),
+            "from_path_sample": self.path_constructor_sample(),
+            "missing_required_sample": self.missing_required_sample(),
+            "cow_getter_sample": self.cow_getter_sample(),
+            "serde_default_sample": self.serde_default_sample(),
+            "sample_path": self.config_paths().first().map(|path| path["path"].clone()),
+            "builder_api": self.options.builder_api,
+            "derive_builder": self.options.derive_builder,
+            "only_path": self.only_path
         });
         
         let test_content = self.handlebars.render("integration_test", &test_data)?;
-        fs::write(tests_dir.join("integration.rs"), test_content)?;
+        self.write_rust_file(&tests_dir.join("integration.rs"), test_content)?;
         
         Ok(())
     }
     
     /// Copy the input configuration file to the tests/data directory
-    fn copy_config_to_tests_data(&self, output_dir: &PathBuf) -> Result<()> {
+    fn copy_config_to_tests_data(&mut self, output_dir: &Path) -> Result<()> {
         // Create tests/data directory
         let tests_data_dir = output_dir.join("tests").join("data");
-        fs::create_dir_all(&tests_data_dir)?;
+        self.create_dir(&tests_data_dir)?;
         
         // Get the source filename without path
         let source_filename = self.source_path
@@ -284,8 +751,18 @@ impl CodeGenerator {
             .to_string_lossy()
             .to_string();
         
+        // Input read from standard input has no file to copy, and a file including others
+        // cannot be read without them, so the tests read the model written back as cola
+        if !self.source_is_file() || self.source_includes_files() {
+            let content = self.source_cola();
+            self.write_file(&tests_data_dir.join("config.md"), content)?;
+            log::info!("Wrote the configuration model to {}", tests_data_dir.join("config.md").display());
+            return Ok(());
+        }
+
         // Copy the input file to tests/data/config.md
-        fs::copy(&self.source_path, tests_data_dir.join("config.md"))?;
+        let content = fs::read(&self.source_path)?;
+        self.write_file(&tests_data_dir.join("config.md"), content)?;
         
         log::info!("Copied {} to {}", source_filename, tests_data_dir.join("config.md").display());
         
@@ -293,7 +770,7 @@ impl CodeGenerator {
     }
 
     /// Generate README.md for the crate
-    fn generate_readme(&self, output_dir: &PathBuf, crate_name: &str) -> Result<()> {
+    fn generate_readme(&mut self, output_dir: &Path, crate_name: &str) -> Result<()> {
         // Extract the config filename from the source path
         let config_filename = self.source_path
             .file_name()
@@ -316,17 +793,105 @@ impl CodeGenerator {
         let readme_content = self.handlebars.render("readme", &readme_data)?;
         
         // Write the README file
-        fs::write(output_dir.join("README.md"), readme_content)?;
+        self.write_file(&output_dir.join("README.md"), readme_content)?;
         
         log::info!("Generated README.md for {}", crate_name);
         Ok(())
     }
 
+    /// Generate SCHEMA.md with a table of fields, types and defaults for every entity path, in
+    /// document order
+    fn generate_schema_doc(&mut self, output_dir: &Path) -> Result<()> {
+        let config_filename = self.source_path
+            .file_name()
+            .unwrap_or_default()
+            .to_string_lossy()
+            .to_string();
+
+        let mut entities = Vec::new();
+        let root_id = self.model.root_id();
+        for id in std::iter::once(root_id).chain(self.model.iter_entities().map(|(id, _, _)| id)) {
+            let Some(node) = self.model.get_node(id) else {
+                continue;
+            };
+            let node_b = node.borrow();
+            let ConfigNode::Entity(ent) = &*node_b else {
+                continue;
+            };
+            // The root only has fields of its own when generating a subtree
+            if id == root_id && ent.fields.is_empty() {
+                continue;
+            }
+
+            let mut names: Vec<&String> = ent.fields.keys().collect();
+            names.sort();
+            let fields: Vec<serde_json::Value> = names
+                .into_iter()
+                .map(|name| {
+                    let value = &ent.fields[name];
+                    let rust_type = self.field_type(id, ent, name, value);
+                    let optional = matches!(value, ConfigValue::Null) || self.model.is_field_optional(id, name);
+                    json!({
+                        "name": name,
+                        "type": if optional { format!("Option<{}>", rust_type) } else { rust_type },
+                        // A pipe would end the table cell early
+                        "default": value.to_string().replace('|', "\\|")
+                    })
+                })
+                .collect();
+
+            entities.push(json!({
+                "path": self.model.get_entity_path(id).filter(|path| !path.is_empty()).unwrap_or_else(|| "root".to_string()),
+                "plural": ent.plural_name,
+                "fields": fields
+            }));
+        }
+
+        let schema_data = json!({
+            "config_filename": config_filename,
+            "entities": entities
+        });
+        let schema_content = self.handlebars.render("schema_doc", &schema_data)?;
+        self.write_file(&output_dir.join("SCHEMA.md"), schema_content)?;
+
+        log::info!("Generated SCHEMA.md in {}", output_dir.display());
+        Ok(())
+    }
+
+    /// Write generated Rust source after running it through the registered post-processors
+    fn write_rust_file(&mut self, path: &Path, content: String) -> Result<()> {
+        let content = self
+            .post_processors
+            .iter()
+            .fold(content, |content, processor| processor(&content));
+        self.write_file(path, content)
+    }
+
+    /// Create a directory and its parents, unless this is a dry run
+    fn create_dir(&self, path: &Path) -> Result<()> {
+        if !self.dry_run {
+            fs::create_dir_all(path)?;
+        }
+        Ok(())
+    }
+
+    /// Write a file and record it in `written_files`; a dry run only records it
+    fn write_file(&mut self, path: &Path, content: impl AsRef<[u8]>) -> Result<()> {
+        let content = content.as_ref();
+        if !self.dry_run {
+            fs::write(path, content)?;
+        }
+        self.written_files.push((path.to_path_buf(), content.len()));
+        Ok(())
+    }
+
     /// Get the crate name from the generation mode
     fn get_crate_name(&self) -> String {
         match &self.mode {
             GenerationMode::Crate { crate_name, .. } => crate_name.clone(),
-            GenerationMode::Module { .. } => "config".to_string(),
+            GenerationMode::Module { .. } | GenerationMode::Yaml { .. } | GenerationMode::Toml { .. } => {
+                "config".to_string()
+            }
         }
     }
 
@@ -357,7 +922,15 @@ impl CodeGenerator {
             let node_b = node.borrow();
             if let ConfigNode::Entity(ent) = &*node_b {
                 // Check if this entity has a plural name
-                if let Some(_plural_name) = &ent.plural_name {
+                if ent.plural_name.is_some() && ent.is_enum {
+                    // Each variant of an enum gets a struct from its first instance
+                    for (value, instance_id) in self.enum_variants(ent) {
+                        let variant_struct_name = self.struct_name(&value);
+                        if !self.emitted_structs.contains(&variant_struct_name) {
+                            self.emit_singular_struct(instance_id, &variant_struct_name, struct_names, out);
+                        }
+                    }
+                } else if let Some(_plural_name) = &ent.plural_name {
                     // This is a plural entity - get first child to generate singular entity struct
                     if !ent.children.is_empty() {
                         let first_child_id = ent.children[0];
@@ -367,7 +940,7 @@ impl CodeGenerator {
                             let first_child_b = first_child.borrow();
                             if let ConfigNode::Entity(_child_ent) = &*first_child_b {
                                 // Generate the singular struct from this child
-                                let singular_struct_name = self.struct_name(&ent.name);
+                                let singular_struct_name = self.singular_struct_name(ent);
                                 if !self.emitted_structs.contains(&singular_struct_name) {
                                     self.emit_singular_struct(first_child_id, &singular_struct_name, struct_names, out);
                                 }
@@ -398,32 +971,48 @@ impl CodeGenerator {
                 let mut getters = Vec::new();
                 let mut field_initializers = Vec::new();
                 
-                // Process primitive fields
-                for (field_name, field_value) in &ent.fields {
+                // Process primitive fields, by name so the generated code is the same every run
+                let mut primitive_fields: Vec<_> = ent.fields.iter().collect();
+                primitive_fields.sort_by(|a, b| a.0.cmp(b.0));
+                for (field_name, field_value) in primitive_fields {
                     let field_name_snake = self.field_name(field_name);
                     let orig_field_name = field_name.clone();
                     
                     // Determine the Rust type for this field
-                    let rust_type = match field_value {
-                        ConfigValue::Integer(_) => "i64".to_string(),
-                        ConfigValue::Float(_) => "f64".to_string(),
-                        ConfigValue::Boolean(_) => "bool".to_string(),
-                        ConfigValue::String(_) => "String".to_string(),
-                    };
+                    let rust_type = self.field_type(node_id, ent, field_name, field_value);
                     
+                    let is_array = matches!(field_value, ConfigValue::Array(_));
+                    let (element_type, element_variant) = self.array_element(field_value);
+                    let is_null = matches!(field_value, ConfigValue::Null);
+                    let is_optional = is_null || self.is_instance_field_optional(node_id, ent, field_name);
+                    let required_check = if self.is_instance_field_required(ent, field_name) {
+                        self.required_check(&field_name_snake, &rust_type, is_optional, is_array)
+                    } else {
+                        None
+                    };
+
                     // Add field to struct
                     fields.push(json!({
                         "name": field_name_snake,
+                        "rename": self.serde_rename(&field_name_snake, field_name),
                         "type": rust_type,
-                        "is_optional": false
+                        "doc": Self::doc_lines(self.model.get_field_doc(node_id, field_name)),
+                        "is_optional": is_optional,
+                        "config_eq": self.config_eq_term(&field_name_snake, &rust_type, is_optional, false)
                     }));
                     
-                    // Add getter
+                    // Add getter; strings are borrowed as `&str`, scalars returned by value
                     getters.push(json!({
                         "name": field_name_snake,
                         "return_type": rust_type,
-                        "is_reference": false,
-                        "is_option": false,
+                        "config_path": self.config_path(node_id, field_name),
+                        "is_reference": !Self::is_copy_type(&rust_type),
+                        "is_str": rust_type == "String",
+                        "is_option": is_optional,
+                        "is_array": is_array,
+                        "element_type": element_type,
+                        "cow_type": self.cow_type(&rust_type, is_array, element_type),
+                        "cow_deref": rust_type == "String" || is_array,
                         "is_primitive": true
                     }));
                     
@@ -433,7 +1022,15 @@ impl CodeGenerator {
                         "type": rust_type,
                         "original_name": orig_field_name,
                         "is_entity": false,
-                        "is_api": false
+                        "is_api": false,
+                        "is_optional": is_optional,
+                        "is_null": is_null,
+                        "is_array": is_array,
+                        "is_narrow_int": Self::is_narrow_integer(&rust_type),
+                        "is_parsed": self.is_parsed_type(&rust_type),
+                        "element_type": element_type,
+                        "element_variant": element_variant,
+                        "required_check": required_check
                     }));
                 }
                 
@@ -442,29 +1039,32 @@ impl CodeGenerator {
                     if let Some(child) = self.model.get_node(child_id) {
                         let child_b = child.borrow();
                         if let ConfigNode::Entity(child_ent) = &*child_b {
-                            let (field_name, field_type) = if let Some(plural) = &child_ent.plural_name {
-                                // If plural, use plural name for field and plural type
-                                (self.field_name(plural), self.struct_name(plural))
-                            } else {
-                                (self.field_name(&child_ent.name), self.struct_name(&child_ent.name))
-                            };
+                            let (field_name, field_type, is_plural, instance_name) = self.child_field(child_ent);
                             
                             let original_name = child_ent.name.clone();
                             let is_api = field_type == "Api";
                             
-                            // Add field to struct (Api fields are optional)
+                            // Add field to struct; entity fields are always present
+                            let serde_name = match &child_ent.plural_name {
+                                Some(plural) if is_plural => plural,
+                                _ => &child_ent.name,
+                            };
                             fields.push(json!({
                                 "name": field_name,
+                                "rename": self.serde_rename(&field_name, serde_name),
                                 "type": field_type,
-                                "is_optional": is_api
+                                "doc": Self::doc_lines(child_ent.doc.clone()),
+                                "is_optional": false,
+                                "config_eq": self.config_eq_term(&field_name, &field_type, false, true)
                             }));
                             
                             // Add getter
                             getters.push(json!({
                                 "name": field_name,
                                 "return_type": field_type,
-                                "is_reference": !is_api && !["i64", "f64", "bool", "String"].contains(&field_type.as_str()),
-                                "is_option": is_api,
+                                "config_path": self.config_path(node_id, &child_ent.name),
+                                "is_reference": true,
+                                "is_option": false,
                                 "is_primitive": false
                             }));
                             
@@ -474,18 +1074,37 @@ impl CodeGenerator {
                                 "type": field_type,
                                 "original_name": original_name,
                                 "is_entity": true,
-                                "is_api": is_api
+                                "is_api": is_api,
+                                "is_plural": is_plural,
+                                "plural_name": child_ent.plural_name,
+                                "instance_name": instance_name
                             }));
                         }
                     }
                 }
                 
+                // The last child entity closes its parent's `Display` tree
+                if let Some(last) = field_initializers.iter_mut().rev().find(|f| f["is_entity"] == true) {
+                    last["tree_last"] = json!(true);
+                }
+
                 // Prepare template data
+                let known_fields: Vec<&String> = ent.fields.keys().collect();
                 let template_data = json!({
                     "struct_name": struct_name,
+                    "doc": Self::doc_lines(ent.parent.and_then(|parent_id| self.model.get_entity_doc(parent_id))),
                     "fields": fields,
                     "getters": getters,
-                    "field_initializers": field_initializers
+                    "validates": field_initializers.iter().any(|f| f["is_entity"] == true || !f["required_check"].is_null()),
+                    "tree_fields": !ent.fields.is_empty() || self.options.keep_extras,
+                    "tree_children": field_initializers.iter().any(|f| f["is_entity"] == true),
+                    "field_initializers": field_initializers,
+                    "keep_extras": self.options.keep_extras,
+                    "derive_serde": self.options.derive_serde,
+                    "derive_partialeq": self.options.derive_partialeq,
+                    "impl_display": self.options.impl_display,
+                    "derive_builder": self.options.derive_builder,
+                    "known_fields": known_fields
                 });
                 
                 // Render the template
@@ -497,6 +1116,87 @@ impl CodeGenerator {
         }
     }
     
+    /// A field of a plural instance is optional when the instance declares it with `?` or
+    /// some other instance of the same plural entity does not have it or sets it to `null`
+    fn is_instance_field_optional(&self, node_id: usize, ent: &EntityNode, field_name: &str) -> bool {
+        if ent.optional_fields.contains(field_name) {
+            return true;
+        }
+        let Some(parent) = ent.parent.and_then(|parent_id| self.model.get_node(parent_id)) else {
+            return false;
+        };
+        let ConfigNode::Entity(parent_ent) = &*parent.borrow() else {
+            return false;
+        };
+        parent_ent.children.iter().filter(|&&id| id != node_id).any(|&sibling_id| {
+            self.model.get_node(sibling_id).is_some_and(|sibling| match &*sibling.borrow() {
+                ConfigNode::Entity(sibling_ent) => {
+                    Self::shares_struct(parent_ent, ent, sibling_ent)
+                        && matches!(sibling_ent.fields.get(field_name), None | Some(ConfigValue::Null))
+                }
+                ConfigNode::Field(_) => false,
+            })
+        })
+    }
+
+    /// Whether two instances of a plural entity share a generated struct: always for a
+    /// collection, and for an enum only when they name the same variant
+    fn shares_struct(parent: &EntityNode, ent: &EntityNode, sibling: &EntityNode) -> bool {
+        let variant = |instance: &EntityNode| match instance.fields.get(ENUM_DISCRIMINATOR) {
+            Some(ConfigValue::String(value)) => Some(value.clone()),
+            _ => None,
+        };
+        !parent.is_enum || variant(ent) == variant(sibling)
+    }
+
+    /// A field of a plural instance is required when any instance of the plural entity declares
+    /// it with `!`, since they share the generated struct
+    fn is_instance_field_required(&self, ent: &EntityNode, field_name: &str) -> bool {
+        if ent.required_fields.contains(field_name) {
+            return true;
+        }
+        let Some(parent) = ent.parent.and_then(|parent_id| self.model.get_node(parent_id)) else {
+            return false;
+        };
+        let ConfigNode::Entity(parent_ent) = &*parent.borrow() else {
+            return false;
+        };
+        parent_ent.children.iter().any(|&sibling_id| {
+            self.model.get_node(sibling_id).is_some_and(|sibling| match &*sibling.borrow() {
+                ConfigNode::Entity(sibling_ent) => {
+                    Self::shares_struct(parent_ent, ent, sibling_ent) && sibling_ent.required_fields.contains(field_name)
+                }
+                ConfigNode::Field(_) => false,
+            })
+        })
+    }
+
+    /// Fields declared required on any `api` entity, which all share the generated `Api` struct
+    fn api_required_fields(&self) -> HashSet<String> {
+        self.model
+            .node_ids()
+            .into_iter()
+            .filter_map(|id| self.model.get_node(id))
+            .flat_map(|node| match &*node.borrow() {
+                ConfigNode::Entity(ent) if self.struct_name(&ent.name) == "Api" => {
+                    ent.required_fields.iter().cloned().collect()
+                }
+                _ => Vec::new(),
+            })
+            .collect()
+    }
+
+    /// The condition under which a required field counts as empty in `validate`, or `None`
+    /// for a plain scalar that always holds a value
+    fn required_check(&self, name: &str, rust_type: &str, is_optional: bool, is_array: bool) -> Option<String> {
+        match (is_optional, is_array || rust_type == "String") {
+            (true, true) => Some(format!("self.{}.as_ref().is_none_or(|value| value.is_empty())", name)),
+            (true, false) => Some(format!("self.{}.is_none()", name)),
+            (false, true) => Some(format!("self.{}.is_empty()", name)),
+            (false, false) => None,
+        }
+    }
+
     /// Emit all entity structs recursively
     fn emit_all_entities(&mut self, node_id: usize, struct_names: &HashMap<usize, String>, out: &mut String) {
         // Skip generating structs for instances of plural entities
@@ -553,24 +1253,59 @@ impl CodeGenerator {
                     // 1. A singular struct (e.g., Llm) for the entity type
                     // 2. A collection wrapper struct (e.g., Llms) with a map field
                     if let Some(plural_name) = &ent.plural_name {
+                        // A flattened entity is typed as its instance, which needs no wrapper
+                        if self.flattens(ent) {
+                            return;
+                        }
+
                         // Generate the collection wrapper struct
                         let collection_struct_name = self.struct_name(plural_name);
-                        let singular_struct_name = self.struct_name(&ent.name);
+                        let singular_struct_name = self.singular_struct_name(ent);
                         
                         // Skip if we already emitted this wrapper struct
                         if self.emitted_structs.contains(&collection_struct_name) {
                             return;
                         }
                         self.emitted_structs.insert(collection_struct_name.clone());
-                        
-                        // Prepare the template data
-                        let template_data = json!({
-                            "struct_name": collection_struct_name,
-                            "singular_struct_name": singular_struct_name
-                        });
+
+                        // Prepare the template data; an enum lists its variants instead of
+                        // an entry struct
+                        let (template, template_data) = if ent.is_enum {
+                            let variants = self.enum_variants(ent);
+                            let variant_list: Vec<&str> = variants.iter().map(|(value, _)| value.as_str()).collect();
+                            let variants: Vec<serde_json::Value> = variants
+                                .iter()
+                                .map(|(value, _)| json!({ "name": self.struct_name(value), "value": format!("{:?}", value) }))
+                                .collect();
+                            ("enum_struct", json!({
+                                "struct_name": collection_struct_name,
+                                "entity_name": ent.name,
+                                "discriminator": ENUM_DISCRIMINATOR,
+                                "first_variant": variants.first().map(|variant| variant["name"].clone()),
+                                "variant_count": variants.len(),
+                                "variant_list": variant_list.join(" | ").escape_default().to_string(),
+                                "variants": variants,
+                                "derive_serde": self.options.derive_serde,
+                                "derive_partialeq": self.options.derive_partialeq,
+                                "impl_display": self.options.impl_display
+                            }))
+                        } else {
+                            let fixed_keys = self.fixed_array_keys(plural_name);
+                            let template = if fixed_keys.is_some() { "fixed_plural_struct" } else { "plural_struct" };
+                            (template, json!({
+                                "struct_name": collection_struct_name,
+                                "singular_struct_name": singular_struct_name,
+                                "key_field": ent.key_field,
+                                "derive_serde": self.options.derive_serde,
+                                "derive_partialeq": self.options.derive_partialeq,
+                                "impl_display": self.options.impl_display,
+                                "entry_count": fixed_keys.as_ref().map(Vec::len),
+                                "keys": fixed_keys.iter().flatten().map(|key| format!("{:?}", key)).collect::<Vec<_>>()
+                            }))
+                        };
                         
                         // Render the template
-                        let struct_content = self.handlebars.render("plural_struct", &template_data)
+                        let struct_content = self.handlebars.render(template, &template_data)
                             .expect("Failed to render plural_struct template");
                         
                         // Add indentation if needed
@@ -579,7 +1314,7 @@ impl CodeGenerator {
                             for line in struct_content.lines() {
                                 out.push_str(&indent);
                                 out.push_str(line);
-                                out.push_str("\n");
+                                out.push('\n');
                             }
                         } else {
                             out.push_str(&struct_content);
@@ -598,7 +1333,17 @@ impl CodeGenerator {
                     // Special case for Api struct - use dedicated template
                     if struct_name == "Api" {
                         // Use the api_struct template
-                        let template_data = json!({});
+                        let required = self.api_required_fields();
+                        let template_data = json!({
+                            "derive_serde": self.options.derive_serde,
+                            "derive_partialeq": self.options.derive_partialeq,
+                            "impl_display": self.options.impl_display,
+                            "derive_builder": self.options.derive_builder,
+                            "cow_getters": self.options.cow_getters,
+                            "required_key": required.contains("key"),
+                            "required_base_url": required.contains("base_url"),
+                            "required_type": required.contains("type")
+                        });
                         
                         // Render the template
                         let struct_content = self.handlebars.render("api_struct", &template_data)
@@ -610,7 +1355,7 @@ impl CodeGenerator {
                             for line in struct_content.lines() {
                                 out.push_str(&indent);
                                 out.push_str(line);
-                                out.push_str("\n");
+                                out.push('\n');
                             }
                         } else {
                             out.push_str(&struct_content);
@@ -624,24 +1369,51 @@ impl CodeGenerator {
                     // Collect field information for the template
                     let mut fields = Vec::new();
                     
-                    // Process primitive fields from the entity's fields map
-                    for (field_name, field_value) in &ent.fields {
+                    // Process primitive fields from the entity's fields map, by name
+                    let mut primitive_fields: Vec<_> = ent.fields.iter().collect();
+                    primitive_fields.sort_by(|a, b| a.0.cmp(b.0));
+                    for (field_name, field_value) in primitive_fields {
                         let field_name_snake = self.field_name(field_name);
                         let original_name = field_name.clone();
                         
                         // Determine the Rust type for this field
-                        let rust_type = match field_value {
-                            ConfigValue::Integer(_) => "i64".to_string(),
-                            ConfigValue::Float(_) => "f64".to_string(),
-                            ConfigValue::Boolean(_) => "bool".to_string(),
-                            ConfigValue::String(_) => "String".to_string(),
-                        };
+                        let rust_type = self.field_type(node_id, ent, field_name, field_value);
                         
+                        let is_array = matches!(field_value, ConfigValue::Array(_));
+                        let is_null = matches!(field_value, ConfigValue::Null);
+                        let is_optional = is_null || self.model.is_field_optional(node_id, field_name);
+                        let (element_type, element_variant) = self.array_element(field_value);
+                        let required_check = if self.model.is_field_required(node_id, field_name) {
+                            self.required_check(&field_name_snake, &rust_type, is_optional, is_array)
+                        } else {
+                            None
+                        };
+                        let default_expr = if self.serde_defaults_enabled() && !is_optional && !self.model.is_field_declared(node_id, field_name) {
+                            Self::default_expr(&rust_type, field_value)
+                        } else {
+                            None
+                        };
+
                         fields.push(json!({
                             "name": field_name_snake,
+                            "rename": self.serde_rename(&field_name_snake, field_name),
                             "type": rust_type,
+                            "doc": Self::doc_lines(self.model.get_field_doc(node_id, field_name)),
+                            "config_path": self.config_path(node_id, field_name),
                             "original_name": original_name,
-                            "optional": false
+                            "required_check": required_check,
+                            "is_optional": is_optional,
+                            "is_null": is_null,
+                            "is_array": is_array,
+                            "is_narrow_int": Self::is_narrow_integer(&rust_type),
+                            "is_parsed": self.is_parsed_type(&rust_type),
+                            "is_copy": Self::is_copy_type(&rust_type),
+                            "cow_type": self.cow_type(&rust_type, is_array, element_type),
+                            "cow_deref": rust_type == "String" || is_array,
+                            "config_eq": self.config_eq_term(&field_name_snake, &rust_type, is_optional, false),
+                            "default_expr": default_expr,
+                            "element_type": element_type,
+                            "element_variant": element_variant
                         }));
                     }
                     
@@ -650,39 +1422,73 @@ impl CodeGenerator {
                         if let Some(child_node) = self.model.get_node(child_id) {
                             let child_node_b = child_node.borrow();
                             if let ConfigNode::Entity(child_ent) = &*child_node_b {
-                                let (field_name, field_type, is_plural) = if let Some(plural) = &child_ent.plural_name {
-                                    // If plural, use plural name for field and plural type
-                                    (self.field_name(plural), self.struct_name(plural), true)
-                                } else {
-                                    (self.field_name(&child_ent.name), self.struct_name(&child_ent.name), false)
-                                };
+                                let (field_name, field_type, is_plural, instance_name) = self.child_field(child_ent);
                                 
-                                let original_name = self.to_original_case(&field_name);
+                                // Looked up by the exact name in the model, which the Rust field
+                                // name may no longer match (`open-ai` becomes `open_ai`)
+                                let original_name = child_ent.name.clone();
                                 let is_api = field_type == "Api";
+                                let serde_name = match &child_ent.plural_name {
+                                    Some(plural) if is_plural => plural,
+                                    _ => &child_ent.name,
+                                };
                                 
                                 fields.push(json!({
                                     "name": field_name,
+                                    "rename": self.serde_rename(&field_name, serde_name),
                                     "type": field_type,
+                                    "doc": Self::doc_lines(child_ent.doc.clone()),
+                                    "config_path": self.config_path(node_id, &child_ent.name),
                                     "original_name": original_name,
-                                    "optional": !is_api,
+                                    "entity_name": child_ent.name,
+                                    "plural_name": child_ent.plural_name,
+                                    "config_eq": self.config_eq_term(&field_name, &field_type, false, true),
                                     "is_entity": true,
                                     "is_api": is_api,
-                                    "is_plural": is_plural
+                                    "is_plural": is_plural,
+                                    "instance_name": instance_name
                                 }));
                             }
                         }
                     }
                     
+                    // The last child entity closes its parent's `Display` tree
+                    if let Some(last) = fields.iter_mut().rev().find(|f| f["is_entity"] == true) {
+                        last["tree_last"] = json!(true);
+                    }
+
                     // Prepare the template data
+                    let known_fields: Vec<&String> = ent.fields.keys().collect();
                     let template_data = json!({
                         "struct_name": struct_name,
+                        "doc": Self::doc_lines(ent.doc.clone()),
+                        "validates": fields.iter().any(|f| f["is_entity"] == true || !f["required_check"].is_null()),
+                        "serde_defaults": fields.iter().any(|f| !f["default_expr"].is_null()),
+                        "tree_fields": !ent.fields.is_empty() || self.options.keep_extras,
+                        "tree_children": fields.iter().any(|f| f["is_entity"] == true),
                         "fields": fields,
-                        "model_import": "colap::model::config_model"
+                        "model_import": "colap::model::config_model",
+                        "keep_extras": self.options.keep_extras,
+                        "derive_serde": self.options.derive_serde,
+                        "derive_partialeq": self.options.derive_partialeq,
+                        "impl_display": self.options.impl_display,
+                        "derive_builder": self.options.derive_builder,
+                        "known_fields": known_fields,
+                        "is_root": node_id == self.model.root_id(),
+                        "index_root": self.options.emit_index && node_id == self.model.root_id(),
+                        "builder_api": self.options.builder_api
                     });
                     
                     // Render the template
-                    let struct_content = self.handlebars.render("entity_struct", &template_data)
+                    let mut struct_content = self.handlebars.render("entity_struct", &template_data)
                         .expect("Failed to render entity_struct template");
+                    if self.options.builder_api {
+                        struct_content.push('\n');
+                        struct_content.push_str(
+                            &self.handlebars.render("builder_struct", &template_data)
+                                .expect("Failed to render builder_struct template"),
+                        );
+                    }
                     
                     // Add indentation if needed
                     if indent_level > 0 {
@@ -690,7 +1496,7 @@ impl CodeGenerator {
                         for line in struct_content.lines() {
                             out.push_str(&indent);
                             out.push_str(line);
-                            out.push_str("\n");
+                            out.push('\n');
                         }
                     } else {
                         out.push_str(&struct_content);
@@ -701,6 +1507,450 @@ impl CodeGenerator {
         }
     }
 
+    /// Collect a command line flag for every scalar field reachable from the root through
+    /// singular entities. Flags are prefixed with the kebab-cased entity path, e.g.
+    /// `--llm-openai-max-tokens`; plural collections are not addressable and are skipped.
+    fn collect_cli_flags(&self, node_id: usize, flag_prefix: &str, access_prefix: &str, flags: &mut Vec<serde_json::Value>) {
+        let Some(node) = self.model.get_node(node_id) else {
+            return;
+        };
+        let node_b = node.borrow();
+        let ConfigNode::Entity(ent) = &*node_b else {
+            return;
+        };
+
+        // The Api struct comes from a fixed template with optional string fields
+        let is_api = node_id != self.model.root_id() && self.struct_name(&ent.name) == "Api";
+
+        let mut field_names: Vec<&String> = ent.fields.keys().collect();
+        field_names.sort();
+        for field_name in field_names {
+            let value = &ent.fields[field_name];
+            // Arrays and durations have no `FromStr` for clap to parse them with, and mapped types
+            // may not meet clap's bounds
+            let rust_type = self.field_type(node_id, ent, field_name, value);
+            if matches!(value, ConfigValue::Array(_)) || rust_type == DURATION_TYPE || self.is_parsed_type(&rust_type) {
+                continue;
+            }
+            if is_api && !["key", "base_url", "type"].contains(&field_name.as_str()) {
+                continue;
+            }
+            let flag = format!("{}{}", flag_prefix, field_name.to_kebab_case());
+            flags.push(json!({
+                "flag": flag,
+                "name": flag.to_snake_case(),
+                "config_path": self.config_path(node_id, field_name),
+                "type": rust_type,
+                "access": format!("{}{}", access_prefix, self.field_name(field_name)),
+                "getter": format!("{}{}()", access_prefix.replace('.', "()."), self.field_name(field_name)),
+                "is_option": is_api
+                    || matches!(value, ConfigValue::Null)
+                    || self.model.is_field_optional(node_id, field_name)
+            }));
+        }
+
+        for &child_id in &ent.children {
+            if let Some(child) = self.model.get_node(child_id)
+                && let ConfigNode::Entity(child_ent) = &*child.borrow()
+                && child_ent.plural_name.is_none()
+            {
+                let flag_prefix = format!("{}{}-", flag_prefix, child_ent.name.to_kebab_case());
+                let access_prefix = format!("{}{}.", access_prefix, self.field_name(&child_ent.name));
+                self.collect_cli_flags(child_id, &flag_prefix, &access_prefix, flags);
+            }
+        }
+    }
+
+    /// Pick a string flag for the generated override test, or null when there is none
+    fn clap_override_sample(&self) -> serde_json::Value {
+        if !self.options.emit_clap {
+            return serde_json::Value::Null;
+        }
+        let mut flags = Vec::new();
+        self.collect_cli_flags(self.model.root_id(), "", "", &mut flags);
+        flags
+            .into_iter()
+            .find(|flag| flag["type"] == "String")
+            .unwrap_or(serde_json::Value::Null)
+    }
+
+    /// The getter chain of a required string field for the generated `Cow` getter test, or None
+    /// without `cow_getters` or when there is no such field outside plural entities
+    fn cow_getter_sample(&self) -> Option<String> {
+        if !self.options.cow_getters {
+            return None;
+        }
+        let mut flags = Vec::new();
+        self.collect_cli_flags(self.model.root_id(), "", "", &mut flags);
+        flags
+            .into_iter()
+            .find(|flag| flag["type"] == "String" && flag["is_option"] == false)
+            .and_then(|flag| flag["getter"].as_str().map(str::to_string))
+    }
+
+    /// The JSON pointer and getter chain of a field with a generated serde default provider, for
+    /// the generated test that deserializes JSON without that field, or None without
+    /// `serde_defaults` or when no field outside plural entities has a provider
+    fn serde_default_sample(&self) -> Option<serde_json::Value> {
+        if !self.serde_defaults_enabled() {
+            return None;
+        }
+        let mut flags = Vec::new();
+        self.collect_cli_flags(self.model.root_id(), "", "", &mut flags);
+        flags.into_iter().find_map(|flag| {
+            let config_path = flag["config_path"].as_str()?;
+            let (entity_path, field_name) = config_path.rsplit_once('/').unwrap_or(("", config_path));
+            let entity_id = self.model.find_entity_by_path(entity_path)?;
+            let value = self.model.get_field_value(entity_id, field_name)?;
+            // NaN would not compare equal to itself in the test
+            let has_provider = flag["is_option"] == false
+                && !matches!(value, ConfigValue::Float(f) if f.is_nan())
+                && !self.model.is_field_declared(entity_id, field_name)
+                && Self::default_expr(flag["type"].as_str()?, &value).is_some();
+            has_provider.then(|| {
+                json!({
+                    "pointer": format!("{:?}", format!("/{}", config_path)),
+                    "getter": flag["getter"]
+                })
+            })
+        })
+    }
+
+    /// The fields to set on `Root::builder()` (or the derived `RootBuilder`) in the generated
+    /// builder test: the child entities of the root, whose getters return values the builder
+    /// takes as is. None without a builder, or when the root has scalar fields of its own.
+    fn builder_sample(&self) -> Option<Vec<String>> {
+        if !self.options.builder_api && !self.options.derive_builder {
+            return None;
+        }
+        let node = self.model.get_node(self.model.root_id())?;
+        let node_b = node.borrow();
+        let ConfigNode::Entity(root) = &*node_b else {
+            return None;
+        };
+        if !root.fields.is_empty() {
+            return None;
+        }
+        let mut names: Vec<String> = Vec::new();
+        for &child_id in &root.children {
+            if let Some(child) = self.model.get_node(child_id)
+                && let ConfigNode::Entity(child_ent) = &*child.borrow()
+            {
+                let (name, _, _, _) = self.child_field(child_ent);
+                if !names.contains(&name) {
+                    names.push(name);
+                }
+            }
+        }
+        Some(names)
+    }
+
+    /// The dotted path of a required field left empty in the source configuration, as
+    /// `missing_required` reports it, for the generated test. Instances of keyed and enum
+    /// plural entities are skipped: their paths use the key field or the variant name.
+    fn missing_required_sample(&self) -> Option<String> {
+        let mut stack = vec![self.model.root_id()];
+        while let Some(id) = stack.pop() {
+            let node = self.model.get_node(id)?;
+            let node_b = node.borrow();
+            let ConfigNode::Entity(ent) = &*node_b else {
+                continue;
+            };
+            let mut names: Vec<&String> = ent.required_fields.iter().collect();
+            names.sort();
+            for name in names {
+                let empty = match ent.fields.get(name) {
+                    Some(ConfigValue::String(value)) => value.is_empty(),
+                    Some(ConfigValue::Array(items)) => items.is_empty(),
+                    _ => false,
+                };
+                if empty {
+                    let path = self.model.get_entity_path(id)?;
+                    let mut parts: Vec<&str> = path.split('/').filter(|part| !part.is_empty()).collect();
+                    parts.push(name);
+                    return Some(parts.join("."));
+                }
+            }
+            if ent.is_enum || ent.key_field.is_some() {
+                continue;
+            }
+            stack.extend(ent.children.iter().rev());
+        }
+        None
+    }
+
+    /// The plural entities directly under the root that have instances, by getter and entity
+    /// name, for the generated presence test. Enums hold a single variant rather than a count.
+    fn root_plural_assertions(&self) -> Vec<serde_json::Value> {
+        let Some(root) = self.model.get_node(self.model.root_id()) else {
+            return Vec::new();
+        };
+        let ConfigNode::Entity(root_ent) = &*root.borrow() else {
+            return Vec::new();
+        };
+        root_ent
+            .children
+            .iter()
+            .filter_map(|&child_id| {
+                let child = self.model.get_node(child_id)?;
+                let child_b = child.borrow();
+                let ConfigNode::Entity(child_ent) = &*child_b else {
+                    return None;
+                };
+                let plural_name = child_ent.plural_name.as_ref()?;
+                if child_ent.is_enum || child_ent.children.is_empty() || self.flattens(child_ent) {
+                    return None;
+                }
+                Some(json!({
+                    "plural": self.field_name(plural_name),
+                    "singular": child_ent.name
+                }))
+            })
+            .collect()
+    }
+
+    /// The first singular entity directly under the root, with its path, struct and field name,
+    /// for the generated `from_path` test
+    fn path_constructor_sample(&self) -> Option<serde_json::Value> {
+        let root = self.model.get_node(self.model.root_id())?;
+        let ConfigNode::Entity(root_ent) = &*root.borrow() else {
+            return None;
+        };
+        root_ent.children.iter().find_map(|&child_id| {
+            let child = self.model.get_node(child_id)?;
+            let ConfigNode::Entity(child_ent) = &*child.borrow() else {
+                return None;
+            };
+            if child_ent.plural_name.is_some() {
+                return None;
+            }
+            Some(json!({
+                "path": self.model.get_entity_path(child_id)?,
+                "struct_name": self.struct_name(&child_ent.name),
+                "field": self.field_name(&child_ent.name)
+            }))
+        })
+    }
+
+    /// The first plural entity reachable from the root through singular entities (in document
+    /// order), with its getter chain and keys in source order, for the generated ordering test
+    fn plural_sample(&self) -> Option<serde_json::Value> {
+        let mut stack = vec![(self.model.root_id(), String::new())];
+        while let Some((id, access)) = stack.pop() {
+            let node = self.model.get_node(id)?;
+            let node_b = node.borrow();
+            let ConfigNode::Entity(ent) = &*node_b else {
+                continue;
+            };
+            let children: Vec<(usize, NodeRef)> = ent
+                .children
+                .iter()
+                .filter_map(|&child_id| Some((child_id, self.model.get_node(child_id)?)))
+                .collect();
+
+            for (_, child) in &children {
+                let ConfigNode::Entity(child_ent) = &*child.borrow() else {
+                    continue;
+                };
+                let Some(plural_name) = &child_ent.plural_name else {
+                    continue;
+                };
+                if child_ent.is_enum || self.flattens(child_ent) {
+                    continue;
+                }
+                // Keys as the generated `from_children` computes them; a repeated key keeps its
+                // first position
+                let mut keys: Vec<String> = Vec::new();
+                for &instance_id in &child_ent.children {
+                    let Some(instance) = self.model.get_node(instance_id) else {
+                        continue;
+                    };
+                    let ConfigNode::Entity(instance_ent) = &*instance.borrow() else {
+                        continue;
+                    };
+                    let key = match child_ent.key_field.as_ref().and_then(|field| instance_ent.fields.get(field)) {
+                        Some(ConfigValue::String(value)) => value.clone(),
+                        Some(value) => value.to_string(),
+                        None => instance_ent.name.clone(),
+                    };
+                    if !keys.contains(&key) {
+                        keys.push(key);
+                    }
+                }
+                if keys.is_empty() {
+                    continue;
+                }
+                let nth = keys.len().min(2) - 1;
+                return Some(json!({
+                    "fixed": self.fixed_array_keys(plural_name).is_some(),
+                    "access": format!("{}{}()", access, self.field_name(plural_name)),
+                    "first": format!("{:?}", keys[0]),
+                    "last": format!("{:?}", keys[keys.len() - 1]),
+                    "nth": nth,
+                    "nth_key": format!("{:?}", keys[nth]),
+                    "count": keys.len()
+                }));
+            }
+
+            for (child_id, child) in children.iter().rev() {
+                let ConfigNode::Entity(child_ent) = &*child.borrow() else {
+                    continue;
+                };
+                if child_ent.plural_name.is_none() && self.struct_name(&child_ent.name) != "Api" {
+                    stack.push((*child_id, format!("{}{}().", access, self.field_name(&child_ent.name))));
+                }
+            }
+        }
+        None
+    }
+
+    /// The entry names of the plural collection `plural_name` when `--fixed-arrays` can store it
+    /// as `[Singular; N]`: every occurrence of the collection in the model has the same entries,
+    /// in the same order, keyed by entity name rather than a key field. N is at most 32, the
+    /// largest array with `Default` and serde impls.
+    fn fixed_array_keys(&self, plural_name: &str) -> Option<Vec<String>> {
+        if !self.options.fixed_arrays {
+            return None;
+        }
+        let mut fixed: Option<Vec<String>> = None;
+        for (id, _, plural) in self.model.iter_entities() {
+            if plural.as_deref() != Some(plural_name) {
+                continue;
+            }
+            let node = self.model.get_node(id)?;
+            let ConfigNode::Entity(ent) = &*node.borrow() else {
+                continue;
+            };
+            if ent.key_field.is_some() || ent.is_enum {
+                return None;
+            }
+            let mut keys: Vec<String> = Vec::new();
+            for &instance_id in &ent.children {
+                if let Some(instance) = self.model.get_node(instance_id)
+                    && let ConfigNode::Entity(instance_ent) = &*instance.borrow()
+                {
+                    if keys.contains(&instance_ent.name) {
+                        return None;
+                    }
+                    keys.push(instance_ent.name.clone());
+                }
+            }
+            match &fixed {
+                Some(previous) if *previous != keys => return None,
+                _ => fixed = Some(keys),
+            }
+        }
+        fixed.filter(|keys| (1..=32).contains(&keys.len()))
+    }
+
+    /// The variants of an `as enum` plural entity: each distinct value of the discriminator
+    /// field of its instances, in source order, with the first instance naming it
+    fn enum_variants(&self, ent: &EntityNode) -> Vec<(String, usize)> {
+        let mut variants: Vec<(String, usize)> = Vec::new();
+        for &instance_id in &ent.children {
+            if let Some(instance) = self.model.get_node(instance_id)
+                && let ConfigNode::Entity(instance_ent) = &*instance.borrow()
+                && let Some(ConfigValue::String(value)) = instance_ent.fields.get(ENUM_DISCRIMINATOR)
+                && !variants.iter().any(|(known, _)| known == value)
+            {
+                variants.push((value.clone(), instance_id));
+            }
+        }
+        variants
+    }
+
+    /// A nested scalar to exercise indexing in the generated tests, taken from the first entity
+    /// below the root (in document order) that has fields, and its field that sorts first
+    fn index_sample(&self) -> Option<serde_json::Value> {
+        if !self.options.emit_index {
+            return None;
+        }
+        let mut stack = vec![(self.model.root_id(), Vec::<String>::new())];
+        while let Some((id, path)) = stack.pop() {
+            let node = self.model.get_node(id)?;
+            let node_b = node.borrow();
+            let ConfigNode::Entity(ent) = &*node_b else {
+                continue;
+            };
+            if !path.is_empty()
+                && let Some((name, value)) = ent.fields.iter().min_by_key(|(name, _)| name.as_str())
+            {
+                let keys: Vec<String> = path
+                    .iter()
+                    .chain(std::iter::once(name))
+                    .map(|key| format!("{:?}", key))
+                    .collect();
+                return Some(json!({
+                    "keys": keys,
+                    "expected": format!("{:?}", value.to_string())
+                }));
+            }
+            for &child_id in ent.children.iter().rev() {
+                if let Some(child) = self.model.get_node(child_id)
+                    && let ConfigNode::Entity(child_ent) = &*child.borrow()
+                {
+                    let mut child_path = path.clone();
+                    child_path.push(child_ent.name.clone());
+                    stack.push((child_id, child_path));
+                }
+            }
+        }
+        None
+    }
+
+    /// The first field (in document order, then by name) annotated with a narrow integer type,
+    /// with a value one past its maximum for the generated out-of-range test
+    fn range_sample(&self) -> Option<serde_json::Value> {
+        let mut stack = vec![self.model.root_id()];
+        while let Some(id) = stack.pop() {
+            let node = self.model.get_node(id)?;
+            let node_b = node.borrow();
+            let ConfigNode::Entity(ent) = &*node_b else {
+                continue;
+            };
+            let is_api = id != self.model.root_id() && self.struct_name(&ent.name) == "Api";
+            let mut narrow: Vec<(&String, &String)> = ent
+                .field_types
+                .iter()
+                .filter(|(name, rust_type)| ent.fields.contains_key(*name) && Self::is_narrow_integer(rust_type))
+                .collect();
+            narrow.sort();
+            if !is_api && let Some((name, rust_type)) = narrow.first() {
+                let max = match rust_type.as_str() {
+                    "i8" => i64::from(i8::MAX),
+                    "i16" => i64::from(i16::MAX),
+                    "i32" => i64::from(i32::MAX),
+                    "u8" => i64::from(u8::MAX),
+                    "u16" => i64::from(u16::MAX),
+                    _ => i64::from(u32::MAX),
+                };
+                let entity_path = self.model.get_entity_path(id)?;
+                let path = if entity_path.is_empty() {
+                    name.to_string()
+                } else {
+                    format!("{}/{}", entity_path, name)
+                };
+                return Some(json!({
+                    "entity_path": entity_path,
+                    "field": name,
+                    "type": rust_type,
+                    "value": max + 1,
+                    "path": path
+                }));
+            }
+            stack.extend(ent.children.iter().rev());
+        }
+        None
+    }
+
+    /// Whether the root declares any field or entity, so an empty configuration misses one
+    fn root_has_content(&self) -> bool {
+        self.model.get_node(self.model.root_id()).is_some_and(|root| match &*root.borrow() {
+            ConfigNode::Entity(ent) => !ent.fields.is_empty() || !ent.children.is_empty(),
+            ConfigNode::Field(_) => false,
+        })
+    }
+
     /// Generate a pluralized struct name for collections
     #[allow(dead_code)]
     fn plural_struct_name(&self, ent: &EntityNode) -> String {
@@ -711,6 +1961,340 @@ impl CodeGenerator {
         }
     }
 
+    /// Get the struct name for the entries of a plural entity. When the singular and plural names
+    /// map to the same struct name (`servers plural servers`), the entry struct gets an `Item`
+    /// suffix so it does not collide with the collection struct.
+    fn singular_struct_name(&self, ent: &EntityNode) -> String {
+        let singular = self.struct_name(&ent.name);
+        match &ent.plural_name {
+            Some(plural) if self.struct_name(plural) == singular => format!("{}Item", singular),
+            _ => singular,
+        }
+    }
+
+    /// Whether the plural entity `ent` is typed as the struct of its single instance rather than
+    /// a collection, with `flatten_singletons`. Keyed and enum plural entities keep the
+    /// collection or enum their declaration asks for.
+    fn flattens(&self, ent: &EntityNode) -> bool {
+        self.options.flatten_singletons
+            && ent.plural_name.is_some()
+            && !ent.is_enum
+            && ent.key_field.is_none()
+            && ent
+                .children
+                .iter()
+                .filter(|&&id| self.model.get_node(id).is_some_and(|node| node.borrow().is_entity()))
+                .count()
+                == 1
+    }
+
+    /// The `ModelBuilder` options the model was built with that change its shape, which the
+    /// generated loaders and tests must set to read the configuration into the same model
+    fn builder_options(&self) -> Vec<&'static str> {
+        [
+            ("repeatable_fields", self.options.repeatable_fields),
+            ("unit_suffixes", self.options.unit_suffixes),
+        ]
+        .into_iter()
+        .filter_map(|(option, enabled)| enabled.then_some(option))
+        .collect()
+    }
+
+    /// Whether any plural entity of the model is flattened
+    fn uses_flattening(&self) -> bool {
+        self.model.iter_entities().any(|(id, _, _)| {
+            self.model
+                .get_node(id)
+                .is_some_and(|node| matches!(&*node.borrow(), ConfigNode::Entity(ent) if self.flattens(ent)))
+        })
+    }
+
+    /// The field name and type of the child entity `ent` in its parent's struct, whether the
+    /// field is a collection, and the instance name of a flattened plural entity
+    fn child_field(&self, ent: &EntityNode) -> (String, String, bool, Option<String>) {
+        match &ent.plural_name {
+            Some(_) if self.flattens(ent) => {
+                let instance = ent.children.iter().find_map(|&id| {
+                    let node = self.model.get_node(id)?;
+                    let node_b = node.borrow();
+                    node_b.is_entity().then(|| node_b.name().to_string())
+                });
+                (self.field_name(&ent.name), self.singular_struct_name(ent), false, instance)
+            }
+            Some(plural) => (self.field_name(plural), self.struct_name(plural), true, None),
+            None => (self.field_name(&ent.name), self.struct_name(&ent.name), false, None),
+        }
+    }
+
+    /// The lines of a doc comment for `///` comments. Backticks are escaped so an unpaired one
+    /// does not open a code span, and `*/` is broken up so the text is safe in any comment.
+    fn doc_lines(doc: Option<String>) -> Vec<String> {
+        doc.map(|doc| doc.lines().map(|line| line.replace('`', "\\`").replace("*/", "*\\/")).collect())
+            .unwrap_or_default()
+    }
+
+    /// Get the Rust type of a field, preferring a type map entry, then its declared type
+    /// annotation over the value. A `null` value carries no type, so the field becomes an
+    /// optional `String`.
+    fn field_type(&self, node_id: usize, ent: &EntityNode, field_name: &str, value: &ConfigValue) -> String {
+        if let Ok(Some((_, mapped))) = self.mapped_type(node_id, ent, field_name) {
+            return if mapped == "Duration" { DURATION_TYPE.to_string() } else { mapped.to_string() };
+        }
+        if let Some(declared) = ent.field_types.get(field_name) {
+            // Durations are stored in milliseconds and read back as a `std::time::Duration`
+            if declared == "Duration" {
+                return DURATION_TYPE.to_string();
+            }
+            return declared.clone();
+        }
+        match value {
+            ConfigValue::Integer(_) => "i64".to_string(),
+            ConfigValue::Float(_) => "f64".to_string(),
+            ConfigValue::Boolean(_) => "bool".to_string(),
+            ConfigValue::String(_) | ConfigValue::Null => "String".to_string(),
+            ConfigValue::Array(_) => format!("Vec<{}>", self.array_element(value).0),
+        }
+    }
+
+    /// The type map entry matching a field, as its key and type. The `Api` struct comes from a
+    /// fixed template, so its fields keep their types.
+    fn mapped_type(&self, node_id: usize, ent: &EntityNode, field_name: &str) -> Result<Option<(&str, &str)>> {
+        if node_id != self.model.root_id() && self.struct_name(&ent.name) == "Api" {
+            return Ok(None);
+        }
+        self.type_map.lookup(&self.config_path(node_id, field_name), field_name)
+    }
+
+    /// Whether a field of this type is read from a string with `FromStr` and written back with
+    /// `Display`, as the types of the type map that are not built in are
+    fn is_parsed_type(&self, rust_type: &str) -> bool {
+        self.type_map.is_custom(rust_type)
+    }
+
+    /// Whether any field of the model has a parsed type, so the generated code checks them
+    fn uses_parsed_types(&self) -> bool {
+        let root_id = self.model.root_id();
+        std::iter::once(root_id).chain(self.model.iter_entities().map(|(id, _, _)| id)).any(|id| {
+            self.model.get_node(id).is_some_and(|node| match &*node.borrow() {
+                ConfigNode::Entity(ent) => ent
+                    .fields
+                    .iter()
+                    .any(|(name, value)| self.is_parsed_type(&self.field_type(id, ent, name, value))),
+                ConfigNode::Field(_) => false,
+            })
+        })
+    }
+
+    /// Every field of the model as its `entity/path.field` key (the key of
+    /// `ConfigModel::as_flat_map`) and the Rust type of its struct field, both as string
+    /// literals, sorted by key
+    fn config_paths(&self) -> Vec<serde_json::Value> {
+        let mut paths = BTreeMap::new();
+        let root_id = self.model.root_id();
+        for id in std::iter::once(root_id).chain(self.model.iter_entities().map(|(id, _, _)| id)) {
+            let Some(path) = self.model.get_entity_path(id) else {
+                continue;
+            };
+            let Some(node) = self.model.get_node(id) else {
+                continue;
+            };
+            let node_b = node.borrow();
+            let ConfigNode::Entity(ent) = &*node_b else {
+                continue;
+            };
+            let in_plural = ent.parent.and_then(|parent_id| self.model.get_node(parent_id)).is_some_and(|parent| {
+                matches!(&*parent.borrow(), ConfigNode::Entity(parent) if parent.plural_name.is_some())
+            });
+            for (name, value) in &ent.fields {
+                let rust_type = self.field_type(id, ent, name, value);
+                let optional = matches!(value, ConfigValue::Null)
+                    || if in_plural {
+                        self.is_instance_field_optional(id, ent, name)
+                    } else {
+                        self.model.is_field_optional(id, name)
+                    };
+                let key = if path.is_empty() { name.clone() } else { format!("{}.{}", path, name) };
+                let rust_type = if optional { format!("Option<{}>", rust_type) } else { rust_type };
+                paths.insert(key, rust_type);
+            }
+        }
+        paths
+            .into_iter()
+            .map(|(path, rust_type)| json!({ "path": format!("{:?}", path), "type": format!("{:?}", rust_type) }))
+            .collect()
+    }
+
+    /// The slash-separated config path of the field or child entity `name` of an entity, for
+    /// getter docs. Instances of plural entities share one struct, so their names become `*`.
+    fn config_path(&self, node_id: usize, name: &str) -> String {
+        let mut segments = vec![name.to_string()];
+        let mut current = node_id;
+        while current != self.model.root_id() {
+            let Some(node) = self.model.get_node(current) else {
+                break;
+            };
+            let ConfigNode::Entity(ent) = &*node.borrow() else {
+                break;
+            };
+            let Some(parent_id) = ent.parent else {
+                break;
+            };
+            let in_plural = self.model.get_node(parent_id).is_some_and(|parent| {
+                matches!(&*parent.borrow(), ConfigNode::Entity(parent) if parent.plural_name.is_some())
+            });
+            segments.push(if in_plural { "*".to_string() } else { ent.name.clone() });
+            current = parent_id;
+        }
+        segments.reverse();
+        segments.join("/")
+    }
+
+    /// The term comparing field `name` of `self` and `other` in a generated `config_eq`: strings
+    /// ignore differences in whitespace, entities compare with their own `config_eq`, types of
+    /// the type map as they are written and other values with `==`
+    fn config_eq_term(&self, name: &str, rust_type: &str, is_optional: bool, is_entity: bool) -> String {
+        let compare = |a: &str, b: &str| {
+            if is_entity {
+                format!("{}.config_eq({})", a, b)
+            } else if self.is_parsed_type(rust_type) {
+                format!("{}.to_string() == {}.to_string()", a, b)
+            } else if rust_type == "String" {
+                format!("{}.split_whitespace().eq({}.split_whitespace())", a, b)
+            } else if rust_type == "Vec<String>" {
+                format!(
+                    "{a}.len() == {b}.len() && {a}.iter().zip({b}.iter()).all(|(a, b)| a.split_whitespace().eq(b.split_whitespace()))",
+                    a = a,
+                    b = b
+                )
+            } else {
+                format!("{} == {}", a, b)
+            }
+        };
+        if is_optional {
+            format!(
+                "self.{name}.as_ref().zip(other.{name}.as_ref()).map_or(self.{name}.is_none() && other.{name}.is_none(), |(a, b)| {compare})",
+                name = name,
+                compare = compare("a", "b")
+            )
+        } else if is_entity {
+            compare(&format!("self.{}", name), &format!("&other.{}", name))
+        } else {
+            compare(&format!("self.{}", name), &format!("other.{}", name))
+        }
+    }
+
+    /// Whether fields get serde default providers, which needs the serde derives
+    fn serde_defaults_enabled(&self) -> bool {
+        self.options.serde_defaults && self.options.derive_serde
+    }
+
+    /// The expression a generated serde default provider returns for a field of type
+    /// `rust_type`: its configured value. None for nulls, empty arrays, arrays of mixed types
+    /// kept as `ConfigScalar` and integers out of range of their annotated type, which keep the
+    /// type's zero value. Arrays keep the elements `from_entity` keeps.
+    fn default_expr(rust_type: &str, value: &ConfigValue) -> Option<String> {
+        match value {
+            ConfigValue::String(s) if rust_type == "String" => Some(format!("{:?}.to_string()", s)),
+            ConfigValue::Boolean(b) if rust_type == "bool" => Some(b.to_string()),
+            ConfigValue::Integer(i) if matches!(rust_type, "f32" | "f64") => Some(format!("{:?}", *i as f64)),
+            ConfigValue::Integer(i) if rust_type == DURATION_TYPE => {
+                u64::try_from(*i).ok().map(|millis| format!("std::time::Duration::from_millis({})", millis))
+            }
+            ConfigValue::Integer(i) => {
+                let fits = match rust_type {
+                    "i64" => true,
+                    "i32" => i32::try_from(*i).is_ok(),
+                    "i16" => i16::try_from(*i).is_ok(),
+                    "i8" => i8::try_from(*i).is_ok(),
+                    "u64" => u64::try_from(*i).is_ok(),
+                    "u32" => u32::try_from(*i).is_ok(),
+                    "u16" => u16::try_from(*i).is_ok(),
+                    "u8" => u8::try_from(*i).is_ok(),
+                    _ => false,
+                };
+                fits.then(|| i.to_string())
+            }
+            ConfigValue::Float(f) if f.is_nan() && matches!(rust_type, "f32" | "f64") => Some(format!("{}::NAN", rust_type)),
+            ConfigValue::Float(f) if f.is_infinite() && matches!(rust_type, "f32" | "f64") => {
+                Some(format!("{}::{}", rust_type, if *f > 0.0 { "INFINITY" } else { "NEG_INFINITY" }))
+            }
+            ConfigValue::Float(f) if rust_type == "f64" || (rust_type == "f32" && f.abs() <= f32::MAX as f64) => {
+                Some(format!("{:?}", f))
+            }
+            ConfigValue::Array(items) => {
+                let element = rust_type.strip_prefix("Vec<")?.strip_suffix('>')?;
+                let items: Vec<String> = items.iter().filter_map(|item| Self::default_expr(element, item)).collect();
+                (!items.is_empty()).then(|| format!("vec![{}]", items.join(", ")))
+            }
+            _ => None,
+        }
+    }
+
+    /// Whether a Rust field type is an integer narrower than `i64`, extracted with `try_from`
+    fn is_narrow_integer(rust_type: &str) -> bool {
+        matches!(rust_type, "i32" | "i16" | "i8" | "u32" | "u16" | "u8")
+    }
+
+    /// Whether getters return a field of this type by value rather than by reference
+    fn is_copy_type(rust_type: &str) -> bool {
+        matches!(rust_type, "i64" | "f64" | "bool" | DURATION_TYPE) || Self::is_narrow_integer(rust_type)
+    }
+
+    /// The type a `Cow` getter borrows for a field with `cow_getters`: `str` for strings, a slice
+    /// for arrays and the field type itself otherwise
+    fn cow_type(&self, rust_type: &str, is_array: bool, element_type: &str) -> Option<String> {
+        if !self.options.cow_getters {
+            return None;
+        }
+        Some(if rust_type == "String" {
+            "str".to_string()
+        } else if is_array {
+            format!("[{}]", element_type)
+        } else {
+            rust_type.to_string()
+        })
+    }
+
+    /// Get the element type and `ConfigValue` variant of an array field, inferred from its first
+    /// element. Empty arrays fall back to strings, as do mixed arrays unless
+    /// `heterogeneous_arrays` types them as `ConfigScalar`, which has no single variant.
+    fn array_element(&self, value: &ConfigValue) -> (&'static str, &'static str) {
+        let items = match value {
+            ConfigValue::Array(items) => items,
+            _ => return ("String", "String"),
+        };
+        let element = match items.first() {
+            Some(ConfigValue::Integer(_)) => ("i64", "Integer"),
+            Some(ConfigValue::Float(_)) => ("f64", "Float"),
+            Some(ConfigValue::Boolean(_)) => ("bool", "Boolean"),
+            _ => ("String", "String"),
+        };
+        let uniform = items
+            .iter()
+            .all(|item| std::mem::discriminant(item) == std::mem::discriminant(&items[0]));
+        match (uniform, self.options.heterogeneous_arrays) {
+            (true, _) => element,
+            (false, true) => ("ConfigScalar", ""),
+            (false, false) => ("String", "String"),
+        }
+    }
+
+    /// Whether any field of the model holds an array whose elements have different types
+    fn has_mixed_arrays(&self) -> bool {
+        std::iter::once(self.model.root_id())
+            .chain(self.model.iter_entities().map(|(id, _, _)| id))
+            .filter_map(|id| self.model.get_node(id))
+            .any(|node| match &*node.borrow() {
+                ConfigNode::Entity(ent) => ent.fields.values().any(|value| match value {
+                    ConfigValue::Array(items) => items
+                        .iter()
+                        .any(|item| std::mem::discriminant(item) != std::mem::discriminant(&items[0])),
+                    _ => false,
+                }),
+                ConfigNode::Field(_) => false,
+            })
+    }
+
     /// Get a struct name (PascalCase)
     fn struct_name(&self, name: &str) -> String {
         name.to_pascal_case()
@@ -726,13 +2310,9 @@ impl CodeGenerator {
         }
     }
     
-    /// Convert back to original case for field lookups
-    fn to_original_case(&self, name: &str) -> String {
-        if name == "type_" {
-            "type".to_string()
-        } else {
-            name.to_string()
-        }
+    /// The name serde should use for a field, when it differs from the Rust field name
+    fn serde_rename(&self, rust_name: &str, original: &str) -> Option<String> {
+        (rust_name != original).then(|| original.to_string())
     }
 
     /// Add indentation to output
@@ -743,13 +2323,134 @@ impl CodeGenerator {
         }
     }
 
+    /// Whether the source path names a file, rather than standing for input read from
+    /// standard input
+    fn source_is_file(&self) -> bool {
+        self.source_path.is_file()
+    }
 
+    /// Whether the source file pulls in other files with `include`
+    fn source_includes_files(&self) -> bool {
+        fs::read_to_string(&self.source_path)
+            .map(|source| source.lines().any(|line| line.trim_start().starts_with("include \"")))
+            .unwrap_or(false)
+    }
 
-    /// Get a relative path to the source file for inclusion in tests
+    /// The configuration as cola, for the generated tests to read when there is no source file.
+    /// The tests narrow it to the `with_only` path themselves, as they do with a source file.
+    fn source_cola(&self) -> String {
+        self.unnarrowed_cola.clone().unwrap_or_else(|| self.model.to_cola())
+    }
+
+    /// Get the path of the source file relative to the generated module's directory, with
+    /// forward slashes, so the module tests can `include_str!` it on any machine and OS
     fn relative_source_path(&self) -> String {
-        // This is a simplistic implementation that assumes the source file is
-        // within the same project. In a real implementation, you would use
-        // a better approach to generate a relative path that works for tests.
-        self.source_path.to_string_lossy().replace('\\', "/")
+        let base_dir = match &self.mode {
+            GenerationMode::Module { output_file }
+            | GenerationMode::Yaml { output_file }
+            | GenerationMode::Toml { output_file } => output_file
+                .parent()
+                .map(Path::to_path_buf)
+                .unwrap_or_default(),
+            GenerationMode::Crate { output_dir, .. } => output_dir.clone(),
+        };
+        relative_path(&base_dir, &self.source_path)
+            .to_string_lossy()
+            .replace('\\', "/")
+    }
+}
+
+/// A mapping key, quoted when YAML would read the plain name as a boolean or null
+fn yaml_key(name: &str) -> String {
+    let reserved = ["true", "false", "yes", "no", "on", "off", "null", "y", "n"];
+    if reserved.contains(&name.to_ascii_lowercase().as_str()) {
+        format!("\"{}\"", name)
+    } else {
+        name.to_string()
+    }
+}
+
+/// A YAML scalar, or a flow sequence for arrays. Strings are double-quoted with JSON escapes,
+/// which YAML accepts, and floats always keep a fractional part so they read back as floats.
+fn yaml_value(value: &ConfigValue) -> String {
+    match value {
+        ConfigValue::String(s) => serde_json::to_string(s).unwrap_or_default(),
+        ConfigValue::Integer(i) => i.to_string(),
+        ConfigValue::Float(f) if f.is_nan() => ".nan".to_string(),
+        ConfigValue::Float(f) if f.is_infinite() => {
+            if *f > 0.0 { ".inf" } else { "-.inf" }.to_string()
+        }
+        ConfigValue::Float(f) => format!("{:?}", f),
+        ConfigValue::Boolean(b) => b.to_string(),
+        ConfigValue::Null => "null".to_string(),
+        ConfigValue::Array(items) => {
+            let items: Vec<String> = items.iter().map(yaml_value).collect();
+            format!("[{}]", items.join(", "))
+        }
+    }
+}
+
+/// A TOML key, bare when it only has letters, digits, `_` and `-`, and quoted otherwise
+fn toml_key(name: &str) -> String {
+    let bare = !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-');
+    if bare { name.to_string() } else { serde_json::to_string(name).unwrap_or_default() }
+}
+
+/// A dotted TOML table name
+fn toml_path(path: &[String]) -> String {
+    path.iter().map(|name| toml_key(name)).collect::<Vec<_>>().join(".")
+}
+
+/// A TOML value, or `None` for null, which TOML cannot express. Strings are basic strings with
+/// JSON escapes, which TOML shares, and floats keep a fractional part so they read back as
+/// floats. Nulls inside arrays are dropped.
+fn toml_value(value: &ConfigValue) -> Option<String> {
+    Some(match value {
+        ConfigValue::String(s) => serde_json::to_string(s).unwrap_or_default(),
+        ConfigValue::Integer(i) => i.to_string(),
+        ConfigValue::Float(f) if f.is_nan() => "nan".to_string(),
+        ConfigValue::Float(f) if f.is_infinite() => if *f > 0.0 { "inf" } else { "-inf" }.to_string(),
+        ConfigValue::Float(f) => format!("{:?}", f),
+        ConfigValue::Boolean(b) => b.to_string(),
+        ConfigValue::Null => return None,
+        ConfigValue::Array(items) => {
+            let items: Vec<String> = items.iter().filter_map(toml_value).collect();
+            format!("[{}]", items.join(", "))
+        }
+    })
+}
+
+/// Compute the path of `target` relative to the directory `base`. Both are made absolute
+/// against the current directory first; `.` and `..` components are resolved lexically.
+fn relative_path(base: &Path, target: &Path) -> PathBuf {
+    let normalize = |path: &Path| -> Vec<OsString> {
+        let absolute = std::path::absolute(path).unwrap_or_else(|_| path.to_path_buf());
+        let mut components = Vec::new();
+        for component in absolute.components() {
+            match component {
+                Component::CurDir => {}
+                Component::ParentDir => {
+                    components.pop();
+                }
+                other => components.push(other.as_os_str().to_os_string()),
+            }
+        }
+        components
+    };
+    let base = normalize(base);
+    let target = normalize(target);
+
+    let common = base
+        .iter()
+        .zip(target.iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let mut relative = PathBuf::new();
+    for _ in common..base.len() {
+        relative.push("..");
+    }
+    for component in &target[common..] {
+        relative.push(component);
     }
+    relative
 }
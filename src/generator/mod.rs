@@ -3,3 +3,5 @@ mod generator_impl;
 
 // Re-export the main components from generator_impl
 pub use generator_impl::*;
+pub mod rust_import;
+pub mod type_map;
@@ -0,0 +1,261 @@
+// SPDX-License-Identifier: Apache-2.0
+//! Reverse code generation: a starter Cola configuration from hand-written Rust config structs
+use std::collections::HashMap;
+
+use heck::ToSnakeCase;
+use syn::{Fields, GenericArgument, Item, ItemStruct, PathArguments, Type};
+
+/// How a struct field is written in Cola
+enum FieldKind<'a> {
+    /// A scalar, written as a placeholder literal
+    Scalar(&'static str),
+    /// A `Vec` of scalars, written as an array with one placeholder element
+    Array(&'static str),
+    /// Another struct of the source, written as a nested entity
+    Entity(&'a ItemStruct),
+    /// A `Vec` or map of structs, written as a plural entity with one example instance
+    Plural(&'a ItemStruct),
+}
+
+/// Convert the structs of a Rust source file into a Cola skeleton with placeholder values.
+///
+/// The root is the struct named `root`, or else the first struct that no other struct refers
+/// to. Struct fields become nested entities, `Vec`s and maps of structs become plural entities
+/// with one `example` instance, and `Option` fields are marked optional with `?`. Cola has no
+/// fields on the root, so a root struct with scalar fields is written as an entity itself;
+/// otherwise its fields are written as the top-level entities.
+pub fn rust_to_cola(source: &str, root: Option<&str>) -> Result<String, String> {
+    let file = syn::parse_file(source).map_err(|e| format!("Failed to parse Rust source: {}", e))?;
+    let structs: Vec<&ItemStruct> = file
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            Item::Struct(item_struct) => Some(item_struct),
+            _ => None,
+        })
+        .collect();
+    let importer = RustImporter {
+        structs: structs.iter().map(|s| (s.ident.to_string(), *s)).collect(),
+    };
+
+    let root = match root {
+        Some(name) => importer
+            .structs
+            .get(name)
+            .copied()
+            .ok_or_else(|| format!("No struct named '{}' in Rust source", name))?,
+        None => structs
+            .iter()
+            .copied()
+            .find(|candidate| {
+                let name = candidate.ident.to_string();
+                !structs.iter().any(|other| RustImporter::refers_to(other, &name))
+            })
+            .or_else(|| structs.first().copied())
+            .ok_or_else(|| "No struct found in Rust source".to_string())?,
+    };
+
+    let mut out = String::from("```cola\n");
+    let mut visiting = vec![root.ident.to_string()];
+    let fields = importer.fields(root, &visiting);
+    if fields.iter().all(|(_, kind, _)| matches!(kind, FieldKind::Entity(_) | FieldKind::Plural(_))) {
+        for (name, kind, _) in &fields {
+            importer.write_child(name, kind, 0, &mut visiting, &mut out);
+        }
+    } else {
+        let name = root.ident.to_string().to_snake_case();
+        importer.write_entity(&name, root, 0, &mut visiting, &mut out);
+    }
+    out.push_str("```\n");
+    Ok(out)
+}
+
+/// The structs of a source file, by name
+struct RustImporter<'a> {
+    structs: HashMap<String, &'a ItemStruct>,
+}
+
+impl<'a> RustImporter<'a> {
+    /// Check whether any field of `item` mentions the struct `name`
+    fn refers_to(item: &ItemStruct, name: &str) -> bool {
+        let Fields::Named(named) = &item.fields else {
+            return false;
+        };
+        named.named.iter().any(|field| mentions(&field.ty, name))
+    }
+
+    /// The named fields of a struct with how each is written and whether it is optional.
+    /// Fields whose struct type is already being written (recursive types) are left out.
+    fn fields(&self, item: &'a ItemStruct, visiting: &[String]) -> Vec<(String, FieldKind<'a>, bool)> {
+        let Fields::Named(named) = &item.fields else {
+            return Vec::new();
+        };
+        named
+            .named
+            .iter()
+            .filter_map(|field| {
+                let name = field.ident.as_ref()?.to_string();
+                let name = name.strip_prefix("r#").unwrap_or(&name).to_string();
+                let (ty, optional) = match generic_arg(&field.ty, &["Option"], 0) {
+                    Some(inner) => (inner, true),
+                    None => (&field.ty, false),
+                };
+                let kind = self.field_kind(ty);
+                let nested = match &kind {
+                    FieldKind::Entity(nested) | FieldKind::Plural(nested) => Some(nested.ident.to_string()),
+                    _ => None,
+                };
+                if nested.is_some_and(|nested| visiting.contains(&nested)) {
+                    return None;
+                }
+                Some((name, kind, optional))
+            })
+            .collect()
+    }
+
+    /// Classify a field type
+    fn field_kind(&self, ty: &'a Type) -> FieldKind<'a> {
+        let ty = generic_arg(ty, &["Box", "Rc", "Arc"], 0).unwrap_or(ty);
+        let collection = generic_arg(ty, &["Vec", "VecDeque", "HashSet", "BTreeSet"], 0)
+            .or_else(|| generic_arg(ty, &["HashMap", "BTreeMap", "IndexMap"], 1));
+        match collection {
+            Some(element) => match self.struct_of(element) {
+                Some(item) => FieldKind::Plural(item),
+                None => FieldKind::Array(scalar_placeholder(element)),
+            },
+            None => match self.struct_of(ty) {
+                Some(item) => FieldKind::Entity(item),
+                None => FieldKind::Scalar(scalar_placeholder(ty)),
+            },
+        }
+    }
+
+    /// The struct of the source a type names, if any
+    fn struct_of(&self, ty: &Type) -> Option<&'a ItemStruct> {
+        self.structs.get(&last_segment(ty)?).copied()
+    }
+
+    /// Write an entity with its scalar fields first, then its nested entities
+    fn write_entity(
+        &self,
+        name: &str,
+        item: &'a ItemStruct,
+        depth: usize,
+        visiting: &mut Vec<String>,
+        out: &mut String,
+    ) {
+        let indent = "    ".repeat(depth);
+        out.push_str(&format!("{}{}:\n", indent, name));
+        self.write_body(item, depth + 1, visiting, out);
+        out.push_str(&format!("{};\n", indent));
+    }
+
+    /// Write the fields and nested entities of a struct at the given depth
+    fn write_body(&self, item: &'a ItemStruct, depth: usize, visiting: &mut Vec<String>, out: &mut String) {
+        let indent = "    ".repeat(depth);
+        let fields = self.fields(item, visiting);
+        let lines: Vec<String> = fields
+            .iter()
+            .filter_map(|(name, kind, optional)| {
+                let marker = if *optional { "?" } else { "" };
+                match kind {
+                    FieldKind::Scalar(value) => Some(format!("{}{}{}: {}", indent, name, marker, value)),
+                    FieldKind::Array(value) => Some(format!("{}{}{}: [{}]", indent, name, marker, value)),
+                    _ => None,
+                }
+            })
+            .collect();
+        if !lines.is_empty() {
+            out.push_str(&lines.join(",\n"));
+            out.push('\n');
+        }
+        for (name, kind, _) in &fields {
+            self.write_child(name, kind, depth, visiting, out);
+        }
+    }
+
+    /// Write a field that holds a struct as a singular or plural entity
+    fn write_child(
+        &self,
+        name: &str,
+        kind: &FieldKind<'a>,
+        depth: usize,
+        visiting: &mut Vec<String>,
+        out: &mut String,
+    ) {
+        match kind {
+            FieldKind::Entity(item) => {
+                visiting.push(item.ident.to_string());
+                self.write_entity(name, item, depth, visiting, out);
+                visiting.pop();
+            }
+            FieldKind::Plural(item) => {
+                let indent = "    ".repeat(depth);
+                let singular = item.ident.to_string().to_snake_case();
+                out.push_str(&format!("{}{} plural {}:\n", indent, singular, name));
+                visiting.push(item.ident.to_string());
+                self.write_entity("example", item, depth + 1, visiting, out);
+                visiting.pop();
+                out.push_str(&format!("{};\n", indent));
+            }
+            FieldKind::Scalar(_) | FieldKind::Array(_) => {}
+        }
+    }
+}
+
+/// The name of the last path segment of a type, looking through references
+fn last_segment(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Path(type_path) => type_path.path.segments.last().map(|s| s.ident.to_string()),
+        Type::Reference(reference) => last_segment(&reference.elem),
+        _ => None,
+    }
+}
+
+/// The `index`th generic type argument of a type whose last segment is one of `wrappers`
+fn generic_arg<'t>(ty: &'t Type, wrappers: &[&str], index: usize) -> Option<&'t Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if !wrappers.contains(&segment.ident.to_string().as_str()) {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    args.args
+        .iter()
+        .filter_map(|arg| match arg {
+            GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        })
+        .nth(index)
+}
+
+/// A Cola placeholder literal for a scalar Rust type; unknown types become strings
+fn scalar_placeholder(ty: &Type) -> &'static str {
+    match last_segment(ty).as_deref() {
+        Some("i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64" | "u128" | "usize") => "0",
+        Some("f32" | "f64") => "0.0",
+        Some("bool") => "false",
+        _ => "\"\"",
+    }
+}
+
+/// Check whether a type names the struct `name`, directly or in a generic argument
+fn mentions(ty: &Type, name: &str) -> bool {
+    match ty {
+        Type::Path(type_path) => type_path.path.segments.iter().any(|segment| {
+            segment.ident == name
+                || match &segment.arguments {
+                    PathArguments::AngleBracketed(args) => args.args.iter().any(|arg| {
+                        matches!(arg, GenericArgument::Type(ty) if mentions(ty, name))
+                    }),
+                    _ => false,
+                }
+        }),
+        Type::Reference(reference) => mentions(&reference.elem, name),
+        _ => false,
+    }
+}
@@ -0,0 +1,175 @@
+// SPDX-License-Identifier: Apache-2.0
+//! Field type overrides read from a type map file, applied with `CodeGenerator::with_type_map`
+//!
+//! ```toml
+//! # `use` paths of the mapped types that are not built in
+//! imports = ["url::Url"]
+//!
+//! [types]
+//! "*_url" = "Url"          # every field whose name ends in `_url`
+//! "server/port" = "u16"    # a single field, by its config path
+//!
+//! # Added to the `Cargo.toml` of a generated crate
+//! [dependencies]
+//! url = "2"
+//! ```
+//!
+//! A key with a `/` matches the config path of a field as shown in the getter docs, where the
+//! instances of plural entities are `*`; any other key matches the field name. `*` in a key
+//! matches any run of characters. Built-in targets are the types of cola type annotations.
+//! Other types must be named by an import, and are read from string values with `FromStr` and
+//! written back with `Display`.
+use std::path::Path;
+
+use anyhow::{Context, Result};
+
+/// Types a field can be mapped to without an import, as in cola type annotations
+const BUILTIN_TYPES: &[&str] = &["String", "i64", "i32", "i16", "i8", "u32", "u16", "u8", "f64", "bool", "Duration"];
+
+/// A parsed type map file
+#[derive(Debug, Clone, Default)]
+pub struct TypeMap {
+    imports: Vec<String>,
+    /// Key pattern and Rust type of every entry
+    types: Vec<(String, String)>,
+    /// Crate name and TOML value of every extra dependency
+    dependencies: Vec<(String, String)>,
+}
+
+impl TypeMap {
+    /// Read and check a type map file
+    pub fn load(path: &Path) -> Result<Self> {
+        let source = std::fs::read_to_string(path).with_context(|| format!("Unable to read {}", path.display()))?;
+        Self::from_toml_str(&source).with_context(|| format!("Invalid type map {}", path.display()))
+    }
+
+    /// Parse a type map, checking that every import is a `use` path, every target a Rust type
+    /// and every type that is not built in named by an import
+    pub fn from_toml_str(source: &str) -> Result<Self> {
+        let mut table: toml::Table = source.parse()?;
+        let section = |table: &mut toml::Table, name: &str| match table.remove(name) {
+            None => Ok(toml::Table::new()),
+            Some(toml::Value::Table(section)) => Ok(section),
+            Some(_) => Err(anyhow::anyhow!("`{}` must be a table", name)),
+        };
+        let imports = match table.remove("imports") {
+            None => Vec::new(),
+            Some(toml::Value::Array(imports)) => imports
+                .into_iter()
+                .map(|import| match import {
+                    toml::Value::String(import) => Ok(import),
+                    other => Err(anyhow::anyhow!("Import {} is not a string", other)),
+                })
+                .collect::<Result<_>>()?,
+            Some(_) => anyhow::bail!("`imports` must be an array of `use` paths"),
+        };
+        let types = section(&mut table, "types")?
+            .into_iter()
+            .map(|(pattern, rust_type)| match rust_type {
+                toml::Value::String(rust_type) => Ok((pattern, rust_type)),
+                other => Err(anyhow::anyhow!("Type map entry '{}' maps to {}, which is not a string", pattern, other)),
+            })
+            .collect::<Result<_>>()?;
+        let dependencies = section(&mut table, "dependencies")?
+            .into_iter()
+            .map(|(name, value)| (name, value.to_string()))
+            .collect();
+        if let Some(key) = table.keys().next() {
+            anyhow::bail!("Unknown type map key `{}`", key);
+        }
+
+        for import in &imports {
+            syn::parse_str::<syn::UseTree>(import)
+                .map_err(|_| anyhow::anyhow!("Import '{}' is not a `use` path", import))?;
+        }
+        let type_map = TypeMap { imports, types, dependencies };
+        for (pattern, rust_type) in &type_map.types {
+            syn::parse_str::<syn::Type>(rust_type)
+                .map_err(|_| anyhow::anyhow!("Type map entry '{}' maps to '{}', which is not a Rust type", pattern, rust_type))?;
+            if type_map.is_custom(rust_type) && !type_map.is_imported(rust_type) {
+                anyhow::bail!(
+                    "Type map entry '{}' maps to '{}', which is not a built-in type; add its `use` path to `imports`",
+                    pattern,
+                    rust_type
+                );
+            }
+        }
+        Ok(type_map)
+    }
+
+    /// The `use` paths to import in the generated code
+    pub fn imports(&self) -> &[String] {
+        &self.imports
+    }
+
+    /// The extra dependencies of a generated crate, as crate names and TOML values
+    pub fn dependencies(&self) -> &[(String, String)] {
+        &self.dependencies
+    }
+
+    /// The entries of the map, as key patterns and Rust types
+    pub fn entries(&self) -> &[(String, String)] {
+        &self.types
+    }
+
+    /// Whether `rust_type` is one of the mapped types that are not built in
+    pub fn is_custom(&self, rust_type: &str) -> bool {
+        !BUILTIN_TYPES.contains(&rust_type) && self.types.iter().any(|(_, mapped)| mapped == rust_type)
+    }
+
+    /// The entry mapping the field `field_name` at `config_path`, as its key and type. Keys
+    /// matching the config path take precedence over keys matching the name; entries of the same
+    /// kind that map the field to different types are an error.
+    pub fn lookup(&self, config_path: &str, field_name: &str) -> Result<Option<(&str, &str)>> {
+        for by_path in [true, false] {
+            let subject = if by_path { config_path } else { field_name };
+            let mut matches = self
+                .types
+                .iter()
+                .filter(|(pattern, _)| pattern.contains('/') == by_path && glob_match(pattern, subject));
+            let Some((pattern, rust_type)) = matches.next() else {
+                continue;
+            };
+            if let Some((other, other_type)) = matches.find(|(_, other_type)| other_type != rust_type) {
+                anyhow::bail!(
+                    "Field '{}' matches type map entries '{}' ({}) and '{}' ({})",
+                    config_path,
+                    pattern,
+                    rust_type,
+                    other,
+                    other_type
+                );
+            }
+            return Ok(Some((pattern, rust_type)));
+        }
+        Ok(None)
+    }
+
+    /// Whether the first path segment of `rust_type` is the name an import brings into scope
+    fn is_imported(&self, rust_type: &str) -> bool {
+        let head = rust_type.split([':', '<']).next().unwrap_or(rust_type).trim();
+        self.imports.iter().any(|import| {
+            let name = import.rsplit(" as ").next().unwrap_or(import);
+            name.rsplit("::").next().is_some_and(|last| last.trim() == head)
+        })
+    }
+}
+
+/// Whether `subject` matches `pattern`, where `*` matches any run of characters
+fn glob_match(pattern: &str, subject: &str) -> bool {
+    let Some((first, rest)) = pattern.split_once('*') else {
+        return pattern == subject;
+    };
+    let Some(mut remaining) = subject.strip_prefix(first) else {
+        return false;
+    };
+    let mut parts: Vec<&str> = rest.split('*').collect();
+    let last = parts.pop().unwrap_or_default();
+    for part in parts {
+        match remaining.find(part) {
+            Some(index) => remaining = &remaining[index + part.len()..],
+            None => return false,
+        }
+    }
+    remaining.len() >= last.len() && remaining.ends_with(last)
+}
@@ -18,33 +18,54 @@ use rustemo::debug::{log, logn};
 #[cfg(debug_assertions)]
 use rustemo::colored::*;
 pub type Input = str;
-const STATE_COUNT: usize = 61usize;
-const MAX_RECOGNIZERS: usize = 7usize;
+const STATE_COUNT: usize = 146usize;
+const MAX_RECOGNIZERS: usize = 14usize;
 #[allow(dead_code)]
-const TERMINAL_COUNT: usize = 21usize;
+const TERMINAL_COUNT: usize = 42usize;
 #[allow(clippy::upper_case_acronyms)]
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum TokenKind {
     #[default]
     STOP,
+    Ampersand,
+    AsKeyword,
+    Bang,
     BooleanTrue,
     BooleanFalse,
     ColaCodeStart,
     ColaCodeEnd,
     Colon,
+    CloseBrace,
+    CloseBracket,
+    CloseParen,
     Comma,
+    Comment,
+    EnumKeyword,
+    Equals,
+    IncludeKeyword,
     HeadingLine,
     Identifier,
+    KeyKeyword,
+    Minus,
+    Null,
     Number,
+    OpenBrace,
+    OpenBracket,
+    OpenParen,
     ParagraphLine,
     PluralKeyword,
+    Plus,
+    Question,
     QuotedStringDouble,
+    QuotedStringTriple,
     QuotedStringSingle,
     RegularCodeLine,
     RegularCodeStartNamed,
     RegularCodeStartUnnamed,
     RegularCodeEnd,
     Semicolon,
+    Slash,
+    Star,
     UnquotedString,
     WS,
 }
@@ -69,29 +90,87 @@ pub enum ProdKind {
     CodeBlockP2,
     ColaCodeBlockP1,
     ColaSyntaxP1,
-    Entity1P1,
-    Entity1P2,
-    Entity0P1,
-    Entity0P2,
+    ColaItem1P1,
+    ColaItem1P2,
+    ColaItem0P1,
+    ColaItem0P2,
+    ColaItemP1,
+    ColaItemP2,
+    ColaItemP3,
+    ColaItemP4,
     EntityP1,
     EntityP2,
+    EntityP3,
     PluralEntityP1,
+    PluralKeyOptP1,
+    PluralKeyOptP2,
+    PluralEnumOptP1,
+    PluralEnumOptP2,
     SingularEntityP1,
-    EntityDefinitionP1,
+    InlinePluralEntityP1,
+    InlineInstancesOptP1,
+    InlineInstancesOptP2,
+    InlineInstancesP1,
+    InlineInstancesP2,
+    InlineInstancesP3,
+    PluralKeyP1,
+    PluralEnumP1,
+    EntityDefinitionPlain,
     NestedBlock1P1,
     NestedBlock1P2,
     NestedBlock0P1,
     NestedBlock0P2,
+    EntityDefinitionBraced,
     NestedBlockP1,
     NestedBlockP2,
+    NestedBlockP3,
+    NestedBlockP4,
+    IncludeP1,
+    AnchorDefinitionP1,
+    AnchorReferenceP1,
     FieldListP1,
     FieldListP2,
+    FieldEntryP1,
+    FieldEntryP2,
+    FieldEntryP3,
+    FieldEntryP4,
+    FieldEntryP5,
+    FieldEntryP6,
+    FieldEntryP7,
     FieldP1,
+    TypeAnnotationOptP1,
+    TypeAnnotationOptP2,
+    OptionalFieldP1,
+    RequiredFieldP1,
+    FieldDeclarationP1,
+    OptionalDeclarationP1,
+    RequiredDeclarationP1,
+    TypeNameP1,
+    ComputedFieldP1,
+    ExpressionAdd,
+    ExpressionSub,
+    ExpressionMul,
+    ExpressionDiv,
+    ExpressionGroup,
+    ExpressionP6,
+    ExpressionP7,
+    ExpressionP8,
+    TypeAnnotationP1,
     FieldValueP1,
     FieldValueP2,
     FieldValueP3,
     FieldValueP4,
     FieldValueP5,
+    FieldValueP6,
+    FieldValueP7,
+    FieldValueP8,
+    FieldValueP9,
+    ArrayValueP1,
+    ArrayItemsOptP1,
+    ArrayItemsOptP2,
+    ArrayItemsP1,
+    ArrayItemsP2,
+    ArrayItemsP3,
     RegularCodeBlockP1,
     RegularCodeLine1P1,
     RegularCodeLine1P2,
@@ -100,9 +179,12 @@ pub enum ProdKind {
     RegularCodeStartP1,
     RegularCodeStartP2,
     LayoutP1,
-    WS1P1,
-    WS1P2,
-    LayoutP2,
+    LayoutItem1P1,
+    LayoutItem1P2,
+    LayoutItem0P1,
+    LayoutItem0P2,
+    LayoutItemP1,
+    LayoutItemP2,
 }
 use ProdKind as PK;
 impl std::fmt::Debug for ProdKind {
@@ -121,34 +203,110 @@ impl std::fmt::Debug for ProdKind {
             ProdKind::ColaCodeBlockP1 => {
                 "ColaCodeBlock: ColaCodeStart ColaSyntax ColaCodeEnd"
             }
-            ProdKind::ColaSyntaxP1 => "ColaSyntax: Entity0",
-            ProdKind::Entity1P1 => "Entity1: Entity1 Entity",
-            ProdKind::Entity1P2 => "Entity1: Entity",
-            ProdKind::Entity0P1 => "Entity0: Entity1",
-            ProdKind::Entity0P2 => "Entity0: ",
+            ProdKind::ColaSyntaxP1 => "ColaSyntax: ColaItem0",
+            ProdKind::ColaItem1P1 => "ColaItem1: ColaItem1 ColaItem",
+            ProdKind::ColaItem1P2 => "ColaItem1: ColaItem",
+            ProdKind::ColaItem0P1 => "ColaItem0: ColaItem1",
+            ProdKind::ColaItem0P2 => "ColaItem0: ",
+            ProdKind::ColaItemP1 => "ColaItem: Entity",
+            ProdKind::ColaItemP2 => "ColaItem: FieldList",
+            ProdKind::ColaItemP3 => "ColaItem: AnchorDefinition",
+            ProdKind::ColaItemP4 => "ColaItem: Include",
             ProdKind::EntityP1 => "Entity: PluralEntity",
             ProdKind::EntityP2 => "Entity: SingularEntity",
+            ProdKind::EntityP3 => "Entity: InlinePluralEntity",
             ProdKind::PluralEntityP1 => {
-                "PluralEntity: Identifier PluralKeyword Identifier Colon EntityDefinition Semicolon"
+                "PluralEntity: Identifier PluralKeyword Identifier PluralKeyOpt PluralEnumOpt Colon EntityDefinition Semicolon"
             }
+            ProdKind::PluralKeyOptP1 => "PluralKeyOpt: PluralKey",
+            ProdKind::PluralKeyOptP2 => "PluralKeyOpt: ",
+            ProdKind::PluralEnumOptP1 => "PluralEnumOpt: PluralEnum",
+            ProdKind::PluralEnumOptP2 => "PluralEnumOpt: ",
             ProdKind::SingularEntityP1 => {
                 "SingularEntity: Identifier Colon EntityDefinition Semicolon"
             }
-            ProdKind::EntityDefinitionP1 => "EntityDefinition: NestedBlock0",
+            ProdKind::InlinePluralEntityP1 => {
+                "InlinePluralEntity: Identifier Equals OpenBracket InlineInstancesOpt CloseBracket"
+            }
+            ProdKind::InlineInstancesOptP1 => "InlineInstancesOpt: InlineInstances",
+            ProdKind::InlineInstancesOptP2 => "InlineInstancesOpt: ",
+            ProdKind::InlineInstancesP1 => "InlineInstances: Identifier",
+            ProdKind::InlineInstancesP2 => "InlineInstances: Identifier Comma",
+            ProdKind::InlineInstancesP3 => {
+                "InlineInstances: Identifier Comma InlineInstances"
+            }
+            ProdKind::PluralKeyP1 => "PluralKey: KeyKeyword Identifier",
+            ProdKind::PluralEnumP1 => "PluralEnum: AsKeyword EnumKeyword",
+            ProdKind::EntityDefinitionPlain => "EntityDefinition: NestedBlock0",
             ProdKind::NestedBlock1P1 => "NestedBlock1: NestedBlock1 NestedBlock",
             ProdKind::NestedBlock1P2 => "NestedBlock1: NestedBlock",
             ProdKind::NestedBlock0P1 => "NestedBlock0: NestedBlock1",
             ProdKind::NestedBlock0P2 => "NestedBlock0: ",
+            ProdKind::EntityDefinitionBraced => {
+                "EntityDefinition: OpenBrace NestedBlock0 CloseBrace"
+            }
             ProdKind::NestedBlockP1 => "NestedBlock: FieldList",
             ProdKind::NestedBlockP2 => "NestedBlock: Entity",
-            ProdKind::FieldListP1 => "FieldList: Field",
-            ProdKind::FieldListP2 => "FieldList: FieldList Comma Field",
-            ProdKind::FieldP1 => "Field: Identifier Colon FieldValue",
-            ProdKind::FieldValueP1 => "FieldValue: QuotedStringDouble",
-            ProdKind::FieldValueP2 => "FieldValue: QuotedStringSingle",
-            ProdKind::FieldValueP3 => "FieldValue: Number",
-            ProdKind::FieldValueP4 => "FieldValue: BooleanTrue",
-            ProdKind::FieldValueP5 => "FieldValue: BooleanFalse",
+            ProdKind::NestedBlockP3 => "NestedBlock: AnchorDefinition",
+            ProdKind::NestedBlockP4 => "NestedBlock: Include",
+            ProdKind::IncludeP1 => "Include: IncludeKeyword QuotedStringDouble",
+            ProdKind::AnchorDefinitionP1 => {
+                "AnchorDefinition: Ampersand Identifier FieldValue"
+            }
+            ProdKind::AnchorReferenceP1 => "AnchorReference: Star Identifier",
+            ProdKind::FieldListP1 => "FieldList: FieldEntry",
+            ProdKind::FieldListP2 => "FieldList: FieldList Comma FieldEntry",
+            ProdKind::FieldEntryP1 => "FieldEntry: Field",
+            ProdKind::FieldEntryP2 => "FieldEntry: OptionalField",
+            ProdKind::FieldEntryP3 => "FieldEntry: RequiredField",
+            ProdKind::FieldEntryP4 => "FieldEntry: ComputedField",
+            ProdKind::FieldEntryP5 => "FieldEntry: FieldDeclaration",
+            ProdKind::FieldEntryP6 => "FieldEntry: OptionalDeclaration",
+            ProdKind::FieldEntryP7 => "FieldEntry: RequiredDeclaration",
+            ProdKind::FieldP1 => "Field: Identifier Colon TypeAnnotationOpt FieldValue",
+            ProdKind::TypeAnnotationOptP1 => "TypeAnnotationOpt: TypeAnnotation",
+            ProdKind::TypeAnnotationOptP2 => "TypeAnnotationOpt: ",
+            ProdKind::OptionalFieldP1 => {
+                "OptionalField: Identifier Question Colon TypeAnnotationOpt FieldValue"
+            }
+            ProdKind::RequiredFieldP1 => {
+                "RequiredField: Identifier Bang Colon TypeAnnotationOpt FieldValue"
+            }
+            ProdKind::FieldDeclarationP1 => "FieldDeclaration: Identifier Colon TypeName",
+            ProdKind::OptionalDeclarationP1 => {
+                "OptionalDeclaration: Identifier Question Colon TypeName"
+            }
+            ProdKind::RequiredDeclarationP1 => {
+                "RequiredDeclaration: Identifier Bang Colon TypeName"
+            }
+            ProdKind::TypeNameP1 => "TypeName: Identifier",
+            ProdKind::ComputedFieldP1 => "ComputedField: Identifier Equals Expression",
+            ProdKind::ExpressionAdd => "Expression: Expression Plus Expression",
+            ProdKind::ExpressionSub => "Expression: Expression Minus Expression",
+            ProdKind::ExpressionMul => "Expression: Expression Star Expression",
+            ProdKind::ExpressionDiv => "Expression: Expression Slash Expression",
+            ProdKind::ExpressionGroup => "Expression: OpenParen Expression CloseParen",
+            ProdKind::ExpressionP6 => "Expression: Number",
+            ProdKind::ExpressionP7 => "Expression: Identifier",
+            ProdKind::ExpressionP8 => "Expression: AnchorReference",
+            ProdKind::TypeAnnotationP1 => "TypeAnnotation: Identifier Equals",
+            ProdKind::FieldValueP1 => "FieldValue: QuotedStringTriple",
+            ProdKind::FieldValueP2 => "FieldValue: QuotedStringDouble",
+            ProdKind::FieldValueP3 => "FieldValue: QuotedStringSingle",
+            ProdKind::FieldValueP4 => "FieldValue: Number",
+            ProdKind::FieldValueP5 => "FieldValue: BooleanTrue",
+            ProdKind::FieldValueP6 => "FieldValue: BooleanFalse",
+            ProdKind::FieldValueP7 => "FieldValue: Null",
+            ProdKind::FieldValueP8 => "FieldValue: ArrayValue",
+            ProdKind::FieldValueP9 => "FieldValue: AnchorReference",
+            ProdKind::ArrayValueP1 => {
+                "ArrayValue: OpenBracket ArrayItemsOpt CloseBracket"
+            }
+            ProdKind::ArrayItemsOptP1 => "ArrayItemsOpt: ArrayItems",
+            ProdKind::ArrayItemsOptP2 => "ArrayItemsOpt: ",
+            ProdKind::ArrayItemsP1 => "ArrayItems: FieldValue",
+            ProdKind::ArrayItemsP2 => "ArrayItems: FieldValue Comma",
+            ProdKind::ArrayItemsP3 => "ArrayItems: FieldValue Comma ArrayItems",
             ProdKind::RegularCodeBlockP1 => {
                 "RegularCodeBlock: RegularCodeStart RegularCodeLine0 RegularCodeEnd"
             }
@@ -160,10 +318,13 @@ impl std::fmt::Debug for ProdKind {
             ProdKind::RegularCodeLine0P2 => "RegularCodeLine0: ",
             ProdKind::RegularCodeStartP1 => "RegularCodeStart: RegularCodeStartNamed",
             ProdKind::RegularCodeStartP2 => "RegularCodeStart: RegularCodeStartUnnamed",
-            ProdKind::LayoutP1 => "Layout: WS1",
-            ProdKind::WS1P1 => "WS1: WS1 WS",
-            ProdKind::WS1P2 => "WS1: WS",
-            ProdKind::LayoutP2 => "Layout: ",
+            ProdKind::LayoutP1 => "Layout: LayoutItem0",
+            ProdKind::LayoutItem1P1 => "LayoutItem1: LayoutItem1 LayoutItem",
+            ProdKind::LayoutItem1P2 => "LayoutItem1: LayoutItem",
+            ProdKind::LayoutItem0P1 => "LayoutItem0: LayoutItem1",
+            ProdKind::LayoutItem0P2 => "LayoutItem0: ",
+            ProdKind::LayoutItemP1 => "LayoutItem: WS",
+            ProdKind::LayoutItemP2 => "LayoutItem: Comment",
         };
         write!(f, "{name}")
     }
@@ -182,24 +343,51 @@ pub enum NonTermKind {
     CodeBlock,
     ColaCodeBlock,
     ColaSyntax,
-    Entity1,
-    Entity0,
+    ColaItem1,
+    ColaItem0,
+    ColaItem,
     Entity,
     PluralEntity,
+    PluralKeyOpt,
+    PluralEnumOpt,
     SingularEntity,
+    InlinePluralEntity,
+    InlineInstancesOpt,
+    InlineInstances,
+    PluralKey,
+    PluralEnum,
     EntityDefinition,
     NestedBlock1,
     NestedBlock0,
     NestedBlock,
+    Include,
+    AnchorDefinition,
+    AnchorReference,
     FieldList,
+    FieldEntry,
     Field,
+    TypeAnnotationOpt,
+    OptionalField,
+    RequiredField,
+    FieldDeclaration,
+    OptionalDeclaration,
+    RequiredDeclaration,
+    TypeName,
+    ComputedField,
+    Expression,
+    TypeAnnotation,
     FieldValue,
+    ArrayValue,
+    ArrayItemsOpt,
+    ArrayItems,
     RegularCodeBlock,
     RegularCodeLine1,
     RegularCodeLine0,
     RegularCodeStart,
     Layout,
-    WS1,
+    LayoutItem1,
+    LayoutItem0,
+    LayoutItem,
 }
 impl From<ProdKind> for NonTermKind {
     fn from(prod: ProdKind) -> Self {
@@ -216,29 +404,87 @@ impl From<ProdKind> for NonTermKind {
             ProdKind::CodeBlockP2 => NonTermKind::CodeBlock,
             ProdKind::ColaCodeBlockP1 => NonTermKind::ColaCodeBlock,
             ProdKind::ColaSyntaxP1 => NonTermKind::ColaSyntax,
-            ProdKind::Entity1P1 => NonTermKind::Entity1,
-            ProdKind::Entity1P2 => NonTermKind::Entity1,
-            ProdKind::Entity0P1 => NonTermKind::Entity0,
-            ProdKind::Entity0P2 => NonTermKind::Entity0,
+            ProdKind::ColaItem1P1 => NonTermKind::ColaItem1,
+            ProdKind::ColaItem1P2 => NonTermKind::ColaItem1,
+            ProdKind::ColaItem0P1 => NonTermKind::ColaItem0,
+            ProdKind::ColaItem0P2 => NonTermKind::ColaItem0,
+            ProdKind::ColaItemP1 => NonTermKind::ColaItem,
+            ProdKind::ColaItemP2 => NonTermKind::ColaItem,
+            ProdKind::ColaItemP3 => NonTermKind::ColaItem,
+            ProdKind::ColaItemP4 => NonTermKind::ColaItem,
             ProdKind::EntityP1 => NonTermKind::Entity,
             ProdKind::EntityP2 => NonTermKind::Entity,
+            ProdKind::EntityP3 => NonTermKind::Entity,
             ProdKind::PluralEntityP1 => NonTermKind::PluralEntity,
+            ProdKind::PluralKeyOptP1 => NonTermKind::PluralKeyOpt,
+            ProdKind::PluralKeyOptP2 => NonTermKind::PluralKeyOpt,
+            ProdKind::PluralEnumOptP1 => NonTermKind::PluralEnumOpt,
+            ProdKind::PluralEnumOptP2 => NonTermKind::PluralEnumOpt,
             ProdKind::SingularEntityP1 => NonTermKind::SingularEntity,
-            ProdKind::EntityDefinitionP1 => NonTermKind::EntityDefinition,
+            ProdKind::InlinePluralEntityP1 => NonTermKind::InlinePluralEntity,
+            ProdKind::InlineInstancesOptP1 => NonTermKind::InlineInstancesOpt,
+            ProdKind::InlineInstancesOptP2 => NonTermKind::InlineInstancesOpt,
+            ProdKind::InlineInstancesP1 => NonTermKind::InlineInstances,
+            ProdKind::InlineInstancesP2 => NonTermKind::InlineInstances,
+            ProdKind::InlineInstancesP3 => NonTermKind::InlineInstances,
+            ProdKind::PluralKeyP1 => NonTermKind::PluralKey,
+            ProdKind::PluralEnumP1 => NonTermKind::PluralEnum,
+            ProdKind::EntityDefinitionPlain => NonTermKind::EntityDefinition,
             ProdKind::NestedBlock1P1 => NonTermKind::NestedBlock1,
             ProdKind::NestedBlock1P2 => NonTermKind::NestedBlock1,
             ProdKind::NestedBlock0P1 => NonTermKind::NestedBlock0,
             ProdKind::NestedBlock0P2 => NonTermKind::NestedBlock0,
+            ProdKind::EntityDefinitionBraced => NonTermKind::EntityDefinition,
             ProdKind::NestedBlockP1 => NonTermKind::NestedBlock,
             ProdKind::NestedBlockP2 => NonTermKind::NestedBlock,
+            ProdKind::NestedBlockP3 => NonTermKind::NestedBlock,
+            ProdKind::NestedBlockP4 => NonTermKind::NestedBlock,
+            ProdKind::IncludeP1 => NonTermKind::Include,
+            ProdKind::AnchorDefinitionP1 => NonTermKind::AnchorDefinition,
+            ProdKind::AnchorReferenceP1 => NonTermKind::AnchorReference,
             ProdKind::FieldListP1 => NonTermKind::FieldList,
             ProdKind::FieldListP2 => NonTermKind::FieldList,
+            ProdKind::FieldEntryP1 => NonTermKind::FieldEntry,
+            ProdKind::FieldEntryP2 => NonTermKind::FieldEntry,
+            ProdKind::FieldEntryP3 => NonTermKind::FieldEntry,
+            ProdKind::FieldEntryP4 => NonTermKind::FieldEntry,
+            ProdKind::FieldEntryP5 => NonTermKind::FieldEntry,
+            ProdKind::FieldEntryP6 => NonTermKind::FieldEntry,
+            ProdKind::FieldEntryP7 => NonTermKind::FieldEntry,
             ProdKind::FieldP1 => NonTermKind::Field,
+            ProdKind::TypeAnnotationOptP1 => NonTermKind::TypeAnnotationOpt,
+            ProdKind::TypeAnnotationOptP2 => NonTermKind::TypeAnnotationOpt,
+            ProdKind::OptionalFieldP1 => NonTermKind::OptionalField,
+            ProdKind::RequiredFieldP1 => NonTermKind::RequiredField,
+            ProdKind::FieldDeclarationP1 => NonTermKind::FieldDeclaration,
+            ProdKind::OptionalDeclarationP1 => NonTermKind::OptionalDeclaration,
+            ProdKind::RequiredDeclarationP1 => NonTermKind::RequiredDeclaration,
+            ProdKind::TypeNameP1 => NonTermKind::TypeName,
+            ProdKind::ComputedFieldP1 => NonTermKind::ComputedField,
+            ProdKind::ExpressionAdd => NonTermKind::Expression,
+            ProdKind::ExpressionSub => NonTermKind::Expression,
+            ProdKind::ExpressionMul => NonTermKind::Expression,
+            ProdKind::ExpressionDiv => NonTermKind::Expression,
+            ProdKind::ExpressionGroup => NonTermKind::Expression,
+            ProdKind::ExpressionP6 => NonTermKind::Expression,
+            ProdKind::ExpressionP7 => NonTermKind::Expression,
+            ProdKind::ExpressionP8 => NonTermKind::Expression,
+            ProdKind::TypeAnnotationP1 => NonTermKind::TypeAnnotation,
             ProdKind::FieldValueP1 => NonTermKind::FieldValue,
             ProdKind::FieldValueP2 => NonTermKind::FieldValue,
             ProdKind::FieldValueP3 => NonTermKind::FieldValue,
             ProdKind::FieldValueP4 => NonTermKind::FieldValue,
             ProdKind::FieldValueP5 => NonTermKind::FieldValue,
+            ProdKind::FieldValueP6 => NonTermKind::FieldValue,
+            ProdKind::FieldValueP7 => NonTermKind::FieldValue,
+            ProdKind::FieldValueP8 => NonTermKind::FieldValue,
+            ProdKind::FieldValueP9 => NonTermKind::FieldValue,
+            ProdKind::ArrayValueP1 => NonTermKind::ArrayValue,
+            ProdKind::ArrayItemsOptP1 => NonTermKind::ArrayItemsOpt,
+            ProdKind::ArrayItemsOptP2 => NonTermKind::ArrayItemsOpt,
+            ProdKind::ArrayItemsP1 => NonTermKind::ArrayItems,
+            ProdKind::ArrayItemsP2 => NonTermKind::ArrayItems,
+            ProdKind::ArrayItemsP3 => NonTermKind::ArrayItems,
             ProdKind::RegularCodeBlockP1 => NonTermKind::RegularCodeBlock,
             ProdKind::RegularCodeLine1P1 => NonTermKind::RegularCodeLine1,
             ProdKind::RegularCodeLine1P2 => NonTermKind::RegularCodeLine1,
@@ -247,9 +493,12 @@ impl From<ProdKind> for NonTermKind {
             ProdKind::RegularCodeStartP1 => NonTermKind::RegularCodeStart,
             ProdKind::RegularCodeStartP2 => NonTermKind::RegularCodeStart,
             ProdKind::LayoutP1 => NonTermKind::Layout,
-            ProdKind::WS1P1 => NonTermKind::WS1,
-            ProdKind::WS1P2 => NonTermKind::WS1,
-            ProdKind::LayoutP2 => NonTermKind::Layout,
+            ProdKind::LayoutItem1P1 => NonTermKind::LayoutItem1,
+            ProdKind::LayoutItem1P2 => NonTermKind::LayoutItem1,
+            ProdKind::LayoutItem0P1 => NonTermKind::LayoutItem0,
+            ProdKind::LayoutItem0P2 => NonTermKind::LayoutItem0,
+            ProdKind::LayoutItemP1 => NonTermKind::LayoutItem,
+            ProdKind::LayoutItemP2 => NonTermKind::LayoutItem,
         }
     }
 }
@@ -271,57 +520,142 @@ pub enum State {
     ColaCodeBlockS11,
     RegularCodeBlockS12,
     RegularCodeStartS13,
-    IdentifierS14,
-    ColaSyntaxS15,
-    Entity1S16,
-    Entity0S17,
-    EntityS18,
-    PluralEntityS19,
-    SingularEntityS20,
-    MarkdownItemS21,
-    RegularCodeLineS22,
-    RegularCodeLine1S23,
-    RegularCodeLine0S24,
-    ColonS25,
-    PluralKeywordS26,
-    ColaCodeEndS27,
-    EntityS28,
-    RegularCodeLineS29,
-    RegularCodeEndS30,
-    IdentifierS31,
-    EntityS32,
-    EntityDefinitionS33,
-    NestedBlock1S34,
-    NestedBlock0S35,
-    NestedBlockS36,
-    FieldListS37,
-    FieldS38,
-    IdentifierS39,
-    ColonS40,
-    SemicolonS41,
-    NestedBlockS42,
-    CommaS43,
-    ColonS44,
-    BooleanTrueS45,
-    BooleanFalseS46,
-    NumberS47,
-    QuotedStringDoubleS48,
-    QuotedStringSingleS49,
-    FieldValueS50,
-    IdentifierS51,
-    FieldS52,
-    EntityDefinitionS53,
-    ColonS54,
-    SemicolonS55,
-    AUGLS56,
-    WSS57,
-    LayoutS58,
-    WS1S59,
-    WSS60,
+    AmpersandS14,
+    IncludeKeywordS15,
+    IdentifierS16,
+    ColaSyntaxS17,
+    ColaItem1S18,
+    ColaItem0S19,
+    ColaItemS20,
+    EntityS21,
+    PluralEntityS22,
+    SingularEntityS23,
+    InlinePluralEntityS24,
+    IncludeS25,
+    AnchorDefinitionS26,
+    FieldListS27,
+    FieldEntryS28,
+    FieldS29,
+    OptionalFieldS30,
+    RequiredFieldS31,
+    FieldDeclarationS32,
+    OptionalDeclarationS33,
+    RequiredDeclarationS34,
+    ComputedFieldS35,
+    MarkdownItemS36,
+    RegularCodeLineS37,
+    RegularCodeLine1S38,
+    RegularCodeLine0S39,
+    IdentifierS40,
+    QuotedStringDoubleS41,
+    BangS42,
+    ColonS43,
+    EqualsS44,
+    PluralKeywordS45,
+    QuestionS46,
+    ColaCodeEndS47,
+    ColaItemS48,
+    CommaS49,
+    RegularCodeLineS50,
+    RegularCodeEndS51,
+    BooleanTrueS52,
+    BooleanFalseS53,
+    NullS54,
+    NumberS55,
+    OpenBracketS56,
+    QuotedStringDoubleS57,
+    QuotedStringTripleS58,
+    QuotedStringSingleS59,
+    StarS60,
+    AnchorReferenceS61,
+    FieldValueS62,
+    ArrayValueS63,
+    ColonS64,
+    IdentifierS65,
+    OpenBraceS66,
+    EntityS67,
+    EntityDefinitionS68,
+    NestedBlock1S69,
+    NestedBlock0S70,
+    NestedBlockS71,
+    IncludeS72,
+    AnchorDefinitionS73,
+    FieldListS74,
+    TypeAnnotationOptS75,
+    TypeNameS76,
+    TypeAnnotationS77,
+    IdentifierS78,
+    NumberS79,
+    OpenBracketS80,
+    OpenParenS81,
+    AnchorReferenceS82,
+    ExpressionS83,
+    IdentifierS84,
+    ColonS85,
+    IdentifierS86,
+    FieldEntryS87,
+    FieldValueS88,
+    ArrayItemsOptS89,
+    ArrayItemsS90,
+    IdentifierS91,
+    IdentifierS92,
+    TypeAnnotationOptS93,
+    TypeNameS94,
+    EqualsS95,
+    NestedBlock0S96,
+    SemicolonS97,
+    NestedBlockS98,
+    FieldValueS99,
+    IdentifierS100,
+    InlineInstancesOptS101,
+    InlineInstancesS102,
+    ExpressionS103,
+    MinusS104,
+    PlusS105,
+    SlashS106,
+    StarS107,
+    KeyKeywordS108,
+    PluralKeyOptS109,
+    PluralKeyS110,
+    TypeAnnotationOptS111,
+    TypeNameS112,
+    ColonS113,
+    EqualsS114,
+    CommaS115,
+    CloseBracketS116,
+    EqualsS117,
+    FieldValueS118,
+    CloseBraceS119,
+    CommaS120,
+    CloseBracketS121,
+    CloseParenS122,
+    ExpressionS123,
+    ExpressionS124,
+    ExpressionS125,
+    ExpressionS126,
+    IdentifierS127,
+    AsKeywordS128,
+    PluralEnumOptS129,
+    PluralEnumS130,
+    FieldValueS131,
+    ArrayItemsS132,
+    InlineInstancesS133,
+    EnumKeywordS134,
+    ColonS135,
+    EntityDefinitionS136,
+    SemicolonS137,
+    AUGLS138,
+    CommentS139,
+    WSS140,
+    LayoutS141,
+    LayoutItem1S142,
+    LayoutItem0S143,
+    LayoutItemS144,
+    LayoutItemS145,
 }
 impl StateT for State {
     fn default_layout() -> Option<Self> {
-        Some(State::AUGLS56)
+        Some(State::AUGLS138)
     }
 }
 impl From<State> for usize {
@@ -346,53 +680,138 @@ impl std::fmt::Debug for State {
             State::ColaCodeBlockS11 => "11:ColaCodeBlock",
             State::RegularCodeBlockS12 => "12:RegularCodeBlock",
             State::RegularCodeStartS13 => "13:RegularCodeStart",
-            State::IdentifierS14 => "14:Identifier",
-            State::ColaSyntaxS15 => "15:ColaSyntax",
-            State::Entity1S16 => "16:Entity1",
-            State::Entity0S17 => "17:Entity0",
-            State::EntityS18 => "18:Entity",
-            State::PluralEntityS19 => "19:PluralEntity",
-            State::SingularEntityS20 => "20:SingularEntity",
-            State::MarkdownItemS21 => "21:MarkdownItem",
-            State::RegularCodeLineS22 => "22:RegularCodeLine",
-            State::RegularCodeLine1S23 => "23:RegularCodeLine1",
-            State::RegularCodeLine0S24 => "24:RegularCodeLine0",
-            State::ColonS25 => "25:Colon",
-            State::PluralKeywordS26 => "26:PluralKeyword",
-            State::ColaCodeEndS27 => "27:ColaCodeEnd",
-            State::EntityS28 => "28:Entity",
-            State::RegularCodeLineS29 => "29:RegularCodeLine",
-            State::RegularCodeEndS30 => "30:RegularCodeEnd",
-            State::IdentifierS31 => "31:Identifier",
-            State::EntityS32 => "32:Entity",
-            State::EntityDefinitionS33 => "33:EntityDefinition",
-            State::NestedBlock1S34 => "34:NestedBlock1",
-            State::NestedBlock0S35 => "35:NestedBlock0",
-            State::NestedBlockS36 => "36:NestedBlock",
-            State::FieldListS37 => "37:FieldList",
-            State::FieldS38 => "38:Field",
-            State::IdentifierS39 => "39:Identifier",
-            State::ColonS40 => "40:Colon",
-            State::SemicolonS41 => "41:Semicolon",
-            State::NestedBlockS42 => "42:NestedBlock",
-            State::CommaS43 => "43:Comma",
-            State::ColonS44 => "44:Colon",
-            State::BooleanTrueS45 => "45:BooleanTrue",
-            State::BooleanFalseS46 => "46:BooleanFalse",
-            State::NumberS47 => "47:Number",
-            State::QuotedStringDoubleS48 => "48:QuotedStringDouble",
-            State::QuotedStringSingleS49 => "49:QuotedStringSingle",
-            State::FieldValueS50 => "50:FieldValue",
-            State::IdentifierS51 => "51:Identifier",
-            State::FieldS52 => "52:Field",
-            State::EntityDefinitionS53 => "53:EntityDefinition",
-            State::ColonS54 => "54:Colon",
-            State::SemicolonS55 => "55:Semicolon",
-            State::AUGLS56 => "56:AUGL",
-            State::WSS57 => "57:WS",
-            State::LayoutS58 => "58:Layout",
-            State::WS1S59 => "59:WS1",
-            State::WSS60 => "60:WS",
+            State::AmpersandS14 => "14:Ampersand",
+            State::IncludeKeywordS15 => "15:IncludeKeyword",
+            State::IdentifierS16 => "16:Identifier",
+            State::ColaSyntaxS17 => "17:ColaSyntax",
+            State::ColaItem1S18 => "18:ColaItem1",
+            State::ColaItem0S19 => "19:ColaItem0",
+            State::ColaItemS20 => "20:ColaItem",
+            State::EntityS21 => "21:Entity",
+            State::PluralEntityS22 => "22:PluralEntity",
+            State::SingularEntityS23 => "23:SingularEntity",
+            State::InlinePluralEntityS24 => "24:InlinePluralEntity",
+            State::IncludeS25 => "25:Include",
+            State::AnchorDefinitionS26 => "26:AnchorDefinition",
+            State::FieldListS27 => "27:FieldList",
+            State::FieldEntryS28 => "28:FieldEntry",
+            State::FieldS29 => "29:Field",
+            State::OptionalFieldS30 => "30:OptionalField",
+            State::RequiredFieldS31 => "31:RequiredField",
+            State::FieldDeclarationS32 => "32:FieldDeclaration",
+            State::OptionalDeclarationS33 => "33:OptionalDeclaration",
+            State::RequiredDeclarationS34 => "34:RequiredDeclaration",
+            State::ComputedFieldS35 => "35:ComputedField",
+            State::MarkdownItemS36 => "36:MarkdownItem",
+            State::RegularCodeLineS37 => "37:RegularCodeLine",
+            State::RegularCodeLine1S38 => "38:RegularCodeLine1",
+            State::RegularCodeLine0S39 => "39:RegularCodeLine0",
+            State::IdentifierS40 => "40:Identifier",
+            State::QuotedStringDoubleS41 => "41:QuotedStringDouble",
+            State::BangS42 => "42:Bang",
+            State::ColonS43 => "43:Colon",
+            State::EqualsS44 => "44:Equals",
+            State::PluralKeywordS45 => "45:PluralKeyword",
+            State::QuestionS46 => "46:Question",
+            State::ColaCodeEndS47 => "47:ColaCodeEnd",
+            State::ColaItemS48 => "48:ColaItem",
+            State::CommaS49 => "49:Comma",
+            State::RegularCodeLineS50 => "50:RegularCodeLine",
+            State::RegularCodeEndS51 => "51:RegularCodeEnd",
+            State::BooleanTrueS52 => "52:BooleanTrue",
+            State::BooleanFalseS53 => "53:BooleanFalse",
+            State::NullS54 => "54:Null",
+            State::NumberS55 => "55:Number",
+            State::OpenBracketS56 => "56:OpenBracket",
+            State::QuotedStringDoubleS57 => "57:QuotedStringDouble",
+            State::QuotedStringTripleS58 => "58:QuotedStringTriple",
+            State::QuotedStringSingleS59 => "59:QuotedStringSingle",
+            State::StarS60 => "60:Star",
+            State::AnchorReferenceS61 => "61:AnchorReference",
+            State::FieldValueS62 => "62:FieldValue",
+            State::ArrayValueS63 => "63:ArrayValue",
+            State::ColonS64 => "64:Colon",
+            State::IdentifierS65 => "65:Identifier",
+            State::OpenBraceS66 => "66:OpenBrace",
+            State::EntityS67 => "67:Entity",
+            State::EntityDefinitionS68 => "68:EntityDefinition",
+            State::NestedBlock1S69 => "69:NestedBlock1",
+            State::NestedBlock0S70 => "70:NestedBlock0",
+            State::NestedBlockS71 => "71:NestedBlock",
+            State::IncludeS72 => "72:Include",
+            State::AnchorDefinitionS73 => "73:AnchorDefinition",
+            State::FieldListS74 => "74:FieldList",
+            State::TypeAnnotationOptS75 => "75:TypeAnnotationOpt",
+            State::TypeNameS76 => "76:TypeName",
+            State::TypeAnnotationS77 => "77:TypeAnnotation",
+            State::IdentifierS78 => "78:Identifier",
+            State::NumberS79 => "79:Number",
+            State::OpenBracketS80 => "80:OpenBracket",
+            State::OpenParenS81 => "81:OpenParen",
+            State::AnchorReferenceS82 => "82:AnchorReference",
+            State::ExpressionS83 => "83:Expression",
+            State::IdentifierS84 => "84:Identifier",
+            State::ColonS85 => "85:Colon",
+            State::IdentifierS86 => "86:Identifier",
+            State::FieldEntryS87 => "87:FieldEntry",
+            State::FieldValueS88 => "88:FieldValue",
+            State::ArrayItemsOptS89 => "89:ArrayItemsOpt",
+            State::ArrayItemsS90 => "90:ArrayItems",
+            State::IdentifierS91 => "91:Identifier",
+            State::IdentifierS92 => "92:Identifier",
+            State::TypeAnnotationOptS93 => "93:TypeAnnotationOpt",
+            State::TypeNameS94 => "94:TypeName",
+            State::EqualsS95 => "95:Equals",
+            State::NestedBlock0S96 => "96:NestedBlock0",
+            State::SemicolonS97 => "97:Semicolon",
+            State::NestedBlockS98 => "98:NestedBlock",
+            State::FieldValueS99 => "99:FieldValue",
+            State::IdentifierS100 => "100:Identifier",
+            State::InlineInstancesOptS101 => "101:InlineInstancesOpt",
+            State::InlineInstancesS102 => "102:InlineInstances",
+            State::ExpressionS103 => "103:Expression",
+            State::MinusS104 => "104:Minus",
+            State::PlusS105 => "105:Plus",
+            State::SlashS106 => "106:Slash",
+            State::StarS107 => "107:Star",
+            State::KeyKeywordS108 => "108:KeyKeyword",
+            State::PluralKeyOptS109 => "109:PluralKeyOpt",
+            State::PluralKeyS110 => "110:PluralKey",
+            State::TypeAnnotationOptS111 => "111:TypeAnnotationOpt",
+            State::TypeNameS112 => "112:TypeName",
+            State::ColonS113 => "113:Colon",
+            State::EqualsS114 => "114:Equals",
+            State::CommaS115 => "115:Comma",
+            State::CloseBracketS116 => "116:CloseBracket",
+            State::EqualsS117 => "117:Equals",
+            State::FieldValueS118 => "118:FieldValue",
+            State::CloseBraceS119 => "119:CloseBrace",
+            State::CommaS120 => "120:Comma",
+            State::CloseBracketS121 => "121:CloseBracket",
+            State::CloseParenS122 => "122:CloseParen",
+            State::ExpressionS123 => "123:Expression",
+            State::ExpressionS124 => "124:Expression",
+            State::ExpressionS125 => "125:Expression",
+            State::ExpressionS126 => "126:Expression",
+            State::IdentifierS127 => "127:Identifier",
+            State::AsKeywordS128 => "128:AsKeyword",
+            State::PluralEnumOptS129 => "129:PluralEnumOpt",
+            State::PluralEnumS130 => "130:PluralEnum",
+            State::FieldValueS131 => "131:FieldValue",
+            State::ArrayItemsS132 => "132:ArrayItems",
+            State::InlineInstancesS133 => "133:InlineInstances",
+            State::EnumKeywordS134 => "134:EnumKeyword",
+            State::ColonS135 => "135:Colon",
+            State::EntityDefinitionS136 => "136:EntityDefinition",
+            State::SemicolonS137 => "137:Semicolon",
+            State::AUGLS138 => "138:AUGL",
+            State::CommentS139 => "139:Comment",
+            State::WSS140 => "140:WS",
+            State::LayoutS141 => "141:Layout",
+            State::LayoutItem1S142 => "142:LayoutItem1",
+            State::LayoutItem0S143 => "143:LayoutItem0",
+            State::LayoutItemS144 => "144:LayoutItem",
+            State::LayoutItemS145 => "145:LayoutItem",
         };
         write!(f, "{name}")
     }
@@ -405,24 +824,44 @@ pub enum Symbol {
 #[allow(clippy::upper_case_acronyms)]
 #[derive(Debug)]
 pub enum Terminal {
-    BooleanTrue,
-    BooleanFalse,
+    Ampersand,
+    AsKeyword,
+    Bang,
+    BooleanTrue(cola_actions::BooleanTrue),
+    BooleanFalse(cola_actions::BooleanFalse),
     ColaCodeStart(cola_actions::ColaCodeStart),
     ColaCodeEnd(cola_actions::ColaCodeEnd),
     Colon,
+    CloseBrace,
+    CloseBracket,
+    CloseParen,
     Comma,
+    EnumKeyword,
+    Equals,
+    IncludeKeyword,
     HeadingLine(cola_actions::HeadingLine),
     Identifier(cola_actions::Identifier),
+    KeyKeyword,
+    Minus,
+    Null(cola_actions::Null),
     Number(cola_actions::Number),
+    OpenBrace,
+    OpenBracket,
+    OpenParen,
     ParagraphLine(cola_actions::ParagraphLine),
     PluralKeyword,
+    Plus,
+    Question,
     QuotedStringDouble(cola_actions::QuotedStringDouble),
+    QuotedStringTriple(cola_actions::QuotedStringTriple),
     QuotedStringSingle(cola_actions::QuotedStringSingle),
     RegularCodeLine(cola_actions::RegularCodeLine),
     RegularCodeStartNamed(cola_actions::RegularCodeStartNamed),
     RegularCodeStartUnnamed(cola_actions::RegularCodeStartUnnamed),
     RegularCodeEnd(cola_actions::RegularCodeEnd),
     Semicolon,
+    Slash,
+    Star,
 }
 #[derive(Debug)]
 pub enum NonTerminal {
@@ -433,18 +872,43 @@ pub enum NonTerminal {
     CodeBlock(cola_actions::CodeBlock),
     ColaCodeBlock(cola_actions::ColaCodeBlock),
     ColaSyntax(cola_actions::ColaSyntax),
-    Entity1(cola_actions::Entity1),
-    Entity0(cola_actions::Entity0),
+    ColaItem1(cola_actions::ColaItem1),
+    ColaItem0(cola_actions::ColaItem0),
+    ColaItem(cola_actions::ColaItem),
     Entity(cola_actions::Entity),
     PluralEntity(cola_actions::PluralEntity),
+    PluralKeyOpt(cola_actions::PluralKeyOpt),
+    PluralEnumOpt(cola_actions::PluralEnumOpt),
     SingularEntity(cola_actions::SingularEntity),
+    InlinePluralEntity(cola_actions::InlinePluralEntity),
+    InlineInstancesOpt(cola_actions::InlineInstancesOpt),
+    InlineInstances(cola_actions::InlineInstances),
+    PluralKey(cola_actions::PluralKey),
+    PluralEnum(cola_actions::PluralEnum),
     EntityDefinition(cola_actions::EntityDefinition),
     NestedBlock1(cola_actions::NestedBlock1),
     NestedBlock0(cola_actions::NestedBlock0),
     NestedBlock(cola_actions::NestedBlock),
+    Include(cola_actions::Include),
+    AnchorDefinition(cola_actions::AnchorDefinition),
+    AnchorReference(cola_actions::AnchorReference),
     FieldList(cola_actions::FieldList),
+    FieldEntry(cola_actions::FieldEntry),
     Field(cola_actions::Field),
+    TypeAnnotationOpt(cola_actions::TypeAnnotationOpt),
+    OptionalField(cola_actions::OptionalField),
+    RequiredField(cola_actions::RequiredField),
+    FieldDeclaration(cola_actions::FieldDeclaration),
+    OptionalDeclaration(cola_actions::OptionalDeclaration),
+    RequiredDeclaration(cola_actions::RequiredDeclaration),
+    TypeName(cola_actions::TypeName),
+    ComputedField(cola_actions::ComputedField),
+    Expression(cola_actions::Expression),
+    TypeAnnotation(cola_actions::TypeAnnotation),
     FieldValue(cola_actions::FieldValue),
+    ArrayValue(cola_actions::ArrayValue),
+    ArrayItemsOpt(cola_actions::ArrayItemsOpt),
+    ArrayItems(cola_actions::ArrayItems),
     RegularCodeBlock(cola_actions::RegularCodeBlock),
     RegularCodeLine1(cola_actions::RegularCodeLine1),
     RegularCodeLine0(cola_actions::RegularCodeLine0),
@@ -471,8 +935,10 @@ fn action_aug_s0(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
 }
 fn action_colacodestart_s1(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::Entity0P2, 0usize)]),
-        TK::Identifier => Vec::from(&[Shift(State::IdentifierS14)]),
+        TK::Ampersand => Vec::from(&[Shift(State::AmpersandS14)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::ColaItem0P2, 0usize)]),
+        TK::IncludeKeyword => Vec::from(&[Shift(State::IncludeKeywordS15)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS16)]),
         _ => vec![],
     }
 }
@@ -587,61 +1053,234 @@ fn action_regularcodeblock_s12(token_kind: TokenKind) -> Vec<Action<State, ProdK
 }
 fn action_regularcodestart_s13(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::RegularCodeLine => Vec::from(&[Shift(State::RegularCodeLineS22)]),
+        TK::RegularCodeLine => Vec::from(&[Shift(State::RegularCodeLineS37)]),
         TK::RegularCodeEnd => Vec::from(&[Reduce(PK::RegularCodeLine0P2, 0usize)]),
         _ => vec![],
     }
 }
-fn action_identifier_s14(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_ampersand_s14(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS40)]),
+        _ => vec![],
+    }
+}
+fn action_includekeyword_s15(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::QuotedStringDouble => Vec::from(&[Shift(State::QuotedStringDoubleS41)]),
+        _ => vec![],
+    }
+}
+fn action_identifier_s16(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Colon => Vec::from(&[Shift(State::ColonS25)]),
-        TK::PluralKeyword => Vec::from(&[Shift(State::PluralKeywordS26)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS42)]),
+        TK::Colon => Vec::from(&[Shift(State::ColonS43)]),
+        TK::Equals => Vec::from(&[Shift(State::EqualsS44)]),
+        TK::PluralKeyword => Vec::from(&[Shift(State::PluralKeywordS45)]),
+        TK::Question => Vec::from(&[Shift(State::QuestionS46)]),
         _ => vec![],
     }
 }
-fn action_colasyntax_s15(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_colasyntax_s17(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::ColaCodeEnd => Vec::from(&[Shift(State::ColaCodeEndS27)]),
+        TK::ColaCodeEnd => Vec::from(&[Shift(State::ColaCodeEndS47)]),
         _ => vec![],
     }
 }
-fn action_entity1_s16(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_colaitem1_s18(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::Entity0P1, 1usize)]),
-        TK::Identifier => Vec::from(&[Shift(State::IdentifierS14)]),
+        TK::Ampersand => Vec::from(&[Shift(State::AmpersandS14)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::ColaItem0P1, 1usize)]),
+        TK::IncludeKeyword => Vec::from(&[Shift(State::IncludeKeywordS15)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS16)]),
         _ => vec![],
     }
 }
-fn action_entity0_s17(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_colaitem0_s19(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::ColaCodeEnd => Vec::from(&[Reduce(PK::ColaSyntaxP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_entity_s18(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_colaitem_s20(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Ampersand => Vec::from(&[Reduce(PK::ColaItem1P2, 1usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::ColaItem1P2, 1usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::ColaItem1P2, 1usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::ColaItem1P2, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_entity_s21(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::Entity1P2, 1usize)]),
-        TK::Identifier => Vec::from(&[Reduce(PK::Entity1P2, 1usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::ColaItemP1, 1usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::ColaItemP1, 1usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::ColaItemP1, 1usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::ColaItemP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_pluralentity_s19(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_pluralentity_s22(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
+        TK::Ampersand => Vec::from(&[Reduce(PK::EntityP1, 1usize)]),
         TK::ColaCodeEnd => Vec::from(&[Reduce(PK::EntityP1, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::EntityP1, 1usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::EntityP1, 1usize)]),
         TK::Identifier => Vec::from(&[Reduce(PK::EntityP1, 1usize)]),
         TK::Semicolon => Vec::from(&[Reduce(PK::EntityP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_singularentity_s20(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_singularentity_s23(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
+        TK::Ampersand => Vec::from(&[Reduce(PK::EntityP2, 1usize)]),
         TK::ColaCodeEnd => Vec::from(&[Reduce(PK::EntityP2, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::EntityP2, 1usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::EntityP2, 1usize)]),
         TK::Identifier => Vec::from(&[Reduce(PK::EntityP2, 1usize)]),
         TK::Semicolon => Vec::from(&[Reduce(PK::EntityP2, 1usize)]),
         _ => vec![],
     }
 }
-fn action_markdownitem_s21(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_inlinepluralentity_s24(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Ampersand => Vec::from(&[Reduce(PK::EntityP3, 1usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::EntityP3, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::EntityP3, 1usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::EntityP3, 1usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::EntityP3, 1usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::EntityP3, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_include_s25(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Ampersand => Vec::from(&[Reduce(PK::ColaItemP4, 1usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::ColaItemP4, 1usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::ColaItemP4, 1usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::ColaItemP4, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_anchordefinition_s26(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Ampersand => Vec::from(&[Reduce(PK::ColaItemP3, 1usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::ColaItemP3, 1usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::ColaItemP3, 1usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::ColaItemP3, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_fieldlist_s27(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Ampersand => Vec::from(&[Reduce(PK::ColaItemP2, 1usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::ColaItemP2, 1usize)]),
+        TK::Comma => Vec::from(&[Shift(State::CommaS49)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::ColaItemP2, 1usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::ColaItemP2, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_fieldentry_s28(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Ampersand => Vec::from(&[Reduce(PK::FieldListP1, 1usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::FieldListP1, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::FieldListP1, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::FieldListP1, 1usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::FieldListP1, 1usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::FieldListP1, 1usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::FieldListP1, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_field_s29(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Ampersand => Vec::from(&[Reduce(PK::FieldEntryP1, 1usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::FieldEntryP1, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::FieldEntryP1, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::FieldEntryP1, 1usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::FieldEntryP1, 1usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::FieldEntryP1, 1usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::FieldEntryP1, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_optionalfield_s30(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Ampersand => Vec::from(&[Reduce(PK::FieldEntryP2, 1usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::FieldEntryP2, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::FieldEntryP2, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::FieldEntryP2, 1usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::FieldEntryP2, 1usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::FieldEntryP2, 1usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::FieldEntryP2, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_requiredfield_s31(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Ampersand => Vec::from(&[Reduce(PK::FieldEntryP3, 1usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::FieldEntryP3, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::FieldEntryP3, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::FieldEntryP3, 1usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::FieldEntryP3, 1usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::FieldEntryP3, 1usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::FieldEntryP3, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_fielddeclaration_s32(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Ampersand => Vec::from(&[Reduce(PK::FieldEntryP5, 1usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::FieldEntryP5, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::FieldEntryP5, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::FieldEntryP5, 1usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::FieldEntryP5, 1usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::FieldEntryP5, 1usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::FieldEntryP5, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_optionaldeclaration_s33(
+    token_kind: TokenKind,
+) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Ampersand => Vec::from(&[Reduce(PK::FieldEntryP6, 1usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::FieldEntryP6, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::FieldEntryP6, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::FieldEntryP6, 1usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::FieldEntryP6, 1usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::FieldEntryP6, 1usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::FieldEntryP6, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_requireddeclaration_s34(
+    token_kind: TokenKind,
+) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Ampersand => Vec::from(&[Reduce(PK::FieldEntryP7, 1usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::FieldEntryP7, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::FieldEntryP7, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::FieldEntryP7, 1usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::FieldEntryP7, 1usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::FieldEntryP7, 1usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::FieldEntryP7, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_computedfield_s35(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Ampersand => Vec::from(&[Reduce(PK::FieldEntryP4, 1usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::FieldEntryP4, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::FieldEntryP4, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::FieldEntryP4, 1usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::FieldEntryP4, 1usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::FieldEntryP4, 1usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::FieldEntryP4, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_markdownitem_s36(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::MarkdownItem1P1, 2usize)]),
         TK::ColaCodeStart => Vec::from(&[Reduce(PK::MarkdownItem1P1, 2usize)]),
@@ -652,40 +1291,99 @@ fn action_markdownitem_s21(token_kind: TokenKind) -> Vec<Action<State, ProdKind>
         _ => vec![],
     }
 }
-fn action_regularcodeline_s22(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_regularcodeline_s37(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::RegularCodeLine => Vec::from(&[Reduce(PK::RegularCodeLine1P2, 1usize)]),
         TK::RegularCodeEnd => Vec::from(&[Reduce(PK::RegularCodeLine1P2, 1usize)]),
         _ => vec![],
     }
 }
-fn action_regularcodeline1_s23(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_regularcodeline1_s38(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::RegularCodeLine => Vec::from(&[Shift(State::RegularCodeLineS29)]),
+        TK::RegularCodeLine => Vec::from(&[Shift(State::RegularCodeLineS50)]),
         TK::RegularCodeEnd => Vec::from(&[Reduce(PK::RegularCodeLine0P1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_regularcodeline0_s24(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_regularcodeline0_s39(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::RegularCodeEnd => Vec::from(&[Shift(State::RegularCodeEndS51)]),
+        _ => vec![],
+    }
+}
+fn action_identifier_s40(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::BooleanTrue => Vec::from(&[Shift(State::BooleanTrueS52)]),
+        TK::BooleanFalse => Vec::from(&[Shift(State::BooleanFalseS53)]),
+        TK::Null => Vec::from(&[Shift(State::NullS54)]),
+        TK::Number => Vec::from(&[Shift(State::NumberS55)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS56)]),
+        TK::QuotedStringDouble => Vec::from(&[Shift(State::QuotedStringDoubleS57)]),
+        TK::QuotedStringTriple => Vec::from(&[Shift(State::QuotedStringTripleS58)]),
+        TK::QuotedStringSingle => Vec::from(&[Shift(State::QuotedStringSingleS59)]),
+        TK::Star => Vec::from(&[Shift(State::StarS60)]),
+        _ => vec![],
+    }
+}
+fn action_quotedstringdouble_s41(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Ampersand => Vec::from(&[Reduce(PK::IncludeP1, 2usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::IncludeP1, 2usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::IncludeP1, 2usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::IncludeP1, 2usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::IncludeP1, 2usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::IncludeP1, 2usize)]),
+        _ => vec![],
+    }
+}
+fn action_bang_s42(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::RegularCodeEnd => Vec::from(&[Shift(State::RegularCodeEndS30)]),
+        TK::Colon => Vec::from(&[Shift(State::ColonS64)]),
         _ => vec![],
     }
 }
-fn action_colon_s25(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_colon_s43(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Identifier => Vec::from(&[Shift(State::IdentifierS31)]),
+        TK::Ampersand => Vec::from(&[Shift(State::AmpersandS14)]),
+        TK::BooleanTrue => Vec::from(&[Reduce(PK::TypeAnnotationOptP2, 0usize)]),
+        TK::BooleanFalse => Vec::from(&[Reduce(PK::TypeAnnotationOptP2, 0usize)]),
+        TK::IncludeKeyword => Vec::from(&[Shift(State::IncludeKeywordS15)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS65)]),
+        TK::Null => Vec::from(&[Reduce(PK::TypeAnnotationOptP2, 0usize)]),
+        TK::Number => Vec::from(&[Reduce(PK::TypeAnnotationOptP2, 0usize)]),
+        TK::OpenBrace => Vec::from(&[Shift(State::OpenBraceS66)]),
+        TK::OpenBracket => Vec::from(&[Reduce(PK::TypeAnnotationOptP2, 0usize)]),
+        TK::QuotedStringDouble => Vec::from(&[Reduce(PK::TypeAnnotationOptP2, 0usize)]),
+        TK::QuotedStringTriple => Vec::from(&[Reduce(PK::TypeAnnotationOptP2, 0usize)]),
+        TK::QuotedStringSingle => Vec::from(&[Reduce(PK::TypeAnnotationOptP2, 0usize)]),
         TK::Semicolon => Vec::from(&[Reduce(PK::NestedBlock0P2, 0usize)]),
+        TK::Star => Vec::from(&[Reduce(PK::TypeAnnotationOptP2, 0usize)]),
+        _ => vec![],
+    }
+}
+fn action_equals_s44(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS78)]),
+        TK::Number => Vec::from(&[Shift(State::NumberS79)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS80)]),
+        TK::OpenParen => Vec::from(&[Shift(State::OpenParenS81)]),
+        TK::Star => Vec::from(&[Shift(State::StarS60)]),
+        _ => vec![],
+    }
+}
+fn action_pluralkeyword_s45(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS84)]),
         _ => vec![],
     }
 }
-fn action_pluralkeyword_s26(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_question_s46(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Identifier => Vec::from(&[Shift(State::IdentifierS39)]),
+        TK::Colon => Vec::from(&[Shift(State::ColonS85)]),
         _ => vec![],
     }
 }
-fn action_colacodeend_s27(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_colacodeend_s47(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::ColaCodeBlockP1, 3usize)]),
         TK::ColaCodeStart => Vec::from(&[Reduce(PK::ColaCodeBlockP1, 3usize)]),
@@ -696,21 +1394,29 @@ fn action_colacodeend_s27(token_kind: TokenKind) -> Vec<Action<State, ProdKind>>
         _ => vec![],
     }
 }
-fn action_entity_s28(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_colaitem_s48(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Ampersand => Vec::from(&[Reduce(PK::ColaItem1P1, 2usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::ColaItem1P1, 2usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::ColaItem1P1, 2usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::ColaItem1P1, 2usize)]),
+        _ => vec![],
+    }
+}
+fn action_comma_s49(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::Entity1P1, 2usize)]),
-        TK::Identifier => Vec::from(&[Reduce(PK::Entity1P1, 2usize)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS86)]),
         _ => vec![],
     }
 }
-fn action_regularcodeline_s29(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_regularcodeline_s50(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::RegularCodeLine => Vec::from(&[Reduce(PK::RegularCodeLine1P1, 2usize)]),
         TK::RegularCodeEnd => Vec::from(&[Reduce(PK::RegularCodeLine1P1, 2usize)]),
         _ => vec![],
     }
 }
-fn action_regularcodeend_s30(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_regularcodeend_s51(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
         TK::STOP => Vec::from(&[Reduce(PK::RegularCodeBlockP1, 3usize)]),
         TK::ColaCodeStart => Vec::from(&[Reduce(PK::RegularCodeBlockP1, 3usize)]),
@@ -723,418 +1429,1538 @@ fn action_regularcodeend_s30(token_kind: TokenKind) -> Vec<Action<State, ProdKin
         _ => vec![],
     }
 }
-fn action_identifier_s31(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_booleantrue_s52(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Colon => Vec::from(&[Shift(State::ColonS40)]),
-        TK::PluralKeyword => Vec::from(&[Shift(State::PluralKeywordS26)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::FieldValueP5, 1usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::FieldValueP5, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::FieldValueP5, 1usize)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::FieldValueP5, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::FieldValueP5, 1usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::FieldValueP5, 1usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::FieldValueP5, 1usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::FieldValueP5, 1usize)]),
         _ => vec![],
     }
 }
-fn action_entity_s32(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_booleanfalse_s53(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Identifier => Vec::from(&[Reduce(PK::NestedBlockP2, 1usize)]),
-        TK::Semicolon => Vec::from(&[Reduce(PK::NestedBlockP2, 1usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::FieldValueP6, 1usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::FieldValueP6, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::FieldValueP6, 1usize)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::FieldValueP6, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::FieldValueP6, 1usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::FieldValueP6, 1usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::FieldValueP6, 1usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::FieldValueP6, 1usize)]),
         _ => vec![],
     }
 }
-fn action_entitydefinition_s33(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_null_s54(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Semicolon => Vec::from(&[Shift(State::SemicolonS41)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::FieldValueP7, 1usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::FieldValueP7, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::FieldValueP7, 1usize)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::FieldValueP7, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::FieldValueP7, 1usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::FieldValueP7, 1usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::FieldValueP7, 1usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::FieldValueP7, 1usize)]),
         _ => vec![],
     }
 }
-fn action_nestedblock1_s34(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_number_s55(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Identifier => Vec::from(&[Shift(State::IdentifierS31)]),
-        TK::Semicolon => Vec::from(&[Reduce(PK::NestedBlock0P1, 1usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::FieldValueP4, 1usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::FieldValueP4, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::FieldValueP4, 1usize)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::FieldValueP4, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::FieldValueP4, 1usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::FieldValueP4, 1usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::FieldValueP4, 1usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::FieldValueP4, 1usize)]),
         _ => vec![],
     }
 }
-fn action_nestedblock0_s35(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_openbracket_s56(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Semicolon => Vec::from(&[Reduce(PK::EntityDefinitionP1, 1usize)]),
+        TK::BooleanTrue => Vec::from(&[Shift(State::BooleanTrueS52)]),
+        TK::BooleanFalse => Vec::from(&[Shift(State::BooleanFalseS53)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ArrayItemsOptP2, 0usize)]),
+        TK::Null => Vec::from(&[Shift(State::NullS54)]),
+        TK::Number => Vec::from(&[Shift(State::NumberS55)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS56)]),
+        TK::QuotedStringDouble => Vec::from(&[Shift(State::QuotedStringDoubleS57)]),
+        TK::QuotedStringTriple => Vec::from(&[Shift(State::QuotedStringTripleS58)]),
+        TK::QuotedStringSingle => Vec::from(&[Shift(State::QuotedStringSingleS59)]),
+        TK::Star => Vec::from(&[Shift(State::StarS60)]),
         _ => vec![],
     }
 }
-fn action_nestedblock_s36(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_quotedstringdouble_s57(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Identifier => Vec::from(&[Reduce(PK::NestedBlock1P2, 1usize)]),
-        TK::Semicolon => Vec::from(&[Reduce(PK::NestedBlock1P2, 1usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::FieldValueP2, 1usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::FieldValueP2, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::FieldValueP2, 1usize)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::FieldValueP2, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::FieldValueP2, 1usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::FieldValueP2, 1usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::FieldValueP2, 1usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::FieldValueP2, 1usize)]),
         _ => vec![],
     }
 }
-fn action_fieldlist_s37(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_quotedstringtriple_s58(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Comma => Vec::from(&[Shift(State::CommaS43)]),
-        TK::Identifier => Vec::from(&[Reduce(PK::NestedBlockP1, 1usize)]),
-        TK::Semicolon => Vec::from(&[Reduce(PK::NestedBlockP1, 1usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::FieldValueP1, 1usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::FieldValueP1, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::FieldValueP1, 1usize)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::FieldValueP1, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::FieldValueP1, 1usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::FieldValueP1, 1usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::FieldValueP1, 1usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::FieldValueP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_field_s38(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_quotedstringsingle_s59(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Comma => Vec::from(&[Reduce(PK::FieldListP1, 1usize)]),
-        TK::Identifier => Vec::from(&[Reduce(PK::FieldListP1, 1usize)]),
-        TK::Semicolon => Vec::from(&[Reduce(PK::FieldListP1, 1usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::FieldValueP3, 1usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::FieldValueP3, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::FieldValueP3, 1usize)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::FieldValueP3, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::FieldValueP3, 1usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::FieldValueP3, 1usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::FieldValueP3, 1usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::FieldValueP3, 1usize)]),
         _ => vec![],
     }
 }
-fn action_identifier_s39(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_star_s60(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Colon => Vec::from(&[Shift(State::ColonS44)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS91)]),
         _ => vec![],
     }
 }
-fn action_colon_s40(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_anchorreference_s61(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::BooleanTrue => Vec::from(&[Shift(State::BooleanTrueS45)]),
-        TK::BooleanFalse => Vec::from(&[Shift(State::BooleanFalseS46)]),
-        TK::Identifier => Vec::from(&[Shift(State::IdentifierS31)]),
-        TK::Number => Vec::from(&[Shift(State::NumberS47)]),
-        TK::QuotedStringDouble => Vec::from(&[Shift(State::QuotedStringDoubleS48)]),
-        TK::QuotedStringSingle => Vec::from(&[Shift(State::QuotedStringSingleS49)]),
-        TK::Semicolon => Vec::from(&[Reduce(PK::NestedBlock0P2, 0usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::FieldValueP9, 1usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::FieldValueP9, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::FieldValueP9, 1usize)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::FieldValueP9, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::FieldValueP9, 1usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::FieldValueP9, 1usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::FieldValueP9, 1usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::FieldValueP9, 1usize)]),
         _ => vec![],
     }
 }
-fn action_semicolon_s41(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_fieldvalue_s62(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::SingularEntityP1, 4usize)]),
-        TK::Identifier => Vec::from(&[Reduce(PK::SingularEntityP1, 4usize)]),
-        TK::Semicolon => Vec::from(&[Reduce(PK::SingularEntityP1, 4usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::AnchorDefinitionP1, 3usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::AnchorDefinitionP1, 3usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::AnchorDefinitionP1, 3usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::AnchorDefinitionP1, 3usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::AnchorDefinitionP1, 3usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::AnchorDefinitionP1, 3usize)]),
         _ => vec![],
     }
 }
-fn action_nestedblock_s42(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_arrayvalue_s63(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Identifier => Vec::from(&[Reduce(PK::NestedBlock1P1, 2usize)]),
-        TK::Semicolon => Vec::from(&[Reduce(PK::NestedBlock1P1, 2usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::FieldValueP8, 1usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::FieldValueP8, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::FieldValueP8, 1usize)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::FieldValueP8, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::FieldValueP8, 1usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::FieldValueP8, 1usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::FieldValueP8, 1usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::FieldValueP8, 1usize)]),
         _ => vec![],
     }
 }
-fn action_comma_s43(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_colon_s64(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Identifier => Vec::from(&[Shift(State::IdentifierS51)]),
+        TK::BooleanTrue => Vec::from(&[Reduce(PK::TypeAnnotationOptP2, 0usize)]),
+        TK::BooleanFalse => Vec::from(&[Reduce(PK::TypeAnnotationOptP2, 0usize)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS92)]),
+        TK::Null => Vec::from(&[Reduce(PK::TypeAnnotationOptP2, 0usize)]),
+        TK::Number => Vec::from(&[Reduce(PK::TypeAnnotationOptP2, 0usize)]),
+        TK::OpenBracket => Vec::from(&[Reduce(PK::TypeAnnotationOptP2, 0usize)]),
+        TK::QuotedStringDouble => Vec::from(&[Reduce(PK::TypeAnnotationOptP2, 0usize)]),
+        TK::QuotedStringTriple => Vec::from(&[Reduce(PK::TypeAnnotationOptP2, 0usize)]),
+        TK::QuotedStringSingle => Vec::from(&[Reduce(PK::TypeAnnotationOptP2, 0usize)]),
+        TK::Star => Vec::from(&[Reduce(PK::TypeAnnotationOptP2, 0usize)]),
         _ => vec![],
     }
 }
-fn action_colon_s44(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_identifier_s65(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Identifier => Vec::from(&[Shift(State::IdentifierS31)]),
-        TK::Semicolon => Vec::from(&[Reduce(PK::NestedBlock0P2, 0usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::TypeNameP1, 1usize)]),
+        TK::Bang => Vec::from(&[Shift(State::BangS42)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::TypeNameP1, 1usize)]),
+        TK::Colon => Vec::from(&[Shift(State::ColonS43)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::TypeNameP1, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::TypeNameP1, 1usize)]),
+        TK::Equals => Vec::from(&[Shift(State::EqualsS95)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::TypeNameP1, 1usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::TypeNameP1, 1usize)]),
+        TK::PluralKeyword => Vec::from(&[Shift(State::PluralKeywordS45)]),
+        TK::Question => Vec::from(&[Shift(State::QuestionS46)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::TypeNameP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_booleantrue_s45(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_openbrace_s66(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Comma => Vec::from(&[Reduce(PK::FieldValueP4, 1usize)]),
-        TK::Identifier => Vec::from(&[Reduce(PK::FieldValueP4, 1usize)]),
-        TK::Semicolon => Vec::from(&[Reduce(PK::FieldValueP4, 1usize)]),
+        TK::Ampersand => Vec::from(&[Shift(State::AmpersandS14)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::NestedBlock0P2, 0usize)]),
+        TK::IncludeKeyword => Vec::from(&[Shift(State::IncludeKeywordS15)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS16)]),
         _ => vec![],
     }
 }
-fn action_booleanfalse_s46(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_entity_s67(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Comma => Vec::from(&[Reduce(PK::FieldValueP5, 1usize)]),
-        TK::Identifier => Vec::from(&[Reduce(PK::FieldValueP5, 1usize)]),
-        TK::Semicolon => Vec::from(&[Reduce(PK::FieldValueP5, 1usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::NestedBlockP2, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::NestedBlockP2, 1usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::NestedBlockP2, 1usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::NestedBlockP2, 1usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::NestedBlockP2, 1usize)]),
         _ => vec![],
     }
 }
-fn action_number_s47(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_entitydefinition_s68(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Comma => Vec::from(&[Reduce(PK::FieldValueP3, 1usize)]),
-        TK::Identifier => Vec::from(&[Reduce(PK::FieldValueP3, 1usize)]),
-        TK::Semicolon => Vec::from(&[Reduce(PK::FieldValueP3, 1usize)]),
+        TK::Semicolon => Vec::from(&[Shift(State::SemicolonS97)]),
         _ => vec![],
     }
 }
-fn action_quotedstringdouble_s48(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_nestedblock1_s69(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Comma => Vec::from(&[Reduce(PK::FieldValueP1, 1usize)]),
-        TK::Identifier => Vec::from(&[Reduce(PK::FieldValueP1, 1usize)]),
-        TK::Semicolon => Vec::from(&[Reduce(PK::FieldValueP1, 1usize)]),
+        TK::Ampersand => Vec::from(&[Shift(State::AmpersandS14)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::NestedBlock0P1, 1usize)]),
+        TK::IncludeKeyword => Vec::from(&[Shift(State::IncludeKeywordS15)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS16)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::NestedBlock0P1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_quotedstringsingle_s49(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_nestedblock0_s70(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Comma => Vec::from(&[Reduce(PK::FieldValueP2, 1usize)]),
-        TK::Identifier => Vec::from(&[Reduce(PK::FieldValueP2, 1usize)]),
-        TK::Semicolon => Vec::from(&[Reduce(PK::FieldValueP2, 1usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::EntityDefinitionPlain, 1usize)]),
         _ => vec![],
     }
 }
-fn action_fieldvalue_s50(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_nestedblock_s71(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Comma => Vec::from(&[Reduce(PK::FieldP1, 3usize)]),
-        TK::Identifier => Vec::from(&[Reduce(PK::FieldP1, 3usize)]),
-        TK::Semicolon => Vec::from(&[Reduce(PK::FieldP1, 3usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::NestedBlock1P2, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::NestedBlock1P2, 1usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::NestedBlock1P2, 1usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::NestedBlock1P2, 1usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::NestedBlock1P2, 1usize)]),
         _ => vec![],
     }
 }
-fn action_identifier_s51(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_include_s72(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Colon => Vec::from(&[Shift(State::ColonS54)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::NestedBlockP4, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::NestedBlockP4, 1usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::NestedBlockP4, 1usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::NestedBlockP4, 1usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::NestedBlockP4, 1usize)]),
         _ => vec![],
     }
 }
-fn action_field_s52(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_anchordefinition_s73(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Comma => Vec::from(&[Reduce(PK::FieldListP2, 3usize)]),
-        TK::Identifier => Vec::from(&[Reduce(PK::FieldListP2, 3usize)]),
-        TK::Semicolon => Vec::from(&[Reduce(PK::FieldListP2, 3usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::NestedBlockP3, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::NestedBlockP3, 1usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::NestedBlockP3, 1usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::NestedBlockP3, 1usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::NestedBlockP3, 1usize)]),
         _ => vec![],
     }
 }
-fn action_entitydefinition_s53(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_fieldlist_s74(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::Semicolon => Vec::from(&[Shift(State::SemicolonS55)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::NestedBlockP1, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::NestedBlockP1, 1usize)]),
+        TK::Comma => Vec::from(&[Shift(State::CommaS49)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::NestedBlockP1, 1usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::NestedBlockP1, 1usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::NestedBlockP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_colon_s54(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_typeannotationopt_s75(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::BooleanTrue => Vec::from(&[Shift(State::BooleanTrueS45)]),
-        TK::BooleanFalse => Vec::from(&[Shift(State::BooleanFalseS46)]),
-        TK::Number => Vec::from(&[Shift(State::NumberS47)]),
-        TK::QuotedStringDouble => Vec::from(&[Shift(State::QuotedStringDoubleS48)]),
-        TK::QuotedStringSingle => Vec::from(&[Shift(State::QuotedStringSingleS49)]),
+        TK::BooleanTrue => Vec::from(&[Shift(State::BooleanTrueS52)]),
+        TK::BooleanFalse => Vec::from(&[Shift(State::BooleanFalseS53)]),
+        TK::Null => Vec::from(&[Shift(State::NullS54)]),
+        TK::Number => Vec::from(&[Shift(State::NumberS55)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS56)]),
+        TK::QuotedStringDouble => Vec::from(&[Shift(State::QuotedStringDoubleS57)]),
+        TK::QuotedStringTriple => Vec::from(&[Shift(State::QuotedStringTripleS58)]),
+        TK::QuotedStringSingle => Vec::from(&[Shift(State::QuotedStringSingleS59)]),
+        TK::Star => Vec::from(&[Shift(State::StarS60)]),
         _ => vec![],
     }
 }
-fn action_semicolon_s55(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_typename_s76(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::PluralEntityP1, 6usize)]),
-        TK::Identifier => Vec::from(&[Reduce(PK::PluralEntityP1, 6usize)]),
-        TK::Semicolon => Vec::from(&[Reduce(PK::PluralEntityP1, 6usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::FieldDeclarationP1, 3usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::FieldDeclarationP1, 3usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::FieldDeclarationP1, 3usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::FieldDeclarationP1, 3usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::FieldDeclarationP1, 3usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::FieldDeclarationP1, 3usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::FieldDeclarationP1, 3usize)]),
         _ => vec![],
     }
 }
-fn action_augl_s56(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_typeannotation_s77(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::LayoutP2, 0usize)]),
-        TK::WS => Vec::from(&[Shift(State::WSS57)]),
+        TK::BooleanTrue => Vec::from(&[Reduce(PK::TypeAnnotationOptP1, 1usize)]),
+        TK::BooleanFalse => Vec::from(&[Reduce(PK::TypeAnnotationOptP1, 1usize)]),
+        TK::Null => Vec::from(&[Reduce(PK::TypeAnnotationOptP1, 1usize)]),
+        TK::Number => Vec::from(&[Reduce(PK::TypeAnnotationOptP1, 1usize)]),
+        TK::OpenBracket => Vec::from(&[Reduce(PK::TypeAnnotationOptP1, 1usize)]),
+        TK::QuotedStringDouble => Vec::from(&[Reduce(PK::TypeAnnotationOptP1, 1usize)]),
+        TK::QuotedStringTriple => Vec::from(&[Reduce(PK::TypeAnnotationOptP1, 1usize)]),
+        TK::QuotedStringSingle => Vec::from(&[Reduce(PK::TypeAnnotationOptP1, 1usize)]),
+        TK::Star => Vec::from(&[Reduce(PK::TypeAnnotationOptP1, 1usize)]),
         _ => vec![],
     }
 }
-fn action_ws_s57(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_identifier_s78(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::WS1P2, 1usize)]),
-        TK::WS => Vec::from(&[Reduce(PK::WS1P2, 1usize)]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::ExpressionP7, 1usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::ExpressionP7, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionP7, 1usize)]),
+        TK::CloseParen => Vec::from(&[Reduce(PK::ExpressionP7, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionP7, 1usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::ExpressionP7, 1usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::ExpressionP7, 1usize)]),
+        TK::Minus => Vec::from(&[Reduce(PK::ExpressionP7, 1usize)]),
+        TK::Plus => Vec::from(&[Reduce(PK::ExpressionP7, 1usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::ExpressionP7, 1usize)]),
+        TK::Slash => Vec::from(&[Reduce(PK::ExpressionP7, 1usize)]),
+        TK::Star => Vec::from(&[Reduce(PK::ExpressionP7, 1usize)]),
         _ => vec![],
     }
 }
-fn action_layout_s58(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_number_s79(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Accept]),
+        TK::Ampersand => Vec::from(&[Reduce(PK::ExpressionP6, 1usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::ExpressionP6, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionP6, 1usize)]),
+        TK::CloseParen => Vec::from(&[Reduce(PK::ExpressionP6, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionP6, 1usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::ExpressionP6, 1usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::ExpressionP6, 1usize)]),
+        TK::Minus => Vec::from(&[Reduce(PK::ExpressionP6, 1usize)]),
+        TK::Plus => Vec::from(&[Reduce(PK::ExpressionP6, 1usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::ExpressionP6, 1usize)]),
+        TK::Slash => Vec::from(&[Reduce(PK::ExpressionP6, 1usize)]),
+        TK::Star => Vec::from(&[Reduce(PK::ExpressionP6, 1usize)]),
         _ => vec![],
     }
 }
-fn action_ws1_s59(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_openbracket_s80(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::LayoutP1, 1usize)]),
-        TK::WS => Vec::from(&[Shift(State::WSS60)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::InlineInstancesOptP2, 0usize)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS100)]),
         _ => vec![],
     }
 }
-fn action_ws_s60(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+fn action_openparen_s81(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
     match token_kind {
-        TK::STOP => Vec::from(&[Reduce(PK::WS1P1, 2usize)]),
-        TK::WS => Vec::from(&[Reduce(PK::WS1P1, 2usize)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS78)]),
+        TK::Number => Vec::from(&[Shift(State::NumberS79)]),
+        TK::OpenParen => Vec::from(&[Shift(State::OpenParenS81)]),
+        TK::Star => Vec::from(&[Shift(State::StarS60)]),
         _ => vec![],
     }
 }
-fn goto_aug_s0(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Cola => State::ColaS6,
-        NonTermKind::MarkdownItem1 => State::MarkdownItem1S7,
-        NonTermKind::MarkdownItem0 => State::MarkdownItem0S8,
-        NonTermKind::MarkdownItem => State::MarkdownItemS9,
-        NonTermKind::CodeBlock => State::CodeBlockS10,
-        NonTermKind::ColaCodeBlock => State::ColaCodeBlockS11,
-        NonTermKind::RegularCodeBlock => State::RegularCodeBlockS12,
-        NonTermKind::RegularCodeStart => State::RegularCodeStartS13,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::AUGS0
-            )
-        }
+fn action_anchorreference_s82(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Ampersand => Vec::from(&[Reduce(PK::ExpressionP8, 1usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::ExpressionP8, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionP8, 1usize)]),
+        TK::CloseParen => Vec::from(&[Reduce(PK::ExpressionP8, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionP8, 1usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::ExpressionP8, 1usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::ExpressionP8, 1usize)]),
+        TK::Minus => Vec::from(&[Reduce(PK::ExpressionP8, 1usize)]),
+        TK::Plus => Vec::from(&[Reduce(PK::ExpressionP8, 1usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::ExpressionP8, 1usize)]),
+        TK::Slash => Vec::from(&[Reduce(PK::ExpressionP8, 1usize)]),
+        TK::Star => Vec::from(&[Reduce(PK::ExpressionP8, 1usize)]),
+        _ => vec![],
     }
 }
-fn goto_colacodestart_s1(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::ColaSyntax => State::ColaSyntaxS15,
-        NonTermKind::Entity1 => State::Entity1S16,
-        NonTermKind::Entity0 => State::Entity0S17,
-        NonTermKind::Entity => State::EntityS18,
-        NonTermKind::PluralEntity => State::PluralEntityS19,
-        NonTermKind::SingularEntity => State::SingularEntityS20,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::ColaCodeStartS1
-            )
-        }
+fn action_expression_s83(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Ampersand => Vec::from(&[Reduce(PK::ComputedFieldP1, 3usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::ComputedFieldP1, 3usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ComputedFieldP1, 3usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ComputedFieldP1, 3usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::ComputedFieldP1, 3usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::ComputedFieldP1, 3usize)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS104)]),
+        TK::Plus => Vec::from(&[Shift(State::PlusS105)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::ComputedFieldP1, 3usize)]),
+        TK::Slash => Vec::from(&[Shift(State::SlashS106)]),
+        TK::Star => Vec::from(&[Shift(State::StarS107)]),
+        _ => vec![],
     }
 }
-fn goto_markdownitem1_s7(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::MarkdownItem => State::MarkdownItemS21,
-        NonTermKind::CodeBlock => State::CodeBlockS10,
-        NonTermKind::ColaCodeBlock => State::ColaCodeBlockS11,
-        NonTermKind::RegularCodeBlock => State::RegularCodeBlockS12,
-        NonTermKind::RegularCodeStart => State::RegularCodeStartS13,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::MarkdownItem1S7
-            )
-        }
+fn action_identifier_s84(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::AsKeyword => Vec::from(&[Reduce(PK::PluralKeyOptP2, 0usize)]),
+        TK::Colon => Vec::from(&[Reduce(PK::PluralKeyOptP2, 0usize)]),
+        TK::KeyKeyword => Vec::from(&[Shift(State::KeyKeywordS108)]),
+        _ => vec![],
     }
 }
-fn goto_regularcodestart_s13(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::RegularCodeLine1 => State::RegularCodeLine1S23,
-        NonTermKind::RegularCodeLine0 => State::RegularCodeLine0S24,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::RegularCodeStartS13
-            )
-        }
+fn action_colon_s85(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::BooleanTrue => Vec::from(&[Reduce(PK::TypeAnnotationOptP2, 0usize)]),
+        TK::BooleanFalse => Vec::from(&[Reduce(PK::TypeAnnotationOptP2, 0usize)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS92)]),
+        TK::Null => Vec::from(&[Reduce(PK::TypeAnnotationOptP2, 0usize)]),
+        TK::Number => Vec::from(&[Reduce(PK::TypeAnnotationOptP2, 0usize)]),
+        TK::OpenBracket => Vec::from(&[Reduce(PK::TypeAnnotationOptP2, 0usize)]),
+        TK::QuotedStringDouble => Vec::from(&[Reduce(PK::TypeAnnotationOptP2, 0usize)]),
+        TK::QuotedStringTriple => Vec::from(&[Reduce(PK::TypeAnnotationOptP2, 0usize)]),
+        TK::QuotedStringSingle => Vec::from(&[Reduce(PK::TypeAnnotationOptP2, 0usize)]),
+        TK::Star => Vec::from(&[Reduce(PK::TypeAnnotationOptP2, 0usize)]),
+        _ => vec![],
     }
 }
-fn goto_entity1_s16(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Entity => State::EntityS28,
-        NonTermKind::PluralEntity => State::PluralEntityS19,
-        NonTermKind::SingularEntity => State::SingularEntityS20,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::Entity1S16
-            )
-        }
+fn action_identifier_s86(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Bang => Vec::from(&[Shift(State::BangS42)]),
+        TK::Colon => Vec::from(&[Shift(State::ColonS113)]),
+        TK::Equals => Vec::from(&[Shift(State::EqualsS114)]),
+        TK::Question => Vec::from(&[Shift(State::QuestionS46)]),
+        _ => vec![],
     }
 }
-fn goto_colon_s25(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Entity => State::EntityS32,
-        NonTermKind::PluralEntity => State::PluralEntityS19,
-        NonTermKind::SingularEntity => State::SingularEntityS20,
-        NonTermKind::EntityDefinition => State::EntityDefinitionS33,
-        NonTermKind::NestedBlock1 => State::NestedBlock1S34,
-        NonTermKind::NestedBlock0 => State::NestedBlock0S35,
-        NonTermKind::NestedBlock => State::NestedBlockS36,
-        NonTermKind::FieldList => State::FieldListS37,
-        NonTermKind::Field => State::FieldS38,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::ColonS25
-            )
-        }
+fn action_fieldentry_s87(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Ampersand => Vec::from(&[Reduce(PK::FieldListP2, 3usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::FieldListP2, 3usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::FieldListP2, 3usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::FieldListP2, 3usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::FieldListP2, 3usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::FieldListP2, 3usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::FieldListP2, 3usize)]),
+        _ => vec![],
     }
 }
-fn goto_nestedblock1_s34(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Entity => State::EntityS32,
-        NonTermKind::PluralEntity => State::PluralEntityS19,
-        NonTermKind::SingularEntity => State::SingularEntityS20,
-        NonTermKind::NestedBlock => State::NestedBlockS42,
-        NonTermKind::FieldList => State::FieldListS37,
-        NonTermKind::Field => State::FieldS38,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::NestedBlock1S34
-            )
-        }
+fn action_fieldvalue_s88(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ArrayItemsP1, 1usize)]),
+        TK::Comma => Vec::from(&[Shift(State::CommaS115)]),
+        _ => vec![],
     }
 }
-fn goto_colon_s40(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Entity => State::EntityS32,
-        NonTermKind::PluralEntity => State::PluralEntityS19,
-        NonTermKind::SingularEntity => State::SingularEntityS20,
-        NonTermKind::EntityDefinition => State::EntityDefinitionS33,
-        NonTermKind::NestedBlock1 => State::NestedBlock1S34,
-        NonTermKind::NestedBlock0 => State::NestedBlock0S35,
-        NonTermKind::NestedBlock => State::NestedBlockS36,
-        NonTermKind::FieldList => State::FieldListS37,
-        NonTermKind::Field => State::FieldS38,
-        NonTermKind::FieldValue => State::FieldValueS50,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::ColonS40
-            )
-        }
+fn action_arrayitemsopt_s89(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CloseBracket => Vec::from(&[Shift(State::CloseBracketS116)]),
+        _ => vec![],
     }
 }
-fn goto_comma_s43(nonterm_kind: NonTermKind) -> State {
-    match nonterm_kind {
-        NonTermKind::Field => State::FieldS52,
-        _ => {
-            panic!(
-                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::CommaS43
-            )
-        }
+fn action_arrayitems_s90(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ArrayItemsOptP1, 1usize)]),
+        _ => vec![],
     }
 }
-fn goto_colon_s44(nonterm_kind: NonTermKind) -> State {
+fn action_identifier_s91(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Ampersand => Vec::from(&[Reduce(PK::AnchorReferenceP1, 2usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::AnchorReferenceP1, 2usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::AnchorReferenceP1, 2usize)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::AnchorReferenceP1, 2usize)]),
+        TK::CloseParen => Vec::from(&[Reduce(PK::AnchorReferenceP1, 2usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::AnchorReferenceP1, 2usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::AnchorReferenceP1, 2usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::AnchorReferenceP1, 2usize)]),
+        TK::Minus => Vec::from(&[Reduce(PK::AnchorReferenceP1, 2usize)]),
+        TK::Plus => Vec::from(&[Reduce(PK::AnchorReferenceP1, 2usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::AnchorReferenceP1, 2usize)]),
+        TK::Slash => Vec::from(&[Reduce(PK::AnchorReferenceP1, 2usize)]),
+        TK::Star => Vec::from(&[Reduce(PK::AnchorReferenceP1, 2usize)]),
+        _ => vec![],
+    }
+}
+fn action_identifier_s92(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Ampersand => Vec::from(&[Reduce(PK::TypeNameP1, 1usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::TypeNameP1, 1usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::TypeNameP1, 1usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::TypeNameP1, 1usize)]),
+        TK::Equals => Vec::from(&[Shift(State::EqualsS117)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::TypeNameP1, 1usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::TypeNameP1, 1usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::TypeNameP1, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_typeannotationopt_s93(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::BooleanTrue => Vec::from(&[Shift(State::BooleanTrueS52)]),
+        TK::BooleanFalse => Vec::from(&[Shift(State::BooleanFalseS53)]),
+        TK::Null => Vec::from(&[Shift(State::NullS54)]),
+        TK::Number => Vec::from(&[Shift(State::NumberS55)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS56)]),
+        TK::QuotedStringDouble => Vec::from(&[Shift(State::QuotedStringDoubleS57)]),
+        TK::QuotedStringTriple => Vec::from(&[Shift(State::QuotedStringTripleS58)]),
+        TK::QuotedStringSingle => Vec::from(&[Shift(State::QuotedStringSingleS59)]),
+        TK::Star => Vec::from(&[Shift(State::StarS60)]),
+        _ => vec![],
+    }
+}
+fn action_typename_s94(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Ampersand => Vec::from(&[Reduce(PK::RequiredDeclarationP1, 4usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::RequiredDeclarationP1, 4usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::RequiredDeclarationP1, 4usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::RequiredDeclarationP1, 4usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::RequiredDeclarationP1, 4usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::RequiredDeclarationP1, 4usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::RequiredDeclarationP1, 4usize)]),
+        _ => vec![],
+    }
+}
+fn action_equals_s95(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::BooleanTrue => Vec::from(&[Reduce(PK::TypeAnnotationP1, 2usize)]),
+        TK::BooleanFalse => Vec::from(&[Reduce(PK::TypeAnnotationP1, 2usize)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS78)]),
+        TK::Null => Vec::from(&[Reduce(PK::TypeAnnotationP1, 2usize)]),
+        TK::Number => Vec::from(&[Reduce(PK::TypeAnnotationP1, 2usize)]),
+        TK::OpenBracket => Vec::from(&[Reduce(PK::TypeAnnotationP1, 2usize)]),
+        TK::OpenParen => Vec::from(&[Shift(State::OpenParenS81)]),
+        TK::QuotedStringDouble => Vec::from(&[Reduce(PK::TypeAnnotationP1, 2usize)]),
+        TK::QuotedStringTriple => Vec::from(&[Reduce(PK::TypeAnnotationP1, 2usize)]),
+        TK::QuotedStringSingle => Vec::from(&[Reduce(PK::TypeAnnotationP1, 2usize)]),
+        TK::Star => Vec::from(&[Reduce(PK::TypeAnnotationP1, 2usize)]),
+        _ => vec![],
+    }
+}
+fn action_nestedblock0_s96(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CloseBrace => Vec::from(&[Shift(State::CloseBraceS119)]),
+        _ => vec![],
+    }
+}
+fn action_semicolon_s97(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Ampersand => Vec::from(&[Reduce(PK::SingularEntityP1, 4usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::SingularEntityP1, 4usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::SingularEntityP1, 4usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::SingularEntityP1, 4usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::SingularEntityP1, 4usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::SingularEntityP1, 4usize)]),
+        _ => vec![],
+    }
+}
+fn action_nestedblock_s98(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Ampersand => Vec::from(&[Reduce(PK::NestedBlock1P1, 2usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::NestedBlock1P1, 2usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::NestedBlock1P1, 2usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::NestedBlock1P1, 2usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::NestedBlock1P1, 2usize)]),
+        _ => vec![],
+    }
+}
+fn action_fieldvalue_s99(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Ampersand => Vec::from(&[Reduce(PK::FieldP1, 4usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::FieldP1, 4usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::FieldP1, 4usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::FieldP1, 4usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::FieldP1, 4usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::FieldP1, 4usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::FieldP1, 4usize)]),
+        _ => vec![],
+    }
+}
+fn action_identifier_s100(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CloseBracket => Vec::from(&[Reduce(PK::InlineInstancesP1, 1usize)]),
+        TK::Comma => Vec::from(&[Shift(State::CommaS120)]),
+        _ => vec![],
+    }
+}
+fn action_inlineinstancesopt_s101(
+    token_kind: TokenKind,
+) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CloseBracket => Vec::from(&[Shift(State::CloseBracketS121)]),
+        _ => vec![],
+    }
+}
+fn action_inlineinstances_s102(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CloseBracket => Vec::from(&[Reduce(PK::InlineInstancesOptP1, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_expression_s103(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CloseParen => Vec::from(&[Shift(State::CloseParenS122)]),
+        TK::Minus => Vec::from(&[Shift(State::MinusS104)]),
+        TK::Plus => Vec::from(&[Shift(State::PlusS105)]),
+        TK::Slash => Vec::from(&[Shift(State::SlashS106)]),
+        TK::Star => Vec::from(&[Shift(State::StarS107)]),
+        _ => vec![],
+    }
+}
+fn action_minus_s104(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS78)]),
+        TK::Number => Vec::from(&[Shift(State::NumberS79)]),
+        TK::OpenParen => Vec::from(&[Shift(State::OpenParenS81)]),
+        TK::Star => Vec::from(&[Shift(State::StarS60)]),
+        _ => vec![],
+    }
+}
+fn action_plus_s105(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS78)]),
+        TK::Number => Vec::from(&[Shift(State::NumberS79)]),
+        TK::OpenParen => Vec::from(&[Shift(State::OpenParenS81)]),
+        TK::Star => Vec::from(&[Shift(State::StarS60)]),
+        _ => vec![],
+    }
+}
+fn action_slash_s106(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS78)]),
+        TK::Number => Vec::from(&[Shift(State::NumberS79)]),
+        TK::OpenParen => Vec::from(&[Shift(State::OpenParenS81)]),
+        TK::Star => Vec::from(&[Shift(State::StarS60)]),
+        _ => vec![],
+    }
+}
+fn action_star_s107(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS78)]),
+        TK::Number => Vec::from(&[Shift(State::NumberS79)]),
+        TK::OpenParen => Vec::from(&[Shift(State::OpenParenS81)]),
+        TK::Star => Vec::from(&[Shift(State::StarS60)]),
+        _ => vec![],
+    }
+}
+fn action_keykeyword_s108(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS127)]),
+        _ => vec![],
+    }
+}
+fn action_pluralkeyopt_s109(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::AsKeyword => Vec::from(&[Shift(State::AsKeywordS128)]),
+        TK::Colon => Vec::from(&[Reduce(PK::PluralEnumOptP2, 0usize)]),
+        _ => vec![],
+    }
+}
+fn action_pluralkey_s110(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::AsKeyword => Vec::from(&[Reduce(PK::PluralKeyOptP1, 1usize)]),
+        TK::Colon => Vec::from(&[Reduce(PK::PluralKeyOptP1, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_typeannotationopt_s111(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::BooleanTrue => Vec::from(&[Shift(State::BooleanTrueS52)]),
+        TK::BooleanFalse => Vec::from(&[Shift(State::BooleanFalseS53)]),
+        TK::Null => Vec::from(&[Shift(State::NullS54)]),
+        TK::Number => Vec::from(&[Shift(State::NumberS55)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS56)]),
+        TK::QuotedStringDouble => Vec::from(&[Shift(State::QuotedStringDoubleS57)]),
+        TK::QuotedStringTriple => Vec::from(&[Shift(State::QuotedStringTripleS58)]),
+        TK::QuotedStringSingle => Vec::from(&[Shift(State::QuotedStringSingleS59)]),
+        TK::Star => Vec::from(&[Shift(State::StarS60)]),
+        _ => vec![],
+    }
+}
+fn action_typename_s112(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Ampersand => Vec::from(&[Reduce(PK::OptionalDeclarationP1, 4usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::OptionalDeclarationP1, 4usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::OptionalDeclarationP1, 4usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::OptionalDeclarationP1, 4usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::OptionalDeclarationP1, 4usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::OptionalDeclarationP1, 4usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::OptionalDeclarationP1, 4usize)]),
+        _ => vec![],
+    }
+}
+fn action_colon_s113(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::BooleanTrue => Vec::from(&[Reduce(PK::TypeAnnotationOptP2, 0usize)]),
+        TK::BooleanFalse => Vec::from(&[Reduce(PK::TypeAnnotationOptP2, 0usize)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS92)]),
+        TK::Null => Vec::from(&[Reduce(PK::TypeAnnotationOptP2, 0usize)]),
+        TK::Number => Vec::from(&[Reduce(PK::TypeAnnotationOptP2, 0usize)]),
+        TK::OpenBracket => Vec::from(&[Reduce(PK::TypeAnnotationOptP2, 0usize)]),
+        TK::QuotedStringDouble => Vec::from(&[Reduce(PK::TypeAnnotationOptP2, 0usize)]),
+        TK::QuotedStringTriple => Vec::from(&[Reduce(PK::TypeAnnotationOptP2, 0usize)]),
+        TK::QuotedStringSingle => Vec::from(&[Reduce(PK::TypeAnnotationOptP2, 0usize)]),
+        TK::Star => Vec::from(&[Reduce(PK::TypeAnnotationOptP2, 0usize)]),
+        _ => vec![],
+    }
+}
+fn action_equals_s114(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS78)]),
+        TK::Number => Vec::from(&[Shift(State::NumberS79)]),
+        TK::OpenParen => Vec::from(&[Shift(State::OpenParenS81)]),
+        TK::Star => Vec::from(&[Shift(State::StarS60)]),
+        _ => vec![],
+    }
+}
+fn action_comma_s115(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::BooleanTrue => Vec::from(&[Shift(State::BooleanTrueS52)]),
+        TK::BooleanFalse => Vec::from(&[Shift(State::BooleanFalseS53)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ArrayItemsP2, 2usize)]),
+        TK::Null => Vec::from(&[Shift(State::NullS54)]),
+        TK::Number => Vec::from(&[Shift(State::NumberS55)]),
+        TK::OpenBracket => Vec::from(&[Shift(State::OpenBracketS56)]),
+        TK::QuotedStringDouble => Vec::from(&[Shift(State::QuotedStringDoubleS57)]),
+        TK::QuotedStringTriple => Vec::from(&[Shift(State::QuotedStringTripleS58)]),
+        TK::QuotedStringSingle => Vec::from(&[Shift(State::QuotedStringSingleS59)]),
+        TK::Star => Vec::from(&[Shift(State::StarS60)]),
+        _ => vec![],
+    }
+}
+fn action_closebracket_s116(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Ampersand => Vec::from(&[Reduce(PK::ArrayValueP1, 3usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::ArrayValueP1, 3usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ArrayValueP1, 3usize)]),
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ArrayValueP1, 3usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ArrayValueP1, 3usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::ArrayValueP1, 3usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::ArrayValueP1, 3usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::ArrayValueP1, 3usize)]),
+        _ => vec![],
+    }
+}
+fn action_equals_s117(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::BooleanTrue => Vec::from(&[Reduce(PK::TypeAnnotationP1, 2usize)]),
+        TK::BooleanFalse => Vec::from(&[Reduce(PK::TypeAnnotationP1, 2usize)]),
+        TK::Null => Vec::from(&[Reduce(PK::TypeAnnotationP1, 2usize)]),
+        TK::Number => Vec::from(&[Reduce(PK::TypeAnnotationP1, 2usize)]),
+        TK::OpenBracket => Vec::from(&[Reduce(PK::TypeAnnotationP1, 2usize)]),
+        TK::QuotedStringDouble => Vec::from(&[Reduce(PK::TypeAnnotationP1, 2usize)]),
+        TK::QuotedStringTriple => Vec::from(&[Reduce(PK::TypeAnnotationP1, 2usize)]),
+        TK::QuotedStringSingle => Vec::from(&[Reduce(PK::TypeAnnotationP1, 2usize)]),
+        TK::Star => Vec::from(&[Reduce(PK::TypeAnnotationP1, 2usize)]),
+        _ => vec![],
+    }
+}
+fn action_fieldvalue_s118(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Ampersand => Vec::from(&[Reduce(PK::RequiredFieldP1, 5usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::RequiredFieldP1, 5usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::RequiredFieldP1, 5usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::RequiredFieldP1, 5usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::RequiredFieldP1, 5usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::RequiredFieldP1, 5usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::RequiredFieldP1, 5usize)]),
+        _ => vec![],
+    }
+}
+fn action_closebrace_s119(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Semicolon => Vec::from(&[Reduce(PK::EntityDefinitionBraced, 3usize)]),
+        _ => vec![],
+    }
+}
+fn action_comma_s120(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CloseBracket => Vec::from(&[Reduce(PK::InlineInstancesP2, 2usize)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS100)]),
+        _ => vec![],
+    }
+}
+fn action_closebracket_s121(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Ampersand => Vec::from(&[Reduce(PK::InlinePluralEntityP1, 5usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::InlinePluralEntityP1, 5usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::InlinePluralEntityP1, 5usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::InlinePluralEntityP1, 5usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::InlinePluralEntityP1, 5usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::InlinePluralEntityP1, 5usize)]),
+        _ => vec![],
+    }
+}
+fn action_closeparen_s122(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Ampersand => Vec::from(&[Reduce(PK::ExpressionGroup, 3usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::ExpressionGroup, 3usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionGroup, 3usize)]),
+        TK::CloseParen => Vec::from(&[Reduce(PK::ExpressionGroup, 3usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionGroup, 3usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::ExpressionGroup, 3usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::ExpressionGroup, 3usize)]),
+        TK::Minus => Vec::from(&[Reduce(PK::ExpressionGroup, 3usize)]),
+        TK::Plus => Vec::from(&[Reduce(PK::ExpressionGroup, 3usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::ExpressionGroup, 3usize)]),
+        TK::Slash => Vec::from(&[Reduce(PK::ExpressionGroup, 3usize)]),
+        TK::Star => Vec::from(&[Reduce(PK::ExpressionGroup, 3usize)]),
+        _ => vec![],
+    }
+}
+fn action_expression_s123(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Ampersand => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
+        TK::CloseParen => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
+        TK::Minus => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
+        TK::Plus => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::ExpressionSub, 3usize)]),
+        TK::Slash => Vec::from(&[Shift(State::SlashS106)]),
+        TK::Star => Vec::from(&[Shift(State::StarS107)]),
+        _ => vec![],
+    }
+}
+fn action_expression_s124(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Ampersand => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
+        TK::CloseParen => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
+        TK::Minus => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
+        TK::Plus => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::ExpressionAdd, 3usize)]),
+        TK::Slash => Vec::from(&[Shift(State::SlashS106)]),
+        TK::Star => Vec::from(&[Shift(State::StarS107)]),
+        _ => vec![],
+    }
+}
+fn action_expression_s125(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Ampersand => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
+        TK::CloseParen => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
+        TK::Minus => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
+        TK::Plus => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
+        TK::Slash => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
+        TK::Star => Vec::from(&[Reduce(PK::ExpressionDiv, 3usize)]),
+        _ => vec![],
+    }
+}
+fn action_expression_s126(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Ampersand => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
+        TK::CloseParen => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
+        TK::Minus => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
+        TK::Plus => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
+        TK::Slash => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
+        TK::Star => Vec::from(&[Reduce(PK::ExpressionMul, 3usize)]),
+        _ => vec![],
+    }
+}
+fn action_identifier_s127(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::AsKeyword => Vec::from(&[Reduce(PK::PluralKeyP1, 2usize)]),
+        TK::Colon => Vec::from(&[Reduce(PK::PluralKeyP1, 2usize)]),
+        _ => vec![],
+    }
+}
+fn action_askeyword_s128(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::EnumKeyword => Vec::from(&[Shift(State::EnumKeywordS134)]),
+        _ => vec![],
+    }
+}
+fn action_pluralenumopt_s129(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Colon => Vec::from(&[Shift(State::ColonS135)]),
+        _ => vec![],
+    }
+}
+fn action_pluralenum_s130(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Colon => Vec::from(&[Reduce(PK::PluralEnumOptP1, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_fieldvalue_s131(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Ampersand => Vec::from(&[Reduce(PK::OptionalFieldP1, 5usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::OptionalFieldP1, 5usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::OptionalFieldP1, 5usize)]),
+        TK::Comma => Vec::from(&[Reduce(PK::OptionalFieldP1, 5usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::OptionalFieldP1, 5usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::OptionalFieldP1, 5usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::OptionalFieldP1, 5usize)]),
+        _ => vec![],
+    }
+}
+fn action_arrayitems_s132(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CloseBracket => Vec::from(&[Reduce(PK::ArrayItemsP3, 3usize)]),
+        _ => vec![],
+    }
+}
+fn action_inlineinstances_s133(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::CloseBracket => Vec::from(&[Reduce(PK::InlineInstancesP3, 3usize)]),
+        _ => vec![],
+    }
+}
+fn action_enumkeyword_s134(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Colon => Vec::from(&[Reduce(PK::PluralEnumP1, 2usize)]),
+        _ => vec![],
+    }
+}
+fn action_colon_s135(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Ampersand => Vec::from(&[Shift(State::AmpersandS14)]),
+        TK::IncludeKeyword => Vec::from(&[Shift(State::IncludeKeywordS15)]),
+        TK::Identifier => Vec::from(&[Shift(State::IdentifierS16)]),
+        TK::OpenBrace => Vec::from(&[Shift(State::OpenBraceS66)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::NestedBlock0P2, 0usize)]),
+        _ => vec![],
+    }
+}
+fn action_entitydefinition_s136(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Semicolon => Vec::from(&[Shift(State::SemicolonS137)]),
+        _ => vec![],
+    }
+}
+fn action_semicolon_s137(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::Ampersand => Vec::from(&[Reduce(PK::PluralEntityP1, 8usize)]),
+        TK::ColaCodeEnd => Vec::from(&[Reduce(PK::PluralEntityP1, 8usize)]),
+        TK::CloseBrace => Vec::from(&[Reduce(PK::PluralEntityP1, 8usize)]),
+        TK::IncludeKeyword => Vec::from(&[Reduce(PK::PluralEntityP1, 8usize)]),
+        TK::Identifier => Vec::from(&[Reduce(PK::PluralEntityP1, 8usize)]),
+        TK::Semicolon => Vec::from(&[Reduce(PK::PluralEntityP1, 8usize)]),
+        _ => vec![],
+    }
+}
+fn action_augl_s138(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::LayoutItem0P2, 0usize)]),
+        TK::Comment => Vec::from(&[Shift(State::CommentS139)]),
+        TK::WS => Vec::from(&[Shift(State::WSS140)]),
+        _ => vec![],
+    }
+}
+fn action_comment_s139(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::LayoutItemP2, 1usize)]),
+        TK::Comment => Vec::from(&[Reduce(PK::LayoutItemP2, 1usize)]),
+        TK::WS => Vec::from(&[Reduce(PK::LayoutItemP2, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_ws_s140(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::LayoutItemP1, 1usize)]),
+        TK::Comment => Vec::from(&[Reduce(PK::LayoutItemP1, 1usize)]),
+        TK::WS => Vec::from(&[Reduce(PK::LayoutItemP1, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_layout_s141(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Accept]),
+        _ => vec![],
+    }
+}
+fn action_layoutitem1_s142(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::LayoutItem0P1, 1usize)]),
+        TK::Comment => Vec::from(&[Shift(State::CommentS139)]),
+        TK::WS => Vec::from(&[Shift(State::WSS140)]),
+        _ => vec![],
+    }
+}
+fn action_layoutitem0_s143(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::LayoutP1, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_layoutitem_s144(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::LayoutItem1P2, 1usize)]),
+        TK::Comment => Vec::from(&[Reduce(PK::LayoutItem1P2, 1usize)]),
+        TK::WS => Vec::from(&[Reduce(PK::LayoutItem1P2, 1usize)]),
+        _ => vec![],
+    }
+}
+fn action_layoutitem_s145(token_kind: TokenKind) -> Vec<Action<State, ProdKind>> {
+    match token_kind {
+        TK::STOP => Vec::from(&[Reduce(PK::LayoutItem1P1, 2usize)]),
+        TK::Comment => Vec::from(&[Reduce(PK::LayoutItem1P1, 2usize)]),
+        TK::WS => Vec::from(&[Reduce(PK::LayoutItem1P1, 2usize)]),
+        _ => vec![],
+    }
+}
+fn goto_aug_s0(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::Entity => State::EntityS32,
-        NonTermKind::PluralEntity => State::PluralEntityS19,
-        NonTermKind::SingularEntity => State::SingularEntityS20,
-        NonTermKind::EntityDefinition => State::EntityDefinitionS53,
-        NonTermKind::NestedBlock1 => State::NestedBlock1S34,
-        NonTermKind::NestedBlock0 => State::NestedBlock0S35,
-        NonTermKind::NestedBlock => State::NestedBlockS36,
-        NonTermKind::FieldList => State::FieldListS37,
-        NonTermKind::Field => State::FieldS38,
+        NonTermKind::Cola => State::ColaS6,
+        NonTermKind::MarkdownItem1 => State::MarkdownItem1S7,
+        NonTermKind::MarkdownItem0 => State::MarkdownItem0S8,
+        NonTermKind::MarkdownItem => State::MarkdownItemS9,
+        NonTermKind::CodeBlock => State::CodeBlockS10,
+        NonTermKind::ColaCodeBlock => State::ColaCodeBlockS11,
+        NonTermKind::RegularCodeBlock => State::RegularCodeBlockS12,
+        NonTermKind::RegularCodeStart => State::RegularCodeStartS13,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::ColonS44
+                State::AUGS0
             )
         }
     }
 }
-fn goto_colon_s54(nonterm_kind: NonTermKind) -> State {
+fn goto_colacodestart_s1(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::FieldValue => State::FieldValueS50,
+        NonTermKind::ColaSyntax => State::ColaSyntaxS17,
+        NonTermKind::ColaItem1 => State::ColaItem1S18,
+        NonTermKind::ColaItem0 => State::ColaItem0S19,
+        NonTermKind::ColaItem => State::ColaItemS20,
+        NonTermKind::Entity => State::EntityS21,
+        NonTermKind::PluralEntity => State::PluralEntityS22,
+        NonTermKind::SingularEntity => State::SingularEntityS23,
+        NonTermKind::InlinePluralEntity => State::InlinePluralEntityS24,
+        NonTermKind::Include => State::IncludeS25,
+        NonTermKind::AnchorDefinition => State::AnchorDefinitionS26,
+        NonTermKind::FieldList => State::FieldListS27,
+        NonTermKind::FieldEntry => State::FieldEntryS28,
+        NonTermKind::Field => State::FieldS29,
+        NonTermKind::OptionalField => State::OptionalFieldS30,
+        NonTermKind::RequiredField => State::RequiredFieldS31,
+        NonTermKind::FieldDeclaration => State::FieldDeclarationS32,
+        NonTermKind::OptionalDeclaration => State::OptionalDeclarationS33,
+        NonTermKind::RequiredDeclaration => State::RequiredDeclarationS34,
+        NonTermKind::ComputedField => State::ComputedFieldS35,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::ColonS54
+                State::ColaCodeStartS1
             )
         }
     }
 }
-fn goto_augl_s56(nonterm_kind: NonTermKind) -> State {
+fn goto_markdownitem1_s7(nonterm_kind: NonTermKind) -> State {
     match nonterm_kind {
-        NonTermKind::Layout => State::LayoutS58,
-        NonTermKind::WS1 => State::WS1S59,
+        NonTermKind::MarkdownItem => State::MarkdownItemS36,
+        NonTermKind::CodeBlock => State::CodeBlockS10,
+        NonTermKind::ColaCodeBlock => State::ColaCodeBlockS11,
+        NonTermKind::RegularCodeBlock => State::RegularCodeBlockS12,
+        NonTermKind::RegularCodeStart => State::RegularCodeStartS13,
         _ => {
             panic!(
                 "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
-                State::AUGLS56
+                State::MarkdownItem1S7
             )
         }
     }
 }
-fn goto_invalid(_nonterm_kind: NonTermKind) -> State {
-    panic!("Invalid GOTO entry!");
-}
-pub(crate) static PARSER_DEFINITION: ColaParserDefinition = ColaParserDefinition {
-    actions: [
-        action_aug_s0,
-        action_colacodestart_s1,
-        action_headingline_s2,
+fn goto_regularcodestart_s13(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::RegularCodeLine1 => State::RegularCodeLine1S38,
+        NonTermKind::RegularCodeLine0 => State::RegularCodeLine0S39,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::RegularCodeStartS13
+            )
+        }
+    }
+}
+fn goto_colaitem1_s18(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::ColaItem => State::ColaItemS48,
+        NonTermKind::Entity => State::EntityS21,
+        NonTermKind::PluralEntity => State::PluralEntityS22,
+        NonTermKind::SingularEntity => State::SingularEntityS23,
+        NonTermKind::InlinePluralEntity => State::InlinePluralEntityS24,
+        NonTermKind::Include => State::IncludeS25,
+        NonTermKind::AnchorDefinition => State::AnchorDefinitionS26,
+        NonTermKind::FieldList => State::FieldListS27,
+        NonTermKind::FieldEntry => State::FieldEntryS28,
+        NonTermKind::Field => State::FieldS29,
+        NonTermKind::OptionalField => State::OptionalFieldS30,
+        NonTermKind::RequiredField => State::RequiredFieldS31,
+        NonTermKind::FieldDeclaration => State::FieldDeclarationS32,
+        NonTermKind::OptionalDeclaration => State::OptionalDeclarationS33,
+        NonTermKind::RequiredDeclaration => State::RequiredDeclarationS34,
+        NonTermKind::ComputedField => State::ComputedFieldS35,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::ColaItem1S18
+            )
+        }
+    }
+}
+fn goto_identifier_s40(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::AnchorReference => State::AnchorReferenceS61,
+        NonTermKind::FieldValue => State::FieldValueS62,
+        NonTermKind::ArrayValue => State::ArrayValueS63,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::IdentifierS40
+            )
+        }
+    }
+}
+fn goto_colon_s43(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Entity => State::EntityS67,
+        NonTermKind::PluralEntity => State::PluralEntityS22,
+        NonTermKind::SingularEntity => State::SingularEntityS23,
+        NonTermKind::InlinePluralEntity => State::InlinePluralEntityS24,
+        NonTermKind::EntityDefinition => State::EntityDefinitionS68,
+        NonTermKind::NestedBlock1 => State::NestedBlock1S69,
+        NonTermKind::NestedBlock0 => State::NestedBlock0S70,
+        NonTermKind::NestedBlock => State::NestedBlockS71,
+        NonTermKind::Include => State::IncludeS72,
+        NonTermKind::AnchorDefinition => State::AnchorDefinitionS73,
+        NonTermKind::FieldList => State::FieldListS74,
+        NonTermKind::FieldEntry => State::FieldEntryS28,
+        NonTermKind::Field => State::FieldS29,
+        NonTermKind::TypeAnnotationOpt => State::TypeAnnotationOptS75,
+        NonTermKind::OptionalField => State::OptionalFieldS30,
+        NonTermKind::RequiredField => State::RequiredFieldS31,
+        NonTermKind::FieldDeclaration => State::FieldDeclarationS32,
+        NonTermKind::OptionalDeclaration => State::OptionalDeclarationS33,
+        NonTermKind::RequiredDeclaration => State::RequiredDeclarationS34,
+        NonTermKind::TypeName => State::TypeNameS76,
+        NonTermKind::ComputedField => State::ComputedFieldS35,
+        NonTermKind::TypeAnnotation => State::TypeAnnotationS77,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::ColonS43
+            )
+        }
+    }
+}
+fn goto_equals_s44(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::AnchorReference => State::AnchorReferenceS82,
+        NonTermKind::Expression => State::ExpressionS83,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::EqualsS44
+            )
+        }
+    }
+}
+fn goto_comma_s49(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::FieldEntry => State::FieldEntryS87,
+        NonTermKind::Field => State::FieldS29,
+        NonTermKind::OptionalField => State::OptionalFieldS30,
+        NonTermKind::RequiredField => State::RequiredFieldS31,
+        NonTermKind::FieldDeclaration => State::FieldDeclarationS32,
+        NonTermKind::OptionalDeclaration => State::OptionalDeclarationS33,
+        NonTermKind::RequiredDeclaration => State::RequiredDeclarationS34,
+        NonTermKind::ComputedField => State::ComputedFieldS35,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::CommaS49
+            )
+        }
+    }
+}
+fn goto_openbracket_s56(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::AnchorReference => State::AnchorReferenceS61,
+        NonTermKind::FieldValue => State::FieldValueS88,
+        NonTermKind::ArrayValue => State::ArrayValueS63,
+        NonTermKind::ArrayItemsOpt => State::ArrayItemsOptS89,
+        NonTermKind::ArrayItems => State::ArrayItemsS90,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OpenBracketS56
+            )
+        }
+    }
+}
+fn goto_colon_s64(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::TypeAnnotationOpt => State::TypeAnnotationOptS93,
+        NonTermKind::TypeName => State::TypeNameS94,
+        NonTermKind::TypeAnnotation => State::TypeAnnotationS77,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::ColonS64
+            )
+        }
+    }
+}
+fn goto_openbrace_s66(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Entity => State::EntityS67,
+        NonTermKind::PluralEntity => State::PluralEntityS22,
+        NonTermKind::SingularEntity => State::SingularEntityS23,
+        NonTermKind::InlinePluralEntity => State::InlinePluralEntityS24,
+        NonTermKind::NestedBlock1 => State::NestedBlock1S69,
+        NonTermKind::NestedBlock0 => State::NestedBlock0S96,
+        NonTermKind::NestedBlock => State::NestedBlockS71,
+        NonTermKind::Include => State::IncludeS72,
+        NonTermKind::AnchorDefinition => State::AnchorDefinitionS73,
+        NonTermKind::FieldList => State::FieldListS74,
+        NonTermKind::FieldEntry => State::FieldEntryS28,
+        NonTermKind::Field => State::FieldS29,
+        NonTermKind::OptionalField => State::OptionalFieldS30,
+        NonTermKind::RequiredField => State::RequiredFieldS31,
+        NonTermKind::FieldDeclaration => State::FieldDeclarationS32,
+        NonTermKind::OptionalDeclaration => State::OptionalDeclarationS33,
+        NonTermKind::RequiredDeclaration => State::RequiredDeclarationS34,
+        NonTermKind::ComputedField => State::ComputedFieldS35,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OpenBraceS66
+            )
+        }
+    }
+}
+fn goto_nestedblock1_s69(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Entity => State::EntityS67,
+        NonTermKind::PluralEntity => State::PluralEntityS22,
+        NonTermKind::SingularEntity => State::SingularEntityS23,
+        NonTermKind::InlinePluralEntity => State::InlinePluralEntityS24,
+        NonTermKind::NestedBlock => State::NestedBlockS98,
+        NonTermKind::Include => State::IncludeS72,
+        NonTermKind::AnchorDefinition => State::AnchorDefinitionS73,
+        NonTermKind::FieldList => State::FieldListS74,
+        NonTermKind::FieldEntry => State::FieldEntryS28,
+        NonTermKind::Field => State::FieldS29,
+        NonTermKind::OptionalField => State::OptionalFieldS30,
+        NonTermKind::RequiredField => State::RequiredFieldS31,
+        NonTermKind::FieldDeclaration => State::FieldDeclarationS32,
+        NonTermKind::OptionalDeclaration => State::OptionalDeclarationS33,
+        NonTermKind::RequiredDeclaration => State::RequiredDeclarationS34,
+        NonTermKind::ComputedField => State::ComputedFieldS35,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::NestedBlock1S69
+            )
+        }
+    }
+}
+fn goto_typeannotationopt_s75(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::AnchorReference => State::AnchorReferenceS61,
+        NonTermKind::FieldValue => State::FieldValueS99,
+        NonTermKind::ArrayValue => State::ArrayValueS63,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TypeAnnotationOptS75
+            )
+        }
+    }
+}
+fn goto_openbracket_s80(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::InlineInstancesOpt => State::InlineInstancesOptS101,
+        NonTermKind::InlineInstances => State::InlineInstancesS102,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OpenBracketS80
+            )
+        }
+    }
+}
+fn goto_openparen_s81(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::AnchorReference => State::AnchorReferenceS82,
+        NonTermKind::Expression => State::ExpressionS103,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::OpenParenS81
+            )
+        }
+    }
+}
+fn goto_identifier_s84(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::PluralKeyOpt => State::PluralKeyOptS109,
+        NonTermKind::PluralKey => State::PluralKeyS110,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::IdentifierS84
+            )
+        }
+    }
+}
+fn goto_colon_s85(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::TypeAnnotationOpt => State::TypeAnnotationOptS111,
+        NonTermKind::TypeName => State::TypeNameS112,
+        NonTermKind::TypeAnnotation => State::TypeAnnotationS77,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::ColonS85
+            )
+        }
+    }
+}
+fn goto_typeannotationopt_s93(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::AnchorReference => State::AnchorReferenceS61,
+        NonTermKind::FieldValue => State::FieldValueS118,
+        NonTermKind::ArrayValue => State::ArrayValueS63,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TypeAnnotationOptS93
+            )
+        }
+    }
+}
+fn goto_equals_s95(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::AnchorReference => State::AnchorReferenceS82,
+        NonTermKind::Expression => State::ExpressionS83,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::EqualsS95
+            )
+        }
+    }
+}
+fn goto_minus_s104(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::AnchorReference => State::AnchorReferenceS82,
+        NonTermKind::Expression => State::ExpressionS123,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::MinusS104
+            )
+        }
+    }
+}
+fn goto_plus_s105(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::AnchorReference => State::AnchorReferenceS82,
+        NonTermKind::Expression => State::ExpressionS124,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::PlusS105
+            )
+        }
+    }
+}
+fn goto_slash_s106(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::AnchorReference => State::AnchorReferenceS82,
+        NonTermKind::Expression => State::ExpressionS125,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::SlashS106
+            )
+        }
+    }
+}
+fn goto_star_s107(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::AnchorReference => State::AnchorReferenceS82,
+        NonTermKind::Expression => State::ExpressionS126,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::StarS107
+            )
+        }
+    }
+}
+fn goto_pluralkeyopt_s109(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::PluralEnumOpt => State::PluralEnumOptS129,
+        NonTermKind::PluralEnum => State::PluralEnumS130,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::PluralKeyOptS109
+            )
+        }
+    }
+}
+fn goto_typeannotationopt_s111(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::AnchorReference => State::AnchorReferenceS61,
+        NonTermKind::FieldValue => State::FieldValueS131,
+        NonTermKind::ArrayValue => State::ArrayValueS63,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::TypeAnnotationOptS111
+            )
+        }
+    }
+}
+fn goto_colon_s113(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::TypeAnnotationOpt => State::TypeAnnotationOptS75,
+        NonTermKind::TypeName => State::TypeNameS76,
+        NonTermKind::TypeAnnotation => State::TypeAnnotationS77,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::ColonS113
+            )
+        }
+    }
+}
+fn goto_equals_s114(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::AnchorReference => State::AnchorReferenceS82,
+        NonTermKind::Expression => State::ExpressionS83,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::EqualsS114
+            )
+        }
+    }
+}
+fn goto_comma_s115(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::AnchorReference => State::AnchorReferenceS61,
+        NonTermKind::FieldValue => State::FieldValueS88,
+        NonTermKind::ArrayValue => State::ArrayValueS63,
+        NonTermKind::ArrayItems => State::ArrayItemsS132,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::CommaS115
+            )
+        }
+    }
+}
+fn goto_comma_s120(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::InlineInstances => State::InlineInstancesS133,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::CommaS120
+            )
+        }
+    }
+}
+fn goto_colon_s135(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Entity => State::EntityS67,
+        NonTermKind::PluralEntity => State::PluralEntityS22,
+        NonTermKind::SingularEntity => State::SingularEntityS23,
+        NonTermKind::InlinePluralEntity => State::InlinePluralEntityS24,
+        NonTermKind::EntityDefinition => State::EntityDefinitionS136,
+        NonTermKind::NestedBlock1 => State::NestedBlock1S69,
+        NonTermKind::NestedBlock0 => State::NestedBlock0S70,
+        NonTermKind::NestedBlock => State::NestedBlockS71,
+        NonTermKind::Include => State::IncludeS72,
+        NonTermKind::AnchorDefinition => State::AnchorDefinitionS73,
+        NonTermKind::FieldList => State::FieldListS74,
+        NonTermKind::FieldEntry => State::FieldEntryS28,
+        NonTermKind::Field => State::FieldS29,
+        NonTermKind::OptionalField => State::OptionalFieldS30,
+        NonTermKind::RequiredField => State::RequiredFieldS31,
+        NonTermKind::FieldDeclaration => State::FieldDeclarationS32,
+        NonTermKind::OptionalDeclaration => State::OptionalDeclarationS33,
+        NonTermKind::RequiredDeclaration => State::RequiredDeclarationS34,
+        NonTermKind::ComputedField => State::ComputedFieldS35,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::ColonS135
+            )
+        }
+    }
+}
+fn goto_augl_s138(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::Layout => State::LayoutS141,
+        NonTermKind::LayoutItem1 => State::LayoutItem1S142,
+        NonTermKind::LayoutItem0 => State::LayoutItem0S143,
+        NonTermKind::LayoutItem => State::LayoutItemS144,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::AUGLS138
+            )
+        }
+    }
+}
+fn goto_layoutitem1_s142(nonterm_kind: NonTermKind) -> State {
+    match nonterm_kind {
+        NonTermKind::LayoutItem => State::LayoutItemS145,
+        _ => {
+            panic!(
+                "Invalid terminal kind ({nonterm_kind:?}) for GOTO state ({:?}).",
+                State::LayoutItem1S142
+            )
+        }
+    }
+}
+fn goto_invalid(_nonterm_kind: NonTermKind) -> State {
+    panic!("Invalid GOTO entry!");
+}
+pub(crate) static PARSER_DEFINITION: ColaParserDefinition = ColaParserDefinition {
+    actions: [
+        action_aug_s0,
+        action_colacodestart_s1,
+        action_headingline_s2,
         action_paragraphline_s3,
         action_regularcodestartnamed_s4,
         action_regularcodestartunnamed_s5,
@@ -1146,53 +2972,138 @@ pub(crate) static PARSER_DEFINITION: ColaParserDefinition = ColaParserDefinition
         action_colacodeblock_s11,
         action_regularcodeblock_s12,
         action_regularcodestart_s13,
-        action_identifier_s14,
-        action_colasyntax_s15,
-        action_entity1_s16,
-        action_entity0_s17,
-        action_entity_s18,
-        action_pluralentity_s19,
-        action_singularentity_s20,
-        action_markdownitem_s21,
-        action_regularcodeline_s22,
-        action_regularcodeline1_s23,
-        action_regularcodeline0_s24,
-        action_colon_s25,
-        action_pluralkeyword_s26,
-        action_colacodeend_s27,
-        action_entity_s28,
-        action_regularcodeline_s29,
-        action_regularcodeend_s30,
-        action_identifier_s31,
-        action_entity_s32,
-        action_entitydefinition_s33,
-        action_nestedblock1_s34,
-        action_nestedblock0_s35,
-        action_nestedblock_s36,
-        action_fieldlist_s37,
-        action_field_s38,
-        action_identifier_s39,
-        action_colon_s40,
-        action_semicolon_s41,
-        action_nestedblock_s42,
-        action_comma_s43,
-        action_colon_s44,
-        action_booleantrue_s45,
-        action_booleanfalse_s46,
-        action_number_s47,
-        action_quotedstringdouble_s48,
-        action_quotedstringsingle_s49,
-        action_fieldvalue_s50,
-        action_identifier_s51,
-        action_field_s52,
-        action_entitydefinition_s53,
-        action_colon_s54,
-        action_semicolon_s55,
-        action_augl_s56,
-        action_ws_s57,
-        action_layout_s58,
-        action_ws1_s59,
-        action_ws_s60,
+        action_ampersand_s14,
+        action_includekeyword_s15,
+        action_identifier_s16,
+        action_colasyntax_s17,
+        action_colaitem1_s18,
+        action_colaitem0_s19,
+        action_colaitem_s20,
+        action_entity_s21,
+        action_pluralentity_s22,
+        action_singularentity_s23,
+        action_inlinepluralentity_s24,
+        action_include_s25,
+        action_anchordefinition_s26,
+        action_fieldlist_s27,
+        action_fieldentry_s28,
+        action_field_s29,
+        action_optionalfield_s30,
+        action_requiredfield_s31,
+        action_fielddeclaration_s32,
+        action_optionaldeclaration_s33,
+        action_requireddeclaration_s34,
+        action_computedfield_s35,
+        action_markdownitem_s36,
+        action_regularcodeline_s37,
+        action_regularcodeline1_s38,
+        action_regularcodeline0_s39,
+        action_identifier_s40,
+        action_quotedstringdouble_s41,
+        action_bang_s42,
+        action_colon_s43,
+        action_equals_s44,
+        action_pluralkeyword_s45,
+        action_question_s46,
+        action_colacodeend_s47,
+        action_colaitem_s48,
+        action_comma_s49,
+        action_regularcodeline_s50,
+        action_regularcodeend_s51,
+        action_booleantrue_s52,
+        action_booleanfalse_s53,
+        action_null_s54,
+        action_number_s55,
+        action_openbracket_s56,
+        action_quotedstringdouble_s57,
+        action_quotedstringtriple_s58,
+        action_quotedstringsingle_s59,
+        action_star_s60,
+        action_anchorreference_s61,
+        action_fieldvalue_s62,
+        action_arrayvalue_s63,
+        action_colon_s64,
+        action_identifier_s65,
+        action_openbrace_s66,
+        action_entity_s67,
+        action_entitydefinition_s68,
+        action_nestedblock1_s69,
+        action_nestedblock0_s70,
+        action_nestedblock_s71,
+        action_include_s72,
+        action_anchordefinition_s73,
+        action_fieldlist_s74,
+        action_typeannotationopt_s75,
+        action_typename_s76,
+        action_typeannotation_s77,
+        action_identifier_s78,
+        action_number_s79,
+        action_openbracket_s80,
+        action_openparen_s81,
+        action_anchorreference_s82,
+        action_expression_s83,
+        action_identifier_s84,
+        action_colon_s85,
+        action_identifier_s86,
+        action_fieldentry_s87,
+        action_fieldvalue_s88,
+        action_arrayitemsopt_s89,
+        action_arrayitems_s90,
+        action_identifier_s91,
+        action_identifier_s92,
+        action_typeannotationopt_s93,
+        action_typename_s94,
+        action_equals_s95,
+        action_nestedblock0_s96,
+        action_semicolon_s97,
+        action_nestedblock_s98,
+        action_fieldvalue_s99,
+        action_identifier_s100,
+        action_inlineinstancesopt_s101,
+        action_inlineinstances_s102,
+        action_expression_s103,
+        action_minus_s104,
+        action_plus_s105,
+        action_slash_s106,
+        action_star_s107,
+        action_keykeyword_s108,
+        action_pluralkeyopt_s109,
+        action_pluralkey_s110,
+        action_typeannotationopt_s111,
+        action_typename_s112,
+        action_colon_s113,
+        action_equals_s114,
+        action_comma_s115,
+        action_closebracket_s116,
+        action_equals_s117,
+        action_fieldvalue_s118,
+        action_closebrace_s119,
+        action_comma_s120,
+        action_closebracket_s121,
+        action_closeparen_s122,
+        action_expression_s123,
+        action_expression_s124,
+        action_expression_s125,
+        action_expression_s126,
+        action_identifier_s127,
+        action_askeyword_s128,
+        action_pluralenumopt_s129,
+        action_pluralenum_s130,
+        action_fieldvalue_s131,
+        action_arrayitems_s132,
+        action_inlineinstances_s133,
+        action_enumkeyword_s134,
+        action_colon_s135,
+        action_entitydefinition_s136,
+        action_semicolon_s137,
+        action_augl_s138,
+        action_comment_s139,
+        action_ws_s140,
+        action_layout_s141,
+        action_layoutitem1_s142,
+        action_layoutitem0_s143,
+        action_layoutitem_s144,
+        action_layoutitem_s145,
     ],
     gotos: [
         goto_aug_s0,
@@ -1211,16 +3122,15 @@ pub(crate) static PARSER_DEFINITION: ColaParserDefinition = ColaParserDefinition
         goto_regularcodestart_s13,
         goto_invalid,
         goto_invalid,
-        goto_entity1_s16,
         goto_invalid,
         goto_invalid,
+        goto_colaitem1_s18,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_colon_s25,
         goto_invalid,
         goto_invalid,
         goto_invalid,
@@ -1229,90 +3139,153 @@ pub(crate) static PARSER_DEFINITION: ColaParserDefinition = ColaParserDefinition
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_nestedblock1_s34,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_colon_s40,
         goto_invalid,
         goto_invalid,
-        goto_comma_s43,
-        goto_colon_s44,
+        goto_identifier_s40,
         goto_invalid,
         goto_invalid,
+        goto_colon_s43,
+        goto_equals_s44,
         goto_invalid,
         goto_invalid,
         goto_invalid,
         goto_invalid,
+        goto_comma_s49,
         goto_invalid,
         goto_invalid,
         goto_invalid,
-        goto_colon_s54,
         goto_invalid,
-        goto_augl_s56,
         goto_invalid,
         goto_invalid,
+        goto_openbracket_s56,
         goto_invalid,
         goto_invalid,
-    ],
-    token_kinds: [
-        [
-            Some((TK::STOP, true)),
-            Some((TK::ColaCodeStart, false)),
-            Some((TK::HeadingLine, false)),
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_colon_s64,
+        goto_invalid,
+        goto_openbrace_s66,
+        goto_invalid,
+        goto_invalid,
+        goto_nestedblock1_s69,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_typeannotationopt_s75,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_openbracket_s80,
+        goto_openparen_s81,
+        goto_invalid,
+        goto_invalid,
+        goto_identifier_s84,
+        goto_colon_s85,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_typeannotationopt_s93,
+        goto_invalid,
+        goto_equals_s95,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_minus_s104,
+        goto_plus_s105,
+        goto_slash_s106,
+        goto_star_s107,
+        goto_invalid,
+        goto_pluralkeyopt_s109,
+        goto_invalid,
+        goto_typeannotationopt_s111,
+        goto_invalid,
+        goto_colon_s113,
+        goto_equals_s114,
+        goto_comma_s115,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_comma_s120,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_colon_s135,
+        goto_invalid,
+        goto_invalid,
+        goto_augl_s138,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+        goto_layoutitem1_s142,
+        goto_invalid,
+        goto_invalid,
+        goto_invalid,
+    ],
+    token_kinds: [
+        [
+            Some((TK::STOP, true)),
+            Some((TK::ColaCodeStart, false)),
+            Some((TK::HeadingLine, false)),
             Some((TK::ParagraphLine, false)),
             Some((TK::RegularCodeStartNamed, false)),
             Some((TK::RegularCodeStartUnnamed, false)),
             None,
-        ],
-        [
-            Some((TK::ColaCodeEnd, false)),
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::STOP, true)),
-            Some((TK::ColaCodeStart, false)),
-            Some((TK::HeadingLine, false)),
-            Some((TK::ParagraphLine, false)),
-            Some((TK::RegularCodeStartNamed, false)),
-            Some((TK::RegularCodeStartUnnamed, false)),
             None,
-        ],
-        [
-            Some((TK::STOP, true)),
-            Some((TK::ColaCodeStart, false)),
-            Some((TK::HeadingLine, false)),
-            Some((TK::ParagraphLine, false)),
-            Some((TK::RegularCodeStartNamed, false)),
-            Some((TK::RegularCodeStartUnnamed, false)),
             None,
         ],
         [
-            Some((TK::RegularCodeLine, false)),
-            Some((TK::RegularCodeEnd, false)),
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
             None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::RegularCodeLine, false)),
-            Some((TK::RegularCodeEnd, false)),
             None,
             None,
             None,
             None,
             None,
         ],
-        [Some((TK::STOP, false)), None, None, None, None, None, None],
         [
             Some((TK::STOP, true)),
             Some((TK::ColaCodeStart, false)),
@@ -1321,33 +3294,12 @@ pub(crate) static PARSER_DEFINITION: ColaParserDefinition = ColaParserDefinition
             Some((TK::RegularCodeStartNamed, false)),
             Some((TK::RegularCodeStartUnnamed, false)),
             None,
-        ],
-        [Some((TK::STOP, false)), None, None, None, None, None, None],
-        [
-            Some((TK::STOP, true)),
-            Some((TK::ColaCodeStart, false)),
-            Some((TK::HeadingLine, false)),
-            Some((TK::ParagraphLine, false)),
-            Some((TK::RegularCodeStartNamed, false)),
-            Some((TK::RegularCodeStartUnnamed, false)),
             None,
-        ],
-        [
-            Some((TK::STOP, true)),
-            Some((TK::ColaCodeStart, false)),
-            Some((TK::HeadingLine, false)),
-            Some((TK::ParagraphLine, false)),
-            Some((TK::RegularCodeStartNamed, false)),
-            Some((TK::RegularCodeStartUnnamed, false)),
             None,
-        ],
-        [
-            Some((TK::STOP, true)),
-            Some((TK::ColaCodeStart, false)),
-            Some((TK::HeadingLine, false)),
-            Some((TK::ParagraphLine, false)),
-            Some((TK::RegularCodeStartNamed, false)),
-            Some((TK::RegularCodeStartUnnamed, false)),
+            None,
+            None,
+            None,
+            None,
             None,
         ],
         [
@@ -1358,39 +3310,40 @@ pub(crate) static PARSER_DEFINITION: ColaParserDefinition = ColaParserDefinition
             Some((TK::RegularCodeStartNamed, false)),
             Some((TK::RegularCodeStartUnnamed, false)),
             None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
         ],
         [
+            Some((TK::RegularCodeEnd, true)),
             Some((TK::RegularCodeLine, false)),
-            Some((TK::RegularCodeEnd, false)),
             None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::PluralKeyword, true)),
-            Some((TK::Colon, true)),
+            None,
+            None,
             None,
             None,
             None,
             None,
             None,
         ],
-        [Some((TK::ColaCodeEnd, false)), None, None, None, None, None, None],
         [
-            Some((TK::ColaCodeEnd, false)),
-            Some((TK::Identifier, false)),
+            Some((TK::RegularCodeEnd, true)),
+            Some((TK::RegularCodeLine, false)),
+            None,
+            None,
             None,
             None,
             None,
             None,
             None,
-        ],
-        [Some((TK::ColaCodeEnd, false)), None, None, None, None, None, None],
-        [
-            Some((TK::ColaCodeEnd, false)),
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
@@ -1398,18 +3351,16 @@ pub(crate) static PARSER_DEFINITION: ColaParserDefinition = ColaParserDefinition
             None,
         ],
         [
-            Some((TK::Semicolon, true)),
-            Some((TK::ColaCodeEnd, false)),
-            Some((TK::Identifier, false)),
+            Some((TK::STOP, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::Semicolon, true)),
-            Some((TK::ColaCodeEnd, false)),
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
@@ -1423,10 +3374,8 @@ pub(crate) static PARSER_DEFINITION: ColaParserDefinition = ColaParserDefinition
             Some((TK::RegularCodeStartNamed, false)),
             Some((TK::RegularCodeStartUnnamed, false)),
             None,
-        ],
-        [
-            Some((TK::RegularCodeLine, false)),
-            Some((TK::RegularCodeEnd, false)),
+            None,
+            None,
             None,
             None,
             None,
@@ -1434,25 +3383,21 @@ pub(crate) static PARSER_DEFINITION: ColaParserDefinition = ColaParserDefinition
             None,
         ],
         [
-            Some((TK::RegularCodeLine, false)),
-            Some((TK::RegularCodeEnd, false)),
+            Some((TK::STOP, false)),
+            None,
+            None,
+            None,
             None,
             None,
             None,
             None,
             None,
-        ],
-        [Some((TK::RegularCodeEnd, false)), None, None, None, None, None, None],
-        [
-            Some((TK::Semicolon, true)),
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
             None,
             None,
         ],
-        [Some((TK::Identifier, false)), None, None, None, None, None, None],
         [
             Some((TK::STOP, true)),
             Some((TK::ColaCodeStart, false)),
@@ -1461,10 +3406,8 @@ pub(crate) static PARSER_DEFINITION: ColaParserDefinition = ColaParserDefinition
             Some((TK::RegularCodeStartNamed, false)),
             Some((TK::RegularCodeStartUnnamed, false)),
             None,
-        ],
-        [
-            Some((TK::ColaCodeEnd, false)),
-            Some((TK::Identifier, false)),
+            None,
+            None,
             None,
             None,
             None,
@@ -1472,8 +3415,15 @@ pub(crate) static PARSER_DEFINITION: ColaParserDefinition = ColaParserDefinition
             None,
         ],
         [
-            Some((TK::RegularCodeLine, false)),
-            Some((TK::RegularCodeEnd, false)),
+            Some((TK::STOP, true)),
+            Some((TK::ColaCodeStart, false)),
+            Some((TK::HeadingLine, false)),
+            Some((TK::ParagraphLine, false)),
+            Some((TK::RegularCodeStartNamed, false)),
+            Some((TK::RegularCodeStartUnnamed, false)),
+            None,
+            None,
+            None,
             None,
             None,
             None,
@@ -1488,176 +3438,371 @@ pub(crate) static PARSER_DEFINITION: ColaParserDefinition = ColaParserDefinition
             Some((TK::RegularCodeStartNamed, false)),
             Some((TK::RegularCodeStartUnnamed, false)),
             None,
-        ],
-        [
-            Some((TK::PluralKeyword, true)),
-            Some((TK::Colon, true)),
             None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::Semicolon, true)),
-            Some((TK::Identifier, false)),
             None,
             None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::ColaCodeStart, false)),
+            Some((TK::HeadingLine, false)),
+            Some((TK::ParagraphLine, false)),
+            Some((TK::RegularCodeStartNamed, false)),
+            Some((TK::RegularCodeStartUnnamed, false)),
             None,
             None,
             None,
-        ],
-        [Some((TK::Semicolon, true)), None, None, None, None, None, None],
-        [
-            Some((TK::Semicolon, true)),
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
             None,
             None,
         ],
-        [Some((TK::Semicolon, true)), None, None, None, None, None, None],
         [
-            Some((TK::Semicolon, true)),
-            Some((TK::Identifier, false)),
+            Some((TK::RegularCodeEnd, true)),
+            Some((TK::RegularCodeLine, false)),
+            None,
+            None,
+            None,
             None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::Comma, true)),
-            Some((TK::Semicolon, true)),
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
             None,
         ],
         [
-            Some((TK::Comma, true)),
-            Some((TK::Semicolon, true)),
             Some((TK::Identifier, false)),
             None,
             None,
             None,
             None,
-        ],
-        [Some((TK::Colon, true)), None, None, None, None, None, None],
-        [
-            Some((TK::BooleanFalse, true)),
-            Some((TK::BooleanTrue, true)),
-            Some((TK::Semicolon, true)),
-            Some((TK::Identifier, false)),
-            Some((TK::Number, false)),
-            Some((TK::QuotedStringDouble, false)),
-            Some((TK::QuotedStringSingle, false)),
-        ],
-        [
-            Some((TK::Semicolon, true)),
-            Some((TK::ColaCodeEnd, false)),
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::Semicolon, true)),
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
             None,
             None,
         ],
-        [Some((TK::Identifier, false)), None, None, None, None, None, None],
         [
-            Some((TK::Semicolon, true)),
-            Some((TK::Identifier, false)),
+            Some((TK::QuotedStringDouble, false)),
             None,
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::Comma, true)),
-            Some((TK::Semicolon, true)),
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
             None,
-        ],
-        [
-            Some((TK::Comma, true)),
-            Some((TK::Semicolon, true)),
-            Some((TK::Identifier, false)),
             None,
             None,
             None,
             None,
         ],
         [
+            Some((TK::PluralKeyword, true)),
+            Some((TK::Bang, true)),
+            Some((TK::Colon, true)),
+            Some((TK::Equals, true)),
+            Some((TK::Question, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::ColaCodeEnd, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::ColaCodeEnd, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::Comma, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
             Some((TK::Comma, true)),
             Some((TK::Semicolon, true)),
+            Some((TK::ColaCodeEnd, false)),
             Some((TK::Identifier, false)),
             None,
             None,
             None,
             None,
+            None,
+            None,
+            None,
         ],
         [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
             Some((TK::Comma, true)),
             Some((TK::Semicolon, true)),
+            Some((TK::ColaCodeEnd, false)),
             Some((TK::Identifier, false)),
             None,
             None,
             None,
             None,
+            None,
+            None,
+            None,
         ],
         [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
             Some((TK::Comma, true)),
             Some((TK::Semicolon, true)),
+            Some((TK::ColaCodeEnd, false)),
             Some((TK::Identifier, false)),
             None,
             None,
             None,
             None,
+            None,
+            None,
+            None,
         ],
         [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
             Some((TK::Comma, true)),
             Some((TK::Semicolon, true)),
+            Some((TK::ColaCodeEnd, false)),
             Some((TK::Identifier, false)),
             None,
             None,
             None,
             None,
+            None,
+            None,
+            None,
         ],
-        [Some((TK::Colon, true)), None, None, None, None, None, None],
         [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
             Some((TK::Comma, true)),
             Some((TK::Semicolon, true)),
+            Some((TK::ColaCodeEnd, false)),
             Some((TK::Identifier, false)),
             None,
             None,
             None,
             None,
+            None,
+            None,
+            None,
         ],
-        [Some((TK::Semicolon, true)), None, None, None, None, None, None],
         [
-            Some((TK::BooleanFalse, true)),
-            Some((TK::BooleanTrue, true)),
-            Some((TK::Number, false)),
-            Some((TK::QuotedStringDouble, false)),
-            Some((TK::QuotedStringSingle, false)),
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::Comma, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
             None,
             None,
         ],
         [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::Comma, true)),
             Some((TK::Semicolon, true)),
             Some((TK::ColaCodeEnd, false)),
             Some((TK::Identifier, false)),
@@ -1665,64 +3810,1838 @@ pub(crate) static PARSER_DEFINITION: ColaParserDefinition = ColaParserDefinition
             None,
             None,
             None,
+            None,
+            None,
+            None,
         ],
-        [Some((TK::STOP, true)), Some((TK::WS, false)), None, None, None, None, None],
-        [Some((TK::STOP, true)), Some((TK::WS, false)), None, None, None, None, None],
-        [Some((TK::STOP, false)), None, None, None, None, None, None],
-        [Some((TK::STOP, true)), Some((TK::WS, false)), None, None, None, None, None],
-        [Some((TK::STOP, true)), Some((TK::WS, false)), None, None, None, None, None],
-    ],
-};
-impl ParserDefinition<State, ProdKind, TokenKind, NonTermKind> for ColaParserDefinition {
-    fn actions(&self, state: State, token: TokenKind) -> Vec<Action<State, ProdKind>> {
-        PARSER_DEFINITION.actions[state as usize](token)
-    }
-    fn goto(&self, state: State, nonterm: NonTermKind) -> State {
-        PARSER_DEFINITION.gotos[state as usize](nonterm)
-    }
-    fn expected_token_kinds(&self, state: State) -> Vec<(TokenKind, bool)> {
-        PARSER_DEFINITION.token_kinds[state as usize].iter().map_while(|t| *t).collect()
-    }
-    fn longest_match() -> bool {
-        true
-    }
-    fn grammar_order() -> bool {
-        true
-    }
-}
-pub(crate) type Context<'i, I> = LRContext<'i, I, State, TokenKind>;
-pub struct ColaParser<
-    'i,
-    I: InputT + ?Sized,
-    L: Lexer<'i, Context<'i, I>, State, TokenKind, Input = I>,
-    B,
->(
-    LRParser<
-        'i,
-        Context<'i, I>,
-        State,
-        ProdKind,
-        TokenKind,
-        NonTermKind,
-        ColaParserDefinition,
-        L,
-        B,
-        I,
-    >,
-);
-#[allow(dead_code)]
-impl<
-    'i,
-> ColaParser<
-    'i,
-    Input,
-    StringLexer<Context<'i, Input>, State, TokenKind, TokenRecognizer, TERMINAL_COUNT>,
-    DefaultBuilder,
-> {
-    pub fn new() -> Self {
-        Self(
-            LRParser::new(
-                &PARSER_DEFINITION,
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::Comma, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::ColaCodeStart, false)),
+            Some((TK::HeadingLine, false)),
+            Some((TK::ParagraphLine, false)),
+            Some((TK::RegularCodeStartNamed, false)),
+            Some((TK::RegularCodeStartUnnamed, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::RegularCodeEnd, true)),
+            Some((TK::RegularCodeLine, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::RegularCodeEnd, true)),
+            Some((TK::RegularCodeLine, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::RegularCodeEnd, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::BooleanTrue, false)),
+            Some((TK::BooleanFalse, false)),
+            Some((TK::Null, true)),
+            Some((TK::OpenBracket, true)),
+            Some((TK::Star, true)),
+            Some((TK::Number, false)),
+            Some((TK::QuotedStringDouble, false)),
+            Some((TK::QuotedStringTriple, false)),
+            Some((TK::QuotedStringSingle, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Colon, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::BooleanTrue, false)),
+            Some((TK::BooleanFalse, false)),
+            Some((TK::Null, true)),
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::OpenBrace, true)),
+            Some((TK::OpenBracket, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::Star, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::Number, false)),
+            Some((TK::QuotedStringDouble, false)),
+            Some((TK::QuotedStringTriple, false)),
+            Some((TK::QuotedStringSingle, false)),
+        ],
+        [
+            Some((TK::OpenBracket, true)),
+            Some((TK::OpenParen, true)),
+            Some((TK::Star, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::Number, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Colon, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::ColaCodeStart, false)),
+            Some((TK::HeadingLine, false)),
+            Some((TK::ParagraphLine, false)),
+            Some((TK::RegularCodeStartNamed, false)),
+            Some((TK::RegularCodeStartUnnamed, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::RegularCodeEnd, true)),
+            Some((TK::RegularCodeLine, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::ColaCodeStart, false)),
+            Some((TK::HeadingLine, false)),
+            Some((TK::ParagraphLine, false)),
+            Some((TK::RegularCodeStartNamed, false)),
+            Some((TK::RegularCodeStartUnnamed, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::CloseBracket, true)),
+            Some((TK::Comma, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::CloseBracket, true)),
+            Some((TK::Comma, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::CloseBracket, true)),
+            Some((TK::Comma, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::CloseBracket, true)),
+            Some((TK::Comma, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::BooleanTrue, false)),
+            Some((TK::BooleanFalse, false)),
+            Some((TK::Null, true)),
+            Some((TK::CloseBracket, true)),
+            Some((TK::OpenBracket, true)),
+            Some((TK::Star, true)),
+            Some((TK::Number, false)),
+            Some((TK::QuotedStringDouble, false)),
+            Some((TK::QuotedStringTriple, false)),
+            Some((TK::QuotedStringSingle, false)),
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::CloseBracket, true)),
+            Some((TK::Comma, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::CloseBracket, true)),
+            Some((TK::Comma, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::CloseBracket, true)),
+            Some((TK::Comma, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::CloseBracket, true)),
+            Some((TK::Comma, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::CloseBracket, true)),
+            Some((TK::Comma, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::BooleanTrue, false)),
+            Some((TK::BooleanFalse, false)),
+            Some((TK::Null, true)),
+            Some((TK::OpenBracket, true)),
+            Some((TK::Star, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::Number, false)),
+            Some((TK::QuotedStringDouble, false)),
+            Some((TK::QuotedStringTriple, false)),
+            Some((TK::QuotedStringSingle, false)),
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::PluralKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::Bang, true)),
+            Some((TK::Colon, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::Comma, true)),
+            Some((TK::Equals, true)),
+            Some((TK::Question, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Semicolon, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Semicolon, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::Comma, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::BooleanTrue, false)),
+            Some((TK::BooleanFalse, false)),
+            Some((TK::Null, true)),
+            Some((TK::OpenBracket, true)),
+            Some((TK::Star, true)),
+            Some((TK::Number, false)),
+            Some((TK::QuotedStringDouble, false)),
+            Some((TK::QuotedStringTriple, false)),
+            Some((TK::QuotedStringSingle, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::Comma, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::BooleanTrue, false)),
+            Some((TK::BooleanFalse, false)),
+            Some((TK::Null, true)),
+            Some((TK::OpenBracket, true)),
+            Some((TK::Star, true)),
+            Some((TK::Number, false)),
+            Some((TK::QuotedStringDouble, false)),
+            Some((TK::QuotedStringTriple, false)),
+            Some((TK::QuotedStringSingle, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::CloseParen, true)),
+            Some((TK::Comma, true)),
+            Some((TK::Minus, true)),
+            Some((TK::Plus, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::Slash, true)),
+            Some((TK::Star, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::CloseParen, true)),
+            Some((TK::Comma, true)),
+            Some((TK::Minus, true)),
+            Some((TK::Plus, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::Slash, true)),
+            Some((TK::Star, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::CloseBracket, true)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OpenParen, true)),
+            Some((TK::Star, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::Number, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::CloseParen, true)),
+            Some((TK::Comma, true)),
+            Some((TK::Minus, true)),
+            Some((TK::Plus, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::Slash, true)),
+            Some((TK::Star, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::Comma, true)),
+            Some((TK::Minus, true)),
+            Some((TK::Plus, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::Slash, true)),
+            Some((TK::Star, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::KeyKeyword, true)),
+            Some((TK::AsKeyword, true)),
+            Some((TK::Colon, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::BooleanTrue, false)),
+            Some((TK::BooleanFalse, false)),
+            Some((TK::Null, true)),
+            Some((TK::OpenBracket, true)),
+            Some((TK::Star, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::Number, false)),
+            Some((TK::QuotedStringDouble, false)),
+            Some((TK::QuotedStringTriple, false)),
+            Some((TK::QuotedStringSingle, false)),
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Bang, true)),
+            Some((TK::Colon, true)),
+            Some((TK::Equals, true)),
+            Some((TK::Question, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::Comma, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CloseBracket, true)),
+            Some((TK::Comma, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CloseBracket, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CloseBracket, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::CloseBracket, true)),
+            Some((TK::CloseParen, true)),
+            Some((TK::Comma, true)),
+            Some((TK::Minus, true)),
+            Some((TK::Plus, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::Slash, true)),
+            Some((TK::Star, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::Comma, true)),
+            Some((TK::Equals, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::BooleanTrue, false)),
+            Some((TK::BooleanFalse, false)),
+            Some((TK::Null, true)),
+            Some((TK::OpenBracket, true)),
+            Some((TK::Star, true)),
+            Some((TK::Number, false)),
+            Some((TK::QuotedStringDouble, false)),
+            Some((TK::QuotedStringTriple, false)),
+            Some((TK::QuotedStringSingle, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::Comma, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::BooleanTrue, false)),
+            Some((TK::BooleanFalse, false)),
+            Some((TK::Null, true)),
+            Some((TK::OpenBracket, true)),
+            Some((TK::OpenParen, true)),
+            Some((TK::Star, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::Number, false)),
+            Some((TK::QuotedStringDouble, false)),
+            Some((TK::QuotedStringTriple, false)),
+            Some((TK::QuotedStringSingle, false)),
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CloseBrace, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::Comma, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CloseBracket, true)),
+            Some((TK::Comma, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CloseBracket, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CloseBracket, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CloseParen, true)),
+            Some((TK::Minus, true)),
+            Some((TK::Plus, true)),
+            Some((TK::Slash, true)),
+            Some((TK::Star, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OpenParen, true)),
+            Some((TK::Star, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::Number, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OpenParen, true)),
+            Some((TK::Star, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::Number, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OpenParen, true)),
+            Some((TK::Star, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::Number, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OpenParen, true)),
+            Some((TK::Star, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::Number, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::AsKeyword, true)),
+            Some((TK::Colon, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::AsKeyword, true)),
+            Some((TK::Colon, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::BooleanTrue, false)),
+            Some((TK::BooleanFalse, false)),
+            Some((TK::Null, true)),
+            Some((TK::OpenBracket, true)),
+            Some((TK::Star, true)),
+            Some((TK::Number, false)),
+            Some((TK::QuotedStringDouble, false)),
+            Some((TK::QuotedStringTriple, false)),
+            Some((TK::QuotedStringSingle, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::Comma, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::BooleanTrue, false)),
+            Some((TK::BooleanFalse, false)),
+            Some((TK::Null, true)),
+            Some((TK::OpenBracket, true)),
+            Some((TK::Star, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::Number, false)),
+            Some((TK::QuotedStringDouble, false)),
+            Some((TK::QuotedStringTriple, false)),
+            Some((TK::QuotedStringSingle, false)),
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::OpenParen, true)),
+            Some((TK::Star, true)),
+            Some((TK::Identifier, false)),
+            Some((TK::Number, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::BooleanTrue, false)),
+            Some((TK::BooleanFalse, false)),
+            Some((TK::Null, true)),
+            Some((TK::CloseBracket, true)),
+            Some((TK::OpenBracket, true)),
+            Some((TK::Star, true)),
+            Some((TK::Number, false)),
+            Some((TK::QuotedStringDouble, false)),
+            Some((TK::QuotedStringTriple, false)),
+            Some((TK::QuotedStringSingle, false)),
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::CloseBracket, true)),
+            Some((TK::Comma, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::BooleanTrue, false)),
+            Some((TK::BooleanFalse, false)),
+            Some((TK::Null, true)),
+            Some((TK::OpenBracket, true)),
+            Some((TK::Star, true)),
+            Some((TK::Number, false)),
+            Some((TK::QuotedStringDouble, false)),
+            Some((TK::QuotedStringTriple, false)),
+            Some((TK::QuotedStringSingle, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::Comma, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Semicolon, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CloseBracket, true)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::CloseParen, true)),
+            Some((TK::Comma, true)),
+            Some((TK::Minus, true)),
+            Some((TK::Plus, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::Slash, true)),
+            Some((TK::Star, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::CloseParen, true)),
+            Some((TK::Comma, true)),
+            Some((TK::Minus, true)),
+            Some((TK::Plus, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::Slash, true)),
+            Some((TK::Star, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::CloseParen, true)),
+            Some((TK::Comma, true)),
+            Some((TK::Minus, true)),
+            Some((TK::Plus, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::Slash, true)),
+            Some((TK::Star, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::CloseParen, true)),
+            Some((TK::Comma, true)),
+            Some((TK::Minus, true)),
+            Some((TK::Plus, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::Slash, true)),
+            Some((TK::Star, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::CloseParen, true)),
+            Some((TK::Comma, true)),
+            Some((TK::Minus, true)),
+            Some((TK::Plus, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::Slash, true)),
+            Some((TK::Star, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+        ],
+        [
+            Some((TK::AsKeyword, true)),
+            Some((TK::Colon, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::EnumKeyword, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Colon, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Colon, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::Comma, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CloseBracket, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::CloseBracket, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Colon, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::OpenBrace, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::Semicolon, true)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::IncludeKeyword, true)),
+            Some((TK::Ampersand, true)),
+            Some((TK::CloseBrace, true)),
+            Some((TK::Semicolon, true)),
+            Some((TK::ColaCodeEnd, false)),
+            Some((TK::Identifier, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::Comment, false)),
+            Some((TK::WS, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::Comment, false)),
+            Some((TK::WS, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::Comment, false)),
+            Some((TK::WS, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::Comment, false)),
+            Some((TK::WS, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::Comment, false)),
+            Some((TK::WS, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+        [
+            Some((TK::STOP, true)),
+            Some((TK::Comment, false)),
+            Some((TK::WS, false)),
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ],
+    ],
+};
+impl ParserDefinition<State, ProdKind, TokenKind, NonTermKind> for ColaParserDefinition {
+    fn actions(&self, state: State, token: TokenKind) -> Vec<Action<State, ProdKind>> {
+        PARSER_DEFINITION.actions[state as usize](token)
+    }
+    fn goto(&self, state: State, nonterm: NonTermKind) -> State {
+        PARSER_DEFINITION.gotos[state as usize](nonterm)
+    }
+    fn expected_token_kinds(&self, state: State) -> Vec<(TokenKind, bool)> {
+        PARSER_DEFINITION.token_kinds[state as usize].iter().map_while(|t| *t).collect()
+    }
+    fn longest_match() -> bool {
+        true
+    }
+    fn grammar_order() -> bool {
+        true
+    }
+}
+pub(crate) type Context<'i, I> = LRContext<'i, I, State, TokenKind>;
+pub struct ColaParser<
+    'i,
+    I: InputT + ?Sized,
+    L: Lexer<'i, Context<'i, I>, State, TokenKind, Input = I>,
+    B,
+>(
+    LRParser<
+        'i,
+        Context<'i, I>,
+        State,
+        ProdKind,
+        TokenKind,
+        NonTermKind,
+        ColaParserDefinition,
+        L,
+        B,
+        I,
+    >,
+);
+#[allow(dead_code)]
+impl<
+    'i,
+> ColaParser<
+    'i,
+    Input,
+    StringLexer<Context<'i, Input>, State, TokenKind, TokenRecognizer, TERMINAL_COUNT>,
+    DefaultBuilder,
+> {
+    pub fn new() -> Self {
+        Self(
+            LRParser::new(
+                &PARSER_DEFINITION,
                 State::default(),
                 false,
                 true,
@@ -1761,722 +5680,1691 @@ where
         self.0.parse_file(file)
     }
 }
-#[allow(dead_code)]
-#[derive(Debug)]
-pub enum Recognizer {
-    Stop,
-    StrMatch(&'static str),
-    RegexMatch(Lazy<Regex>),
-}
-#[allow(dead_code)]
-#[derive(Debug)]
-pub struct TokenRecognizer(TokenKind, Recognizer);
-impl<'i> TokenRecognizerT<'i> for TokenRecognizer {
-    fn recognize(&self, input: &'i str) -> Option<&'i str> {
-        match &self {
-            #[allow(unused_variables)]
-            TokenRecognizer(token_kind, Recognizer::StrMatch(s)) => {
-                logn!("{} {:?} -- ", "    Recognizing".green(), token_kind);
-                if input.starts_with(s) {
-                    log!("{}", "recognized".bold().green());
-                    Some(s)
-                } else {
-                    log!("{}", "not recognized".red());
-                    None
+#[allow(dead_code)]
+#[derive(Debug)]
+pub enum Recognizer {
+    Stop,
+    StrMatch(&'static str),
+    RegexMatch(Lazy<Regex>),
+}
+#[allow(dead_code)]
+#[derive(Debug)]
+pub struct TokenRecognizer(TokenKind, Recognizer);
+impl<'i> TokenRecognizerT<'i> for TokenRecognizer {
+    fn recognize(&self, input: &'i str) -> Option<&'i str> {
+        match &self {
+            #[allow(unused_variables)]
+            TokenRecognizer(token_kind, Recognizer::StrMatch(s)) => {
+                logn!("{} {:?} -- ", "    Recognizing".green(), token_kind);
+                if input.starts_with(s) {
+                    log!("{}", "recognized".bold().green());
+                    Some(s)
+                } else {
+                    log!("{}", "not recognized".red());
+                    None
+                }
+            }
+            #[allow(unused_variables)]
+            TokenRecognizer(token_kind, Recognizer::RegexMatch(r)) => {
+                logn!("{} {:?} -- ", "    Recognizing".green(), token_kind);
+                let match_str = r.find(input);
+                match match_str {
+                    Some(x) => {
+                        let x_str = x.as_str();
+                        log!("{} '{}'", "recognized".bold().green(), x_str);
+                        Some(x_str)
+                    }
+                    _ => {
+                        log!("{}", "not recognized".red());
+                        None
+                    }
+                }
+            }
+            TokenRecognizer(_, Recognizer::Stop) => {
+                logn!("{} STOP -- ", "    Recognizing".green());
+                if input.is_empty() {
+                    log!("{}", "recognized".bold().green());
+                    Some("")
+                } else {
+                    log!("{}", "not recognized".red());
+                    None
+                }
+            }
+        }
+    }
+}
+pub(crate) static RECOGNIZERS: [TokenRecognizer; TERMINAL_COUNT] = [
+    TokenRecognizer(TokenKind::STOP, Recognizer::Stop),
+    TokenRecognizer(TokenKind::Ampersand, Recognizer::StrMatch("&")),
+    TokenRecognizer(TokenKind::AsKeyword, Recognizer::StrMatch("as")),
+    TokenRecognizer(TokenKind::Bang, Recognizer::StrMatch("!")),
+    TokenRecognizer(
+        TokenKind::BooleanTrue,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "true\\b")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::BooleanFalse,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "false\\b")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::ColaCodeStart,
+        Recognizer::RegexMatch(
+            Lazy::new(|| {
+                Regex::new(concat!("^", "```[ \\t]*cola[ \\t]*\\r?\\n")).unwrap()
+            }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::ColaCodeEnd,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "```[ \\t]*(\\r?\\n)?")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(TokenKind::Colon, Recognizer::StrMatch(":")),
+    TokenRecognizer(TokenKind::CloseBrace, Recognizer::StrMatch("}")),
+    TokenRecognizer(TokenKind::CloseBracket, Recognizer::StrMatch("]")),
+    TokenRecognizer(TokenKind::CloseParen, Recognizer::StrMatch(")")),
+    TokenRecognizer(TokenKind::Comma, Recognizer::StrMatch(",")),
+    TokenRecognizer(
+        TokenKind::Comment,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "#[^\\n]*")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(TokenKind::EnumKeyword, Recognizer::StrMatch("enum")),
+    TokenRecognizer(TokenKind::Equals, Recognizer::StrMatch("=")),
+    TokenRecognizer(TokenKind::IncludeKeyword, Recognizer::StrMatch("include")),
+    TokenRecognizer(
+        TokenKind::HeadingLine,
+        Recognizer::RegexMatch(
+            Lazy::new(|| {
+                Regex::new(concat!("^", "#{1,6}[ \\t]+[^\\n]*\\n")).unwrap()
+            }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::Identifier,
+        Recognizer::RegexMatch(
+            Lazy::new(|| {
+                Regex::new(concat!("^", "[a-zA-Z_][a-zA-Z0-9_.-]*")).unwrap()
+            }),
+        ),
+    ),
+    TokenRecognizer(TokenKind::KeyKeyword, Recognizer::StrMatch("key")),
+    TokenRecognizer(TokenKind::Minus, Recognizer::StrMatch("-")),
+    TokenRecognizer(
+        TokenKind::Null,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "null\\b")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::Number,
+        Recognizer::RegexMatch(
+            Lazy::new(|| {
+                Regex::new(
+                        concat!(
+                            "^",
+                            "[+-]?(0[xXoObB][0-9a-zA-Z_.]*|[0-9][0-9_]*(\\.[0-9][0-9_]*)?(ms|s|m|h|KB|MB|GB)?)"
+                        ),
+                    )
+                    .unwrap()
+            }),
+        ),
+    ),
+    TokenRecognizer(TokenKind::OpenBrace, Recognizer::StrMatch("{")),
+    TokenRecognizer(TokenKind::OpenBracket, Recognizer::StrMatch("[")),
+    TokenRecognizer(TokenKind::OpenParen, Recognizer::StrMatch("(")),
+    TokenRecognizer(
+        TokenKind::ParagraphLine,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "[^#`\\n][^\\n]*\\n")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(TokenKind::PluralKeyword, Recognizer::StrMatch("plural")),
+    TokenRecognizer(TokenKind::Plus, Recognizer::StrMatch("+")),
+    TokenRecognizer(TokenKind::Question, Recognizer::StrMatch("?")),
+    TokenRecognizer(
+        TokenKind::QuotedStringDouble,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "\"([^\"\\\\]|\\\\.)*\"")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::QuotedStringTriple,
+        Recognizer::RegexMatch(
+            Lazy::new(|| {
+                Regex::new(concat!("^", "\"\"\"([^\"]|\"[^\"]|\"\"[^\"])*\"{0,2}\"\"\""))
+                    .unwrap()
+            }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::QuotedStringSingle,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "'([^'\\\\]|\\\\.)*'")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::RegularCodeLine,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "[^\\n]*\\n")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::RegularCodeStartNamed,
+        Recognizer::RegexMatch(
+            Lazy::new(|| {
+                Regex::new(concat!("^", "```[a-z]+[ \\t]*\\r?\\n")).unwrap()
+            }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::RegularCodeStartUnnamed,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "```[ \\t]*\\r?\\n")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::RegularCodeEnd,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "```[ \\t]*(\\r?\\n)?")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(TokenKind::Semicolon, Recognizer::StrMatch(";")),
+    TokenRecognizer(TokenKind::Slash, Recognizer::StrMatch("/")),
+    TokenRecognizer(TokenKind::Star, Recognizer::StrMatch("*")),
+    TokenRecognizer(
+        TokenKind::UnquotedString,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "[a-zA-Z0-9_.-]+")).unwrap() }),
+        ),
+    ),
+    TokenRecognizer(
+        TokenKind::WS,
+        Recognizer::RegexMatch(
+            Lazy::new(|| { Regex::new(concat!("^", "\\s+")).unwrap() }),
+        ),
+    ),
+];
+pub struct DefaultBuilder {
+    res_stack: Vec<Symbol>,
+}
+impl DefaultBuilder {
+    #[allow(dead_code)]
+    pub fn new() -> Self {
+        Self { res_stack: vec![] }
+    }
+}
+impl Builder for DefaultBuilder {
+    type Output = cola_actions::Cola;
+    fn get_result(&mut self) -> Self::Output {
+        match self.res_stack.pop().unwrap() {
+            Symbol::NonTerminal(NonTerminal::Cola(r)) => r,
+            _ => panic!("Invalid result on the parse stack!"),
+        }
+    }
+}
+impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind>
+for DefaultBuilder {
+    #![allow(unused_variables)]
+    fn shift_action(
+        &mut self,
+        context: &Context<'i, Input>,
+        token: Token<'i, Input, TokenKind>,
+    ) {
+        let val = match token.kind {
+            TokenKind::STOP => panic!("Cannot shift STOP token!"),
+            TokenKind::Ampersand => Terminal::Ampersand,
+            TokenKind::AsKeyword => Terminal::AsKeyword,
+            TokenKind::Bang => Terminal::Bang,
+            TokenKind::BooleanTrue => {
+                Terminal::BooleanTrue(cola_actions::boolean_true(context, token))
+            }
+            TokenKind::BooleanFalse => {
+                Terminal::BooleanFalse(cola_actions::boolean_false(context, token))
+            }
+            TokenKind::ColaCodeStart => {
+                Terminal::ColaCodeStart(cola_actions::cola_code_start(context, token))
+            }
+            TokenKind::ColaCodeEnd => {
+                Terminal::ColaCodeEnd(cola_actions::cola_code_end(context, token))
+            }
+            TokenKind::Colon => Terminal::Colon,
+            TokenKind::CloseBrace => Terminal::CloseBrace,
+            TokenKind::CloseBracket => Terminal::CloseBracket,
+            TokenKind::CloseParen => Terminal::CloseParen,
+            TokenKind::Comma => Terminal::Comma,
+            TokenKind::EnumKeyword => Terminal::EnumKeyword,
+            TokenKind::Equals => Terminal::Equals,
+            TokenKind::IncludeKeyword => Terminal::IncludeKeyword,
+            TokenKind::HeadingLine => {
+                Terminal::HeadingLine(cola_actions::heading_line(context, token))
+            }
+            TokenKind::Identifier => {
+                Terminal::Identifier(cola_actions::identifier(context, token))
+            }
+            TokenKind::KeyKeyword => Terminal::KeyKeyword,
+            TokenKind::Minus => Terminal::Minus,
+            TokenKind::Null => Terminal::Null(cola_actions::null(context, token)),
+            TokenKind::Number => Terminal::Number(cola_actions::number(context, token)),
+            TokenKind::OpenBrace => Terminal::OpenBrace,
+            TokenKind::OpenBracket => Terminal::OpenBracket,
+            TokenKind::OpenParen => Terminal::OpenParen,
+            TokenKind::ParagraphLine => {
+                Terminal::ParagraphLine(cola_actions::paragraph_line(context, token))
+            }
+            TokenKind::PluralKeyword => Terminal::PluralKeyword,
+            TokenKind::Plus => Terminal::Plus,
+            TokenKind::Question => Terminal::Question,
+            TokenKind::QuotedStringDouble => {
+                Terminal::QuotedStringDouble(
+                    cola_actions::quoted_string_double(context, token),
+                )
+            }
+            TokenKind::QuotedStringTriple => {
+                Terminal::QuotedStringTriple(
+                    cola_actions::quoted_string_triple(context, token),
+                )
+            }
+            TokenKind::QuotedStringSingle => {
+                Terminal::QuotedStringSingle(
+                    cola_actions::quoted_string_single(context, token),
+                )
+            }
+            TokenKind::RegularCodeLine => {
+                Terminal::RegularCodeLine(
+                    cola_actions::regular_code_line(context, token),
+                )
+            }
+            TokenKind::RegularCodeStartNamed => {
+                Terminal::RegularCodeStartNamed(
+                    cola_actions::regular_code_start_named(context, token),
+                )
+            }
+            TokenKind::RegularCodeStartUnnamed => {
+                Terminal::RegularCodeStartUnnamed(
+                    cola_actions::regular_code_start_unnamed(context, token),
+                )
+            }
+            TokenKind::RegularCodeEnd => {
+                Terminal::RegularCodeEnd(cola_actions::regular_code_end(context, token))
+            }
+            TokenKind::Semicolon => Terminal::Semicolon,
+            TokenKind::Slash => Terminal::Slash,
+            TokenKind::Star => Terminal::Star,
+            _ => panic!("Shift of unreachable terminal!"),
+        };
+        self.res_stack.push(Symbol::Terminal(val));
+    }
+    fn reduce_action(
+        &mut self,
+        context: &Context<'i, Input>,
+        prod: ProdKind,
+        prod_len: usize,
+    ) {
+        let prod = match prod {
+            ProdKind::ColaP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::MarkdownItem0(p0)) => {
+                        NonTerminal::Cola(cola_actions::cola_markdown_item0(context, p0))
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::MarkdownItem1P1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 2usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::MarkdownItem1(p0)),
+                        Symbol::NonTerminal(NonTerminal::MarkdownItem(p1)),
+                    ) => {
+                        NonTerminal::MarkdownItem1(
+                            cola_actions::markdown_item1_c1(context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::MarkdownItem1P2 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::MarkdownItem(p0)) => {
+                        NonTerminal::MarkdownItem1(
+                            cola_actions::markdown_item1_markdown_item(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::MarkdownItem0P1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::MarkdownItem1(p0)) => {
+                        NonTerminal::MarkdownItem0(
+                            cola_actions::markdown_item0_markdown_item1(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::MarkdownItem0P2 => {
+                NonTerminal::MarkdownItem0(cola_actions::markdown_item0_empty(context))
+            }
+            ProdKind::MarkdownItemP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::Terminal(Terminal::HeadingLine(p0)) => {
+                        NonTerminal::MarkdownItem(
+                            cola_actions::markdown_item_heading_line(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::MarkdownItemP2 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::CodeBlock(p0)) => {
+                        NonTerminal::MarkdownItem(
+                            cola_actions::markdown_item_code_block(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::MarkdownItemP3 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::Terminal(Terminal::ParagraphLine(p0)) => {
+                        NonTerminal::MarkdownItem(
+                            cola_actions::markdown_item_paragraph_line(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::CodeBlockP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::ColaCodeBlock(p0)) => {
+                        NonTerminal::CodeBlock(
+                            cola_actions::code_block_cola_code_block(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::CodeBlockP2 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::RegularCodeBlock(p0)) => {
+                        NonTerminal::CodeBlock(
+                            cola_actions::code_block_regular_code_block(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::ColaCodeBlockP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 3usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::Terminal(Terminal::ColaCodeStart(p0)),
+                        Symbol::NonTerminal(NonTerminal::ColaSyntax(p1)),
+                        Symbol::Terminal(Terminal::ColaCodeEnd(p2)),
+                    ) => {
+                        NonTerminal::ColaCodeBlock(
+                            cola_actions::cola_code_block_c1(context, p0, p1, p2),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::ColaSyntaxP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::ColaItem0(p0)) => {
+                        NonTerminal::ColaSyntax(
+                            cola_actions::cola_syntax_cola_item0(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::ColaItem1P1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 2usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::ColaItem1(p0)),
+                        Symbol::NonTerminal(NonTerminal::ColaItem(p1)),
+                    ) => {
+                        NonTerminal::ColaItem1(
+                            cola_actions::cola_item1_c1(context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::ColaItem1P2 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::ColaItem(p0)) => {
+                        NonTerminal::ColaItem1(
+                            cola_actions::cola_item1_cola_item(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::ColaItem0P1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::ColaItem1(p0)) => {
+                        NonTerminal::ColaItem0(
+                            cola_actions::cola_item0_cola_item1(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::ColaItem0P2 => {
+                NonTerminal::ColaItem0(cola_actions::cola_item0_empty(context))
+            }
+            ProdKind::ColaItemP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::Entity(p0)) => {
+                        NonTerminal::ColaItem(
+                            cola_actions::cola_item_entity(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::ColaItemP2 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::FieldList(p0)) => {
+                        NonTerminal::ColaItem(
+                            cola_actions::cola_item_field_list(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::ColaItemP3 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::AnchorDefinition(p0)) => {
+                        NonTerminal::ColaItem(
+                            cola_actions::cola_item_anchor_definition(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::ColaItemP4 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::Include(p0)) => {
+                        NonTerminal::ColaItem(
+                            cola_actions::cola_item_include(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::EntityP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::PluralEntity(p0)) => {
+                        NonTerminal::Entity(
+                            cola_actions::entity_plural_entity(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::EntityP2 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::SingularEntity(p0)) => {
+                        NonTerminal::Entity(
+                            cola_actions::entity_singular_entity(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::EntityP3 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::InlinePluralEntity(p0)) => {
+                        NonTerminal::Entity(
+                            cola_actions::entity_inline_plural_entity(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::PluralEntityP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 8usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::Terminal(Terminal::Identifier(p0)),
+                        _,
+                        Symbol::Terminal(Terminal::Identifier(p1)),
+                        Symbol::NonTerminal(NonTerminal::PluralKeyOpt(p2)),
+                        Symbol::NonTerminal(NonTerminal::PluralEnumOpt(p3)),
+                        _,
+                        Symbol::NonTerminal(NonTerminal::EntityDefinition(p4)),
+                        _,
+                    ) => {
+                        NonTerminal::PluralEntity(
+                            cola_actions::plural_entity_c1(context, p0, p1, p2, p3, p4),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::PluralKeyOptP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::PluralKey(p0)) => {
+                        NonTerminal::PluralKeyOpt(
+                            cola_actions::plural_key_opt_plural_key(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::PluralKeyOptP2 => {
+                NonTerminal::PluralKeyOpt(cola_actions::plural_key_opt_empty(context))
+            }
+            ProdKind::PluralEnumOptP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::PluralEnum(p0)) => {
+                        NonTerminal::PluralEnumOpt(
+                            cola_actions::plural_enum_opt_plural_enum(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::PluralEnumOptP2 => {
+                NonTerminal::PluralEnumOpt(cola_actions::plural_enum_opt_empty(context))
+            }
+            ProdKind::SingularEntityP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 4usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::Terminal(Terminal::Identifier(p0)),
+                        _,
+                        Symbol::NonTerminal(NonTerminal::EntityDefinition(p1)),
+                        _,
+                    ) => {
+                        NonTerminal::SingularEntity(
+                            cola_actions::singular_entity_c1(context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::InlinePluralEntityP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 5usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::Terminal(Terminal::Identifier(p0)),
+                        _,
+                        _,
+                        Symbol::NonTerminal(NonTerminal::InlineInstancesOpt(p1)),
+                        _,
+                    ) => {
+                        NonTerminal::InlinePluralEntity(
+                            cola_actions::inline_plural_entity_c1(context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::InlineInstancesOptP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::InlineInstances(p0)) => {
+                        NonTerminal::InlineInstancesOpt(
+                            cola_actions::inline_instances_opt_inline_instances(
+                                context,
+                                p0,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::InlineInstancesOptP2 => {
+                NonTerminal::InlineInstancesOpt(
+                    cola_actions::inline_instances_opt_empty(context),
+                )
+            }
+            ProdKind::InlineInstancesP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::Terminal(Terminal::Identifier(p0)) => {
+                        NonTerminal::InlineInstances(
+                            cola_actions::inline_instances_identifier1(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::InlineInstancesP2 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 2usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (Symbol::Terminal(Terminal::Identifier(p0)), _) => {
+                        NonTerminal::InlineInstances(
+                            cola_actions::inline_instances_identifier2(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::InlineInstancesP3 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 3usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::Terminal(Terminal::Identifier(p0)),
+                        _,
+                        Symbol::NonTerminal(NonTerminal::InlineInstances(p1)),
+                    ) => {
+                        NonTerminal::InlineInstances(
+                            cola_actions::inline_instances_c3(context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            #[allow(unused_variables)]
-            TokenRecognizer(token_kind, Recognizer::RegexMatch(r)) => {
-                logn!("{} {:?} -- ", "    Recognizing".green(), token_kind);
-                let match_str = r.find(input);
-                match match_str {
-                    Some(x) => {
-                        let x_str = x.as_str();
-                        log!("{} '{}'", "recognized".bold().green(), x_str);
-                        Some(x_str)
+            ProdKind::PluralKeyP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 2usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (_, Symbol::Terminal(Terminal::Identifier(p0))) => {
+                        NonTerminal::PluralKey(
+                            cola_actions::plural_key_identifier(context, p0),
+                        )
                     }
-                    _ => {
-                        log!("{}", "not recognized".red());
-                        None
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::PluralEnumP1 => {
+                let _ = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 2usize)
+                    .into_iter();
+                NonTerminal::PluralEnum(cola_actions::plural_enum_c1(context))
+            }
+            ProdKind::EntityDefinitionPlain => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::NestedBlock0(p0)) => {
+                        NonTerminal::EntityDefinition(
+                            cola_actions::entity_definition_plain(context, p0),
+                        )
                     }
+                    _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            TokenRecognizer(_, Recognizer::Stop) => {
-                logn!("{} STOP -- ", "    Recognizing".green());
-                if input.is_empty() {
-                    log!("{}", "recognized".bold().green());
-                    Some("")
-                } else {
-                    log!("{}", "not recognized".red());
-                    None
+            ProdKind::NestedBlock1P1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 2usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::NestedBlock1(p0)),
+                        Symbol::NonTerminal(NonTerminal::NestedBlock(p1)),
+                    ) => {
+                        NonTerminal::NestedBlock1(
+                            cola_actions::nested_block1_c1(context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-        }
-    }
-}
-pub(crate) static RECOGNIZERS: [TokenRecognizer; TERMINAL_COUNT] = [
-    TokenRecognizer(TokenKind::STOP, Recognizer::Stop),
-    TokenRecognizer(TokenKind::BooleanTrue, Recognizer::StrMatch("true")),
-    TokenRecognizer(TokenKind::BooleanFalse, Recognizer::StrMatch("false")),
-    TokenRecognizer(
-        TokenKind::ColaCodeStart,
-        Recognizer::RegexMatch(
-            Lazy::new(|| {
-                Regex::new(concat!("^", "```[ \\t]*cola[ \\t]*\\n")).unwrap()
-            }),
-        ),
-    ),
-    TokenRecognizer(
-        TokenKind::ColaCodeEnd,
-        Recognizer::RegexMatch(
-            Lazy::new(|| { Regex::new(concat!("^", "```[ \\t]*\\n?")).unwrap() }),
-        ),
-    ),
-    TokenRecognizer(TokenKind::Colon, Recognizer::StrMatch(":")),
-    TokenRecognizer(TokenKind::Comma, Recognizer::StrMatch(",")),
-    TokenRecognizer(
-        TokenKind::HeadingLine,
-        Recognizer::RegexMatch(
-            Lazy::new(|| {
-                Regex::new(concat!("^", "#{1,6}[ \\t]+[^\\n]*\\n")).unwrap()
-            }),
-        ),
-    ),
-    TokenRecognizer(
-        TokenKind::Identifier,
-        Recognizer::RegexMatch(
-            Lazy::new(|| {
-                Regex::new(concat!("^", "[a-zA-Z_][a-zA-Z0-9_.-]*")).unwrap()
-            }),
-        ),
-    ),
-    TokenRecognizer(
-        TokenKind::Number,
-        Recognizer::RegexMatch(
-            Lazy::new(|| {
-                Regex::new(concat!("^", "[+-]?[0-9]+(\\.[0-9]+)?")).unwrap()
-            }),
-        ),
-    ),
-    TokenRecognizer(
-        TokenKind::ParagraphLine,
-        Recognizer::RegexMatch(
-            Lazy::new(|| { Regex::new(concat!("^", "[^#`\\n][^\\n]*\\n")).unwrap() }),
-        ),
-    ),
-    TokenRecognizer(TokenKind::PluralKeyword, Recognizer::StrMatch("plural")),
-    TokenRecognizer(
-        TokenKind::QuotedStringDouble,
-        Recognizer::RegexMatch(
-            Lazy::new(|| { Regex::new(concat!("^", "\"([^\"\\\\]|\\\\.)*\"")).unwrap() }),
-        ),
-    ),
-    TokenRecognizer(
-        TokenKind::QuotedStringSingle,
-        Recognizer::RegexMatch(
-            Lazy::new(|| { Regex::new(concat!("^", "'([^'\\\\]|\\\\.)*'")).unwrap() }),
-        ),
-    ),
-    TokenRecognizer(
-        TokenKind::RegularCodeLine,
-        Recognizer::RegexMatch(
-            Lazy::new(|| { Regex::new(concat!("^", "[^\\n]*\\n")).unwrap() }),
-        ),
-    ),
-    TokenRecognizer(
-        TokenKind::RegularCodeStartNamed,
-        Recognizer::RegexMatch(
-            Lazy::new(|| { Regex::new(concat!("^", "```[a-z]+[ \\t]*\\n")).unwrap() }),
-        ),
-    ),
-    TokenRecognizer(
-        TokenKind::RegularCodeStartUnnamed,
-        Recognizer::RegexMatch(
-            Lazy::new(|| { Regex::new(concat!("^", "```[ \\t]*\\n")).unwrap() }),
-        ),
-    ),
-    TokenRecognizer(
-        TokenKind::RegularCodeEnd,
-        Recognizer::RegexMatch(
-            Lazy::new(|| { Regex::new(concat!("^", "```[ \\t]*\\n?")).unwrap() }),
-        ),
-    ),
-    TokenRecognizer(TokenKind::Semicolon, Recognizer::StrMatch(";")),
-    TokenRecognizer(
-        TokenKind::UnquotedString,
-        Recognizer::RegexMatch(
-            Lazy::new(|| { Regex::new(concat!("^", "[a-zA-Z0-9_.-]+")).unwrap() }),
-        ),
-    ),
-    TokenRecognizer(
-        TokenKind::WS,
-        Recognizer::RegexMatch(
-            Lazy::new(|| { Regex::new(concat!("^", "\\s+")).unwrap() }),
-        ),
-    ),
-];
-pub struct DefaultBuilder {
-    res_stack: Vec<Symbol>,
-}
-impl DefaultBuilder {
-    #[allow(dead_code)]
-    pub fn new() -> Self {
-        Self { res_stack: vec![] }
-    }
-}
-impl Builder for DefaultBuilder {
-    type Output = cola_actions::Cola;
-    fn get_result(&mut self) -> Self::Output {
-        match self.res_stack.pop().unwrap() {
-            Symbol::NonTerminal(NonTerminal::Cola(r)) => r,
-            _ => panic!("Invalid result on the parse stack!"),
-        }
-    }
-}
-impl<'i> LRBuilder<'i, Input, Context<'i, Input>, State, ProdKind, TokenKind>
-for DefaultBuilder {
-    #![allow(unused_variables)]
-    fn shift_action(
-        &mut self,
-        context: &Context<'i, Input>,
-        token: Token<'i, Input, TokenKind>,
-    ) {
-        let val = match token.kind {
-            TokenKind::STOP => panic!("Cannot shift STOP token!"),
-            TokenKind::BooleanTrue => Terminal::BooleanTrue,
-            TokenKind::BooleanFalse => Terminal::BooleanFalse,
-            TokenKind::ColaCodeStart => {
-                Terminal::ColaCodeStart(cola_actions::cola_code_start(context, token))
+            ProdKind::NestedBlock1P2 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::NestedBlock(p0)) => {
+                        NonTerminal::NestedBlock1(
+                            cola_actions::nested_block1_nested_block(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::NestedBlock0P1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::NestedBlock1(p0)) => {
+                        NonTerminal::NestedBlock0(
+                            cola_actions::nested_block0_nested_block1(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::NestedBlock0P2 => {
+                NonTerminal::NestedBlock0(cola_actions::nested_block0_empty(context))
+            }
+            ProdKind::EntityDefinitionBraced => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 3usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (_, Symbol::NonTerminal(NonTerminal::NestedBlock0(p0)), _) => {
+                        NonTerminal::EntityDefinition(
+                            cola_actions::entity_definition_braced(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::NestedBlockP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::FieldList(p0)) => {
+                        NonTerminal::NestedBlock(
+                            cola_actions::nested_block_field_list(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::NestedBlockP2 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::Entity(p0)) => {
+                        NonTerminal::NestedBlock(
+                            cola_actions::nested_block_entity(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::NestedBlockP3 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::AnchorDefinition(p0)) => {
+                        NonTerminal::NestedBlock(
+                            cola_actions::nested_block_anchor_definition(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
             }
-            TokenKind::ColaCodeEnd => {
-                Terminal::ColaCodeEnd(cola_actions::cola_code_end(context, token))
+            ProdKind::NestedBlockP4 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::Include(p0)) => {
+                        NonTerminal::NestedBlock(
+                            cola_actions::nested_block_include(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
             }
-            TokenKind::Colon => Terminal::Colon,
-            TokenKind::Comma => Terminal::Comma,
-            TokenKind::HeadingLine => {
-                Terminal::HeadingLine(cola_actions::heading_line(context, token))
+            ProdKind::IncludeP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 2usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (_, Symbol::Terminal(Terminal::QuotedStringDouble(p0))) => {
+                        NonTerminal::Include(
+                            cola_actions::include_quoted_string_double(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
             }
-            TokenKind::Identifier => {
-                Terminal::Identifier(cola_actions::identifier(context, token))
+            ProdKind::AnchorDefinitionP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 3usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        _,
+                        Symbol::Terminal(Terminal::Identifier(p0)),
+                        Symbol::NonTerminal(NonTerminal::FieldValue(p1)),
+                    ) => {
+                        NonTerminal::AnchorDefinition(
+                            cola_actions::anchor_definition_c1(context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
             }
-            TokenKind::Number => Terminal::Number(cola_actions::number(context, token)),
-            TokenKind::ParagraphLine => {
-                Terminal::ParagraphLine(cola_actions::paragraph_line(context, token))
+            ProdKind::AnchorReferenceP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 2usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (_, Symbol::Terminal(Terminal::Identifier(p0))) => {
+                        NonTerminal::AnchorReference(
+                            cola_actions::anchor_reference_identifier(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
             }
-            TokenKind::PluralKeyword => Terminal::PluralKeyword,
-            TokenKind::QuotedStringDouble => {
-                Terminal::QuotedStringDouble(
-                    cola_actions::quoted_string_double(context, token),
-                )
+            ProdKind::FieldListP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::FieldEntry(p0)) => {
+                        NonTerminal::FieldList(
+                            cola_actions::field_list_field_entry(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
             }
-            TokenKind::QuotedStringSingle => {
-                Terminal::QuotedStringSingle(
-                    cola_actions::quoted_string_single(context, token),
-                )
+            ProdKind::FieldListP2 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 3usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::FieldList(p0)),
+                        _,
+                        Symbol::NonTerminal(NonTerminal::FieldEntry(p1)),
+                    ) => {
+                        NonTerminal::FieldList(
+                            cola_actions::field_list_c2(context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
             }
-            TokenKind::RegularCodeLine => {
-                Terminal::RegularCodeLine(
-                    cola_actions::regular_code_line(context, token),
-                )
+            ProdKind::FieldEntryP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::Field(p0)) => {
+                        NonTerminal::FieldEntry(
+                            cola_actions::field_entry_field(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
             }
-            TokenKind::RegularCodeStartNamed => {
-                Terminal::RegularCodeStartNamed(
-                    cola_actions::regular_code_start_named(context, token),
-                )
+            ProdKind::FieldEntryP2 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::OptionalField(p0)) => {
+                        NonTerminal::FieldEntry(
+                            cola_actions::field_entry_optional_field(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
             }
-            TokenKind::RegularCodeStartUnnamed => {
-                Terminal::RegularCodeStartUnnamed(
-                    cola_actions::regular_code_start_unnamed(context, token),
-                )
+            ProdKind::FieldEntryP3 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::RequiredField(p0)) => {
+                        NonTerminal::FieldEntry(
+                            cola_actions::field_entry_required_field(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
             }
-            TokenKind::RegularCodeEnd => {
-                Terminal::RegularCodeEnd(cola_actions::regular_code_end(context, token))
+            ProdKind::FieldEntryP4 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 1usize)
+                    .into_iter();
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::ComputedField(p0)) => {
+                        NonTerminal::FieldEntry(
+                            cola_actions::field_entry_computed_field(context, p0),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
             }
-            TokenKind::Semicolon => Terminal::Semicolon,
-            _ => panic!("Shift of unreachable terminal!"),
-        };
-        self.res_stack.push(Symbol::Terminal(val));
-    }
-    fn reduce_action(
-        &mut self,
-        context: &Context<'i, Input>,
-        prod: ProdKind,
-        prod_len: usize,
-    ) {
-        let prod = match prod {
-            ProdKind::ColaP1 => {
+            ProdKind::FieldEntryP5 => {
                 let mut i = self
                     .res_stack
                     .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
                 match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::MarkdownItem0(p0)) => {
-                        NonTerminal::Cola(cola_actions::cola_markdown_item0(context, p0))
+                    Symbol::NonTerminal(NonTerminal::FieldDeclaration(p0)) => {
+                        NonTerminal::FieldEntry(
+                            cola_actions::field_entry_field_declaration(context, p0),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::MarkdownItem1P1 => {
+            ProdKind::FieldEntryP6 => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 2usize)
+                    .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
-                match (i.next().unwrap(), i.next().unwrap()) {
-                    (
-                        Symbol::NonTerminal(NonTerminal::MarkdownItem1(p0)),
-                        Symbol::NonTerminal(NonTerminal::MarkdownItem(p1)),
-                    ) => {
-                        NonTerminal::MarkdownItem1(
-                            cola_actions::markdown_item1_c1(context, p0, p1),
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::OptionalDeclaration(p0)) => {
+                        NonTerminal::FieldEntry(
+                            cola_actions::field_entry_optional_declaration(context, p0),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::MarkdownItem1P2 => {
+            ProdKind::FieldEntryP7 => {
                 let mut i = self
                     .res_stack
                     .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
                 match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::MarkdownItem(p0)) => {
-                        NonTerminal::MarkdownItem1(
-                            cola_actions::markdown_item1_markdown_item(context, p0),
+                    Symbol::NonTerminal(NonTerminal::RequiredDeclaration(p0)) => {
+                        NonTerminal::FieldEntry(
+                            cola_actions::field_entry_required_declaration(context, p0),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::MarkdownItem0P1 => {
+            ProdKind::FieldP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 4usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::Terminal(Terminal::Identifier(p0)),
+                        _,
+                        Symbol::NonTerminal(NonTerminal::TypeAnnotationOpt(p1)),
+                        Symbol::NonTerminal(NonTerminal::FieldValue(p2)),
+                    ) => NonTerminal::Field(cola_actions::field_c1(context, p0, p1, p2)),
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::TypeAnnotationOptP1 => {
                 let mut i = self
                     .res_stack
                     .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
                 match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::MarkdownItem1(p0)) => {
-                        NonTerminal::MarkdownItem0(
-                            cola_actions::markdown_item0_markdown_item1(context, p0),
+                    Symbol::NonTerminal(NonTerminal::TypeAnnotation(p0)) => {
+                        NonTerminal::TypeAnnotationOpt(
+                            cola_actions::type_annotation_opt_type_annotation(
+                                context,
+                                p0,
+                            ),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::TypeAnnotationOptP2 => {
+                NonTerminal::TypeAnnotationOpt(
+                    cola_actions::type_annotation_opt_empty(context),
+                )
+            }
+            ProdKind::OptionalFieldP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 5usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::Terminal(Terminal::Identifier(p0)),
+                        _,
+                        _,
+                        Symbol::NonTerminal(NonTerminal::TypeAnnotationOpt(p1)),
+                        Symbol::NonTerminal(NonTerminal::FieldValue(p2)),
+                    ) => {
+                        NonTerminal::OptionalField(
+                            cola_actions::optional_field_c1(context, p0, p1, p2),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::RequiredFieldP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 5usize)
+                    .into_iter();
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::Terminal(Terminal::Identifier(p0)),
+                        _,
+                        _,
+                        Symbol::NonTerminal(NonTerminal::TypeAnnotationOpt(p1)),
+                        Symbol::NonTerminal(NonTerminal::FieldValue(p2)),
+                    ) => {
+                        NonTerminal::RequiredField(
+                            cola_actions::required_field_c1(context, p0, p1, p2),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
+            }
+            ProdKind::FieldDeclarationP1 => {
+                let mut i = self
+                    .res_stack
+                    .split_off(self.res_stack.len() - 3usize)
+                    .into_iter();
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::Terminal(Terminal::Identifier(p0)),
+                        _,
+                        Symbol::NonTerminal(NonTerminal::TypeName(p1)),
+                    ) => {
+                        NonTerminal::FieldDeclaration(
+                            cola_actions::field_declaration_c1(context, p0, p1),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::MarkdownItem0P2 => {
-                NonTerminal::MarkdownItem0(cola_actions::markdown_item0_empty(context))
-            }
-            ProdKind::MarkdownItemP1 => {
+            ProdKind::OptionalDeclarationP1 => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
+                    .split_off(self.res_stack.len() - 4usize)
                     .into_iter();
-                match i.next().unwrap() {
-                    Symbol::Terminal(Terminal::HeadingLine(p0)) => {
-                        NonTerminal::MarkdownItem(
-                            cola_actions::markdown_item_heading_line(context, p0),
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::Terminal(Terminal::Identifier(p0)),
+                        _,
+                        _,
+                        Symbol::NonTerminal(NonTerminal::TypeName(p1)),
+                    ) => {
+                        NonTerminal::OptionalDeclaration(
+                            cola_actions::optional_declaration_c1(context, p0, p1),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::MarkdownItemP2 => {
+            ProdKind::RequiredDeclarationP1 => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
+                    .split_off(self.res_stack.len() - 4usize)
                     .into_iter();
-                match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::CodeBlock(p0)) => {
-                        NonTerminal::MarkdownItem(
-                            cola_actions::markdown_item_code_block(context, p0),
+                match (
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                    i.next().unwrap(),
+                ) {
+                    (
+                        Symbol::Terminal(Terminal::Identifier(p0)),
+                        _,
+                        _,
+                        Symbol::NonTerminal(NonTerminal::TypeName(p1)),
+                    ) => {
+                        NonTerminal::RequiredDeclaration(
+                            cola_actions::required_declaration_c1(context, p0, p1),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::MarkdownItemP3 => {
+            ProdKind::TypeNameP1 => {
                 let mut i = self
                     .res_stack
                     .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
                 match i.next().unwrap() {
-                    Symbol::Terminal(Terminal::ParagraphLine(p0)) => {
-                        NonTerminal::MarkdownItem(
-                            cola_actions::markdown_item_paragraph_line(context, p0),
+                    Symbol::Terminal(Terminal::Identifier(p0)) => {
+                        NonTerminal::TypeName(
+                            cola_actions::type_name_identifier(context, p0),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::CodeBlockP1 => {
+            ProdKind::ComputedFieldP1 => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
+                    .split_off(self.res_stack.len() - 3usize)
                     .into_iter();
-                match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::ColaCodeBlock(p0)) => {
-                        NonTerminal::CodeBlock(
-                            cola_actions::code_block_cola_code_block(context, p0),
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::Terminal(Terminal::Identifier(p0)),
+                        _,
+                        Symbol::NonTerminal(NonTerminal::Expression(p1)),
+                    ) => {
+                        NonTerminal::ComputedField(
+                            cola_actions::computed_field_c1(context, p0, p1),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::CodeBlockP2 => {
+            ProdKind::ExpressionAdd => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
+                    .split_off(self.res_stack.len() - 3usize)
                     .into_iter();
-                match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::RegularCodeBlock(p0)) => {
-                        NonTerminal::CodeBlock(
-                            cola_actions::code_block_regular_code_block(context, p0),
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::Expression(p0)),
+                        _,
+                        Symbol::NonTerminal(NonTerminal::Expression(p1)),
+                    ) => {
+                        NonTerminal::Expression(
+                            cola_actions::expression_add(context, p0, p1),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::ColaCodeBlockP1 => {
+            ProdKind::ExpressionSub => {
                 let mut i = self
                     .res_stack
                     .split_off(self.res_stack.len() - 3usize)
                     .into_iter();
                 match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
                     (
-                        Symbol::Terminal(Terminal::ColaCodeStart(p0)),
-                        Symbol::NonTerminal(NonTerminal::ColaSyntax(p1)),
-                        Symbol::Terminal(Terminal::ColaCodeEnd(p2)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p0)),
+                        _,
+                        Symbol::NonTerminal(NonTerminal::Expression(p1)),
                     ) => {
-                        NonTerminal::ColaCodeBlock(
-                            cola_actions::cola_code_block_c1(context, p0, p1, p2),
+                        NonTerminal::Expression(
+                            cola_actions::expression_sub(context, p0, p1),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::ColaSyntaxP1 => {
+            ProdKind::ExpressionMul => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
+                    .split_off(self.res_stack.len() - 3usize)
                     .into_iter();
-                match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::Entity0(p0)) => {
-                        NonTerminal::ColaSyntax(
-                            cola_actions::cola_syntax_entity0(context, p0),
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::Expression(p0)),
+                        _,
+                        Symbol::NonTerminal(NonTerminal::Expression(p1)),
+                    ) => {
+                        NonTerminal::Expression(
+                            cola_actions::expression_mul(context, p0, p1),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::Entity1P1 => {
+            ProdKind::ExpressionDiv => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 2usize)
+                    .split_off(self.res_stack.len() - 3usize)
                     .into_iter();
-                match (i.next().unwrap(), i.next().unwrap()) {
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
                     (
-                        Symbol::NonTerminal(NonTerminal::Entity1(p0)),
-                        Symbol::NonTerminal(NonTerminal::Entity(p1)),
-                    ) => NonTerminal::Entity1(cola_actions::entity1_c1(context, p0, p1)),
+                        Symbol::NonTerminal(NonTerminal::Expression(p0)),
+                        _,
+                        Symbol::NonTerminal(NonTerminal::Expression(p1)),
+                    ) => {
+                        NonTerminal::Expression(
+                            cola_actions::expression_div(context, p0, p1),
+                        )
+                    }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::Entity1P2 => {
+            ProdKind::ExpressionGroup => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
+                    .split_off(self.res_stack.len() - 3usize)
                     .into_iter();
-                match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::Entity(p0)) => {
-                        NonTerminal::Entity1(cola_actions::entity1_entity(context, p0))
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (_, Symbol::NonTerminal(NonTerminal::Expression(p0)), _) => {
+                        NonTerminal::Expression(
+                            cola_actions::expression_group(context, p0),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::Entity0P1 => {
+            ProdKind::ExpressionP6 => {
                 let mut i = self
                     .res_stack
                     .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
                 match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::Entity1(p0)) => {
-                        NonTerminal::Entity0(cola_actions::entity0_entity1(context, p0))
+                    Symbol::Terminal(Terminal::Number(p0)) => {
+                        NonTerminal::Expression(
+                            cola_actions::expression_number(context, p0),
+                        )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::Entity0P2 => {
-                NonTerminal::Entity0(cola_actions::entity0_empty(context))
-            }
-            ProdKind::EntityP1 => {
+            ProdKind::ExpressionP7 => {
                 let mut i = self
                     .res_stack
                     .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
                 match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::PluralEntity(p0)) => {
-                        NonTerminal::Entity(
-                            cola_actions::entity_plural_entity(context, p0),
+                    Symbol::Terminal(Terminal::Identifier(p0)) => {
+                        NonTerminal::Expression(
+                            cola_actions::expression_identifier(context, p0),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::EntityP2 => {
+            ProdKind::ExpressionP8 => {
                 let mut i = self
                     .res_stack
                     .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
                 match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::SingularEntity(p0)) => {
-                        NonTerminal::Entity(
-                            cola_actions::entity_singular_entity(context, p0),
+                    Symbol::NonTerminal(NonTerminal::AnchorReference(p0)) => {
+                        NonTerminal::Expression(
+                            cola_actions::expression_anchor_reference(context, p0),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::PluralEntityP1 => {
+            ProdKind::TypeAnnotationP1 => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 6usize)
+                    .split_off(self.res_stack.len() - 2usize)
                     .into_iter();
-                match (
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                ) {
-                    (
-                        Symbol::Terminal(Terminal::Identifier(p0)),
-                        _,
-                        Symbol::Terminal(Terminal::Identifier(p1)),
-                        _,
-                        Symbol::NonTerminal(NonTerminal::EntityDefinition(p2)),
-                        _,
-                    ) => {
-                        NonTerminal::PluralEntity(
-                            cola_actions::plural_entity_c1(context, p0, p1, p2),
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (Symbol::Terminal(Terminal::Identifier(p0)), _) => {
+                        NonTerminal::TypeAnnotation(
+                            cola_actions::type_annotation_identifier(context, p0),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::SingularEntityP1 => {
+            ProdKind::FieldValueP1 => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 4usize)
+                    .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
-                match (
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                    i.next().unwrap(),
-                ) {
-                    (
-                        Symbol::Terminal(Terminal::Identifier(p0)),
-                        _,
-                        Symbol::NonTerminal(NonTerminal::EntityDefinition(p1)),
-                        _,
-                    ) => {
-                        NonTerminal::SingularEntity(
-                            cola_actions::singular_entity_c1(context, p0, p1),
+                match i.next().unwrap() {
+                    Symbol::Terminal(Terminal::QuotedStringTriple(p0)) => {
+                        NonTerminal::FieldValue(
+                            cola_actions::field_value_quoted_string_triple(context, p0),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::EntityDefinitionP1 => {
+            ProdKind::FieldValueP2 => {
                 let mut i = self
                     .res_stack
                     .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
                 match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::NestedBlock0(p0)) => {
-                        NonTerminal::EntityDefinition(
-                            cola_actions::entity_definition_nested_block0(context, p0),
+                    Symbol::Terminal(Terminal::QuotedStringDouble(p0)) => {
+                        NonTerminal::FieldValue(
+                            cola_actions::field_value_quoted_string_double(context, p0),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::NestedBlock1P1 => {
+            ProdKind::FieldValueP3 => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 2usize)
+                    .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
-                match (i.next().unwrap(), i.next().unwrap()) {
-                    (
-                        Symbol::NonTerminal(NonTerminal::NestedBlock1(p0)),
-                        Symbol::NonTerminal(NonTerminal::NestedBlock(p1)),
-                    ) => {
-                        NonTerminal::NestedBlock1(
-                            cola_actions::nested_block1_c1(context, p0, p1),
+                match i.next().unwrap() {
+                    Symbol::Terminal(Terminal::QuotedStringSingle(p0)) => {
+                        NonTerminal::FieldValue(
+                            cola_actions::field_value_quoted_string_single(context, p0),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::NestedBlock1P2 => {
+            ProdKind::FieldValueP4 => {
                 let mut i = self
                     .res_stack
                     .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
                 match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::NestedBlock(p0)) => {
-                        NonTerminal::NestedBlock1(
-                            cola_actions::nested_block1_nested_block(context, p0),
+                    Symbol::Terminal(Terminal::Number(p0)) => {
+                        NonTerminal::FieldValue(
+                            cola_actions::field_value_number(context, p0),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::NestedBlock0P1 => {
+            ProdKind::FieldValueP5 => {
                 let mut i = self
                     .res_stack
                     .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
                 match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::NestedBlock1(p0)) => {
-                        NonTerminal::NestedBlock0(
-                            cola_actions::nested_block0_nested_block1(context, p0),
+                    Symbol::Terminal(Terminal::BooleanTrue(p0)) => {
+                        NonTerminal::FieldValue(
+                            cola_actions::field_value_boolean_true(context, p0),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::NestedBlock0P2 => {
-                NonTerminal::NestedBlock0(cola_actions::nested_block0_empty(context))
-            }
-            ProdKind::NestedBlockP1 => {
+            ProdKind::FieldValueP6 => {
                 let mut i = self
                     .res_stack
                     .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
                 match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::FieldList(p0)) => {
-                        NonTerminal::NestedBlock(
-                            cola_actions::nested_block_field_list(context, p0),
+                    Symbol::Terminal(Terminal::BooleanFalse(p0)) => {
+                        NonTerminal::FieldValue(
+                            cola_actions::field_value_boolean_false(context, p0),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::NestedBlockP2 => {
+            ProdKind::FieldValueP7 => {
                 let mut i = self
                     .res_stack
                     .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
                 match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::Entity(p0)) => {
-                        NonTerminal::NestedBlock(
-                            cola_actions::nested_block_entity(context, p0),
+                    Symbol::Terminal(Terminal::Null(p0)) => {
+                        NonTerminal::FieldValue(
+                            cola_actions::field_value_null(context, p0),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::FieldListP1 => {
+            ProdKind::FieldValueP8 => {
                 let mut i = self
                     .res_stack
                     .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
                 match i.next().unwrap() {
-                    Symbol::NonTerminal(NonTerminal::Field(p0)) => {
-                        NonTerminal::FieldList(
-                            cola_actions::field_list_field(context, p0),
+                    Symbol::NonTerminal(NonTerminal::ArrayValue(p0)) => {
+                        NonTerminal::FieldValue(
+                            cola_actions::field_value_array_value(context, p0),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::FieldListP2 => {
+            ProdKind::FieldValueP9 => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 3usize)
+                    .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
-                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
-                    (
-                        Symbol::NonTerminal(NonTerminal::FieldList(p0)),
-                        _,
-                        Symbol::NonTerminal(NonTerminal::Field(p1)),
-                    ) => {
-                        NonTerminal::FieldList(
-                            cola_actions::field_list_c2(context, p0, p1),
+                match i.next().unwrap() {
+                    Symbol::NonTerminal(NonTerminal::AnchorReference(p0)) => {
+                        NonTerminal::FieldValue(
+                            cola_actions::field_value_anchor_reference(context, p0),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::FieldP1 => {
+            ProdKind::ArrayValueP1 => {
                 let mut i = self
                     .res_stack
                     .split_off(self.res_stack.len() - 3usize)
                     .into_iter();
                 match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
-                    (
-                        Symbol::Terminal(Terminal::Identifier(p0)),
-                        _,
-                        Symbol::NonTerminal(NonTerminal::FieldValue(p1)),
-                    ) => NonTerminal::Field(cola_actions::field_c1(context, p0, p1)),
+                    (_, Symbol::NonTerminal(NonTerminal::ArrayItemsOpt(p0)), _) => {
+                        NonTerminal::ArrayValue(
+                            cola_actions::array_value_array_items_opt(context, p0),
+                        )
+                    }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::FieldValueP1 => {
+            ProdKind::ArrayItemsOptP1 => {
                 let mut i = self
                     .res_stack
                     .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
                 match i.next().unwrap() {
-                    Symbol::Terminal(Terminal::QuotedStringDouble(p0)) => {
-                        NonTerminal::FieldValue(
-                            cola_actions::field_value_quoted_string_double(context, p0),
+                    Symbol::NonTerminal(NonTerminal::ArrayItems(p0)) => {
+                        NonTerminal::ArrayItemsOpt(
+                            cola_actions::array_items_opt_array_items(context, p0),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::FieldValueP2 => {
+            ProdKind::ArrayItemsOptP2 => {
+                NonTerminal::ArrayItemsOpt(cola_actions::array_items_opt_empty(context))
+            }
+            ProdKind::ArrayItemsP1 => {
                 let mut i = self
                     .res_stack
                     .split_off(self.res_stack.len() - 1usize)
                     .into_iter();
                 match i.next().unwrap() {
-                    Symbol::Terminal(Terminal::QuotedStringSingle(p0)) => {
-                        NonTerminal::FieldValue(
-                            cola_actions::field_value_quoted_string_single(context, p0),
+                    Symbol::NonTerminal(NonTerminal::FieldValue(p0)) => {
+                        NonTerminal::ArrayItems(
+                            cola_actions::array_items_field_value1(context, p0),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::FieldValueP3 => {
+            ProdKind::ArrayItemsP2 => {
                 let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
+                    .split_off(self.res_stack.len() - 2usize)
                     .into_iter();
-                match i.next().unwrap() {
-                    Symbol::Terminal(Terminal::Number(p0)) => {
-                        NonTerminal::FieldValue(
-                            cola_actions::field_value_number(context, p0),
+                match (i.next().unwrap(), i.next().unwrap()) {
+                    (Symbol::NonTerminal(NonTerminal::FieldValue(p0)), _) => {
+                        NonTerminal::ArrayItems(
+                            cola_actions::array_items_field_value2(context, p0),
                         )
                     }
                     _ => panic!("Invalid symbol parse stack data."),
                 }
             }
-            ProdKind::FieldValueP4 => {
-                let _ = self
-                    .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
-                    .into_iter();
-                NonTerminal::FieldValue(cola_actions::field_value_boolean_true(context))
-            }
-            ProdKind::FieldValueP5 => {
-                let _ = self
+            ProdKind::ArrayItemsP3 => {
+                let mut i = self
                     .res_stack
-                    .split_off(self.res_stack.len() - 1usize)
+                    .split_off(self.res_stack.len() - 3usize)
                     .into_iter();
-                NonTerminal::FieldValue(cola_actions::field_value_boolean_false(context))
+                match (i.next().unwrap(), i.next().unwrap(), i.next().unwrap()) {
+                    (
+                        Symbol::NonTerminal(NonTerminal::FieldValue(p0)),
+                        _,
+                        Symbol::NonTerminal(NonTerminal::ArrayItems(p1)),
+                    ) => {
+                        NonTerminal::ArrayItems(
+                            cola_actions::array_items_c3(context, p0, p1),
+                        )
+                    }
+                    _ => panic!("Invalid symbol parse stack data."),
+                }
             }
             ProdKind::RegularCodeBlockP1 => {
                 let mut i = self
@@ -391,3 +391,586 @@ pub fn regular_code_start_regular_code_start_unnamed(
 ) -> RegularCodeStart {
     RegularCodeStart::RegularCodeStartUnnamed(regular_code_start_unnamed)
 }
+pub type TypeAnnotationOpt = Option<TypeAnnotation>;
+pub fn type_annotation_opt_type_annotation(
+    _ctx: &Ctx,
+    type_annotation: TypeAnnotation,
+) -> TypeAnnotationOpt {
+    Some(type_annotation)
+}
+pub fn type_annotation_opt_empty(_ctx: &Ctx) -> TypeAnnotationOpt {
+    None
+}
+pub type TypeAnnotation = Identifier;
+pub fn type_annotation_identifier(_ctx: &Ctx, identifier: Identifier) -> TypeAnnotation {
+    identifier
+}
+pub fn field_list_field_entry(_ctx: &Ctx, field_entry: FieldEntry) -> FieldList {
+    FieldList::FieldEntry(field_entry)
+}
+#[derive(Debug, Clone)]
+pub enum FieldEntry {
+    Field(Field),
+    ComputedField(ComputedField),
+}
+pub fn field_entry_field(_ctx: &Ctx, field: Field) -> FieldEntry {
+    FieldEntry::Field(field)
+}
+pub fn field_entry_computed_field(
+    _ctx: &Ctx,
+    computed_field: ComputedField,
+) -> FieldEntry {
+    FieldEntry::ComputedField(computed_field)
+}
+#[derive(Debug, Clone)]
+pub struct ComputedFieldBase {
+    pub identifier: Identifier,
+    pub expression: Expression,
+}
+pub type ComputedField = ValLoc<ComputedFieldBase>;
+pub fn computed_field_c1(
+    _ctx: &Ctx,
+    identifier: Identifier,
+    expression: Expression,
+) -> ComputedField {
+    ComputedField::new(
+        ComputedFieldBase {
+            identifier,
+            expression,
+        },
+        Some(_ctx.location()),
+    )
+}
+#[derive(Debug, Clone)]
+pub struct AddBase {
+    pub left: Box<Expression>,
+    pub right: Box<Expression>,
+}
+pub type Add = ValLoc<AddBase>;
+#[derive(Debug, Clone)]
+pub struct SubBase {
+    pub left: Box<Expression>,
+    pub right: Box<Expression>,
+}
+pub type Sub = ValLoc<SubBase>;
+#[derive(Debug, Clone)]
+pub struct MulBase {
+    pub left: Box<Expression>,
+    pub right: Box<Expression>,
+}
+pub type Mul = ValLoc<MulBase>;
+#[derive(Debug, Clone)]
+pub struct DivBase {
+    pub left: Box<Expression>,
+    pub right: Box<Expression>,
+}
+pub type Div = ValLoc<DivBase>;
+#[derive(Debug, Clone)]
+pub enum Expression {
+    Add(Add),
+    Sub(Sub),
+    Mul(Mul),
+    Div(Div),
+    Group(Box<Expression>),
+    Number(Number),
+    Identifier(Identifier),
+}
+pub fn expression_add(_ctx: &Ctx, left: Expression, right: Expression) -> Expression {
+    Expression::Add(
+        Add::new(
+            AddBase {
+                left: Box::new(left),
+                right: Box::new(right),
+            },
+            Some(_ctx.location()),
+        ),
+    )
+}
+pub fn expression_sub(_ctx: &Ctx, left: Expression, right: Expression) -> Expression {
+    Expression::Sub(
+        Sub::new(
+            SubBase {
+                left: Box::new(left),
+                right: Box::new(right),
+            },
+            Some(_ctx.location()),
+        ),
+    )
+}
+pub fn expression_mul(_ctx: &Ctx, left: Expression, right: Expression) -> Expression {
+    Expression::Mul(
+        Mul::new(
+            MulBase {
+                left: Box::new(left),
+                right: Box::new(right),
+            },
+            Some(_ctx.location()),
+        ),
+    )
+}
+pub fn expression_div(_ctx: &Ctx, left: Expression, right: Expression) -> Expression {
+    Expression::Div(
+        Div::new(
+            DivBase {
+                left: Box::new(left),
+                right: Box::new(right),
+            },
+            Some(_ctx.location()),
+        ),
+    )
+}
+pub fn expression_group(_ctx: &Ctx, expression: Expression) -> Expression {
+    Expression::Group(Box::new(expression))
+}
+pub fn expression_number(_ctx: &Ctx, number: Number) -> Expression {
+    Expression::Number(number)
+}
+pub fn expression_identifier(_ctx: &Ctx, identifier: Identifier) -> Expression {
+    Expression::Identifier(identifier)
+}
+pub type QuestionOpt = Option<QuestionOptNoO>;
+#[derive(Debug, Clone)]
+pub enum QuestionOptNoO {
+    Question,
+}
+pub fn question_opt_question(_ctx: &Ctx) -> QuestionOpt {
+    Some(QuestionOptNoO::Question)
+}
+pub fn question_opt_empty(_ctx: &Ctx) -> QuestionOpt {
+    None
+}
+pub fn field_entry_optional_field(
+    _ctx: &Ctx,
+    optional_field: OptionalField,
+) -> FieldEntry {
+    FieldEntry::OptionalField(optional_field)
+}
+#[derive(Debug, Clone)]
+pub struct OptionalFieldBase {
+    pub identifier: Identifier,
+    pub type_annotation_opt: TypeAnnotationOpt,
+    pub field_value: FieldValue,
+}
+pub type OptionalField = ValLoc<OptionalFieldBase>;
+pub fn optional_field_c1(
+    _ctx: &Ctx,
+    identifier: Identifier,
+    type_annotation_opt: TypeAnnotationOpt,
+    field_value: FieldValue,
+) -> OptionalField {
+    OptionalField::new(
+        OptionalFieldBase {
+            identifier,
+            type_annotation_opt,
+            field_value,
+        },
+        Some(_ctx.location()),
+    )
+}
+pub fn field_value_array_value(_ctx: &Ctx, array_value: ArrayValue) -> FieldValue {
+    FieldValue::ArrayValue(array_value)
+}
+pub type ArrayValue = ArrayItemsOpt;
+pub fn array_value_array_items_opt(
+    _ctx: &Ctx,
+    array_items_opt: ArrayItemsOpt,
+) -> ArrayValue {
+    array_items_opt
+}
+pub type ArrayItemsOpt = Option<ArrayItems>;
+pub fn array_items_opt_array_items(
+    _ctx: &Ctx,
+    array_items: ArrayItems,
+) -> ArrayItemsOpt {
+    Some(array_items)
+}
+pub fn array_items_opt_empty(_ctx: &Ctx) -> ArrayItemsOpt {
+    None
+}
+#[derive(Debug, Clone)]
+pub struct ArrayItemsC3Base {
+    pub field_value: Box<FieldValue>,
+    pub array_items: Box<ArrayItems>,
+}
+pub type ArrayItemsC3 = ValLoc<ArrayItemsC3Base>;
+#[derive(Debug, Clone)]
+pub enum ArrayItems {
+    FieldValue1(Box<FieldValue>),
+    FieldValue2(Box<FieldValue>),
+    C3(ArrayItemsC3),
+}
+pub fn array_items_field_value1(_ctx: &Ctx, field_value: FieldValue) -> ArrayItems {
+    ArrayItems::FieldValue1(Box::new(field_value))
+}
+pub fn array_items_field_value2(_ctx: &Ctx, field_value: FieldValue) -> ArrayItems {
+    ArrayItems::FieldValue2(Box::new(field_value))
+}
+pub fn array_items_c3(
+    _ctx: &Ctx,
+    field_value: FieldValue,
+    array_items: ArrayItems,
+) -> ArrayItems {
+    ArrayItems::C3(
+        ArrayItemsC3::new(
+            ArrayItemsC3Base {
+                field_value: Box::new(field_value),
+                array_items: Box::new(array_items),
+            },
+            Some(_ctx.location()),
+        ),
+    )
+}
+pub fn field_value_null(_ctx: &Ctx) -> FieldValue {
+    FieldValue::Null
+}
+pub type PluralKeyOpt = Option<PluralKey>;
+pub fn plural_key_opt_plural_key(_ctx: &Ctx, plural_key: PluralKey) -> PluralKeyOpt {
+    Some(plural_key)
+}
+pub fn plural_key_opt_empty(_ctx: &Ctx) -> PluralKeyOpt {
+    None
+}
+pub type PluralKey = Identifier;
+pub fn plural_key_identifier(_ctx: &Ctx, identifier: Identifier) -> PluralKey {
+    identifier
+}
+pub fn field_entry_required_field(
+    _ctx: &Ctx,
+    required_field: RequiredField,
+) -> FieldEntry {
+    FieldEntry::RequiredField(required_field)
+}
+#[derive(Debug, Clone)]
+pub struct RequiredFieldBase {
+    pub identifier: Identifier,
+    pub type_annotation_opt: TypeAnnotationOpt,
+    pub field_value: FieldValue,
+}
+pub type RequiredField = ValLoc<RequiredFieldBase>;
+pub fn required_field_c1(
+    _ctx: &Ctx,
+    identifier: Identifier,
+    type_annotation_opt: TypeAnnotationOpt,
+    field_value: FieldValue,
+) -> RequiredField {
+    RequiredField::new(
+        RequiredFieldBase {
+            identifier,
+            type_annotation_opt,
+            field_value,
+        },
+        Some(_ctx.location()),
+    )
+}
+pub fn cola_syntax_cola_item0(_ctx: &Ctx, cola_item0: ColaItem0) -> ColaSyntax {
+    cola_item0
+}
+pub type ColaItem1 = Vec<ColaItem>;
+pub fn cola_item1_c1(
+    _ctx: &Ctx,
+    mut cola_item1: ColaItem1,
+    cola_item: ColaItem,
+) -> ColaItem1 {
+    cola_item1.push(cola_item);
+    cola_item1
+}
+pub fn cola_item1_cola_item(_ctx: &Ctx, cola_item: ColaItem) -> ColaItem1 {
+    vec![cola_item]
+}
+pub type ColaItem0 = Option<ColaItem1>;
+pub fn cola_item0_cola_item1(_ctx: &Ctx, cola_item1: ColaItem1) -> ColaItem0 {
+    Some(cola_item1)
+}
+pub fn cola_item0_empty(_ctx: &Ctx) -> ColaItem0 {
+    None
+}
+#[derive(Debug, Clone)]
+pub enum ColaItem {
+    Entity(Entity),
+    AnchorDefinition(AnchorDefinition),
+}
+pub fn cola_item_entity(_ctx: &Ctx, entity: Entity) -> ColaItem {
+    ColaItem::Entity(entity)
+}
+pub fn cola_item_anchor_definition(
+    _ctx: &Ctx,
+    anchor_definition: AnchorDefinition,
+) -> ColaItem {
+    ColaItem::AnchorDefinition(anchor_definition)
+}
+pub fn nested_block_anchor_definition(
+    _ctx: &Ctx,
+    anchor_definition: AnchorDefinition,
+) -> NestedBlock {
+    NestedBlock::AnchorDefinition(anchor_definition)
+}
+#[derive(Debug, Clone)]
+pub struct AnchorDefinitionBase {
+    pub identifier: Identifier,
+    pub field_value: FieldValue,
+}
+pub type AnchorDefinition = ValLoc<AnchorDefinitionBase>;
+pub fn anchor_definition_c1(
+    _ctx: &Ctx,
+    identifier: Identifier,
+    field_value: FieldValue,
+) -> AnchorDefinition {
+    AnchorDefinition::new(
+        AnchorDefinitionBase {
+            identifier,
+            field_value,
+        },
+        Some(_ctx.location()),
+    )
+}
+pub type AnchorReference = Identifier;
+pub fn anchor_reference_identifier(
+    _ctx: &Ctx,
+    identifier: Identifier,
+) -> AnchorReference {
+    identifier
+}
+pub fn expression_anchor_reference(
+    _ctx: &Ctx,
+    anchor_reference: AnchorReference,
+) -> Expression {
+    Expression::AnchorReference(anchor_reference)
+}
+pub fn field_value_anchor_reference(
+    _ctx: &Ctx,
+    anchor_reference: AnchorReference,
+) -> FieldValue {
+    FieldValue::AnchorReference(anchor_reference)
+}
+pub type QuotedStringTriple = ValLoc<String>;
+pub fn quoted_string_triple(_ctx: &Ctx, token: Token) -> QuotedStringTriple {
+    QuotedStringTriple::new(token.value.into(), Some(_ctx.location()))
+}
+pub fn field_value_quoted_string_triple(
+    _ctx: &Ctx,
+    quoted_string_triple: QuotedStringTriple,
+) -> FieldValue {
+    FieldValue::QuotedStringTriple(quoted_string_triple)
+}
+pub type PluralEnumOpt = Option<PluralEnum>;
+pub fn plural_enum_opt_plural_enum(
+    _ctx: &Ctx,
+    plural_enum: PluralEnum,
+) -> PluralEnumOpt {
+    Some(plural_enum)
+}
+pub fn plural_enum_opt_empty(_ctx: &Ctx) -> PluralEnumOpt {
+    None
+}
+#[derive(Debug, Clone)]
+pub enum PluralEnum {
+    C1,
+}
+pub fn plural_enum_c1(_ctx: &Ctx) -> PluralEnum {
+    PluralEnum::C1
+}
+pub fn field_entry_field_declaration(
+    _ctx: &Ctx,
+    field_declaration: FieldDeclaration,
+) -> FieldEntry {
+    FieldEntry::FieldDeclaration(field_declaration)
+}
+#[derive(Debug, Clone)]
+pub struct FieldDeclarationBase {
+    pub identifier: Identifier,
+    pub type_name: TypeName,
+}
+pub type FieldDeclaration = ValLoc<FieldDeclarationBase>;
+pub fn field_declaration_c1(
+    _ctx: &Ctx,
+    identifier: Identifier,
+    type_name: TypeName,
+) -> FieldDeclaration {
+    FieldDeclaration::new(
+        FieldDeclarationBase {
+            identifier,
+            type_name,
+        },
+        Some(_ctx.location()),
+    )
+}
+pub type TypeName = Identifier;
+pub fn type_name_identifier(_ctx: &Ctx, identifier: Identifier) -> TypeName {
+    identifier
+}
+pub fn cola_item_include(_ctx: &Ctx, include: Include) -> ColaItem {
+    ColaItem::Include(include)
+}
+pub fn nested_block_include(_ctx: &Ctx, include: Include) -> NestedBlock {
+    NestedBlock::Include(include)
+}
+pub type Include = QuotedStringDouble;
+pub fn include_quoted_string_double(
+    _ctx: &Ctx,
+    quoted_string_double: QuotedStringDouble,
+) -> Include {
+    quoted_string_double
+}
+pub fn entity_inline_plural_entity(
+    _ctx: &Ctx,
+    inline_plural_entity: InlinePluralEntity,
+) -> Entity {
+    Entity::InlinePluralEntity(inline_plural_entity)
+}
+#[derive(Debug, Clone)]
+pub struct InlinePluralEntityBase {
+    pub identifier: Identifier,
+    pub inline_instances_opt: InlineInstancesOpt,
+}
+pub type InlinePluralEntity = ValLoc<InlinePluralEntityBase>;
+pub fn inline_plural_entity_c1(
+    _ctx: &Ctx,
+    identifier: Identifier,
+    inline_instances_opt: InlineInstancesOpt,
+) -> InlinePluralEntity {
+    InlinePluralEntity::new(
+        InlinePluralEntityBase {
+            identifier,
+            inline_instances_opt,
+        },
+        Some(_ctx.location()),
+    )
+}
+pub type InlineInstancesOpt = Option<InlineInstances>;
+pub fn inline_instances_opt_inline_instances(
+    _ctx: &Ctx,
+    inline_instances: InlineInstances,
+) -> InlineInstancesOpt {
+    Some(inline_instances)
+}
+pub fn inline_instances_opt_empty(_ctx: &Ctx) -> InlineInstancesOpt {
+    None
+}
+#[derive(Debug, Clone)]
+pub struct InlineInstancesC3Base {
+    pub identifier: Identifier,
+    pub inline_instances: Box<InlineInstances>,
+}
+pub type InlineInstancesC3 = ValLoc<InlineInstancesC3Base>;
+#[derive(Debug, Clone)]
+pub enum InlineInstances {
+    Identifier1(Identifier),
+    Identifier2(Identifier),
+    C3(InlineInstancesC3),
+}
+pub fn inline_instances_identifier1(
+    _ctx: &Ctx,
+    identifier: Identifier,
+) -> InlineInstances {
+    InlineInstances::Identifier1(identifier)
+}
+pub fn inline_instances_identifier2(
+    _ctx: &Ctx,
+    identifier: Identifier,
+) -> InlineInstances {
+    InlineInstances::Identifier2(identifier)
+}
+pub fn inline_instances_c3(
+    _ctx: &Ctx,
+    identifier: Identifier,
+    inline_instances: InlineInstances,
+) -> InlineInstances {
+    InlineInstances::C3(
+        InlineInstancesC3::new(
+            InlineInstancesC3Base {
+                identifier,
+                inline_instances: Box::new(inline_instances),
+            },
+            Some(_ctx.location()),
+        ),
+    )
+}
+pub fn entity_definition_nested_block01(
+    _ctx: &Ctx,
+    nested_block0: NestedBlock0,
+) -> EntityDefinition {
+    EntityDefinition::NestedBlock01(nested_block0)
+}
+pub fn entity_definition_nested_block02(
+    _ctx: &Ctx,
+    nested_block0: NestedBlock0,
+) -> EntityDefinition {
+    EntityDefinition::NestedBlock02(nested_block0)
+}
+pub fn entity_definition_plain(
+    _ctx: &Ctx,
+    nested_block0: NestedBlock0,
+) -> EntityDefinition {
+    EntityDefinition::Plain(nested_block0)
+}
+pub fn entity_definition_braced(
+    _ctx: &Ctx,
+    nested_block0: NestedBlock0,
+) -> EntityDefinition {
+    EntityDefinition::Braced(nested_block0)
+}
+pub fn field_entry_optional_declaration(
+    _ctx: &Ctx,
+    optional_declaration: OptionalDeclaration,
+) -> FieldEntry {
+    FieldEntry::OptionalDeclaration(optional_declaration)
+}
+pub fn field_entry_required_declaration(
+    _ctx: &Ctx,
+    required_declaration: RequiredDeclaration,
+) -> FieldEntry {
+    FieldEntry::RequiredDeclaration(required_declaration)
+}
+#[derive(Debug, Clone)]
+pub struct OptionalDeclarationBase {
+    pub identifier: Identifier,
+    pub type_name: TypeName,
+}
+pub type OptionalDeclaration = ValLoc<OptionalDeclarationBase>;
+pub fn optional_declaration_c1(
+    _ctx: &Ctx,
+    identifier: Identifier,
+    type_name: TypeName,
+) -> OptionalDeclaration {
+    OptionalDeclaration::new(
+        OptionalDeclarationBase {
+            identifier,
+            type_name,
+        },
+        Some(_ctx.location()),
+    )
+}
+#[derive(Debug, Clone)]
+pub struct RequiredDeclarationBase {
+    pub identifier: Identifier,
+    pub type_name: TypeName,
+}
+pub type RequiredDeclaration = ValLoc<RequiredDeclarationBase>;
+pub fn required_declaration_c1(
+    _ctx: &Ctx,
+    identifier: Identifier,
+    type_name: TypeName,
+) -> RequiredDeclaration {
+    RequiredDeclaration::new(
+        RequiredDeclarationBase {
+            identifier,
+            type_name,
+        },
+        Some(_ctx.location()),
+    )
+}
+pub fn cola_item_field_list(_ctx: &Ctx, field_list: FieldList) -> ColaItem {
+    ColaItem::FieldList(field_list)
+}
+pub type BooleanTrue = ValLoc<String>;
+pub fn boolean_true(_ctx: &Ctx, token: Token) -> BooleanTrue {
+    BooleanTrue::new(token.value.into(), Some(_ctx.location()))
+}
+pub type BooleanFalse = ValLoc<String>;
+pub fn boolean_false(_ctx: &Ctx, token: Token) -> BooleanFalse {
+    BooleanFalse::new(token.value.into(), Some(_ctx.location()))
+}
+pub type Null = ValLoc<String>;
+pub fn null(_ctx: &Ctx, token: Token) -> Null {
+    Null::new(token.value.into(), Some(_ctx.location()))
+}
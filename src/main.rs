@@ -1,14 +1,26 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use std::path::PathBuf;
+use std::io::Read;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 use clap::{Arg, ArgAction, Command};
+use colap::parser::blocks::describe_parse_error;
 use colap::parser::cola::ColaParser;
-use colap::model::model_builder::ModelBuilder;
+use colap::parser::encoding::{InputEncoding, decode_input};
+use colap::parser::markdown::strip_containers;
+use colap::parser::repeat::expand_repeats;
+use colap::parser::snippet::{caret_snippet, error_position};
+use colap::model::config_model::ConfigModel;
+use colap::model::model_builder::{DEFAULT_MAX_FIELDS_PER_ENTITY, ModelBuilder};
 use rustemo::Parser;
 
-use colap::generator::{CodeGenerator, GenerationMode};
+use colap::generator::rust_import::rust_to_cola;
+use colap::generator::type_map::TypeMap;
+use colap::generator::{CodeGenerator, ColapDependency, GenerationMode, GeneratorOptions};
+
+/// Stands in for the input path when the input is read from standard input
+const STDIN_PATH: &str = "<stdin>";
 
 fn main() -> Result<()> {
     env_logger::init();
@@ -16,10 +28,44 @@ fn main() -> Result<()> {
     let matches = Command::new("colap")
         .version(env!("CARGO_PKG_VERSION"))
         .about("Generate a typed Rust API for a Cola configuration model")
+        .subcommand_negates_reqs(true)
+        .args_conflicts_with_subcommands(true)
+        .subcommand(
+            Command::new("list")
+                .about("Print every entity path of a configuration, one per line")
+                .arg(
+                    Arg::new("input")
+                        .help("Input .cola file or markdown containing Cola code blocks")
+                        .required(true)
+                        .index(1),
+                )
+                .arg(
+                    Arg::new("fields")
+                        .long("fields")
+                        .help("Print `path.field = value` lines for every field instead")
+                        .action(ArgAction::SetTrue),
+                ),
+        )
+        .subcommand(
+            Command::new("import")
+                .about("Print a starter Cola configuration for the structs of a Rust source file")
+                .arg(
+                    Arg::new("input")
+                        .help("Rust source file with the configuration structs")
+                        .required(true)
+                        .index(1),
+                )
+                .arg(
+                    Arg::new("root")
+                        .long("root")
+                        .help("Struct to start from (default: the first struct no other struct uses)")
+                        .action(ArgAction::Set),
+                ),
+        )
         .arg(
             Arg::new("input")
                 .help("Input .cola file or markdown containing Cola code blocks")
-                .required(true)
+                .required_unless_present("stdin")
                 .index(1),
         )
         .arg(
@@ -40,18 +86,206 @@ fn main() -> Result<()> {
             Arg::new("mode")
                 .short('m')
                 .long("mode")
-                .help("Generation mode: 'crate' (default) or 'module'")
-                .value_parser(["crate", "module"])
+                .help("Generation mode: 'crate' (default), 'module', or 'yaml' or 'toml' to dump the model")
+                .value_parser(["crate", "module", "yaml", "toml"])
                 .default_value("crate")
                 .action(ArgAction::Set),
         )
+        .arg(
+            Arg::new("only")
+                .long("only")
+                .value_name("PATH")
+                .help("Generate code for the entity at PATH (e.g. llm/openai) only, with it as the root")
+                .action(ArgAction::Set),
+        )
+        .arg(
+            Arg::new("type-map")
+                .long("type-map")
+                .value_name("FILE")
+                .help("TOML file mapping config paths or field name patterns (e.g. *_url) to Rust types")
+                .action(ArgAction::Set),
+        )
+        .arg(
+            Arg::new("derive-serde")
+                .long("derive-serde")
+                .help("Derive serde `Serialize` and `Deserialize` on the generated structs")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("derive-partialeq")
+                .long("derive-partialeq")
+                .help("Derive `PartialEq` on the generated structs so configs can be compared in tests")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("impl-display")
+                .long("impl-display")
+                .help("Implement `Display` on the generated structs, printing the configuration as a tree")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("serde-defaults")
+                .long("serde-defaults")
+                .help("Make deserialization fill fields missing from the input with their values in the config (requires --derive-serde)")
+                .requires("derive-serde")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("emit-clap")
+                .long("emit-clap")
+                .help("Generate a clap `RootArgs` struct for overriding config fields from the command line")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("emit-index")
+                .long("emit-index")
+                .help("Generate a `ConfigHandle` type and `Index<&str>` impls for `config[\"a\"][\"b\"]` access")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("api")
+                .long("api")
+                .help("Style of the generated structs: 'fields' (default) for public fields, or 'builder' for private fields set through a builder")
+                .value_parser(["fields", "builder"])
+                .default_value("fields")
+                .action(ArgAction::Set),
+        )
+        .arg(
+            Arg::new("getter-style")
+                .long("getter-style")
+                .help("Return type of the generated getters: 'borrow' (default) for `&str`, slices and scalar values, or 'cow' for a `Cow` borrowed from the struct")
+                .value_parser(["borrow", "cow"])
+                .default_value("borrow")
+                .action(ArgAction::Set),
+        )
+        .arg(
+            Arg::new("emit-schema-doc")
+                .long("emit-schema-doc")
+                .help("Write a SCHEMA.md documenting every entity path, its fields, types and defaults (crate mode)")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("derive-builder")
+                .long("derive-builder")
+                .help("Derive `derive_builder::Builder` on the generated structs and add the dependency to the generated crate")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("fixed-arrays")
+                .long("fixed-arrays")
+                .help("Store plural collections with the same entries wherever they appear in a fixed-size array instead of a map")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("flatten-singletons")
+                .long("flatten-singletons")
+                .help("Type a plural entity with a single instance, unless keyed or an enum, as the struct of that instance instead of a collection")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("keep-extras")
+                .long("keep-extras")
+                .help("Keep fields that have no matching struct field in an `extras` map")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("verify-syntax")
+                .long("verify-syntax")
+                .help("Parse the generated code with syn and fail before writing it if it is not valid Rust")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("colap-path")
+                .long("colap-path")
+                .value_name("PATH")
+                .help("Path of the colap dependency in the generated Cargo.toml, relative to the generated crate (default: ../colap)")
+                .conflicts_with("colap-version")
+                .action(ArgAction::Set),
+        )
+        .arg(
+            Arg::new("colap-version")
+                .long("colap-version")
+                .value_name("VERSION")
+                .help("Depend on this crates.io version of colap in the generated Cargo.toml instead of a path")
+                .action(ArgAction::Set),
+        )
+        .arg(
+            Arg::new("heterogeneous-arrays")
+                .long("heterogeneous-arrays")
+                .help("Type arrays with elements of different types as `Vec<ConfigScalar>` instead of keeping only their strings")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("dry-run")
+                .long("dry-run")
+                .help("Print the files that would be generated and their sizes without writing anything")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("repeatable-fields")
+                .long("repeatable-fields")
+                .help("Collect repeated fields of an entity into a list instead of keeping the last value")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("unit-suffixes")
+                .long("unit-suffixes")
+                .help("Read numbers with a duration (ms, s, m, h) or byte size (KB, MB, GB) suffix; durations become `std::time::Duration` fields")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("max-fields")
+                .long("max-fields")
+                .help("Warn about entities with more fields than this (default: 256)")
+                .value_parser(clap::value_parser!(usize))
+                .action(ArgAction::Set),
+        )
+        .arg(
+            Arg::new("strict")
+                .long("strict")
+                .help("Treat model warnings, such as entities over the field limit, as errors and reject duplicate fields and entities")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("decode")
+                .long("decode")
+                .help("Decode the input before parsing: 'base64' or 'gzip'")
+                .value_parser(["base64", "gzip"])
+                .action(ArgAction::Set),
+        )
+        .arg(
+            Arg::new("strip-markdown")
+                .long("strip-markdown")
+                .help("Read code blocks nested in block quotes or list items by removing their '>' markers and indentation")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("stdin")
+                .long("stdin")
+                .help("Read the input from standard input instead of a file")
+                .conflicts_with("input")
+                .action(ArgAction::SetTrue),
+        )
         .get_matches();
 
-    let input_path: PathBuf = matches.get_one::<String>("input").unwrap().into();
+    if let Some(list_matches) = matches.subcommand_matches("list") {
+        let input_path = PathBuf::from(list_matches.get_one::<String>("input").unwrap());
+        return list(&input_path, list_matches.get_flag("fields"));
+    }
+    if let Some(import_matches) = matches.subcommand_matches("import") {
+        let input_path = PathBuf::from(import_matches.get_one::<String>("input").unwrap());
+        return import(&input_path, import_matches.get_one::<String>("root").map(String::as_str));
+    }
+
+    let input_path: PathBuf = match matches.get_one::<String>("input") {
+        Some(input) => input.into(),
+        None => PathBuf::from(STDIN_PATH),
+    };
 
     // Determine crate name - either from CLI arg or based on input file
     let crate_name = match matches.get_one::<String>("crate-name") {
         Some(name) => name.clone(),
+        None if input_path == Path::new(STDIN_PATH) => "stdin-config".to_string(),
         None => {
             // Default to input file stem + "-config"
             let stem = input_path
@@ -77,42 +311,131 @@ fn main() -> Result<()> {
     // Create final output directory path by appending /<crate-name> to the base output
     let output_dir = base_output_dir.join(&crate_name);
 
-    generate(input_path, output_dir, crate_name, mode.clone())
-}
-
-fn generate(input_path: PathBuf, output_dir: PathBuf, crate_name: String, mode: String) -> Result<()> {
-    let source = std::fs::read_to_string(&input_path)
-        .with_context(|| format!("Unable to read {}", input_path.display()))?;
+    // Configure how the model is built from the parsed AST
+    let builder = ModelBuilder {
+        repeatable_fields: matches.get_flag("repeatable-fields"),
+        unit_suffixes: matches.get_flag("unit-suffixes"),
+        max_fields_per_entity: matches
+            .get_one::<usize>("max-fields")
+            .copied()
+            .unwrap_or(DEFAULT_MAX_FIELDS_PER_ENTITY),
+        strict: matches.get_flag("strict"),
+        file_path: input_path.clone(),
+        ..ModelBuilder::new()
+    };
 
-    let _is_markdown = {
-        let ext = input_path
-            .extension()
-            .and_then(|e| e.to_str())
-            .map(|s| s.to_ascii_lowercase())
-            .unwrap_or_default();
-        ext == "md" || ext == "markdown"
+    // Optional features of the generated code
+    let options = GeneratorOptions {
+        emit_clap: matches.get_flag("emit-clap"),
+        keep_extras: matches.get_flag("keep-extras"),
+        emit_index: matches.get_flag("emit-index"),
+        derive_serde: matches.get_flag("derive-serde"),
+        serde_defaults: matches.get_flag("serde-defaults"),
+        derive_partialeq: matches.get_flag("derive-partialeq"),
+        impl_display: matches.get_flag("impl-display"),
+        unit_suffixes: matches.get_flag("unit-suffixes"),
+        repeatable_fields: matches.get_flag("repeatable-fields"),
+        verify_syntax: matches.get_flag("verify-syntax"),
+        emit_schema_doc: matches.get_flag("emit-schema-doc"),
+        fixed_arrays: matches.get_flag("fixed-arrays"),
+        flatten_singletons: matches.get_flag("flatten-singletons"),
+        derive_builder: matches.get_flag("derive-builder"),
+        heterogeneous_arrays: matches.get_flag("heterogeneous-arrays"),
+        builder_api: matches.get_one::<String>("api").is_some_and(|api| api == "builder"),
+        cow_getters: matches.get_one::<String>("getter-style").is_some_and(|style| style == "cow"),
     };
+    // Both would emit a `<Struct>Builder` for every struct
+    if options.derive_builder && options.builder_api {
+        anyhow::bail!("--derive-builder cannot be combined with --api builder");
+    }
 
-    // Parse the input using colap
-    let parser = ColaParser::new();
+    // Encoding of the input file, if it is not plain text
+    let encoding = matches
+        .get_one::<String>("decode")
+        .map(|name| name.parse::<InputEncoding>())
+        .transpose()
+        .map_err(|e| anyhow::anyhow!(e))?;
 
-    // For both markdown and cola files, we use the ColaParser
-    // The parser is designed to handle both cola code blocks in markdown
-    // and direct cola content
-    let cola_ast = match parser.parse(&source) {
-        Ok(ast) => ast,
-        Err(e) => return Err(anyhow::anyhow!("Failed to parse input: {}", e)),
+    let strip_markdown = matches.get_flag("strip-markdown");
+
+    let only = matches.get_one::<String>("only").cloned();
+
+    let type_map = matches
+        .get_one::<String>("type-map")
+        .map(|path| TypeMap::load(Path::new(path)))
+        .transpose()?;
+
+    let dry_run = matches.get_flag("dry-run");
+
+    // How the generated crate depends on colap
+    let colap_dependency = match (matches.get_one::<String>("colap-path"), matches.get_one::<String>("colap-version")) {
+        (_, Some(version)) => ColapDependency::Version(version.clone()),
+        (Some(path), None) => ColapDependency::Path(path.clone()),
+        (None, None) => ColapDependency::default(),
     };
 
-    // Convert the AST to a ConfigModel using ModelBuilder
-    let model = ModelBuilder::build_config_model(&cola_ast)
-        .map_err(|e| anyhow::anyhow!("Failed to build model: {}", e))?;
+    generate(
+        input_path,
+        output_dir,
+        crate_name,
+        mode.clone(),
+        encoding,
+        strip_markdown,
+        builder,
+        options,
+        only,
+        type_map,
+        dry_run,
+        colap_dependency,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn generate(
+    input_path: PathBuf,
+    output_dir: PathBuf,
+    crate_name: String,
+    mode: String,
+    encoding: Option<InputEncoding>,
+    strip_markdown: bool,
+    builder: ModelBuilder,
+    options: GeneratorOptions,
+    only: Option<String>,
+    type_map: Option<TypeMap>,
+    dry_run: bool,
+    colap_dependency: ColapDependency,
+) -> Result<()> {
+    let model = build_model(&input_path, encoding, strip_markdown, &builder)?;
 
     log::info!(
-        "Successfully built ConfigModel from {}",
-        input_path.display()
+        "Successfully built ConfigModel from {}: {}",
+        input_path.display(),
+        model.summary()
     );
 
+    // Catch names that can't become Rust identifiers before writing code that won't compile
+    if mode != "yaml"
+        && mode != "toml"
+        && let Err(issues) = model.validate_codegen_names()
+    {
+        let issues: Vec<String> = issues.iter().map(|issue| issue.to_string()).collect();
+        anyhow::bail!("Names unusable in generated code:\n{}", issues.join("\n"));
+    }
+
+    // Instances of a plural entity share one struct, so a field must have one type across them
+    if mode != "yaml"
+        && mode != "toml"
+        && let Err(conflicts) = model.validate_type_consistency()
+    {
+        let conflicts: Vec<String> = conflicts.iter().map(|conflict| conflict.to_string()).collect();
+        if builder.strict {
+            anyhow::bail!("Fields with different types across instances:\n{}", conflicts.join("\n"));
+        }
+        for conflict in &conflicts {
+            log::warn!("Field with different types across instances: {}", conflict);
+        }
+    }
+
     // Display the configuration using pretty_display
     println!("\nConfig Structure:\n{}", model.pretty_display());
 
@@ -128,7 +451,13 @@ fn generate(input_path: PathBuf, output_dir: PathBuf, crate_name: String, mode:
                 output_file: module_file,
             }
         }
-        "crate" | _ => {
+        "yaml" => GenerationMode::Yaml {
+            output_file: output_dir.with_extension("yaml"),
+        },
+        "toml" => GenerationMode::Toml {
+            output_file: output_dir.with_extension("toml"),
+        },
+        _ => {
             // Default to crate mode
             GenerationMode::Crate {
                 output_dir: output_dir.clone(),
@@ -141,11 +470,141 @@ fn generate(input_path: PathBuf, output_dir: PathBuf, crate_name: String, mode:
         model,
         generation_mode,
         input_path.clone(),
-    )?;
+    )?
+    .with_options(options)
+    .with_dry_run(dry_run)
+    .with_colap_dependency(colap_dependency);
+    if let Some(path) = &only {
+        generator = generator.with_only(path)?;
+    }
+    if let Some(type_map) = type_map {
+        generator = generator.with_type_map(type_map)?;
+    }
     generator.generate()?;
 
+    if dry_run {
+        println!("\nDry run, no files written. Would generate:");
+        for (path, size) in generator.written_files() {
+            println!("  {} ({} bytes)", path.display(), size);
+        }
+        return Ok(());
+    }
+
     log::info!("Successfully generated code to {}", output_dir.display());
     log::info!("Generated crate name: {}", crate_name);
 
     Ok(())
 }
+
+/// Read, decode, parse and build the configuration model of an input file
+///
+/// With `strip_markdown`, code blocks nested in block quotes or list items are unwrapped first.
+fn build_model(
+    input_path: &Path,
+    encoding: Option<InputEncoding>,
+    strip_markdown: bool,
+    builder: &ModelBuilder,
+) -> Result<ConfigModel> {
+    let bytes = read_input(input_path)?;
+    let source = match encoding {
+        Some(encoding) => decode_input(&bytes, encoding).map_err(|e| anyhow::anyhow!(e))?,
+        None => String::from_utf8(bytes)
+            .with_context(|| format!("Unable to read {}", input_path.display()))?,
+    };
+
+    let _is_markdown = {
+        let ext = input_path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(|s| s.to_ascii_lowercase())
+            .unwrap_or_default();
+        ext == "md" || ext == "markdown"
+    };
+
+    // Parse the input using colap
+    let parser = ColaParser::new();
+
+    // For both markdown and cola files, we use the ColaParser
+    // The parser is designed to handle both cola code blocks in markdown
+    // and direct cola content
+    let source = if strip_markdown { strip_containers(&source) } else { source };
+    let source = expand_repeats(&source).map_err(|e| anyhow::anyhow!(e))?;
+    let cola_ast = match parser.parse(&source) {
+        Ok(ast) => ast,
+        Err(e) => {
+            // Point at the offending position before the parser's own message
+            if let Some((line, column)) = error_position(&e) {
+                eprintln!("{}:{}:{}: parse error\n{}", input_path.display(), line, column + 1, caret_snippet(&source, line, column));
+            }
+            return Err(anyhow::anyhow!(describe_parse_error(&source, e)));
+        }
+    };
+
+    // Code blocks in other languages are left out of the model; name them for authoring feedback
+    let skipped = ModelBuilder::skipped_code_blocks(&cola_ast);
+    if !skipped.is_empty() {
+        let tags: Vec<&str> = skipped.iter().map(|tag| tag.as_deref().unwrap_or("untagged")).collect();
+        log::warn!("ignored {} non-cola code blocks ({})", skipped.len(), tags.join(", "));
+    }
+
+    // Convert the AST to a ConfigModel using ModelBuilder, reporting every error it finds
+    let mut model = builder.build_collecting(&cola_ast).map_err(|errors| match errors.as_slice() {
+        [error] => anyhow::anyhow!("Failed to build model: {}", error),
+        _ => {
+            for error in &errors {
+                eprintln!("error: {}", error);
+            }
+            anyhow::anyhow!("Failed to build model: {} errors", errors.len())
+        }
+    })?;
+    builder.attach_doc_comments(&mut model, &source);
+    Ok(model)
+
+}
+
+/// Read the bytes of an input file, or of standard input when the path is [`STDIN_PATH`]
+fn read_input(input_path: &Path) -> Result<Vec<u8>> {
+    if input_path == Path::new(STDIN_PATH) {
+        let mut bytes = Vec::new();
+        std::io::stdin()
+            .read_to_end(&mut bytes)
+            .context("Unable to read standard input")?;
+        return Ok(bytes);
+    }
+    std::fs::read(input_path).with_context(|| format!("Unable to read {}", input_path.display()))
+}
+
+/// Print the entity paths of a configuration, or its fields with `fields`, one per line
+fn list(input_path: &Path, fields: bool) -> Result<()> {
+    let builder = ModelBuilder {
+        file_path: input_path.to_path_buf(),
+        ..ModelBuilder::new()
+    };
+    let model = build_model(input_path, None, false, &builder)?;
+
+    if fields {
+        for (key, value) in model.as_flat_map() {
+            println!("{} = {}", key, value);
+        }
+    } else {
+        // Node ids follow document order, so entities are listed as they appear in the input
+        for id in model.node_ids() {
+            if let Some(path) = model.get_entity_path(id)
+                && !path.is_empty()
+            {
+                println!("{}", path);
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Print a Cola skeleton for the structs of a Rust source file
+fn import(input_path: &Path, root: Option<&str>) -> Result<()> {
+    let source = std::fs::read_to_string(input_path)
+        .with_context(|| format!("Unable to read {}", input_path.display()))?;
+    let cola = rust_to_cola(&source, root).map_err(|e| anyhow::anyhow!(e))?;
+    print!("{}", cola);
+    Ok(())
+}
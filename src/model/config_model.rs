@@ -1,20 +1,27 @@
 // SPDX-License-Identifier: Apache-2.0
 use crate::model::source_location::SourceLocation;
+use heck::{ToPascalCase, ToSnakeCase};
+#[cfg(feature = "serde")]
+use serde::ser::{Serialize, SerializeMap, SerializeSeq, Serializer};
 use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::fmt;
+use std::io;
 use std::rc::Rc;
 
 pub type NodeId = usize;
 pub type NodeRef = Rc<RefCell<ConfigNode>>;
 
 /// Represents the different types of values a configuration field can have
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ConfigValue {
     Integer(i64),
     Float(f64),
     Boolean(bool),
     String(String),
+    Array(Vec<ConfigValue>),
+    /// A field that is present but explicitly has no value (`null`)
+    Null,
 }
 
 impl fmt::Display for ConfigValue {
@@ -24,6 +31,17 @@ impl fmt::Display for ConfigValue {
             ConfigValue::Float(fl) => write!(f, "{}", fl),
             ConfigValue::Boolean(b) => write!(f, "{}", b),
             ConfigValue::String(s) => write!(f, "\"{}\"", s),
+            ConfigValue::Null => write!(f, "null"),
+            ConfigValue::Array(items) => {
+                write!(f, "[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{}", item)?;
+                }
+                write!(f, "]")
+            }
         }
     }
 }
@@ -34,6 +52,7 @@ pub struct ConfigField {
     pub name: String,
     pub value: ConfigValue,
     pub location: Option<SourceLocation>,
+    pub doc: Option<String>, // Text of the `#` comment lines right above the field
 }
 
 /// Represents an entity in the configuration
@@ -44,10 +63,19 @@ pub struct EntityNode {
     pub parent: Option<NodeId>,               // Parent entity ID
     pub children: Vec<NodeId>,                // Child entity IDs
     pub fields: HashMap<String, ConfigValue>, // Field name to value mapping
+    pub field_types: HashMap<String, String>, // Field name to declared type annotation
+    pub optional_fields: HashSet<String>,     // Fields declared optional with `?`
+    pub required_fields: HashSet<String>,     // Fields declared required with `!`
+    pub declared_fields: HashSet<String>,     // Fields declared with a type only (`name: type`)
+    pub key_field: Option<String>,            // Field keying the instances of a plural entity
+    pub is_enum: bool,                        // Plural entity generated as an enum (`as enum`)
+    pub doc: Option<String>,                  // Text of the `#` comment lines right above it
     pub location: Option<SourceLocation>,     // Source location
 }
 
 /// The main node type for our configuration model
+// Generated code matches `ConfigNode::Entity(entity)` directly, so the entity stays unboxed
+#[allow(clippy::large_enum_variant)]
 #[derive(Debug)]
 pub enum ConfigNode {
     Entity(EntityNode),
@@ -68,6 +96,13 @@ impl ConfigNode {
             parent,
             children: vec![],
             fields: HashMap::new(),
+            field_types: HashMap::new(),
+            optional_fields: HashSet::new(),
+            required_fields: HashSet::new(),
+            declared_fields: HashSet::new(),
+            key_field: None,
+            is_enum: false,
+            doc: None,
             location,
         })
     }
@@ -78,6 +113,7 @@ impl ConfigNode {
             name: name.to_string(),
             value,
             location,
+            doc: None,
         })
     }
 
@@ -100,21 +136,124 @@ impl ConfigNode {
     }
 }
 
+/// The field of each instance of an `as enum` plural entity that names its enum variant
+pub const ENUM_DISCRIMINATOR: &str = "type";
+
+/// Rust keywords, strict and reserved, that generated identifiers must not be
+const RUST_KEYWORDS: &[&str] = &[
+    "Self", "abstract", "as", "async", "await", "become", "box", "break", "const", "continue",
+    "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if",
+    "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv",
+    "pub", "ref", "return", "self", "static", "struct", "super", "trait", "true", "try", "type",
+    "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// Why a name can't be used in generated code
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NameIssueKind {
+    /// The name has no valid Rust identifier form
+    Invalid,
+    /// The identifier is a Rust keyword
+    Keyword,
+    /// The identifier is also generated from the other name
+    Collision(String),
+}
+
+/// An entity or field name that would produce an invalid or colliding Rust identifier
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NameIssue {
+    pub name: String,
+    pub identifier: String,
+    pub kind: NameIssueKind,
+    pub location: Option<SourceLocation>,
+}
+
+impl fmt::Display for NameIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            NameIssueKind::Invalid => write!(f, "'{}' is not a valid Rust identifier", self.name)?,
+            NameIssueKind::Keyword => {
+                write!(f, "'{}' becomes '{}', a Rust keyword", self.name, self.identifier)?
+            }
+            NameIssueKind::Collision(other) => write!(
+                f,
+                "'{}' becomes '{}', the same identifier as '{}'",
+                self.name, self.identifier, other
+            )?,
+        }
+        if let Some(location) = &self.location {
+            write!(f, " at {}", location)?;
+        }
+        Ok(())
+    }
+}
+
+/// A field whose type differs between two instances of the same plural entity, which share
+/// one generated struct
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeConflict {
+    /// Path of the plural entity, e.g. `llm`
+    pub path: String,
+    pub field: String,
+    /// Instance the field was first seen in, its type there and its location
+    pub first_instance: String,
+    pub first_type: String,
+    pub first_location: Option<SourceLocation>,
+    /// Instance where the field has a different type
+    pub instance: String,
+    pub field_type: String,
+    pub location: Option<SourceLocation>,
+}
+
+impl fmt::Display for TypeConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let at = |location: &Option<SourceLocation>| location.as_ref().map(|l| format!(" at {}", l)).unwrap_or_default();
+        write!(
+            f,
+            "'{}' of '{}' is {} in '{}'{} but {} in '{}'{}",
+            self.field,
+            self.path,
+            self.first_type,
+            self.first_instance,
+            at(&self.first_location),
+            self.field_type,
+            self.instance,
+            at(&self.location)
+        )
+    }
+}
+
 /// The model that holds the entire configuration structure
 #[derive(Debug)]
 pub struct ConfigModel {
-    nodes: HashMap<NodeId, NodeRef>,
+    nodes: HashMap<NodeId, NodeRef>, // Keyed by NodeId; ids are never reused, so removing nodes keeps the others
+    next_id: NodeId,
     root_id: NodeId,
     original_entity_names: HashMap<String, String>, // Added to store original quoted entity names
+    path_index: RefCell<Option<HashMap<String, NodeId>>>, // Lazily built full path to entity ID lookup
+}
+
+impl Default for ConfigModel {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl ConfigModel {
     /// Create a new empty model
     pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    /// Create a new empty model with room for `capacity` nodes (including the root)
+    /// before the node storage has to grow
+    pub fn with_capacity(capacity: usize) -> Self {
         let mut model = ConfigModel {
-            nodes: HashMap::new(),
+            nodes: HashMap::with_capacity(capacity),
+            next_id: 0,
             root_id: 0,
             original_entity_names: HashMap::new(),
+            path_index: RefCell::new(None),
         };
 
         // Create and set the root node
@@ -140,21 +279,177 @@ impl ConfigModel {
 
     /// Add a node to the model and return its ID
     pub fn add_node(&mut self, node: ConfigNode) -> NodeId {
-        let id = self.nodes.len();
+        let id = self.next_id;
+        self.next_id += 1;
         self.nodes.insert(id, Rc::new(RefCell::new(node)));
+        self.invalidate_path_index();
         id
     }
 
+    /// Drop the path index so the next lookup rebuilds it. Mutating methods on the model call
+    /// this themselves; call it after renaming or re-linking nodes through `get_node` directly.
+    pub fn invalidate_path_index(&self) {
+        self.path_index.borrow_mut().take();
+    }
+
+    /// Build the full path to entity ID index by walking the tree from the root.
+    /// The first entity in document order wins when sibling names collide.
+    fn build_path_index(&self) -> HashMap<String, NodeId> {
+        let mut index = HashMap::new();
+        let mut stack = vec![(self.root_id, String::new())];
+
+        while let Some((id, path)) = stack.pop() {
+            let Some(node) = self.get_node(id) else {
+                continue;
+            };
+            let node_borrow = node.borrow();
+            if let ConfigNode::Entity(entity) = &*node_borrow {
+                // Push in reverse so children are visited in document order
+                for &child_id in entity.children.iter().rev() {
+                    if let Some(child) = self.get_node(child_id)
+                        && let ConfigNode::Entity(child_entity) = &*child.borrow()
+                    {
+                        let child_path = if path.is_empty() {
+                            child_entity.name.clone()
+                        } else {
+                            format!("{}/{}", path, child_entity.name)
+                        };
+                        stack.push((child_id, child_path));
+                    }
+                }
+            }
+            index.entry(path).or_insert(id);
+        }
+
+        index
+    }
+
     /// Get a node by its ID
     pub fn get_node(&self, id: NodeId) -> Option<NodeRef> {
         self.nodes.get(&id).cloned()
     }
 
+    /// Get the number of nodes (entities and fields, including the root) in the model
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Get the ids of every node in ascending order, which is the order they were added in.
+    /// Ids of removed nodes are not reused, so the ids need not be `0..node_count()`.
+    pub fn node_ids(&self) -> Vec<NodeId> {
+        let mut ids: Vec<NodeId> = self.nodes.keys().copied().collect();
+        ids.sort_unstable();
+        ids
+    }
+
     /// Get the root node ID
     pub fn root_id(&self) -> NodeId {
         self.root_id
     }
 
+    /// Get the number of entities in the model, not counting the root
+    pub fn entity_count(&self) -> usize {
+        self.nodes
+            .iter()
+            .filter(|(id, node)| **id != self.root_id && node.borrow().is_entity())
+            .count()
+    }
+
+    /// Get the number of distinct fields over all entities, the root included
+    pub fn field_count(&self) -> usize {
+        self.nodes
+            .values()
+            .map(|node| match &*node.borrow() {
+                ConfigNode::Entity(entity) => entity.fields.len(),
+                ConfigNode::Field(_) => 0,
+            })
+            .sum()
+    }
+
+    /// Iterate over every entity below the root in pre-order (document order, each entity
+    /// before its children), yielding its id, name and plural name
+    pub fn iter_entities(&self) -> impl Iterator<Item = (NodeId, String, Option<String>)> + '_ {
+        let mut stack = vec![self.root_id];
+        std::iter::from_fn(move || {
+            while let Some(id) = stack.pop() {
+                let Some(node) = self.get_node(id) else {
+                    continue;
+                };
+                let node_borrow = node.borrow();
+                let ConfigNode::Entity(entity) = &*node_borrow else {
+                    continue;
+                };
+                stack.extend(entity.children.iter().rev());
+                if id != self.root_id {
+                    return Some((id, entity.name.clone(), entity.plural_name.clone()));
+                }
+            }
+            None
+        })
+    }
+
+    /// Iterate over the fields of an entity, sorted by name. Unknown ids and field nodes have
+    /// no fields.
+    pub fn iter_fields(&self, entity_id: NodeId) -> impl Iterator<Item = (String, ConfigValue)> {
+        let mut fields: Vec<(String, ConfigValue)> = match self.get_node(entity_id) {
+            Some(node) => match &*node.borrow() {
+                ConfigNode::Entity(entity) => entity
+                    .fields
+                    .iter()
+                    .map(|(name, value)| (name.clone(), value.clone()))
+                    .collect(),
+                ConfigNode::Field(_) => Vec::new(),
+            },
+            None => Vec::new(),
+        };
+        fields.sort_by(|a, b| a.0.cmp(&b.0));
+        fields.into_iter()
+    }
+
+    /// Get the nesting depth of the deepest entity: 1 for top-level entities, 0 for a model
+    /// with none
+    pub fn max_depth(&self) -> usize {
+        self.entity_depth(self.root_id)
+    }
+
+    /// Depth of the deepest entity below `id`
+    fn entity_depth(&self, id: NodeId) -> usize {
+        let Some(node) = self.get_node(id) else {
+            return 0;
+        };
+        let node_borrow = node.borrow();
+        let ConfigNode::Entity(entity) = &*node_borrow else {
+            return 0;
+        };
+        entity
+            .children
+            .iter()
+            .filter(|&&child| self.nodes[&child].borrow().is_entity())
+            .map(|&child| 1 + self.entity_depth(child))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// One-line description of the model's size for logging, e.g.
+    /// `3 top-level entities, 12 entities total, 40 fields, max depth 4`
+    pub fn summary(&self) -> String {
+        let top_level = match &*self.nodes[&self.root_id].borrow() {
+            ConfigNode::Entity(root) => root
+                .children
+                .iter()
+                .filter(|&&child| self.nodes[&child].borrow().is_entity())
+                .count(),
+            ConfigNode::Field(_) => 0,
+        };
+        format!(
+            "{} top-level entities, {} entities total, {} fields, max depth {}",
+            top_level,
+            self.entity_count(),
+            self.field_count(),
+            self.max_depth()
+        )
+    }
+
     /// Add a child node to a parent
     pub fn add_child(&mut self, parent_id: NodeId, child_id: NodeId) -> Result<(), String> {
         // Get the parent and child nodes
@@ -166,6 +461,14 @@ impl ConfigModel {
             .get_node(child_id)
             .ok_or_else(|| format!("Child node with ID {} not found", child_id))?;
 
+        // Refuse links that would make the tree cyclic, since traversals recurse without bound
+        if self.is_ancestor_or_self(child_id, parent_id) {
+            return Err(format!(
+                "Cannot add node {} as a child of node {}: it is the same node or one of its ancestors",
+                child_id, parent_id
+            ));
+        }
+
         // Add the child ID to the parent's children
         {
             let mut parent_node_borrow = parent_node.borrow_mut();
@@ -188,9 +491,28 @@ impl ConfigModel {
             // Note: Fields don't track their parent as they're owned by entities directly
         }
 
+        self.invalidate_path_index();
         Ok(())
     }
 
+    /// Check whether `candidate` is `node_id` itself or one of its ancestors
+    fn is_ancestor_or_self(&self, candidate: NodeId, node_id: NodeId) -> bool {
+        let mut current = Some(node_id);
+        while let Some(id) = current {
+            if id == candidate {
+                return true;
+            }
+            current = match self.get_node(id) {
+                Some(node) => match &*node.borrow() {
+                    ConfigNode::Entity(entity) => entity.parent,
+                    ConfigNode::Field(_) => None,
+                },
+                None => None,
+            };
+        }
+        false
+    }
+
     /// Add a field to an entity
     pub fn add_field_to_entity(
         &mut self,
@@ -211,6 +533,187 @@ impl ConfigModel {
         }
     }
     
+    /// Record the declared type annotation (e.g. `i64`) of a field on an entity
+    pub fn set_field_type(
+        &mut self,
+        entity_id: NodeId,
+        field_name: &str,
+        type_name: &str,
+    ) -> Result<(), String> {
+        let entity_node = self
+            .get_node(entity_id)
+            .ok_or_else(|| format!("Entity node with ID {} not found", entity_id))?;
+
+        let mut entity_node_borrow = entity_node.borrow_mut();
+        if let ConfigNode::Entity(ref mut entity) = *entity_node_borrow {
+            entity
+                .field_types
+                .insert(field_name.to_string(), type_name.to_string());
+            Ok(())
+        } else {
+            Err(format!("Node with ID {} is not an entity", entity_id))
+        }
+    }
+
+    /// Get the declared type annotation of a field, if any
+    pub fn get_field_type(&self, entity_id: NodeId, field_name: &str) -> Option<String> {
+        let entity_node = self.get_node(entity_id)?;
+        let entity_borrow = entity_node.borrow();
+
+        if let ConfigNode::Entity(entity) = &*entity_borrow {
+            entity.field_types.get(field_name).cloned()
+        } else {
+            None
+        }
+    }
+
+    /// Record that a field of an entity was declared optional with `?`
+    pub fn set_field_optional(&mut self, entity_id: NodeId, field_name: &str) -> Result<(), String> {
+        let entity_node = self
+            .get_node(entity_id)
+            .ok_or_else(|| format!("Entity node with ID {} not found", entity_id))?;
+
+        let mut entity_node_borrow = entity_node.borrow_mut();
+        if let ConfigNode::Entity(ref mut entity) = *entity_node_borrow {
+            entity.optional_fields.insert(field_name.to_string());
+            Ok(())
+        } else {
+            Err(format!("Node with ID {} is not an entity", entity_id))
+        }
+    }
+
+    /// Key the instances of a plural entity by the value of `field_name` (`plural ... key <field>`)
+    /// rather than by their entity names
+    pub fn set_key_field(&mut self, entity_id: NodeId, field_name: &str) -> Result<(), String> {
+        let entity_node = self
+            .get_node(entity_id)
+            .ok_or_else(|| format!("Entity node with ID {} not found", entity_id))?;
+
+        let mut entity_node_borrow = entity_node.borrow_mut();
+        if let ConfigNode::Entity(ref mut entity) = *entity_node_borrow {
+            entity.key_field = Some(field_name.to_string());
+            Ok(())
+        } else {
+            Err(format!("Node with ID {} is not an entity", entity_id))
+        }
+    }
+
+    /// Generate a plural entity as an enum over the `ENUM_DISCRIMINATOR` field of its instances
+    /// (`plural ... as enum`) rather than as a collection
+    pub fn set_enum_plural(&mut self, entity_id: NodeId) -> Result<(), String> {
+        let entity_node = self
+            .get_node(entity_id)
+            .ok_or_else(|| format!("Entity node with ID {} not found", entity_id))?;
+
+        let mut entity_node_borrow = entity_node.borrow_mut();
+        if let ConfigNode::Entity(ref mut entity) = *entity_node_borrow {
+            entity.is_enum = true;
+            Ok(())
+        } else {
+            Err(format!("Node with ID {} is not an entity", entity_id))
+        }
+    }
+
+    /// Set the doc comment of an entity or field node
+    pub fn set_doc(&mut self, node_id: NodeId, doc: &str) -> Result<(), String> {
+        let node = self
+            .get_node(node_id)
+            .ok_or_else(|| format!("Node with ID {} not found", node_id))?;
+        match &mut *node.borrow_mut() {
+            ConfigNode::Entity(entity) => entity.doc = Some(doc.to_string()),
+            ConfigNode::Field(field) => field.doc = Some(doc.to_string()),
+        }
+        Ok(())
+    }
+
+    /// The doc comment of an entity
+    pub fn get_entity_doc(&self, entity_id: NodeId) -> Option<String> {
+        match &*self.get_node(entity_id)?.borrow() {
+            ConfigNode::Entity(entity) => entity.doc.clone(),
+            ConfigNode::Field(_) => None,
+        }
+    }
+
+    /// The doc comment of a field of an entity, taken from its last field node
+    pub fn get_field_doc(&self, entity_id: NodeId, field_name: &str) -> Option<String> {
+        let node = self.get_node(entity_id)?;
+        let ConfigNode::Entity(entity) = &*node.borrow() else {
+            return None;
+        };
+        entity.children.iter().rev().find_map(|&child_id| match &*self.nodes[&child_id].borrow() {
+            ConfigNode::Field(field) if field.name == field_name => Some(field.doc.clone()),
+            _ => None,
+        })?
+    }
+
+    /// Get the source location of a field of an entity, kept on its field node. A field defined
+    /// more than once has the location of its last definition, which holds its value.
+    pub fn get_field_location(&self, entity_id: NodeId, field_name: &str) -> Option<SourceLocation> {
+        let node = self.get_node(entity_id)?;
+        let ConfigNode::Entity(entity) = &*node.borrow() else {
+            return None;
+        };
+        entity.children.iter().rev().find_map(|&child_id| match &*self.nodes[&child_id].borrow() {
+            ConfigNode::Field(field) if field.name == field_name => Some(field.location.clone()),
+            _ => None,
+        })?
+    }
+
+    /// Check whether a field of an entity was declared optional with `?`
+    pub fn is_field_optional(&self, entity_id: NodeId, field_name: &str) -> bool {
+        self.get_node(entity_id).is_some_and(|node| match &*node.borrow() {
+            ConfigNode::Entity(entity) => entity.optional_fields.contains(field_name),
+            ConfigNode::Field(_) => false,
+        })
+    }
+
+    /// Record that a field of an entity was declared required with `!`
+    pub fn set_field_required(&mut self, entity_id: NodeId, field_name: &str) -> Result<(), String> {
+        let entity_node = self
+            .get_node(entity_id)
+            .ok_or_else(|| format!("Entity node with ID {} not found", entity_id))?;
+
+        let mut entity_node_borrow = entity_node.borrow_mut();
+        if let ConfigNode::Entity(ref mut entity) = *entity_node_borrow {
+            entity.required_fields.insert(field_name.to_string());
+            Ok(())
+        } else {
+            Err(format!("Node with ID {} is not an entity", entity_id))
+        }
+    }
+
+    /// Check whether a field of an entity was declared required with `!`
+    pub fn is_field_required(&self, entity_id: NodeId, field_name: &str) -> bool {
+        self.get_node(entity_id).is_some_and(|node| match &*node.borrow() {
+            ConfigNode::Entity(entity) => entity.required_fields.contains(field_name),
+            ConfigNode::Field(_) => false,
+        })
+    }
+
+    /// Record that a field of an entity was declared with a type and no value, so its value is
+    /// the default of the type rather than one written in the source
+    pub fn set_field_declared(&mut self, entity_id: NodeId, field_name: &str) -> Result<(), String> {
+        let entity_node = self
+            .get_node(entity_id)
+            .ok_or_else(|| format!("Entity node with ID {} not found", entity_id))?;
+
+        let mut entity_node_borrow = entity_node.borrow_mut();
+        if let ConfigNode::Entity(ref mut entity) = *entity_node_borrow {
+            entity.declared_fields.insert(field_name.to_string());
+            Ok(())
+        } else {
+            Err(format!("Node with ID {} is not an entity", entity_id))
+        }
+    }
+
+    /// Check whether a field of an entity was declared with a type only, as in `name: type`
+    pub fn is_field_declared(&self, entity_id: NodeId, field_name: &str) -> bool {
+        self.get_node(entity_id).is_some_and(|node| match &*node.borrow() {
+            ConfigNode::Entity(entity) => entity.declared_fields.contains(field_name),
+            ConfigNode::Field(_) => false,
+        })
+    }
+
     /// Add a field to an entity with source location
     pub fn add_field_with_location(
         &mut self,
@@ -232,112 +735,1202 @@ impl ConfigModel {
         self.add_child(entity_id, field_id)
     }
 
+    /// Replace the value of an existing field of an entity, in the field map and on its field
+    /// node, which keeps its source location
+    pub fn set_field_value(&mut self, entity_id: NodeId, field_name: &str, value: ConfigValue) -> Result<(), String> {
+        let node = self
+            .get_node(entity_id)
+            .ok_or_else(|| format!("Entity node with ID {} not found", entity_id))?;
+        let mut node_b = node.borrow_mut();
+        let ConfigNode::Entity(entity) = &mut *node_b else {
+            return Err(format!("Node with ID {} is not an entity", entity_id));
+        };
+        let Some(slot) = entity.fields.get_mut(field_name) else {
+            return Err(format!("Field '{}' not found on entity '{}'", field_name, entity.name));
+        };
+        *slot = value.clone();
+        for &child_id in &entity.children {
+            if let Some(child) = self.get_node(child_id)
+                && let ConfigNode::Field(field) = &mut *child.borrow_mut()
+                && field.name == field_name
+            {
+                field.value = value.clone();
+            }
+        }
+        Ok(())
+    }
+
+    /// Replace every field value in the model with `f(field_name, value)`. The new value is
+    /// stored in the entity's field map and mirrored onto the entity's field nodes of the same
+    /// name. Entities are visited in id order and fields by name, so `f` sees a stable order.
+    pub fn map_values<F: FnMut(&str, &ConfigValue) -> ConfigValue>(&mut self, mut f: F) {
+        for id in self.node_ids() {
+            let mut node_b = self.nodes[&id].borrow_mut();
+            let ConfigNode::Entity(entity) = &mut *node_b else {
+                continue;
+            };
+
+            let mut names: Vec<String> = entity.fields.keys().cloned().collect();
+            names.sort();
+            for name in names {
+                let value = f(&name, &entity.fields[&name]);
+                for &child_id in &entity.children {
+                    if let ConfigNode::Field(field) = &mut *self.nodes[&child_id].borrow_mut()
+                        && field.name == name
+                    {
+                        field.value = value.clone();
+                    }
+                }
+                entity.fields.insert(name, value);
+            }
+        }
+    }
+
+    /// Rename the field `old` of an entity to `new`, e.g. to migrate a configuration. The
+    /// field map, the mirrored field nodes and the field's type annotation, `?`/`!` markers and
+    /// key-field role all move to the new name. Fails if `old` is absent or `new` already exists.
+    pub fn rename_field(&mut self, entity_id: NodeId, old: &str, new: &str) -> Result<(), String> {
+        let node = self
+            .get_node(entity_id)
+            .ok_or_else(|| format!("Entity node with ID {} not found", entity_id))?;
+        let mut node_b = node.borrow_mut();
+        let ConfigNode::Entity(entity) = &mut *node_b else {
+            return Err(format!("Node with ID {} is not an entity", entity_id));
+        };
+        if entity.fields.contains_key(new) {
+            return Err(format!("Field '{}' already exists on entity '{}'", new, entity.name));
+        }
+        let value = entity
+            .fields
+            .remove(old)
+            .ok_or_else(|| format!("Field '{}' not found on entity '{}'", old, entity.name))?;
+        entity.fields.insert(new.to_string(), value);
+        if let Some(type_name) = entity.field_types.remove(old) {
+            entity.field_types.insert(new.to_string(), type_name);
+        }
+        if entity.optional_fields.remove(old) {
+            entity.optional_fields.insert(new.to_string());
+        }
+        if entity.required_fields.remove(old) {
+            entity.required_fields.insert(new.to_string());
+        }
+        if entity.declared_fields.remove(old) {
+            entity.declared_fields.insert(new.to_string());
+        }
+        if entity.key_field.as_deref() == Some(old) {
+            entity.key_field = Some(new.to_string());
+        }
+        for &child_id in &entity.children {
+            if let ConfigNode::Field(field) = &mut *self.nodes[&child_id].borrow_mut()
+                && field.name == old
+            {
+                field.name = new.to_string();
+            }
+        }
+        Ok(())
+    }
+
+    /// Remove the field `name` of an entity, e.g. to drop a setting that is no longer used, and
+    /// return its value. The field map, the mirrored field nodes and the field's type annotation,
+    /// `?`/`!` markers and key-field role all go. Node ids stay valid.
+    pub fn remove_field(&mut self, entity_id: NodeId, name: &str) -> Result<ConfigValue, String> {
+        let node = self
+            .get_node(entity_id)
+            .ok_or_else(|| format!("Entity node with ID {} not found", entity_id))?;
+        let mut node_b = node.borrow_mut();
+        let ConfigNode::Entity(entity) = &mut *node_b else {
+            return Err(format!("Node with ID {} is not an entity", entity_id));
+        };
+        let value = entity
+            .fields
+            .remove(name)
+            .ok_or_else(|| format!("Field '{}' not found on entity '{}'", name, entity.name))?;
+        entity.field_types.remove(name);
+        entity.optional_fields.remove(name);
+        entity.required_fields.remove(name);
+        entity.declared_fields.remove(name);
+        if entity.key_field.as_deref() == Some(name) {
+            entity.key_field = None;
+        }
+        let (removed, children): (Vec<NodeId>, Vec<NodeId>) = entity
+            .children
+            .iter()
+            .partition(|&child_id| matches!(&*self.nodes[child_id].borrow(), ConfigNode::Field(field) if field.name == name));
+        entity.children = children;
+        for field_id in removed {
+            self.nodes.remove(&field_id);
+        }
+        Ok(value)
+    }
+
+    /// Remove an entity and everything below it from the model and return the removed subtree
+    /// as a model of its own (see [`ConfigModel::subtree_to_model`]). The root cannot be
+    /// removed. The ids of the remaining nodes stay valid.
+    pub fn remove_entity(&mut self, entity_id: NodeId) -> Result<ConfigModel, String> {
+        if entity_id == self.root_id {
+            return Err("The root entity cannot be removed".to_string());
+        }
+        let removed = self
+            .subtree_to_model(entity_id)
+            .ok_or_else(|| format!("Entity node with ID {} not found", entity_id))?;
+        let parent_id = match &*self.nodes[&entity_id].borrow() {
+            ConfigNode::Entity(entity) => entity.parent,
+            ConfigNode::Field(_) => None,
+        };
+        if let Some(parent_id) = parent_id
+            && let ConfigNode::Entity(parent) = &mut *self.nodes[&parent_id].borrow_mut()
+        {
+            parent.children.retain(|&child_id| child_id != entity_id);
+        }
+        self.remove_nodes(entity_id);
+        self.invalidate_path_index();
+        Ok(removed)
+    }
+
+    /// Drop the node `id` and every node below it from the node storage. The caller detaches
+    /// the node from its parent.
+    fn remove_nodes(&mut self, id: NodeId) {
+        if let Some(node) = self.nodes.remove(&id)
+            && let ConfigNode::Entity(entity) = &*node.borrow()
+        {
+            for &child_id in &entity.children {
+                self.remove_nodes(child_id);
+            }
+        }
+    }
+
+    /// Merge `other` into this model with `other` taking precedence, e.g. environment
+    /// overrides over defaults. Fields present in both take the value from `other`; child
+    /// entities present in both are merged recursively, matched by name, so each instance of a
+    /// plural entity merges with the instance of the same name. Entities only in `other` are
+    /// created. A field changing type (a string over an integer, say) fails the merge with the
+    /// field's path and leaves this model unchanged; `null` merges with any type.
+    pub fn merge(&mut self, other: &ConfigModel) -> Result<(), String> {
+        self.merge_into(self.root_id, other)
+    }
+
+    /// Merge `other` into the entity `target` the way `merge` merges it into the root: the root
+    /// fields of `other` become fields of `target` and its entities children of `target`
+    pub fn merge_into(&mut self, target: NodeId, other: &ConfigModel) -> Result<(), String> {
+        // Check the whole tree first so a conflict cannot leave a half-merged model behind
+        self.merge_entity(target, other, other.root_id, true)?;
+        self.merge_entity(target, other, other.root_id, false)?;
+        for (sanitized, original) in &other.original_entity_names {
+            self.original_entity_names
+                .entry(sanitized.clone())
+                .or_insert_with(|| original.clone());
+        }
+        Ok(())
+    }
+
+    /// Merge entity `source` of `other` into entity `target`. With `check_only` the model is
+    /// left alone and only type conflicts are reported.
+    fn merge_entity(
+        &mut self,
+        target: NodeId,
+        other: &ConfigModel,
+        source: NodeId,
+        check_only: bool,
+    ) -> Result<(), String> {
+        let Some(source_node) = other.get_node(source) else {
+            return Ok(());
+        };
+        let source_borrow = source_node.borrow();
+        let ConfigNode::Entity(source_entity) = &*source_borrow else {
+            return Ok(());
+        };
+
+        let mut names: Vec<&String> = source_entity.fields.keys().collect();
+        names.sort();
+        for name in names {
+            let value = &source_entity.fields[name];
+            let existing = self.get_field_value(target, name);
+            if let Some(existing) = &existing
+                && !Self::merge_compatible(existing, value)
+            {
+                let path = self.get_entity_path(target).unwrap_or_default();
+                let field_path = if path.is_empty() {
+                    name.clone()
+                } else {
+                    format!("{}.{}", path, name)
+                };
+                return Err(format!(
+                    "Cannot merge field '{}': {} value over {} value",
+                    field_path,
+                    Self::value_type_name(value),
+                    Self::value_type_name(existing)
+                ));
+            }
+            let declared = source_entity.declared_fields.contains(name);
+            // A declaration only brings the default of its type, which does not replace a value
+            let keeps_value = declared && existing.is_some();
+            if !check_only && !keeps_value {
+                let (location, doc) = source_entity
+                    .children
+                    .iter()
+                    .find_map(|&child_id| match &*other.get_node(child_id)?.borrow() {
+                        ConfigNode::Field(field) if &field.name == name => {
+                            Some((field.location.clone(), field.doc.clone()))
+                        }
+                        _ => None,
+                    })
+                    .unwrap_or_default();
+                self.set_merged_field(target, name, value.clone(), location)?;
+                if let Some(node) = self.get_node(target)
+                    && let ConfigNode::Entity(entity) = &mut *node.borrow_mut()
+                {
+                    if declared {
+                        entity.declared_fields.insert(name.clone());
+                    } else {
+                        entity.declared_fields.remove(name);
+                    }
+                    if doc.is_some() {
+                        for &child_id in &entity.children {
+                            if let ConfigNode::Field(field) = &mut *self.nodes[&child_id].borrow_mut()
+                                && &field.name == name
+                            {
+                                field.doc = doc.clone();
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        if !check_only
+            && let Some(node) = self.get_node(target)
+            && let ConfigNode::Entity(entity) = &mut *node.borrow_mut()
+        {
+            entity.field_types.extend(source_entity.field_types.clone());
+            entity.optional_fields.extend(source_entity.optional_fields.iter().cloned());
+            entity.required_fields.extend(source_entity.required_fields.iter().cloned());
+            if source_entity.key_field.is_some() {
+                entity.key_field = source_entity.key_field.clone();
+            }
+            entity.is_enum |= source_entity.is_enum;
+            if source_entity.doc.is_some() {
+                entity.doc = source_entity.doc.clone();
+            }
+            if entity.plural_name.is_none() {
+                entity.plural_name = source_entity.plural_name.clone();
+            }
+        }
+
+        for &child_id in &source_entity.children {
+            let Some(child) = other.get_node(child_id) else {
+                continue;
+            };
+            let ConfigNode::Entity(child_entity) = &*child.borrow() else {
+                continue;
+            };
+            let target_child = match self.find_child_entity_by_name(target, &child_entity.name) {
+                Some(id) => id,
+                None if check_only => continue,
+                None => {
+                    let path = self.get_entity_path(target).unwrap_or_default();
+                    self.create_entity_at_path(
+                        &path,
+                        &child_entity.name,
+                        child_entity.plural_name.as_deref(),
+                        child_entity.location.clone(),
+                    )?
+                }
+            };
+            self.merge_entity(target_child, other, child_id, check_only)?;
+        }
+        Ok(())
+    }
+
+    /// Whether a merged field may replace `existing` with `value`
+    fn merge_compatible(existing: &ConfigValue, value: &ConfigValue) -> bool {
+        matches!(existing, ConfigValue::Null)
+            || matches!(value, ConfigValue::Null)
+            || std::mem::discriminant(existing) == std::mem::discriminant(value)
+    }
+
+    /// Set a field during a merge, updating the entity's field nodes of the same name or
+    /// adding one if the field is new
+    fn set_merged_field(
+        &mut self,
+        entity_id: NodeId,
+        name: &str,
+        value: ConfigValue,
+        location: Option<SourceLocation>,
+    ) -> Result<(), String> {
+        if self.get_field_value(entity_id, name).is_none() {
+            return self.add_field_with_location(entity_id, name, value, location);
+        }
+        let node = self
+            .get_node(entity_id)
+            .ok_or_else(|| format!("Entity node with ID {} not found", entity_id))?;
+        let mut node_borrow = node.borrow_mut();
+        let ConfigNode::Entity(entity) = &mut *node_borrow else {
+            return Err(format!("Node with ID {} is not an entity", entity_id));
+        };
+        for &child_id in &entity.children {
+            if let ConfigNode::Field(field) = &mut *self.nodes[&child_id].borrow_mut()
+                && field.name == name
+            {
+                field.value = value.clone();
+                if location.is_some() {
+                    field.location = location.clone();
+                }
+            }
+        }
+        entity.fields.insert(name.to_string(), value);
+        Ok(())
+    }
+
+    /// Merge sibling entities that have the same name and the same structural fingerprint (see
+    /// [`ConfigModel::entity_fingerprint`]) into the first of them, at every level of the tree.
+    /// Values of later duplicates win, as in [`ConfigModel::merge`]; siblings whose structure
+    /// differs are kept apart. Returns the number of entities merged away, which are removed
+    /// from the model; the ids of the other nodes stay valid.
+    pub fn deduplicate_entities(&mut self) -> usize {
+        let mut merged = 0;
+        let mut stack = vec![self.root_id];
+        while let Some(id) = stack.pop() {
+            let Some(node) = self.get_node(id) else {
+                continue;
+            };
+            let children: Vec<(NodeId, String)> = match &*node.borrow() {
+                ConfigNode::Entity(entity) => entity
+                    .children
+                    .iter()
+                    .filter_map(|&child_id| match &*self.get_node(child_id)?.borrow() {
+                        ConfigNode::Entity(child) => Some((child_id, child.name.clone())),
+                        ConfigNode::Field(_) => None,
+                    })
+                    .collect(),
+                ConfigNode::Field(_) => continue,
+            };
+
+            let mut kept: Vec<(NodeId, String, u64)> = Vec::new();
+            for (child_id, name) in children {
+                let fingerprint = self.entity_fingerprint(child_id);
+                let first = kept
+                    .iter()
+                    .find(|(_, kept_name, kept_fingerprint)| *kept_name == name && *kept_fingerprint == fingerprint)
+                    .map(|(kept_id, _, _)| *kept_id);
+                let Some(first) = first else {
+                    kept.push((child_id, name, fingerprint));
+                    continue;
+                };
+                let Some(duplicate) = self.subtree_to_model(child_id) else {
+                    continue;
+                };
+                if self.merge_entity(first, &duplicate, duplicate.root_id, true).is_err()
+                    || self.merge_entity(first, &duplicate, duplicate.root_id, false).is_err()
+                {
+                    kept.push((child_id, name, fingerprint));
+                    continue;
+                }
+                if let ConfigNode::Entity(parent) = &mut *self.nodes[&id].borrow_mut() {
+                    parent.children.retain(|&other| other != child_id);
+                }
+                self.remove_nodes(child_id);
+                merged += 1;
+            }
+            // Children are visited after their own duplicates were merged into them
+            stack.extend(kept.iter().rev().map(|(kept_id, _, _)| *kept_id));
+        }
+
+        if merged > 0 {
+            self.invalidate_path_index();
+        }
+        merged
+    }
+
     /// Find an entity by path (e.g., "llm/openai")
     pub fn find_entity_by_path(&self, path: &str) -> Option<NodeId> {
         if path.is_empty() {
             return Some(self.root_id);
         }
 
-        let components: Vec<&str> = path.split('/').collect();
-        let mut current_id = self.root_id;
+        let mut index = self.path_index.borrow_mut();
+        index
+            .get_or_insert_with(|| self.build_path_index())
+            .get(path)
+            .copied()
+    }
+
+    /// Create an entity at the specified path
+    pub fn create_entity_at_path(
+        &mut self,
+        path: &str,
+        name: &str,
+        plural_name: Option<&str>,
+        location: Option<SourceLocation>,
+    ) -> Result<NodeId, String> {
+        // If path is empty, create at root
+        let parent_id = if path.is_empty() {
+            self.root_id
+        } else {
+            self.find_entity_by_path(path)
+                .ok_or_else(|| format!("Parent path '{}' not found", path))?
+        };
+
+        self.create_child_entity(parent_id, name, plural_name, location)
+    }
+
+    /// Create an entity as the last child of `parent_id`. Unlike `create_entity_at_path` this
+    /// needs no path lookup, so it stays cheap while the tree is being built.
+    pub fn create_child_entity(
+        &mut self,
+        parent_id: NodeId,
+        name: &str,
+        plural_name: Option<&str>,
+        location: Option<SourceLocation>,
+    ) -> Result<NodeId, String> {
+        // Create the new entity
+        let entity = ConfigNode::new_entity(name, plural_name, Some(parent_id), location);
+        let entity_id = self.add_node(entity);
+
+        // Add it as a child of the parent
+        self.add_child(parent_id, entity_id)?;
+
+        Ok(entity_id)
+    }
+
+    /// Get a field value from an entity
+    pub fn get_field_value(&self, entity_id: NodeId, field_name: &str) -> Option<ConfigValue> {
+        let entity_node = self.get_node(entity_id)?;
+        let entity_borrow = entity_node.borrow();
+
+        if let ConfigNode::Entity(entity) = &*entity_borrow {
+            entity.fields.get(field_name).cloned()
+        } else {
+            None
+        }
+    }
+
+    /// Get a field value by its full path, e.g. `llm/openai/api/key`: everything before the last
+    /// `/` is the entity path as in `find_entity_by_path`, the rest the field name. A path without
+    /// `/` names a field on the root. `None` if the entity or the field is missing.
+    pub fn get_field_value_by_path(&self, path: &str) -> Option<ConfigValue> {
+        let (entity_path, field_name) = path.rsplit_once('/').unwrap_or(("", path));
+        let entity_id = self.find_entity_by_path(entity_path)?;
+        self.get_field_value(entity_id, field_name)
+    }
+
+    /// Find every entity named `name` (its sanitized name), wherever it is in the tree, in
+    /// document order. The root is not included.
+    pub fn find_entities_by_name(&self, name: &str) -> Vec<NodeId> {
+        self.iter_entities()
+            .filter(|(_, entity_name, _)| entity_name == name)
+            .map(|(id, _, _)| id)
+            .collect()
+    }
+
+    /// Like `find_entities_by_name`, but matching the name as written in the source
+    /// (`get_original_entity_name`), e.g. `my-service` rather than `my_service`
+    pub fn find_entities_by_original_name(&self, name: &str) -> Vec<NodeId> {
+        self.iter_entities()
+            .filter(|(_, entity_name, _)| self.get_original_entity_name(entity_name) == name)
+            .map(|(id, _, _)| id)
+            .collect()
+    }
+
+    /// Find a child entity by name within a parent entity
+    pub fn find_child_entity_by_name(&self, parent_id: NodeId, child_name: &str) -> Option<NodeId> {
+        let parent_node = self.get_node(parent_id)?;
+        let parent_borrow = parent_node.borrow();
+        
+        if let ConfigNode::Entity(parent_entity) = &*parent_borrow {
+            for &child_id in &parent_entity.children {
+                if let Some(child_node) = self.get_node(child_id) {
+                    let child_borrow = child_node.borrow();
+                    if let ConfigNode::Entity(child_entity) = &*child_borrow
+                        && child_entity.name == child_name
+                    {
+                        return Some(child_id);
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Resolve a slash-separated entity path relative to the entity `from`, e.g.
+    /// `../sibling/child`. `.` stays on the current entity, `..` moves to its parent, any other
+    /// segment descends into the child entity of that name, and a leading `/` starts from the
+    /// root. Going above the root or naming a missing entity resolves to nothing.
+    pub fn resolve_relative(&self, from: NodeId, rel: &str) -> Option<NodeId> {
+        let (mut current, rel) = match rel.strip_prefix('/') {
+            Some(rest) => (self.root_id, rest),
+            None => (from, rel),
+        };
+        if !self.get_node(current)?.borrow().is_entity() {
+            return None;
+        }
+        for segment in rel.split('/') {
+            current = match segment {
+                "" | "." => current,
+                ".." => match &*self.get_node(current)?.borrow() {
+                    ConfigNode::Entity(entity) if current != self.root_id => entity.parent?,
+                    _ => return None,
+                },
+                name => self.find_child_entity_by_name(current, name)?,
+            };
+        }
+        Some(current)
+    }
+
+    /// Copy the subtree below an entity into a new model with that entity as its root: its
+    /// fields become root fields and its children top-level entities. Unknown ids and field
+    /// nodes have no subtree.
+    pub fn subtree_to_model(&self, entity_id: NodeId) -> Option<ConfigModel> {
+        let node = self.get_node(entity_id)?;
+        let node_borrow = node.borrow();
+        let ConfigNode::Entity(entity) = &*node_borrow else {
+            return None;
+        };
+
+        let mut model = ConfigModel::new();
+        model.original_entity_names = self.original_entity_names.clone();
+        let root_id = model.root_id;
+        if let ConfigNode::Entity(root) = &mut *model.nodes[&root_id].borrow_mut() {
+            Self::copy_entity_data(entity, root);
+            root.location = entity.location.clone();
+        }
+        for &child_id in &entity.children {
+            self.copy_node_into(child_id, &mut model, root_id);
+        }
+        Some(model)
+    }
+
+    /// Copy node `id` and everything below it into `target` as the last child of `parent_id`
+    fn copy_node_into(&self, id: NodeId, target: &mut ConfigModel, parent_id: NodeId) {
+        let Some(node) = self.get_node(id) else {
+            return;
+        };
+        let node_borrow = node.borrow();
+        let copy = match &*node_borrow {
+            ConfigNode::Field(field) => {
+                let mut copy = ConfigNode::new_field(&field.name, field.value.clone(), field.location.clone());
+                if let ConfigNode::Field(copy_field) = &mut copy {
+                    copy_field.doc = field.doc.clone();
+                }
+                copy
+            }
+            ConfigNode::Entity(entity) => {
+                let mut copy = ConfigNode::new_entity(
+                    &entity.name,
+                    entity.plural_name.as_deref(),
+                    Some(parent_id),
+                    entity.location.clone(),
+                );
+                if let ConfigNode::Entity(copy_entity) = &mut copy {
+                    Self::copy_entity_data(entity, copy_entity);
+                    copy_entity.key_field = entity.key_field.clone();
+                    copy_entity.is_enum = entity.is_enum;
+                    copy_entity.doc = entity.doc.clone();
+                }
+                copy
+            }
+        };
+        let copy_id = target.add_node(copy);
+        if let ConfigNode::Entity(parent) = &mut *target.nodes[&parent_id].borrow_mut() {
+            parent.children.push(copy_id);
+        }
+        if let ConfigNode::Entity(entity) = &*node_borrow {
+            for &child_id in &entity.children {
+                self.copy_node_into(child_id, target, copy_id);
+            }
+        }
+    }
+
+    /// Copy the fields of an entity and what the model records about them
+    fn copy_entity_data(from: &EntityNode, to: &mut EntityNode) {
+        to.fields = from.fields.clone();
+        to.field_types = from.field_types.clone();
+        to.optional_fields = from.optional_fields.clone();
+        to.required_fields = from.required_fields.clone();
+        to.declared_fields = from.declared_fields.clone();
+    }
+
+    /// Group the instances of a plural entity by the value of one of their fields, e.g. models
+    /// by `type`. String values are used as is, other values in their Cola form (`8080`,
+    /// `true`); instances without the field are left out. Each group keeps document order.
+    pub fn group_by_field(&self, plural_id: NodeId, field: &str) -> HashMap<String, Vec<NodeId>> {
+        let mut groups: HashMap<String, Vec<NodeId>> = HashMap::new();
+        let Some(node) = self.get_node(plural_id) else {
+            return groups;
+        };
+        let ConfigNode::Entity(plural) = &*node.borrow() else {
+            return groups;
+        };
+        for &child_id in &plural.children {
+            let Some(child) = self.get_node(child_id) else {
+                continue;
+            };
+            if let ConfigNode::Entity(instance) = &*child.borrow()
+                && let Some(value) = instance.fields.get(field)
+            {
+                let key = match value {
+                    ConfigValue::String(s) => s.clone(),
+                    other => other.to_string(),
+                };
+                groups.entry(key).or_default().push(child_id);
+            }
+        }
+        groups
+    }
+
+    /// Get the slash-separated path of an entity (e.g. "llm/openai"), as accepted by
+    /// `find_entity_by_path`. The root has the empty path; unknown ids and field nodes have none.
+    pub fn get_entity_path(&self, id: NodeId) -> Option<String> {
+        let mut names = Vec::new();
+        let mut current = id;
+        loop {
+            let node = self.get_node(current)?;
+            let node_borrow = node.borrow();
+            let ConfigNode::Entity(entity) = &*node_borrow else {
+                return None;
+            };
+            match entity.parent {
+                Some(parent) if current != self.root_id => {
+                    names.push(entity.name.clone());
+                    current = parent;
+                }
+                _ => break,
+            }
+        }
+        names.reverse();
+        Some(names.join("/"))
+    }
+
+    /// Export the model as JSON. The root is an object of its fields and child entities; each
+    /// entity is a nested object under its original name (`get_original_entity_name`), and a
+    /// plural entity an array holding one single-key object per instance, in document order.
+    /// Non-finite floats, which JSON cannot represent, become `null`.
+    pub fn to_json(&self) -> serde_json::Value {
+        self.entity_json(self.root_id)
+    }
+
+    /// Stream the JSON of `to_json` to a writer without building the intermediate
+    /// `serde_json::Value`, for models too large to hold twice in memory. Fields are written in
+    /// name order; keys are otherwise laid out exactly as in `to_json`. Without the `serde`
+    /// feature the value is built first and then written.
+    pub fn write_json<W: io::Write>(&self, writer: W) -> io::Result<()> {
+        #[cfg(feature = "serde")]
+        let written = EntityJson { model: self, id: self.root_id }.serialize(&mut serde_json::Serializer::new(writer));
+        #[cfg(not(feature = "serde"))]
+        let written = serde_json::to_writer(writer, &self.to_json());
+        written.map_err(io::Error::from)
+    }
 
-        for component in components {
-            let found = {
-                let current_node = self.get_node(current_id)?;
-                let current_node_borrow = current_node.borrow();
+    /// The JSON object of an entity's fields and child entities
+    fn entity_json(&self, id: NodeId) -> serde_json::Value {
+        let mut object = serde_json::Map::new();
+        let Some(node) = self.get_node(id) else {
+            return serde_json::Value::Object(object);
+        };
+        let ConfigNode::Entity(entity) = &*node.borrow() else {
+            return serde_json::Value::Object(object);
+        };
+        for (name, value) in &entity.fields {
+            object.insert(name.clone(), value_json(value));
+        }
+        for &child_id in &entity.children {
+            let Some(child) = self.get_node(child_id) else {
+                continue;
+            };
+            let ConfigNode::Entity(child_entity) = &*child.borrow() else {
+                continue;
+            };
+            let value = if child_entity.plural_name.is_some() {
+                let instances = child_entity.children.iter().filter_map(|&instance_id| {
+                    let instance = self.get_node(instance_id)?;
+                    let ConfigNode::Entity(instance_entity) = &*instance.borrow() else {
+                        return None;
+                    };
+                    let mut entry = serde_json::Map::new();
+                    entry.insert(
+                        self.get_original_entity_name(&instance_entity.name),
+                        self.entity_json(instance_id),
+                    );
+                    Some(serde_json::Value::Object(entry))
+                });
+                serde_json::Value::Array(instances.collect())
+            } else {
+                self.entity_json(child_id)
+            };
+            object.insert(self.get_original_entity_name(&child_entity.name), value);
+        }
+        serde_json::Value::Object(object)
+    }
+
+    /// Flatten every field of the model into a map from `entity/path.field` (just `field` for
+    /// fields on the root) to its value, sorted by key
+    pub fn as_flat_map(&self) -> BTreeMap<String, ConfigValue> {
+        let mut flat = BTreeMap::new();
+        for id in self.node_ids() {
+            let Some(path) = self.get_entity_path(id) else {
+                continue;
+            };
+            if let ConfigNode::Entity(entity) = &*self.nodes[&id].borrow() {
+                for (name, value) in &entity.fields {
+                    let key = if path.is_empty() {
+                        name.clone()
+                    } else {
+                        format!("{}.{}", path, name)
+                    };
+                    flat.insert(key, value.clone());
+                }
+            }
+        }
+        flat
+    }
+
+    /// Export every field as a dotenv line, `PREFIX_ENTITY_PATH_FIELD=value`, in the key order
+    /// of [`ConfigModel::as_flat_map`]. Names are upper-cased with anything but letters and
+    /// digits turned into `_`. Strings and arrays (comma-joined) are double-quoted with `\`,
+    /// `"`, `$` and newlines escaped; `null` becomes an empty value.
+    pub fn to_env(&self, prefix: &str) -> String {
+        let mut out = String::new();
+        for (key, value) in self.as_flat_map() {
+            let name: String = key
+                .chars()
+                .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_uppercase() } else { '_' })
+                .collect();
+            let value = match value {
+                ConfigValue::String(s) => Self::env_quote(&s),
+                ConfigValue::Array(items) => {
+                    let items: Vec<String> = items
+                        .iter()
+                        .map(|item| match item {
+                            ConfigValue::String(s) => s.clone(),
+                            item => item.to_string(),
+                        })
+                        .collect();
+                    Self::env_quote(&items.join(","))
+                }
+                ConfigValue::Null => String::new(),
+                value => value.to_string(),
+            };
+            if prefix.is_empty() {
+                out.push_str(&format!("{}={}\n", name, value));
+            } else {
+                out.push_str(&format!("{}_{}={}\n", prefix, name, value));
+            }
+        }
+        out
+    }
+
+    /// Double-quote a dotenv value, escaping what would otherwise end or expand it
+    fn env_quote(s: &str) -> String {
+        let mut quoted = String::with_capacity(s.len() + 2);
+        quoted.push('"');
+        for c in s.chars() {
+            match c {
+                '\\' | '"' | '$' => {
+                    quoted.push('\\');
+                    quoted.push(c);
+                }
+                '\n' => quoted.push_str("\\n"),
+                c => quoted.push(c),
+            }
+        }
+        quoted.push('"');
+        quoted
+    }
+
+    /// Serialize the model back to Cola, wrapped in a ```` ```cola ```` block so that `ColaParser`
+    /// accepts it. Fields come in document order where the model recorded it (field nodes),
+    /// then by name; arrays are written as `[..]` literals. Cola has no syntax for fields on the
+    /// root, so those are not written.
+    pub fn to_cola(&self) -> String {
+        let mut out = String::from("```cola\n");
+        if let ConfigNode::Entity(root) = &*self.nodes[&self.root_id].borrow() {
+            self.write_cola_fields(root, "", &mut out);
+            for &child_id in &root.children {
+                self.write_cola_entity(child_id, 0, &mut out);
+            }
+        }
+        out.push_str("```\n");
+        out
+    }
+
+    /// Write an entity and its subtree as Cola
+    fn write_cola_entity(&self, id: NodeId, depth: usize, out: &mut String) {
+        let node = &self.nodes[&id];
+        let node_borrow = node.borrow();
+        let ConfigNode::Entity(entity) = &*node_borrow else {
+            return;
+        };
+
+        let indent = "    ".repeat(depth);
+        let as_enum = if entity.is_enum { " as enum" } else { "" };
+        match (&entity.plural_name, &entity.key_field) {
+            (Some(plural), Some(key)) => out.push_str(&format!(
+                "{}{} plural {} key {}{}:\n",
+                indent, entity.name, plural, key, as_enum
+            )),
+            (Some(plural), None) => {
+                out.push_str(&format!("{}{} plural {}{}:\n", indent, entity.name, plural, as_enum))
+            }
+            (None, _) => out.push_str(&format!("{}{}:\n", indent, entity.name)),
+        }
+        self.write_cola_fields(entity, &format!("{}    ", indent), out);
+
+        for &child_id in &entity.children {
+            self.write_cola_entity(child_id, depth + 1, out);
+        }
+        out.push_str(&format!("{};\n", indent));
+    }
+
+    /// Write the fields of an entity as a Cola field list, one field per line
+    fn write_cola_fields(&self, entity: &EntityNode, indent: &str, out: &mut String) {
+        // Field nodes record the document order; fields added without one follow by name
+        let mut names: Vec<&String> = Vec::new();
+        for &child_id in &entity.children {
+            if let ConfigNode::Field(field) = &*self.nodes[&child_id].borrow()
+                && let Some((name, _)) = entity.fields.get_key_value(&field.name)
+                && !names.contains(&name)
+            {
+                names.push(name);
+            }
+        }
+        let mut rest: Vec<&String> = entity.fields.keys().filter(|name| !names.contains(name)).collect();
+        rest.sort();
+        names.extend(rest);
+
+        let mut lines = Vec::new();
+        for name in names {
+            let marker = Self::field_marker(entity, name);
+            if entity.declared_fields.contains(name.as_str())
+                && let Some(type_name) = entity.field_types.get(name.as_str())
+            {
+                lines.push(format!("{}{}{}: {}", indent, name, marker, type_name));
+                continue;
+            }
+            let annotation = entity
+                .field_types
+                .get(name.as_str())
+                .map(|t| format!("{} = ", t))
+                .unwrap_or_default();
+            lines.push(format!(
+                "{}{}{}: {}{}",
+                indent,
+                name,
+                marker,
+                annotation,
+                Self::cola_literal(&entity.fields[name.as_str()])
+            ));
+        }
+        if !lines.is_empty() {
+            out.push_str(&lines.join(",\n"));
+            out.push('\n');
+        }
+    }
+
+    /// Format a value as a Cola literal. Floats keep a decimal point so they re-parse as
+    /// floats. Strings use single quotes when they contain a double quote, and triple quotes
+    /// opened by a line break, which the parser drops, when they contain both kinds.
+    fn cola_literal(value: &ConfigValue) -> String {
+        match value {
+            ConfigValue::Float(f) if f.fract() == 0.0 && f.is_finite() => format!("{:.1}", f),
+            ConfigValue::String(s) if s.contains('"') && s.contains('\'') => format!("\"\"\"\n{}\"\"\"", s),
+            ConfigValue::String(s) if s.contains('"') => format!("'{}'", s),
+            ConfigValue::Array(items) => {
+                let items: Vec<String> = items.iter().map(Self::cola_literal).collect();
+                format!("[{}]", items.join(", "))
+            }
+            value => value.to_string(),
+        }
+    }
+
+    /// Check that every entity and field name can become a Rust identifier the way the
+    /// generator transforms it: PascalCase struct names and snake_case field names, with `type`
+    /// renamed to `type_`. Reports names with no valid identifier form, names that become a
+    /// keyword, and different names that become the same struct name or the same field of one
+    /// struct. Instances of a plural entity are map keys, so only their fields are checked.
+    pub fn validate_codegen_names(&self) -> Result<(), Vec<NameIssue>> {
+        let mut issues = Vec::new();
+        let mut struct_names: HashMap<String, String> = HashMap::new();
+
+        for id in self.node_ids() {
+            if id == self.root_id {
+                continue;
+            }
+            let node = self.nodes[&id].borrow();
+            let ConfigNode::Entity(entity) = &*node else {
+                continue;
+            };
+            let is_instance = entity.parent.is_some_and(|parent| match &*self.nodes[&parent].borrow() {
+                ConfigNode::Entity(parent) => parent.plural_name.is_some(),
+                ConfigNode::Field(_) => false,
+            });
+            if is_instance {
+                continue;
+            }
+
+            // A plural entity generates its entry struct and its collection struct
+            for name in std::iter::once(&entity.name).chain(entity.plural_name.as_ref()) {
+                let identifier = name.to_pascal_case();
+                if let Some(kind) = Self::identifier_issue(&identifier) {
+                    issues.push(NameIssue {
+                        name: name.clone(),
+                        identifier,
+                        kind,
+                        location: entity.location.clone(),
+                    });
+                    continue;
+                }
+                match struct_names.get(&identifier) {
+                    Some(other) if other != name => issues.push(NameIssue {
+                        name: name.clone(),
+                        identifier,
+                        kind: NameIssueKind::Collision(other.clone()),
+                        location: entity.location.clone(),
+                    }),
+                    Some(_) => {}
+                    None => {
+                        struct_names.insert(identifier, name.clone());
+                    }
+                }
+            }
+        }
+
+        for id in self.node_ids() {
+            let node = self.nodes[&id].borrow();
+            let ConfigNode::Entity(entity) = &*node else {
+                continue;
+            };
+            self.member_name_issues(entity, &mut issues);
+        }
+
+        if issues.is_empty() { Ok(()) } else { Err(issues) }
+    }
+
+    /// Check that each field of the instances of a plural entity has the same type in every
+    /// instance, as the instances share one generated struct. The declared type annotation is
+    /// used when there is one; `null` and empty arrays carry no type and match any.
+    pub fn validate_type_consistency(&self) -> Result<(), Vec<TypeConflict>> {
+        let mut conflicts = Vec::new();
+        for id in self.node_ids() {
+            let node = self.nodes[&id].borrow();
+            let ConfigNode::Entity(entity) = &*node else {
+                continue;
+            };
+            if entity.plural_name.is_none() {
+                continue;
+            }
+            let path = self.get_entity_path(id).unwrap_or_default();
+            // Field name to the instance, type and location it was first seen with
+            let mut seen: HashMap<String, (String, String, Option<SourceLocation>)> = HashMap::new();
+            for &child_id in &entity.children {
+                let child = self.nodes[&child_id].borrow();
+                let ConfigNode::Entity(instance) = &*child else {
+                    continue;
+                };
+                let mut names: Vec<&String> = instance.fields.keys().collect();
+                names.sort();
+                for name in names {
+                    let value = &instance.fields[name];
+                    if matches!(value, ConfigValue::Null) || matches!(value, ConfigValue::Array(items) if items.is_empty()) {
+                        continue;
+                    }
+                    let field_type = instance
+                        .field_types
+                        .get(name)
+                        .cloned()
+                        .unwrap_or_else(|| Self::value_type_name(value));
+                    let location = self.field_location(instance, name);
+                    match seen.get(name) {
+                        Some((first_instance, first_type, first_location)) if *first_type != field_type => {
+                            conflicts.push(TypeConflict {
+                                path: path.clone(),
+                                field: name.clone(),
+                                first_instance: first_instance.clone(),
+                                first_type: first_type.clone(),
+                                first_location: first_location.clone(),
+                                instance: instance.name.clone(),
+                                field_type,
+                                location,
+                            })
+                        }
+                        Some(_) => {}
+                        None => {
+                            seen.insert(name.clone(), (instance.name.clone(), field_type, location));
+                        }
+                    }
+                }
+            }
+        }
+        if conflicts.is_empty() { Ok(()) } else { Err(conflicts) }
+    }
 
-                if let ConfigNode::Entity(entity) = &*current_node_borrow {
-                    let child_ids = &entity.children;
+    /// The source location of a field of an entity, kept on its field node
+    fn field_location(&self, entity: &EntityNode, name: &str) -> Option<SourceLocation> {
+        entity.children.iter().find_map(|&child_id| match &*self.nodes[&child_id].borrow() {
+            ConfigNode::Field(field) if field.name == name => field.location.clone(),
+            _ => None,
+        })
+    }
 
-                    // Find a child entity with the matching name
-                    child_ids
-                        .iter()
-                        .find(|&&id| {
-                            if let Some(child_node) = self.get_node(id) {
-                                let child_borrow = child_node.borrow();
-                                if let ConfigNode::Entity(child_entity) = &*child_borrow {
-                                    return child_entity.name == component;
-                                }
-                            }
-                            false
-                        })
-                        .cloned()
-                } else {
-                    None
+    /// Check the fields and child entities of one entity, which become fields of its struct
+    fn member_name_issues(&self, entity: &EntityNode, issues: &mut Vec<NameIssue>) {
+        let mut members: Vec<(String, Option<SourceLocation>, bool)> = Vec::new();
+        let mut field_names: Vec<&String> = entity.fields.keys().collect();
+        field_names.sort();
+        for name in field_names {
+            let location = entity.children.iter().find_map(|&child| match &*self.nodes[&child].borrow() {
+                ConfigNode::Field(field) if &field.name == name => field.location.clone(),
+                _ => None,
+            });
+            members.push((name.clone(), location, false));
+        }
+        // Children of a plural entity are its instances, not struct fields
+        if entity.plural_name.is_none() {
+            let mut seen = HashSet::new();
+            for &child in &entity.children {
+                if let ConfigNode::Entity(child) = &*self.nodes[&child].borrow() {
+                    let name = child.plural_name.as_ref().unwrap_or(&child.name);
+                    if seen.insert(name.clone()) {
+                        members.push((name.clone(), child.location.clone(), true));
+                    }
                 }
-            };
-
-            if let Some(next_id) = found {
-                current_id = next_id;
-            } else {
-                return None;
             }
         }
 
-        Some(current_id)
+        let mut identifiers: HashMap<String, String> = HashMap::new();
+        for (name, location, is_entity) in members {
+            let identifier = if name == "type" { "type_".to_string() } else { name.to_snake_case() };
+            if let Some(kind) = Self::identifier_issue(&identifier) {
+                // An invalid entity name was already reported for its struct name
+                if !(is_entity && kind == NameIssueKind::Invalid) {
+                    issues.push(NameIssue { name, identifier, kind, location });
+                }
+                continue;
+            }
+            match identifiers.get(&identifier) {
+                Some(other) => issues.push(NameIssue {
+                    name,
+                    identifier,
+                    kind: NameIssueKind::Collision(other.clone()),
+                    location,
+                }),
+                None => {
+                    identifiers.insert(identifier, name);
+                }
+            }
+        }
     }
 
-    /// Create an entity at the specified path
-    pub fn create_entity_at_path(
-        &mut self,
-        path: &str,
-        name: &str,
-        plural_name: Option<&str>,
-        location: Option<SourceLocation>,
-    ) -> Result<NodeId, String> {
-        // If path is empty, create at root
-        if path.is_empty() {
-            let entity = ConfigNode::new_entity(name, plural_name, Some(self.root_id), location);
-            let entity_id = self.add_node(entity);
-            self.add_child(self.root_id, entity_id)?;
-            return Ok(entity_id);
+    /// Why a generated identifier is unusable, if it is
+    fn identifier_issue(identifier: &str) -> Option<NameIssueKind> {
+        let mut chars = identifier.chars();
+        let valid = identifier != "_"
+            && chars.next().is_some_and(|c| c.is_alphabetic() || c == '_')
+            && chars.all(|c| c.is_alphanumeric() || c == '_');
+        if !valid {
+            Some(NameIssueKind::Invalid)
+        } else if RUST_KEYWORDS.contains(&identifier) {
+            Some(NameIssueKind::Keyword)
+        } else {
+            None
         }
+    }
 
-        // Find the parent entity
-        let parent_id = self
-            .find_entity_by_path(path)
-            .ok_or_else(|| format!("Parent path '{}' not found", path))?;
-
-        // Create the new entity
-        let entity = ConfigNode::new_entity(name, plural_name, Some(parent_id), location);
-        let entity_id = self.add_node(entity);
+    /// The `?` or `!` written after a field name declared optional or required
+    fn field_marker(entity: &EntityNode, name: &str) -> &'static str {
+        if entity.optional_fields.contains(name) {
+            "?"
+        } else if entity.required_fields.contains(name) {
+            "!"
+        } else {
+            ""
+        }
+    }
 
-        // Add it as a child of the parent
-        self.add_child(parent_id, entity_id)?;
+    /// Structural fingerprint of the whole model, see [`ConfigModel::entity_fingerprint`]
+    pub fn fingerprint(&self) -> u64 {
+        self.entity_fingerprint(self.root_id)
+    }
 
-        Ok(entity_id)
+    /// Structural fingerprint of an entity subtree: entity names, plural names, field names,
+    /// field types and `?`/`!` markers, but not field values. Fields and children are taken in
+    /// name order, and a plural entity contributes the shape of its first instance only, which is
+    /// the instance the generated entry struct is modelled on. The hash is FNV-1a, so it is
+    /// stable across builds and platforms.
+    pub fn entity_fingerprint(&self, id: NodeId) -> u64 {
+        const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0100_0000_01b3;
+        self.schema_signature(id, true)
+            .bytes()
+            .fold(FNV_OFFSET, |hash, byte| (hash ^ byte as u64).wrapping_mul(FNV_PRIME))
     }
 
-    /// Get a field value from an entity
-    pub fn get_field_value(&self, entity_id: NodeId, field_name: &str) -> Option<ConfigValue> {
-        let entity_node = self.get_node(entity_id)?;
-        let entity_borrow = entity_node.borrow();
+    /// Canonical text form of an entity's structure that the fingerprint is computed from
+    fn schema_signature(&self, id: NodeId, include_name: bool) -> String {
+        let Some(node) = self.get_node(id) else {
+            return String::new();
+        };
+        let node_borrow = node.borrow();
+        let ConfigNode::Entity(entity) = &*node_borrow else {
+            return String::new();
+        };
 
-        if let ConfigNode::Entity(entity) = &*entity_borrow {
-            entity.fields.get(field_name).cloned()
-        } else {
-            None
+        let mut signature = if include_name { entity.name.clone() } else { "*".to_string() };
+        if let Some(plural) = &entity.plural_name {
+            signature.push('|');
+            signature.push_str(plural);
+        }
+        if let Some(key) = &entity.key_field {
+            signature.push('#');
+            signature.push_str(key);
+        }
+        if entity.is_enum {
+            signature.push_str("@enum");
         }
-    }
 
-    /// Find a child entity by name within a parent entity
-    pub fn find_child_entity_by_name(&self, parent_id: NodeId, child_name: &str) -> Option<NodeId> {
-        let parent_node = self.get_node(parent_id)?;
-        let parent_borrow = parent_node.borrow();
-        
-        if let ConfigNode::Entity(parent_entity) = &*parent_borrow {
-            for &child_id in &parent_entity.children {
-                if let Some(child_node) = self.get_node(child_id) {
-                    let child_borrow = child_node.borrow();
-                    if let ConfigNode::Entity(child_entity) = &*child_borrow {
-                        if child_entity.name == child_name {
-                            return Some(child_id);
-                        }
-                    }
+        let mut fields: Vec<String> = entity
+            .fields
+            .iter()
+            .map(|(name, value)| {
+                let marker = Self::field_marker(entity, name);
+                match entity.field_types.get(name) {
+                    Some(declared) => format!("{}{}:{}", name, marker, declared),
+                    None => format!("{}{}:{}", name, marker, Self::value_type_name(value)),
                 }
-            }
+            })
+            .collect();
+        fields.sort();
+
+        let child_entities = entity.children.iter().copied().filter(|&child_id| {
+            self.get_node(child_id)
+                .is_some_and(|child| child.borrow().is_entity())
+        });
+        let mut children: Vec<String> = if entity.plural_name.is_some() {
+            child_entities
+                .take(1)
+                .map(|child_id| self.schema_signature(child_id, false))
+                .collect()
+        } else {
+            child_entities
+                .map(|child_id| self.schema_signature(child_id, true))
+                .collect()
+        };
+        children.sort();
+
+        format!("{}{{{}}}[{}]", signature, fields.join(","), children.join(";"))
+    }
+
+    /// The type name of a value as it appears in a schema signature
+    fn value_type_name(value: &ConfigValue) -> String {
+        match value {
+            ConfigValue::Integer(_) => "i64".to_string(),
+            ConfigValue::Float(_) => "f64".to_string(),
+            ConfigValue::Boolean(_) => "bool".to_string(),
+            ConfigValue::String(_) => "String".to_string(),
+            ConfigValue::Null => "null".to_string(),
+            ConfigValue::Array(items) => match items.first() {
+                Some(first) => format!("Vec<{}>", Self::value_type_name(first)),
+                None => "Vec<_>".to_string(),
+            },
         }
-        None
     }
 
     /// Display the node tree recursively
@@ -493,6 +2086,126 @@ impl fmt::Display for ConfigModel {
     }
 }
 
+/// The JSON value of a field value
+fn value_json(value: &ConfigValue) -> serde_json::Value {
+    match value {
+        ConfigValue::Integer(i) => serde_json::Value::from(*i),
+        ConfigValue::Float(f) => serde_json::Number::from_f64(*f)
+            .map(serde_json::Value::Number)
+            .unwrap_or(serde_json::Value::Null),
+        ConfigValue::Boolean(b) => serde_json::Value::Bool(*b),
+        ConfigValue::String(s) => serde_json::Value::String(s.clone()),
+        ConfigValue::Array(items) => serde_json::Value::Array(items.iter().map(value_json).collect()),
+        ConfigValue::Null => serde_json::Value::Null,
+    }
+}
+
+/// Serializes the model as `to_json` lays it out, so it can be written with any serde format
+#[cfg(feature = "serde")]
+impl Serialize for ConfigModel {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        EntityJson { model: self, id: self.root_id }.serialize(serializer)
+    }
+}
+
+/// Serializes a value as `to_json` writes it, with non-finite floats as a unit
+#[cfg(feature = "serde")]
+impl Serialize for ConfigValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        ValueJson(self).serialize(serializer)
+    }
+}
+
+/// Streams an entity as the JSON object `ConfigModel::entity_json` would build
+#[cfg(feature = "serde")]
+struct EntityJson<'a> {
+    model: &'a ConfigModel,
+    id: NodeId,
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for EntityJson<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let Some(node) = self.model.get_node(self.id) else {
+            return serializer.serialize_map(Some(0))?.end();
+        };
+        let ConfigNode::Entity(entity) = &*node.borrow() else {
+            return serializer.serialize_map(Some(0))?.end();
+        };
+        let mut map = serializer.serialize_map(None)?;
+        let mut fields: Vec<_> = entity.fields.iter().collect();
+        fields.sort_by(|a, b| a.0.cmp(b.0));
+        for (name, value) in fields {
+            map.serialize_entry(name, &ValueJson(value))?;
+        }
+        for &child_id in &entity.children {
+            let Some(child) = self.model.get_node(child_id) else {
+                continue;
+            };
+            let ConfigNode::Entity(child_entity) = &*child.borrow() else {
+                continue;
+            };
+            let key = self.model.get_original_entity_name(&child_entity.name);
+            if child_entity.plural_name.is_some() {
+                map.serialize_entry(
+                    &key,
+                    &InstancesJson { model: self.model, ids: &child_entity.children },
+                )?;
+            } else {
+                map.serialize_entry(&key, &EntityJson { model: self.model, id: child_id })?;
+            }
+        }
+        map.end()
+    }
+}
+
+/// Streams the instances of a plural entity as an array of single-key objects
+#[cfg(feature = "serde")]
+struct InstancesJson<'a> {
+    model: &'a ConfigModel,
+    ids: &'a [NodeId],
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for InstancesJson<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(None)?;
+        for &instance_id in self.ids {
+            let Some(instance) = self.model.get_node(instance_id) else {
+                continue;
+            };
+            let ConfigNode::Entity(instance_entity) = &*instance.borrow() else {
+                continue;
+            };
+            let mut entry = BTreeMap::new();
+            entry.insert(
+                self.model.get_original_entity_name(&instance_entity.name),
+                EntityJson { model: self.model, id: instance_id },
+            );
+            seq.serialize_element(&entry)?;
+        }
+        seq.end()
+    }
+}
+
+/// Streams a field value as the JSON `value_json` would build
+#[cfg(feature = "serde")]
+struct ValueJson<'a>(&'a ConfigValue);
+
+#[cfg(feature = "serde")]
+impl Serialize for ValueJson<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            ConfigValue::Integer(i) => serializer.serialize_i64(*i),
+            ConfigValue::Float(f) if f.is_finite() => serializer.serialize_f64(*f),
+            ConfigValue::Float(_) | ConfigValue::Null => serializer.serialize_unit(),
+            ConfigValue::Boolean(b) => serializer.serialize_bool(*b),
+            ConfigValue::String(s) => serializer.serialize_str(s),
+            ConfigValue::Array(items) => serializer.collect_seq(items.iter().map(ValueJson)),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -555,5 +2268,653 @@ mod tests {
         // Test finding entity by path
         let found_gpt4_id = model.find_entity_by_path("llm/openai/model/gpt-4").unwrap();
         assert_eq!(found_gpt4_id, gpt4_id);
+
+        assert_eq!(model.summary(), "1 top-level entities, 4 entities total, 4 fields, max depth 4");
+    }
+
+    #[test]
+    fn test_add_child_rejects_cycles() {
+        let mut model = ConfigModel::new();
+        let llm_id = model.create_entity_at_path("", "llm", None, None).unwrap();
+        let openai_id = model
+            .create_entity_at_path("llm", "openai", None, None)
+            .unwrap();
+
+        // An ancestor cannot become a child of its descendant
+        let err = model.add_child(openai_id, llm_id).unwrap_err();
+        assert!(err.contains("ancestors"), "unexpected error: {}", err);
+        let err = model.add_child(openai_id, model.root_id()).unwrap_err();
+        assert!(err.contains("ancestors"), "unexpected error: {}", err);
+
+        // Nor can a node become its own child
+        assert!(model.add_child(llm_id, llm_id).is_err());
+
+        // The tree is unchanged and still displays
+        assert_eq!(model.find_entity_by_path("llm/openai"), Some(openai_id));
+        assert!(model.pretty_display().contains("openai"));
+    }
+
+    #[test]
+    fn test_path_index_rebuilt_after_mutation() {
+        let mut model = ConfigModel::new();
+        let llm_id = model.create_entity_at_path("", "llm", None, None).unwrap();
+
+        // Populate the index, including a miss for a path that does not exist yet
+        assert_eq!(model.find_entity_by_path("llm"), Some(llm_id));
+        assert_eq!(model.find_entity_by_path("llm/openai"), None);
+
+        // Adding an entity must invalidate the cached index
+        let openai_id = model
+            .create_entity_at_path("llm", "openai", None, None)
+            .unwrap();
+        assert_eq!(model.find_entity_by_path("llm/openai"), Some(openai_id));
+        assert_eq!(model.find_entity_by_path("llm"), Some(llm_id));
+
+        // Renaming through the node handle requires an explicit invalidation
+        if let ConfigNode::Entity(entity) = &mut *model.get_node(openai_id).unwrap().borrow_mut() {
+            entity.name = "anthropic".to_string();
+        }
+        model.invalidate_path_index();
+        assert_eq!(model.find_entity_by_path("llm/openai"), None);
+        assert_eq!(model.find_entity_by_path("llm/anthropic"), Some(openai_id));
+    }
+
+    #[test]
+    fn test_map_values_uppercases_strings() {
+        let mut model = ConfigModel::new();
+        let openai_id = model.create_entity_at_path("", "openai", None, None).unwrap();
+        model
+            .add_field_with_location(openai_id, "name", ConfigValue::String("gpt-4".to_string()), None)
+            .unwrap();
+        model
+            .add_field_with_location(openai_id, "max_tokens", ConfigValue::Integer(1000), None)
+            .unwrap();
+
+        model.map_values(|_, value| match value {
+            ConfigValue::String(s) => ConfigValue::String(s.to_uppercase()),
+            other => other.clone(),
+        });
+
+        // The field map is updated and non-string values are left alone
+        match model.get_field_value(openai_id, "name") {
+            Some(ConfigValue::String(s)) => assert_eq!(s, "GPT-4"),
+            other => panic!("Expected an uppercased string, got {:?}", other),
+        }
+        assert!(matches!(
+            model.get_field_value(openai_id, "max_tokens"),
+            Some(ConfigValue::Integer(1000))
+        ));
+
+        // The mirrored field node carries the new value as well
+        let node = model.get_node(openai_id).unwrap();
+        let ConfigNode::Entity(entity) = &*node.borrow() else {
+            panic!("Expected an entity");
+        };
+        let name_node = entity
+            .children
+            .iter()
+            .filter_map(|&id| model.get_node(id))
+            .find(|child| child.borrow().name() == "name")
+            .expect("Expected a field node for name");
+        match &*name_node.borrow() {
+            ConfigNode::Field(field) => assert_eq!(field.value.to_string(), "\"GPT-4\""),
+            other => panic!("Expected a field node, got {:?}", other),
+        };
+    }
+
+    #[test]
+    fn test_fingerprint_tracks_structure_not_values() {
+        let build = |max_tokens: i64, extra_field: bool| {
+            let mut model = ConfigModel::new();
+            model.create_entity_at_path("", "model", Some("models"), None).unwrap();
+            let gpt4_id = model.create_entity_at_path("model", "gpt-4", None, None).unwrap();
+            model
+                .add_field_to_entity(gpt4_id, "max_tokens", ConfigValue::Integer(max_tokens))
+                .unwrap();
+            if extra_field {
+                model
+                    .add_field_to_entity(gpt4_id, "vision", ConfigValue::Boolean(true))
+                    .unwrap();
+            }
+            model
+        };
+
+        let base = build(1000, false).fingerprint();
+        assert_eq!(base, build(1000, false).fingerprint());
+        assert_eq!(base, build(8192, false).fingerprint());
+        assert_ne!(base, build(1000, true).fingerprint());
+
+        // Further instances of a plural entity do not change the schema
+        let mut more = build(1000, false);
+        let o1_id = more.create_entity_at_path("model", "o1", None, None).unwrap();
+        more.add_field_to_entity(o1_id, "max_tokens", ConfigValue::Integer(1)).unwrap();
+        assert_eq!(base, more.fingerprint());
+    }
+
+    #[test]
+    fn test_entity_paths_and_flat_map() {
+        let mut model = ConfigModel::new();
+        let root_id = model.root_id();
+        model
+            .add_field_to_entity(root_id, "version", ConfigValue::Integer(2))
+            .unwrap();
+        let llm_id = model.create_entity_at_path("", "llm", Some("llms"), None).unwrap();
+        let openai_id = model.create_entity_at_path("llm", "openai", None, None).unwrap();
+        model
+            .add_field_to_entity(openai_id, "max_tokens", ConfigValue::Integer(1000))
+            .unwrap();
+
+        assert_eq!(model.get_entity_path(root_id).as_deref(), Some(""));
+        assert_eq!(model.get_entity_path(llm_id).as_deref(), Some("llm"));
+        assert_eq!(model.get_entity_path(openai_id).as_deref(), Some("llm/openai"));
+        assert_eq!(model.get_entity_path(99), None);
+
+        let flat: Vec<(String, String)> = model
+            .as_flat_map()
+            .into_iter()
+            .map(|(key, value)| (key, value.to_string()))
+            .collect();
+        assert_eq!(
+            flat,
+            vec![
+                ("llm/openai.max_tokens".to_string(), "1000".to_string()),
+                ("version".to_string(), "2".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_codegen_names() {
+        let mut model = ConfigModel::new();
+        let location = SourceLocation::new("config.md".to_string(), (3, 0), (3, 4));
+        model.create_entity_at_path("", "-.-", None, Some(location.clone())).unwrap();
+        let service_id = model.create_entity_at_path("", "service", None, None).unwrap();
+        model.add_field_to_entity(service_id, "type", ConfigValue::Integer(1)).unwrap();
+        model.add_field_to_entity(service_id, "fn", ConfigValue::Integer(1)).unwrap();
+        model.add_field_to_entity(service_id, "max-size", ConfigValue::Integer(1)).unwrap();
+        model.add_field_to_entity(service_id, "max_size", ConfigValue::Integer(1)).unwrap();
+
+        let issues = model.validate_codegen_names().unwrap_err();
+        assert_eq!(issues.len(), 3);
+        assert_eq!(issues[0].name, "-.-");
+        assert_eq!(issues[0].kind, NameIssueKind::Invalid);
+        assert_eq!(issues[0].location, Some(location));
+        assert_eq!(issues[1].kind, NameIssueKind::Keyword);
+        assert_eq!(issues[1].identifier, "fn");
+        assert_eq!(issues[2].name, "max_size");
+        assert_eq!(issues[2].kind, NameIssueKind::Collision("max-size".to_string()));
+
+        let mut valid = ConfigModel::new();
+        let api_id = valid.create_entity_at_path("", "api", None, None).unwrap();
+        valid.add_field_to_entity(api_id, "type", ConfigValue::Integer(1)).unwrap();
+        assert!(valid.validate_codegen_names().is_ok());
+    }
+
+    #[test]
+    fn test_validate_type_consistency() {
+        let mut model = ConfigModel::new();
+        let plural_id = model.create_entity_at_path("", "llm", Some("llms"), None).unwrap();
+        let openai_id = model.create_child_entity(plural_id, "openai", None, None).unwrap();
+        let claude_id = model.create_child_entity(plural_id, "claude", None, None).unwrap();
+        let first = SourceLocation::new("config.md".to_string(), (3, 8), (3, 20));
+        let second = SourceLocation::new("config.md".to_string(), (7, 8), (7, 22));
+        model.add_field_with_location(openai_id, "timeout", ConfigValue::Integer(30), Some(first.clone())).unwrap();
+        model.add_field_with_location(claude_id, "timeout", ConfigValue::String("30s".to_string()), Some(second.clone())).unwrap();
+        model.add_field_to_entity(openai_id, "model", ConfigValue::String("gpt-4o".to_string())).unwrap();
+        model.add_field_to_entity(claude_id, "model", ConfigValue::String("opus".to_string())).unwrap();
+        model.add_field_to_entity(claude_id, "stop", ConfigValue::Null).unwrap();
+        model.add_field_to_entity(openai_id, "stop", ConfigValue::String("\n".to_string())).unwrap();
+
+        let conflicts = model.validate_type_consistency().unwrap_err();
+        assert_eq!(conflicts.len(), 1);
+        let conflict = &conflicts[0];
+        assert_eq!((conflict.path.as_str(), conflict.field.as_str()), ("llm", "timeout"));
+        assert_eq!((conflict.first_instance.as_str(), conflict.first_type.as_str()), ("openai", "i64"));
+        assert_eq!((conflict.instance.as_str(), conflict.field_type.as_str()), ("claude", "String"));
+        assert_eq!(conflict.first_location, Some(first));
+        assert_eq!(conflict.location, Some(second));
+        assert!(conflict.to_string().starts_with("'timeout' of 'llm' is i64 in 'openai' at config.md: [(3,8)-(3,20)] but String in 'claude'"));
+
+        model.add_field_to_entity(claude_id, "timeout", ConfigValue::Integer(60)).unwrap();
+        assert!(model.validate_type_consistency().is_ok());
+    }
+
+    #[test]
+    fn test_to_json_nests_entities_and_lists_plurals() {
+        let mut model = ConfigModel::new();
+        let root_id = model.root_id();
+        model.add_field_to_entity(root_id, "debug", ConfigValue::Boolean(true)).unwrap();
+        let service_id = model.create_entity_at_path("", "my_service", None, None).unwrap();
+        model.add_field_to_entity(service_id, "port", ConfigValue::Integer(8080)).unwrap();
+        model.add_field_to_entity(service_id, "ratio", ConfigValue::Float(0.5)).unwrap();
+        model
+            .add_field_to_entity(
+                service_id,
+                "tags",
+                ConfigValue::Array(vec![ConfigValue::String("a".to_string()), ConfigValue::Null]),
+            )
+            .unwrap();
+        model.create_entity_at_path("", "llm", Some("llms"), None).unwrap();
+        let openai_id = model.create_entity_at_path("llm", "openai", None, None).unwrap();
+        model
+            .add_field_to_entity(openai_id, "model", ConfigValue::String("gpt-4o".to_string()))
+            .unwrap();
+        model.create_entity_at_path("llm", "local", None, None).unwrap();
+        model.set_original_entity_names(HashMap::from([(
+            "my_service".to_string(),
+            "my-service".to_string(),
+        )]));
+
+        assert_eq!(
+            model.to_json(),
+            serde_json::json!({
+                "debug": true,
+                "my-service": { "port": 8080, "ratio": 0.5, "tags": ["a", null] },
+                "llm": [
+                    { "openai": { "model": "gpt-4o" } },
+                    { "local": {} }
+                ]
+            })
+        );
+    }
+
+    #[test]
+    fn test_rename_field_moves_value_and_field_node() {
+        let mut model = ConfigModel::new();
+        let server_id = model.create_entity_at_path("", "server", None, None).unwrap();
+        model
+            .add_field_with_location(server_id, "hostname", ConfigValue::String("localhost".to_string()), None)
+            .unwrap();
+        model.add_field_to_entity(server_id, "port", ConfigValue::Integer(8080)).unwrap();
+        model.set_field_type(server_id, "hostname", "String").unwrap();
+
+        model.rename_field(server_id, "hostname", "host").unwrap();
+        assert!(matches!(model.get_field_value(server_id, "host"), Some(ConfigValue::String(s)) if s == "localhost"));
+        assert!(model.get_field_value(server_id, "hostname").is_none());
+        assert_eq!(model.get_field_type(server_id, "host").as_deref(), Some("String"));
+        let children = match &*model.get_node(server_id).unwrap().borrow() {
+            ConfigNode::Entity(entity) => entity.children.clone(),
+            ConfigNode::Field(_) => Vec::new(),
+        };
+        let field_names: Vec<String> = children
+            .into_iter()
+            .filter_map(|id| match &*model.get_node(id)?.borrow() {
+                ConfigNode::Field(field) => Some(field.name.clone()),
+                ConfigNode::Entity(_) => None,
+            })
+            .collect();
+        assert_eq!(field_names, vec!["host".to_string()]);
+
+        assert!(model.rename_field(server_id, "hostname", "name").is_err());
+        assert!(model.rename_field(server_id, "host", "port").is_err());
+        assert!(matches!(model.get_field_value(server_id, "host"), Some(ConfigValue::String(_))));
+    }
+
+    #[test]
+    fn test_write_json_streams_the_same_structure_as_to_json() {
+        let mut model = merge_defaults();
+        let server_id = model.find_entity_by_path("server").unwrap();
+        model
+            .add_field_to_entity(
+                server_id,
+                "weights",
+                ConfigValue::Array(vec![ConfigValue::Float(0.25), ConfigValue::Float(f64::NAN)]),
+            )
+            .unwrap();
+        model.add_field_to_entity(server_id, "proxy", ConfigValue::Null).unwrap();
+        model.create_entity_at_path("server", "tls_config", None, None).unwrap();
+        model.set_original_entity_names(HashMap::from([(
+            "tls_config".to_string(),
+            "tls-config".to_string(),
+        )]));
+
+        let mut streamed = Vec::new();
+        model.write_json(&mut streamed).unwrap();
+        let parsed: serde_json::Value = serde_json::from_slice(&streamed).unwrap();
+        assert_eq!(parsed, model.to_json());
+        assert_eq!(parsed["server"]["weights"], serde_json::json!([0.25, null]));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_serialization_matches_to_json() {
+        let model = merge_defaults();
+        let serialized = serde_json::to_string(&model).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&serialized).unwrap();
+        assert_eq!(parsed, model.to_json());
+        assert!(parsed["llm"].is_array());
+
+        let value = ConfigValue::Array(vec![ConfigValue::Integer(1), ConfigValue::Float(f64::INFINITY)]);
+        assert_eq!(serde_json::to_value(&value).unwrap(), serde_json::json!([1, null]));
+    }
+
+    /// A defaults model with a `server` entity and two `llm` instances
+    fn merge_defaults() -> ConfigModel {
+        let mut model = ConfigModel::new();
+        let server_id = model.create_entity_at_path("", "server", None, None).unwrap();
+        model
+            .add_field_to_entity(server_id, "host", ConfigValue::String("localhost".to_string()))
+            .unwrap();
+        model.add_field_to_entity(server_id, "port", ConfigValue::Integer(8080)).unwrap();
+        model.create_entity_at_path("", "llm", Some("llms"), None).unwrap();
+        let openai_id = model.create_entity_at_path("llm", "openai", None, None).unwrap();
+        model.add_field_to_entity(openai_id, "max_tokens", ConfigValue::Integer(1000)).unwrap();
+        model
+            .add_field_to_entity(openai_id, "model", ConfigValue::String("gpt-4o".to_string()))
+            .unwrap();
+        let local_id = model.create_entity_at_path("llm", "local", None, None).unwrap();
+        model.add_field_to_entity(local_id, "max_tokens", ConfigValue::Integer(512)).unwrap();
+        model
+    }
+
+    #[test]
+    fn test_get_field_value_by_path() {
+        let mut model = merge_defaults();
+        let root = model.root_id();
+        model.add_field_to_entity(root, "version", ConfigValue::Integer(2)).unwrap();
+
+        assert!(matches!(model.get_field_value_by_path("server/port"), Some(ConfigValue::Integer(8080))));
+        assert!(matches!(
+            model.get_field_value_by_path("llm/openai/model"),
+            Some(ConfigValue::String(model)) if model == "gpt-4o"
+        ));
+        assert!(matches!(model.get_field_value_by_path("version"), Some(ConfigValue::Integer(2))));
+
+        // Missing field, missing leaf entity and missing intermediate entity
+        assert!(model.get_field_value_by_path("server/tls").is_none());
+        assert!(model.get_field_value_by_path("llm/anthropic/model").is_none());
+        assert!(model.get_field_value_by_path("provider/openai/model").is_none());
+        // An entity is not a field
+        assert!(model.get_field_value_by_path("llm/openai").is_none());
+        assert!(model.get_field_value_by_path("").is_none());
+    }
+
+    #[test]
+    fn test_merge_overrides_fields_and_adds_entities() {
+        let mut model = merge_defaults();
+
+        let mut overrides = ConfigModel::new();
+        let server_id = overrides.create_entity_at_path("", "server", None, None).unwrap();
+        overrides.add_field_to_entity(server_id, "port", ConfigValue::Integer(9090)).unwrap();
+        overrides.add_field_to_entity(server_id, "tls", ConfigValue::Boolean(true)).unwrap();
+        overrides.create_entity_at_path("", "llm", Some("llms"), None).unwrap();
+        let openai_id = overrides.create_entity_at_path("llm", "openai", None, None).unwrap();
+        overrides
+            .add_field_to_entity(openai_id, "max_tokens", ConfigValue::Integer(4096))
+            .unwrap();
+        let anthropic_id = overrides.create_entity_at_path("llm", "anthropic", None, None).unwrap();
+        overrides
+            .add_field_to_entity(anthropic_id, "max_tokens", ConfigValue::Integer(8192))
+            .unwrap();
+        let logging_id = overrides.create_entity_at_path("", "logging", None, None).unwrap();
+        overrides
+            .add_field_to_entity(logging_id, "level", ConfigValue::String("debug".to_string()))
+            .unwrap();
+
+        model.merge(&overrides).unwrap();
+
+        let flat: Vec<String> = model
+            .as_flat_map()
+            .into_iter()
+            .map(|(path, value)| format!("{} = {}", path, value))
+            .collect();
+        assert_eq!(
+            flat,
+            [
+                "llm/anthropic.max_tokens = 8192",
+                "llm/local.max_tokens = 512",
+                "llm/openai.max_tokens = 4096",
+                "llm/openai.model = \"gpt-4o\"",
+                "logging.level = \"debug\"",
+                "server.host = \"localhost\"",
+                "server.port = 9090",
+                "server.tls = true",
+            ]
+        );
+
+        // Instances merge by name rather than being duplicated
+        let llm_id = model.find_entity_by_path("llm").unwrap();
+        let groups = model.group_by_field(llm_id, "max_tokens");
+        assert_eq!(groups.values().map(Vec::len).sum::<usize>(), 3);
+    }
+
+    #[test]
+    fn test_merge_rejects_type_conflicts() {
+        let mut model = merge_defaults();
+
+        let mut overrides = ConfigModel::new();
+        overrides.create_entity_at_path("", "llm", Some("llms"), None).unwrap();
+        let openai_id = overrides.create_entity_at_path("llm", "openai", None, None).unwrap();
+        overrides.add_field_to_entity(openai_id, "model", ConfigValue::Null).unwrap();
+        overrides
+            .add_field_to_entity(openai_id, "max_tokens", ConfigValue::String("many".to_string()))
+            .unwrap();
+
+        let err = model.merge(&overrides).unwrap_err();
+        assert_eq!(
+            err,
+            "Cannot merge field 'llm/openai.max_tokens': String value over i64 value"
+        );
+
+        // The check runs before anything is merged, so even the compatible null is not applied
+        let openai_id = model.find_entity_by_path("llm/openai").unwrap();
+        assert!(matches!(
+            model.get_field_value(openai_id, "model"),
+            Some(ConfigValue::String(s)) if s == "gpt-4o"
+        ));
+    }
+
+    #[test]
+    fn test_iter_entities_pre_order_and_fields() {
+        let model = merge_defaults();
+
+        let entities: Vec<(String, Option<String>)> = model
+            .iter_entities()
+            .map(|(_, name, plural)| (name, plural))
+            .collect();
+        assert_eq!(
+            entities,
+            [
+                ("server".to_string(), None),
+                ("llm".to_string(), Some("llms".to_string())),
+                ("openai".to_string(), None),
+                ("local".to_string(), None),
+            ]
+        );
+        assert_eq!(model.iter_entities().count(), model.entity_count());
+
+        let (openai_id, _, _) = model.iter_entities().find(|(_, name, _)| name == "openai").unwrap();
+        let fields: Vec<String> = model
+            .iter_fields(openai_id)
+            .map(|(name, value)| format!("{} = {}", name, value))
+            .collect();
+        assert_eq!(fields, ["max_tokens = 1000", "model = \"gpt-4o\""]);
+        assert_eq!(model.iter_fields(usize::MAX).count(), 0);
+    }
+
+    #[test]
+    fn test_find_entities_by_name_at_any_depth() {
+        let mut model = ConfigModel::new();
+        let model_id = model.create_entity_at_path("", "model", None, None).unwrap();
+        model.create_entity_at_path("", "llm", Some("llms"), None).unwrap();
+        model.create_entity_at_path("llm", "openai", None, None).unwrap();
+        let nested_id = model.create_entity_at_path("llm/openai", "model", None, None).unwrap();
+        let service_id = model.create_entity_at_path("", "my_service", None, None).unwrap();
+        let deep_id = model.create_entity_at_path("my_service", "my_service", None, None).unwrap();
+        model.set_original_entity_names(HashMap::from([(
+            "my_service".to_string(),
+            "my-service".to_string(),
+        )]));
+
+        assert_eq!(model.find_entities_by_name("model"), vec![model_id, nested_id]);
+        assert_eq!(model.find_entities_by_name("my_service"), vec![service_id, deep_id]);
+        assert!(model.find_entities_by_name("my-service").is_empty());
+        assert!(model.find_entities_by_name("root").is_empty());
+
+        assert_eq!(model.find_entities_by_original_name("my-service"), vec![service_id, deep_id]);
+        assert!(model.find_entities_by_original_name("my_service").is_empty());
+        assert_eq!(model.find_entities_by_original_name("model"), vec![model_id, nested_id]);
+    }
+
+    /// Count the nodes reachable from the root, which are all the nodes of a model that keeps
+    /// no detached ones
+    fn reachable_node_count(model: &ConfigModel) -> usize {
+        let mut count = 0;
+        let mut stack = vec![model.root_id()];
+        while let Some(id) = stack.pop() {
+            count += 1;
+            if let ConfigNode::Entity(entity) = &*model.get_node(id).unwrap().borrow() {
+                stack.extend(&entity.children);
+            }
+        }
+        count
+    }
+
+    #[test]
+    fn test_remove_field_drops_value_markers_and_field_node() {
+        let mut model = ConfigModel::new();
+        let server_id = model.create_entity_at_path("", "server", None, None).unwrap();
+        model
+            .add_field_with_location(server_id, "host", ConfigValue::String("localhost".to_string()), None)
+            .unwrap();
+        model.add_field_with_location(server_id, "port", ConfigValue::Integer(8080), None).unwrap();
+        model.set_field_type(server_id, "port", "u16").unwrap();
+        model.set_field_optional(server_id, "port").unwrap();
+
+        let value = model.remove_field(server_id, "port").unwrap();
+        assert!(matches!(value, ConfigValue::Integer(8080)));
+        assert!(model.get_field_value(server_id, "port").is_none());
+        assert_eq!(model.get_field_type(server_id, "port"), None);
+        assert!(!model.is_field_optional(server_id, "port"));
+        let field_names: Vec<String> = model.iter_fields(server_id).map(|(name, _)| name).collect();
+        assert_eq!(field_names, vec!["host".to_string()]);
+        let children = match &*model.get_node(server_id).unwrap().borrow() {
+            ConfigNode::Entity(entity) => entity.children.clone(),
+            ConfigNode::Field(_) => Vec::new(),
+        };
+        assert_eq!(children.len(), 1);
+        assert_eq!(model.node_count(), reachable_node_count(&model));
+
+        assert!(model.remove_field(server_id, "port").is_err());
+        assert!(model.remove_field(model.root_id(), "host").is_err());
+    }
+
+    #[test]
+    fn test_remove_entity_detaches_the_subtree() {
+        let mut model = merge_defaults();
+        let entities_before = model.entity_count();
+        let llm_id = model.find_entity_by_path("llm").unwrap();
+
+        let removed = model.remove_entity(llm_id).unwrap();
+        // The removed model is rooted at `llm`, with its instances as top-level entities
+        assert_eq!(removed.entity_count(), 2);
+        assert!(matches!(removed.get_field_value_by_path("openai/max_tokens"), Some(ConfigValue::Integer(1000))));
+        assert!(model.find_entity_by_path("llm").is_none());
+        assert!(model.find_entity_by_path("llm/openai").is_none());
+        assert_eq!(model.entity_count(), entities_before - 3);
+        assert_eq!(model.field_count(), 2);
+        assert!(model.get_entity_path(llm_id).is_none());
+        assert!(model.get_node(llm_id).is_none());
+        assert_eq!(model.node_count(), reachable_node_count(&model));
+        let server_id = model.find_entity_by_path("server").unwrap();
+        assert!(matches!(model.get_field_value(server_id, "port"), Some(ConfigValue::Integer(8080))));
+
+        assert!(model.remove_entity(model.root_id()).is_err());
+    }
+
+    #[test]
+    fn test_remove_entity_keeps_the_ids_of_other_entities() {
+        let mut model = merge_defaults();
+        let openai_id = model.find_entity_by_path("llm/openai").unwrap();
+        let local_id = model.find_entity_by_path("llm/local").unwrap();
+        assert!(local_id > openai_id);
+
+        model.remove_entity(openai_id).unwrap();
+        assert_eq!(model.get_entity_path(local_id).as_deref(), Some("llm/local"));
+        assert_eq!(model.find_entity_by_path("llm/local"), Some(local_id));
+        assert!(matches!(model.get_field_value(local_id, "max_tokens"), Some(ConfigValue::Integer(512))));
+        assert!(model.get_entity_path(openai_id).is_none());
+        assert_eq!(model.find_entities_by_name("openai"), Vec::<NodeId>::new());
+    }
+
+    #[test]
+    fn test_deduplicate_entities_merges_identical_siblings() {
+        let mut model = ConfigModel::new();
+        let root = model.root_id();
+        for port in [8080, 9090] {
+            let server_id = model.create_child_entity(root, "server", None, None).unwrap();
+            model.add_field_with_location(server_id, "port", ConfigValue::Integer(port), None).unwrap();
+            let tls_id = model.create_child_entity(server_id, "tls", None, None).unwrap();
+            model.add_field_to_entity(tls_id, "enabled", ConfigValue::Boolean(true)).unwrap();
+        }
+        // Same name, different structure
+        let other_id = model.create_child_entity(root, "server", None, None).unwrap();
+        model.add_field_to_entity(other_id, "host", ConfigValue::String("localhost".to_string())).unwrap();
+        assert_eq!(model.entity_count(), 5);
+        let servers_before = model.find_entities_by_name("server");
+
+        assert_eq!(model.deduplicate_entities(), 1);
+        assert_eq!(model.entity_count(), 3);
+        assert_eq!(model.node_count(), reachable_node_count(&model));
+        // The merged duplicate is removed and the other entities keep their ids
+        let servers = model.find_entities_by_name("server");
+        assert_eq!(servers, vec![servers_before[0], servers_before[2]]);
+        assert!(model.get_node(servers_before[1]).is_none());
+        // The later duplicate's value wins, and its nested entity merges into the first's
+        assert!(matches!(model.get_field_value(servers[0], "port"), Some(ConfigValue::Integer(9090))));
+        assert_eq!(model.find_entities_by_name("tls").len(), 1);
+        assert!(model.get_field_value(servers[1], "host").is_some());
+
+        assert_eq!(model.deduplicate_entities(), 0);
+    }
+
+    #[test]
+    fn test_subtree_to_model_roots_the_entity() {
+        let model = merge_defaults();
+        let openai_id = model.find_entity_by_path("llm/openai").unwrap();
+
+        let subtree = model.subtree_to_model(openai_id).unwrap();
+        let flat: Vec<String> = subtree
+            .as_flat_map()
+            .into_iter()
+            .map(|(path, value)| format!("{} = {}", path, value))
+            .collect();
+        assert_eq!(flat, ["max_tokens = 1000", "model = \"gpt-4o\""]);
+        assert_eq!(subtree.entity_count(), 0);
+
+        let llm_id = model.find_entity_by_path("llm").unwrap();
+        let subtree = model.subtree_to_model(llm_id).unwrap();
+        let local_id = subtree.find_entity_by_path("local").unwrap();
+        assert!(matches!(
+            subtree.get_field_value(local_id, "max_tokens"),
+            Some(ConfigValue::Integer(512))
+        ));
+        assert!(model.subtree_to_model(usize::MAX).is_none());
+    }
+
+    #[test]
+    fn test_resolve_relative_walks_parents_and_children() {
+        let mut model = merge_defaults();
+        let openai_id = model.find_entity_by_path("llm/openai").unwrap();
+        let limits_id = model.create_entity_at_path("llm/openai", "limits", None, None).unwrap();
+        let local_id = model.find_entity_by_path("llm/local").unwrap();
+        let server_id = model.find_entity_by_path("server").unwrap();
+
+        // Descent and the current entity
+        assert_eq!(model.resolve_relative(openai_id, "limits"), Some(limits_id));
+        assert_eq!(model.resolve_relative(openai_id, "./limits/."), Some(limits_id));
+        assert_eq!(model.resolve_relative(model.root_id(), "llm/openai/limits"), Some(limits_id));
+
+        // Up from a deep entity to a sibling, a cousin and the root
+        assert_eq!(model.resolve_relative(limits_id, ".."), Some(openai_id));
+        assert_eq!(model.resolve_relative(limits_id, "../../local"), Some(local_id));
+        assert_eq!(model.resolve_relative(limits_id, "../../../server"), Some(server_id));
+        assert_eq!(model.resolve_relative(limits_id, "/server"), Some(server_id));
+        assert_eq!(model.resolve_relative(limits_id, "../../.."), Some(model.root_id()));
+
+        // Above the root, missing names and unknown ids
+        assert_eq!(model.resolve_relative(limits_id, "../../../.."), None);
+        assert_eq!(model.resolve_relative(limits_id, "../missing"), None);
+        assert_eq!(model.resolve_relative(usize::MAX, "."), None);
     }
 }
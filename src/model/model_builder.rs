@@ -1,44 +1,373 @@
 // SPDX-License-Identifier: Apache-2.0
 use crate::parser::cola_actions::{
-    CodeBlock, Cola, Entity, FieldList, FieldValue, MarkdownItem, NestedBlock,
+    AnchorDefinition, AnchorReference, ArrayItems, CodeBlock, Cola, ColaItem, ComputedField,
+    Entity, EntityDefinition, Expression, Field, FieldBase, FieldDeclaration, FieldDeclarationBase,
+    FieldEntry, FieldList, FieldValue, Identifier, Include, InlineInstances, InlinePluralEntity,
+    MarkdownItem, NestedBlock, Number, RegularCodeStart,
 };
-use crate::model::config_model::{ConfigModel, ConfigValue};
+use crate::parser::blocks::describe_parse_error;
+use crate::parser::cola::ColaParser;
+use crate::parser::repeat::expand_repeats;
+use crate::model::config_model::{ConfigModel, ConfigNode, ConfigValue, ENUM_DISCRIMINATOR};
 use crate::model::source_location::SourceLocation;
-use std::path::PathBuf;
+use rustemo::Parser;
+use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Default soft limit on the number of fields of a single entity
+pub const DEFAULT_MAX_FIELDS_PER_ENTITY: usize = 256;
+
+/// Marker written after a field name
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum FieldMarker {
+    None,
+    /// `?`: the field may be absent
+    Optional,
+    /// `!`: the field must not be empty
+    Required,
+}
+
+/// Values named by `&name value`, in scope for the rest of the document
+type Anchors = HashMap<String, ConfigValue>;
+
+/// Duration suffixes of number literals, in milliseconds per unit. `ms` comes before `s`, which
+/// it ends with.
+const DURATION_UNITS: [(&str, i64); 4] = [("ms", 1), ("s", 1_000), ("m", 60_000), ("h", 3_600_000)];
+
+/// Byte size suffixes of number literals, in bytes per unit (multiples of 1024)
+const BYTE_UNITS: [(&str, i64); 3] = [("KB", 1 << 10), ("MB", 1 << 20), ("GB", 1 << 30)];
+
+/// An error found while building a model, with the location of the field or entity it concerns
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuildError {
+    pub message: String,
+    pub location: Option<SourceLocation>,
+    /// Whether `message` already names where the error is, often more precisely than
+    /// `location`, so displaying the error does not prefix `location` again
+    pub located: bool,
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.location {
+            Some(location) if !self.located => write!(f, "{}: {}", location, self.message),
+            _ => write!(f, "{}", self.message),
+        }
+    }
+}
+
+/// Where the recoverable errors of a build go: returned at once, or collected to be reported
+/// together while the build goes on without the offending field or entity
+#[derive(Default)]
+struct ErrorSink {
+    collect: bool,
+    errors: Vec<BuildError>,
+}
+
+impl ErrorSink {
+    /// Pass `result` through, or record its error and return `Ok(None)` when collecting. The
+    /// recoverable errors name their location themselves, so they are recorded as located.
+    fn recover<T>(&mut self, result: Result<T, String>, location: Option<&SourceLocation>) -> Result<Option<T>, String> {
+        match result {
+            Ok(value) => Ok(Some(value)),
+            Err(message) if self.collect => {
+                self.errors.push(BuildError { message, location: location.cloned(), located: true });
+                Ok(None)
+            }
+            Err(message) => Err(message),
+        }
+    }
+}
 
 /// Builds a ConfigModel from a parsed Cola AST
-pub struct ModelBuilder;
+#[derive(Debug, Clone)]
+pub struct ModelBuilder {
+    /// Accumulate repeated occurrences of a field into a `ConfigValue::Array`
+    /// instead of keeping only the last value
+    pub repeatable_fields: bool,
+    /// Soft limit on the number of fields of an entity; exceeding it logs a warning
+    pub max_fields_per_entity: usize,
+    /// Turn diagnostics that are otherwise only warnings into build errors, and reject fields
+    /// and sibling entities defined twice in one entity
+    pub strict: bool,
+    /// Input file recorded in the source location of every entity and field, so diagnostics
+    /// point at it; empty when the input has no file. `include` paths are relative to its
+    /// directory.
+    pub file_path: PathBuf,
+    /// Files that include the one being built, outermost first, to detect include cycles;
+    /// empty for the top-level file
+    pub include_chain: Vec<PathBuf>,
+    /// Read numbers with a unit suffix: `30s` or `250ms` become an Integer of milliseconds
+    /// and type their field as a `Duration`, `256MB` becomes an Integer of bytes. Off, such a
+    /// number is an error.
+    pub unit_suffixes: bool,
+}
+
+impl Default for ModelBuilder {
+    fn default() -> Self {
+        Self {
+            repeatable_fields: false,
+            max_fields_per_entity: DEFAULT_MAX_FIELDS_PER_ENTITY,
+            strict: false,
+            file_path: PathBuf::new(),
+            include_chain: Vec::new(),
+            unit_suffixes: false,
+        }
+    }
+}
 
 impl ModelBuilder {
-    /// Convert a Cola AST to a ConfigModel
+    /// Create a builder with the default (lenient) options
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Convert a Cola AST to a ConfigModel using the default options
     pub fn build_config_model(cola: &Cola) -> Result<ConfigModel, String> {
-        let mut model = ConfigModel::new();
+        Self::default().build(cola)
+    }
+
+    /// Convert a Cola AST parsed from `file_path` to a ConfigModel using the default options,
+    /// recording the file in every source location
+    pub fn build_config_model_with_path(cola: &Cola, file_path: &Path) -> Result<ConfigModel, String> {
+        Self {
+            file_path: file_path.to_path_buf(),
+            ..Self::default()
+        }
+        .build(cola)
+    }
+
+    /// Convert a Cola AST to a ConfigModel using the default options, reporting every
+    /// recoverable error instead of the first (see `build_collecting`)
+    pub fn build_config_model_collecting(cola: &Cola) -> Result<ConfigModel, Vec<BuildError>> {
+        Self::default().build_collecting(cola)
+    }
+
+    /// Convert a Cola AST to a ConfigModel using this builder's options
+    pub fn build(&self, cola: &Cola) -> Result<ConfigModel, String> {
+        self.build_into(cola, &mut ErrorSink::default())
+    }
+
+    /// Parse Cola text (or Markdown with cola blocks) and build it using this builder's options.
+    /// `repeat` directives are expanded before parsing and the doc comments of the source are
+    /// attached, as the CLI does.
+    pub fn build_from_str(&self, source: &str) -> Result<ConfigModel, String> {
+        let source = expand_repeats(source)?;
+        let cola = ColaParser::new()
+            .parse(&source)
+            .map_err(|e| describe_parse_error(&source, e))?;
+        let mut model = self.build(&cola)?;
+        self.attach_doc_comments(&mut model, &source);
+        Ok(model)
+    }
+
+    /// Convert a Cola AST to a ConfigModel using this builder's options, going on past the
+    /// errors confined to a field, an anchor or an entity's key, enum or duplicate checks.
+    /// Each of those is skipped and reported; an include that fails, or an error in the model
+    /// itself, still ends the build and is reported last.
+    pub fn build_collecting(&self, cola: &Cola) -> Result<ConfigModel, Vec<BuildError>> {
+        let mut sink = ErrorSink { collect: true, errors: Vec::new() };
+        let result = self.build_into(cola, &mut sink);
+        let mut errors = sink.errors;
+        match result {
+            Ok(model) if errors.is_empty() => Ok(model),
+            Ok(_) => Err(errors),
+            Err(message) => {
+                errors.push(BuildError { message, location: None, located: false });
+                Err(errors)
+            }
+        }
+    }
+
+    /// Build a model, sending recoverable errors to `errors`
+    fn build_into(&self, cola: &Cola, errors: &mut ErrorSink) -> Result<ConfigModel, String> {
+        let mut model = ConfigModel::with_capacity(Self::estimate_node_count(cola));
         let root_id = model.root_id();
+        let mut anchors = Anchors::new();
 
         if let Some(markdown_items) = cola {
             for markdown_item in markdown_items {
-                match markdown_item {
-                    MarkdownItem::CodeBlock(CodeBlock::ColaCodeBlock(cola_block)) => {
-                        if let Some(entities) = &cola_block.cola_syntax {
-                            for entity in entities {
-                                Self::process_entity(&mut model, root_id, "", entity)?;
+                // Ignore non-cola code blocks, headings, paragraphs
+                if let MarkdownItem::CodeBlock(CodeBlock::ColaCodeBlock(cola_block)) = markdown_item
+                    && let Some(items) = &cola_block.cola_syntax
+                {
+                    for item in items {
+                        match item {
+                            ColaItem::Entity(entity) => {
+                                self.process_entity(&mut model, root_id, "", entity, &mut anchors, errors)?
+                            }
+                            ColaItem::FieldList(field_list) => {
+                                self.process_field_list(&mut model, root_id, field_list, &anchors, errors)?
                             }
+                            ColaItem::AnchorDefinition(anchor) => {
+                                self.define_anchor_recovering(anchor, &mut anchors, errors)?
+                            }
+                            ColaItem::Include(include) => self.process_include(&mut model, root_id, include)?,
                         }
                     }
-                    _ => {} // Ignore non-cola code blocks, headings, paragraphs
                 }
             }
         }
 
+        let warnings = self.field_count_warnings(&model);
+        if self.strict && !warnings.is_empty() {
+            if !errors.collect {
+                return Err(warnings.join("\n"));
+            }
+            errors
+                .errors
+                .extend(warnings.into_iter().map(|message| BuildError { message, location: None, located: false }));
+            return Ok(model);
+        }
+        for warning in &warnings {
+            log::warn!("{}", warning);
+        }
+
         Ok(model)
     }
 
+    /// Give every entity and field of a model built from `source` the text of the `#` comment
+    /// lines right above it as its doc comment. Comments are layout to the parser, so they are
+    /// recovered from the source lines the nodes' locations point at; a blank line or any other
+    /// line ends the comment. Nodes from included files got theirs when they were included.
+    pub fn attach_doc_comments(&self, model: &mut ConfigModel, source: &str) {
+        let lines: Vec<&str> = source.lines().collect();
+        for id in model.node_ids() {
+            let Some(line) = model.get_node(id).and_then(|node| {
+                let node = node.borrow();
+                let location = match &*node {
+                    ConfigNode::Entity(entity) => entity.location.as_ref(),
+                    ConfigNode::Field(field) => field.location.as_ref(),
+                }?;
+                (location.file_path == self.file_path).then_some(location.start_line)
+            }) else {
+                continue;
+            };
+            // Locations count lines from 1
+            let mut start = (line as usize).saturating_sub(1).min(lines.len());
+            while start > 0 && lines[start - 1].trim_start().starts_with('#') {
+                start -= 1;
+            }
+            let end = (line as usize).saturating_sub(1).min(lines.len());
+            if start == end {
+                continue;
+            }
+            let doc: Vec<&str> = lines[start..end]
+                .iter()
+                .map(|line| {
+                    let text = line.trim_start().trim_start_matches('#');
+                    text.strip_prefix(' ').unwrap_or(text).trim_end()
+                })
+                .collect();
+            let _ = model.set_doc(id, &doc.join("\n"));
+        }
+    }
+
+    /// Describe every entity of a model with more fields than `max_fields_per_entity`
+    pub fn field_count_warnings(&self, model: &ConfigModel) -> Vec<String> {
+        let mut warnings = Vec::new();
+        for id in model.node_ids() {
+            let Some(node) = model.get_node(id) else {
+                continue;
+            };
+            let node = node.borrow();
+            let ConfigNode::Entity(entity) = &*node else {
+                continue;
+            };
+            if entity.fields.len() <= self.max_fields_per_entity {
+                continue;
+            }
+            let path = model.get_entity_path(id).unwrap_or_default();
+            let location = entity
+                .location
+                .as_ref()
+                .map(|loc| format!(" at line {}, column {}", loc.start_line, loc.start_column))
+                .unwrap_or_default();
+            warnings.push(format!(
+                "Entity '{}'{} has {} fields, more than the limit of {}",
+                if path.is_empty() { "root" } else { &path },
+                location,
+                entity.fields.len(),
+                self.max_fields_per_entity
+            ));
+        }
+        warnings
+    }
+
+    /// Count the nodes the model of a Cola AST will hold: the root, one per entity and one
+    /// per field occurrence
+    pub fn estimate_node_count(cola: &Cola) -> usize {
+        let mut count = 1;
+        if let Some(markdown_items) = cola {
+            for markdown_item in markdown_items {
+                if let MarkdownItem::CodeBlock(CodeBlock::ColaCodeBlock(cola_block)) = markdown_item
+                    && let Some(items) = &cola_block.cola_syntax
+                {
+                    count += items
+                        .iter()
+                        .map(|item| match item {
+                            ColaItem::Entity(entity) => Self::count_entity_nodes(entity),
+                            ColaItem::FieldList(field_list) => Self::count_field_list_nodes(field_list),
+                            ColaItem::AnchorDefinition(_) | ColaItem::Include(_) => 0,
+                        })
+                        .sum::<usize>();
+                }
+            }
+        }
+        count
+    }
+
+    /// The language tags of the non-cola code blocks of a Cola AST, which the build ignores, in
+    /// document order; `None` for a block without a tag
+    pub fn skipped_code_blocks(cola: &Cola) -> Vec<Option<String>> {
+        cola.iter()
+            .flatten()
+            .filter_map(|markdown_item| match markdown_item {
+                MarkdownItem::CodeBlock(CodeBlock::RegularCodeBlock(block)) => Some(match &block.regular_code_start {
+                    RegularCodeStart::RegularCodeStartNamed(start) => {
+                        Some(start.as_ref().trim_start_matches('`').trim().to_string())
+                    }
+                    RegularCodeStart::RegularCodeStartUnnamed(_) => None,
+                }),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Count an entity and everything nested in it
+    fn count_entity_nodes(entity: &Entity) -> usize {
+        let entity_def = match entity {
+            Entity::SingularEntity(singular) => Self::nested_blocks(&singular.entity_definition),
+            Entity::PluralEntity(plural) => Self::nested_blocks(&plural.entity_definition),
+            Entity::InlinePluralEntity(inline) => return 1 + Self::inline_instance_names(inline).len(),
+        };
+        let nested = entity_def.iter().flatten().map(|nested_block| match nested_block {
+            NestedBlock::FieldList(field_list) => Self::count_field_list_nodes(field_list),
+            NestedBlock::Entity(entity) => Self::count_entity_nodes(entity),
+            NestedBlock::AnchorDefinition(_) | NestedBlock::Include(_) => 0,
+        });
+        1 + nested.sum::<usize>()
+    }
+
+    /// Count the fields of a field list
+    fn count_field_list_nodes(field_list: &FieldList) -> usize {
+        match field_list {
+            FieldList::FieldEntry(_) => 1,
+            FieldList::C2(field_list_c2) => 1 + Self::count_field_list_nodes(&field_list_c2.field_list),
+        }
+    }
+
     /// Process an entity and add it to the ConfigModel
     fn process_entity(
+        &self,
         model: &mut ConfigModel,
-        _parent_id: usize,
+        parent_id: usize,
         parent_path: &str,
         entity: &Entity,
+        anchors: &mut Anchors,
+        errors: &mut ErrorSink,
     ) -> Result<(), String> {
         match entity {
             Entity::SingularEntity(singular) => {
@@ -46,53 +375,29 @@ impl ModelBuilder {
                 // We need to handle this differently since we can't directly access ValLoc fields
                 let identifier = &singular.identifier;
                 // Extract the string value
-                let entity_name = match identifier.as_ref() {
-                    s => s.trim(), // Trim the string
-                };
+                let entity_name = identifier.as_ref().trim();
                 let path = if parent_path.is_empty() {
                     entity_name.to_string()
                 } else {
                     format!("{}/{}", parent_path, entity_name)
                 };
 
-                // Extract source location from the rustemo ValLoc object
-                let location = singular.location.as_ref().map(|loc| {
-                    // Convert rustemo Location to our SourceLocation
-                    // Extract start position (line, column)
-                    let (start_line, start_column) = match &loc.start {
-                        rustemo::Position::LineBased(lc) => (lc.line, lc.column),
-                        rustemo::Position::Position(_) => (1, 0), // Fallback for byte offset position
-                    };
-                    
-                    // Extract end position (line, column) if available
-                    let (end_line, end_column) = if let Some(end) = &loc.end {
-                        match end {
-                            rustemo::Position::LineBased(lc) => (lc.line, lc.column),
-                            rustemo::Position::Position(_) => (start_line, start_column), // Fallback
-                        }
-                    } else {
-                        (start_line, start_column) // Default to start position if end is not available
-                    };
-                    
-                    SourceLocation {
-                        file_path: PathBuf::new(), // We may not have a file path in the Location
-                        start_line: start_line as u32,
-                        start_column: start_column as u32,
-                        end_line: end_line as u32,
-                        end_column: end_column as u32,
-                    }
-                });
+                let location = singular.location.as_ref().map(|loc| self.source_location(loc));
+                if self.strict && !self.check_duplicate_entity_recovering(model, parent_id, entity_name, location.as_ref(), errors)? {
+                    return Ok(());
+                }
 
                 // Create the entity at this path
-                let entity_id =
-                    model.create_entity_at_path(parent_path, entity_name, None, location)?;
+                let entity_id = model.create_child_entity(parent_id, entity_name, None, location)?;
 
                 // Process entity contents
-                Self::process_entity_definition(
+                self.process_entity_definition(
                     model,
                     entity_id,
                     &path,
-                    &singular.entity_definition,
+                    Self::nested_blocks(&singular.entity_definition),
+                    anchors,
+                    errors,
                 )?;
 
                 Ok(())
@@ -102,82 +407,198 @@ impl ModelBuilder {
                 let id1 = &plural.identifier_1;
                 let id3 = &plural.identifier_3;
                 // Extract string values
-                let entity_name = match id1.as_ref() {
-                    s => s.trim(),
-                };
-                let plural_name = match id3.as_ref() {
-                    s => s.trim(),
-                };
+                let entity_name = id1.as_ref().trim();
+                let plural_name = id3.as_ref().trim();
                 let path = if parent_path.is_empty() {
                     entity_name.to_string()
                 } else {
                     format!("{}/{}", parent_path, entity_name)
                 };
 
-                // Extract source location from the rustemo ValLoc object
-                let location = plural.location.as_ref().map(|loc| {
-                    // Convert rustemo Location to our SourceLocation
-                    // Extract start position (line, column)
-                    let (start_line, start_column) = match &loc.start {
-                        rustemo::Position::LineBased(lc) => (lc.line, lc.column),
-                        rustemo::Position::Position(_) => (1, 0), // Fallback for byte offset position
-                    };
-                    
-                    // Extract end position (line, column) if available
-                    let (end_line, end_column) = if let Some(end) = &loc.end {
-                        match end {
-                            rustemo::Position::LineBased(lc) => (lc.line, lc.column),
-                            rustemo::Position::Position(_) => (start_line, start_column), // Fallback
-                        }
-                    } else {
-                        (start_line, start_column) // Default to start position if end is not available
-                    };
-                    
-                    SourceLocation {
-                        file_path: PathBuf::new(), // We may not have a file path in the Location
-                        start_line: start_line as u32,
-                        start_column: start_column as u32,
-                        end_line: end_line as u32,
-                        end_column: end_column as u32,
-                    }
-                });
+                let location = plural.location.as_ref().map(|loc| self.source_location(loc));
+                if self.strict && !self.check_duplicate_entity_recovering(model, parent_id, entity_name, location.as_ref(), errors)? {
+                    return Ok(());
+                }
 
                 // Create the entity at this path with plural name
-                let entity_id = model.create_entity_at_path(
-                    parent_path,
+                let entity_id = model.create_child_entity(
+                    parent_id,
                     entity_name,
                     Some(plural_name),
-                    location,
+                    location.clone(),
                 )?;
 
                 // Process entity contents
-                Self::process_entity_definition(
+                self.process_entity_definition(
                     model,
                     entity_id,
                     &path,
-                    &plural.entity_definition,
+                    Self::nested_blocks(&plural.entity_definition),
+                    anchors,
+                    errors,
                 )?;
 
+                // Without its key or enum, the entity is still a valid plain plural entity
+                if let Some(key) = &plural.plural_key_opt {
+                    errors.recover(self.set_key_field(model, entity_id, key.as_ref().trim()), location.as_ref())?;
+                }
+                if plural.plural_enum_opt.is_some() {
+                    errors.recover(self.set_enum_plural(model, entity_id), location.as_ref())?;
+                }
+
+                Ok(())
+            }
+            Entity::InlinePluralEntity(inline) => {
+                // The one name serves as both the entity and its plural name
+                let entity_name = inline.identifier.as_ref().trim();
+                let location = inline.location.as_ref().map(|loc| self.source_location(loc));
+                if self.strict && !self.check_duplicate_entity_recovering(model, parent_id, entity_name, location.as_ref(), errors)? {
+                    return Ok(());
+                }
+                let entity_id = model.create_child_entity(parent_id, entity_name, Some(entity_name), location)?;
+
+                for instance in Self::inline_instance_names(inline) {
+                    let instance_name = instance.as_ref().trim();
+                    let location = instance.location.as_ref().map(|loc| self.source_location(loc));
+                    if self.strict
+                        && !self.check_duplicate_entity_recovering(model, entity_id, instance_name, location.as_ref(), errors)?
+                    {
+                        continue;
+                    }
+                    model.create_child_entity(entity_id, instance_name, None, location)?;
+                }
+
                 Ok(())
             }
         }
     }
 
+    /// The instance names listed by an inline plural entity, in order
+    fn inline_instance_names(inline: &InlinePluralEntity) -> Vec<&Identifier> {
+        let mut names = Vec::new();
+        let mut next = inline.inline_instances_opt.as_ref();
+        while let Some(instances) = next {
+            next = match instances {
+                InlineInstances::Identifier1(name) | InlineInstances::Identifier2(name) => {
+                    names.push(name);
+                    None
+                }
+                InlineInstances::C3(instances_c3) => {
+                    names.push(&instances_c3.identifier);
+                    Some(instances_c3.inline_instances.as_ref())
+                }
+            };
+        }
+        names
+    }
+
+    /// The blocks of an entity body, with or without braces around it
+    fn nested_blocks(entity_def: &EntityDefinition) -> &Option<Vec<NestedBlock>> {
+        match entity_def {
+            EntityDefinition::Plain(nested_blocks) | EntityDefinition::Braced(nested_blocks) => nested_blocks,
+        }
+    }
+
+    /// Key a plural entity by `key_field`, which every instance must set to a non-null value
+    /// that no other instance has, as the value keys the generated collection
+    fn set_key_field(&self, model: &mut ConfigModel, entity_id: usize, key_field: &str) -> Result<(), String> {
+        let node = model
+            .get_node(entity_id)
+            .ok_or_else(|| format!("Entity node with ID {} not found", entity_id))?;
+        if let ConfigNode::Entity(entity) = &*node.borrow() {
+            // Key values seen so far, with the instance and location they were first seen at
+            let mut keys: HashMap<String, (String, Option<SourceLocation>)> = HashMap::new();
+            for &child_id in &entity.children {
+                let Some(child) = model.get_node(child_id) else {
+                    continue;
+                };
+                let ConfigNode::Entity(instance) = &*child.borrow() else {
+                    continue;
+                };
+                let key = match instance.fields.get(key_field) {
+                    None | Some(ConfigValue::Null) => {
+                        return Err(format!(
+                            "Instance '{}' of plural entity '{}' has no key field '{}'{}",
+                            instance.name,
+                            entity.name,
+                            key_field,
+                            Self::at(instance.location.as_ref())
+                        ));
+                    }
+                    Some(key) => key.to_string(),
+                };
+                let location = model.get_field_location(child_id, key_field).or_else(|| instance.location.clone());
+                if let Some((first, first_location)) = keys.get(&key) {
+                    return Err(format!(
+                        "Instances '{}'{} and '{}'{} of plural entity '{}' have the same key {} = {}",
+                        first,
+                        Self::at(first_location.as_ref()),
+                        instance.name,
+                        Self::at(location.as_ref()),
+                        entity.name,
+                        key_field,
+                        key
+                    ));
+                }
+                keys.insert(key, (instance.name.clone(), location));
+            }
+        }
+        model.set_key_field(entity_id, key_field)
+    }
+
+    /// Generate a plural entity as an enum; every instance must name its variant with a string
+    /// `type` field
+    fn set_enum_plural(&self, model: &mut ConfigModel, entity_id: usize) -> Result<(), String> {
+        let node = model
+            .get_node(entity_id)
+            .ok_or_else(|| format!("Entity node with ID {} not found", entity_id))?;
+        if let ConfigNode::Entity(entity) = &*node.borrow() {
+            for &child_id in &entity.children {
+                let Some(child) = model.get_node(child_id) else {
+                    continue;
+                };
+                if let ConfigNode::Entity(instance) = &*child.borrow()
+                    && !matches!(instance.fields.get(ENUM_DISCRIMINATOR), Some(ConfigValue::String(_)))
+                {
+                    let location = instance
+                        .location
+                        .as_ref()
+                        .map(|location| format!(" at {}", location))
+                        .unwrap_or_default();
+                    return Err(format!(
+                        "Instance '{}' of enum entity '{}' has no string '{}' field{}",
+                        instance.name, entity.name, ENUM_DISCRIMINATOR, location
+                    ));
+                }
+            }
+        }
+        model.set_enum_plural(entity_id)
+    }
+
     /// Process the contents of an entity definition
     fn process_entity_definition(
+        &self,
         model: &mut ConfigModel,
         entity_id: usize,
         entity_path: &str,
         entity_def: &Option<Vec<NestedBlock>>,
+        anchors: &mut Anchors,
+        errors: &mut ErrorSink,
     ) -> Result<(), String> {
         if let Some(nested_blocks) = entity_def {
             for nested_block in nested_blocks {
                 match nested_block {
                     NestedBlock::FieldList(field_list) => {
-                        Self::process_field_list(model, entity_id, field_list)?;
+                        self.process_field_list(model, entity_id, field_list, anchors, errors)?;
                     }
                     NestedBlock::Entity(entity) => {
-                        Self::process_entity(model, entity_id, entity_path, entity)?;
+                        self.process_entity(model, entity_id, entity_path, entity, anchors, errors)?;
+                    }
+                    NestedBlock::AnchorDefinition(anchor) => {
+                        self.define_anchor_recovering(anchor, anchors, errors)?;
+                    }
+                    NestedBlock::Include(include) => {
+                        self.process_include(model, entity_id, include)?;
                     }
                 }
             }
@@ -188,111 +609,662 @@ impl ModelBuilder {
 
     /// Process a field list and add fields to the entity
     fn process_field_list(
+        &self,
         model: &mut ConfigModel,
         entity_id: usize,
         field_list: &FieldList,
+        anchors: &Anchors,
+        errors: &mut ErrorSink,
     ) -> Result<(), String> {
-        match field_list {
-            FieldList::Field(field) => {
-                Self::add_field_to_entity(model, entity_id, field)?;
-            }
+        let field_entry = match field_list {
+            FieldList::FieldEntry(field_entry) => field_entry,
             FieldList::C2(field_list_c2) => {
-                Self::process_field_list(model, entity_id, &field_list_c2.field_list)?;
-                Self::add_field_to_entity(model, entity_id, &field_list_c2.field)?;
+                self.process_field_list(model, entity_id, &field_list_c2.field_list, anchors, errors)?;
+                &field_list_c2.field_entry
             }
+        };
+        // A field that fails is left out of the entity
+        let location = Self::field_entry_parts(field_entry).1.as_ref().map(|loc| self.source_location(loc));
+        errors.recover(self.process_field_entry(model, entity_id, field_entry, anchors), location.as_ref())?;
+
+        Ok(())
+    }
+
+    /// The identifier and location of a field entry of any kind
+    fn field_entry_parts(field_entry: &FieldEntry) -> (&Identifier, &Option<rustemo::Location>) {
+        match field_entry {
+            FieldEntry::Field(field) => (&field.identifier, &field.location),
+            FieldEntry::OptionalField(optional) => (&optional.identifier, &optional.location),
+            FieldEntry::RequiredField(required) => (&required.identifier, &required.location),
+            FieldEntry::ComputedField(computed) => (&computed.identifier, &computed.location),
+            FieldEntry::FieldDeclaration(declaration) => (&declaration.identifier, &declaration.location),
+            FieldEntry::OptionalDeclaration(optional) => (&optional.identifier, &optional.location),
+            FieldEntry::RequiredDeclaration(required) => (&required.identifier, &required.location),
         }
+    }
+
+    /// Add a literal or computed field to an entity
+    fn process_field_entry(
+        &self,
+        model: &mut ConfigModel,
+        entity_id: usize,
+        field_entry: &FieldEntry,
+        anchors: &Anchors,
+    ) -> Result<(), String> {
+        if self.strict && !self.repeatable_fields {
+            self.check_duplicate_field(model, entity_id, field_entry)?;
+        }
+        match field_entry {
+            FieldEntry::Field(field) => {
+                self.add_field_to_entity(model, entity_id, field, FieldMarker::None, anchors)
+            }
+            FieldEntry::OptionalField(optional) => {
+                // Same shape as a plain field, only the `?` marker differs
+                let field = Field::new(
+                    FieldBase {
+                        identifier: optional.identifier.clone(),
+                        type_annotation_opt: optional.type_annotation_opt.clone(),
+                        field_value: optional.field_value.clone(),
+                    },
+                    optional.location,
+                );
+                self.add_field_to_entity(model, entity_id, &field, FieldMarker::Optional, anchors)
+            }
+            FieldEntry::RequiredField(required) => {
+                let field = Field::new(
+                    FieldBase {
+                        identifier: required.identifier.clone(),
+                        type_annotation_opt: required.type_annotation_opt.clone(),
+                        field_value: required.field_value.clone(),
+                    },
+                    required.location,
+                );
+                self.add_field_to_entity(model, entity_id, &field, FieldMarker::Required, anchors)
+            }
+            FieldEntry::ComputedField(computed) => {
+                self.add_computed_field_to_entity(model, entity_id, computed, anchors)
+            }
+            FieldEntry::FieldDeclaration(declaration) => {
+                self.add_declared_field_to_entity(model, entity_id, declaration, FieldMarker::None)
+            }
+            FieldEntry::OptionalDeclaration(optional) => {
+                let declaration = FieldDeclaration::new(
+                    FieldDeclarationBase {
+                        identifier: optional.identifier.clone(),
+                        type_name: optional.type_name.clone(),
+                    },
+                    optional.location,
+                );
+                self.add_declared_field_to_entity(model, entity_id, &declaration, FieldMarker::Optional)
+            }
+            FieldEntry::RequiredDeclaration(required) => {
+                let declaration = FieldDeclaration::new(
+                    FieldDeclarationBase {
+                        identifier: required.identifier.clone(),
+                        type_name: required.type_name.clone(),
+                    },
+                    required.location,
+                );
+                self.add_declared_field_to_entity(model, entity_id, &declaration, FieldMarker::Required)
+            }
+        }
+    }
+
+    /// Fail when a field entry names a field the entity already has, reporting both locations
+    fn check_duplicate_field(
+        &self,
+        model: &ConfigModel,
+        entity_id: usize,
+        field_entry: &FieldEntry,
+    ) -> Result<(), String> {
+        let (identifier, location) = Self::field_entry_parts(field_entry);
+        let field_name = identifier.as_ref().trim();
+        if model.get_field_value(entity_id, field_name).is_none() {
+            return Ok(());
+        }
+        let original = model.get_node(entity_id).and_then(|node| match &*node.borrow() {
+            ConfigNode::Entity(entity) => entity.children.iter().find_map(|&child_id| {
+                match &*model.get_node(child_id)?.borrow() {
+                    ConfigNode::Field(field) if field.name == field_name => field.location.clone(),
+                    _ => None,
+                }
+            }),
+            ConfigNode::Field(_) => None,
+        });
+        let path = model.get_entity_path(entity_id).unwrap_or_default();
+        Err(format!(
+            "Duplicate field '{}' in entity '{}'{}, first defined{}",
+            field_name,
+            if path.is_empty() { "root" } else { &path },
+            Self::at(location.as_ref().map(|loc| self.source_location(loc)).as_ref()),
+            Self::at(original.as_ref())
+        ))
+    }
 
+    /// Fail when `parent_id` already has a child entity named `name`, reporting both locations
+    fn check_duplicate_entity(
+        &self,
+        model: &ConfigModel,
+        parent_id: usize,
+        name: &str,
+        location: Option<&SourceLocation>,
+    ) -> Result<(), String> {
+        let Some(existing_id) = model.find_child_entity_by_name(parent_id, name) else {
+            return Ok(());
+        };
+        let original = model.get_node(existing_id).and_then(|node| match &*node.borrow() {
+            ConfigNode::Entity(entity) => entity.location.clone(),
+            ConfigNode::Field(_) => None,
+        });
+        let path = model.get_entity_path(parent_id).unwrap_or_default();
+        Err(format!(
+            "Duplicate entity '{}' in entity '{}'{}, first defined{}",
+            name,
+            if path.is_empty() { "root" } else { &path },
+            Self::at(location),
+            Self::at(original.as_ref())
+        ))
+    }
+
+    /// Check for a duplicate entity, recording the error when errors are collected. Returns
+    /// whether the entity should be built; a duplicate is skipped.
+    fn check_duplicate_entity_recovering(
+        &self,
+        model: &ConfigModel,
+        parent_id: usize,
+        name: &str,
+        location: Option<&SourceLocation>,
+        errors: &mut ErrorSink,
+    ) -> Result<bool, String> {
+        Ok(errors.recover(self.check_duplicate_entity(model, parent_id, name, location), location)?.is_some())
+    }
+
+    /// Format an optional location as ` at <location>`, or nothing when it is unknown
+    fn at(location: Option<&SourceLocation>) -> String {
+        location.map(|location| format!(" at {}", location)).unwrap_or_default()
+    }
+
+    /// Evaluate a computed field against the fields already added to the entity and add
+    /// the result
+    fn add_computed_field_to_entity(
+        &self,
+        model: &mut ConfigModel,
+        entity_id: usize,
+        computed: &ComputedField,
+        anchors: &Anchors,
+    ) -> Result<(), String> {
+        let field_name = computed.identifier.as_ref().trim().to_string();
+        let location = computed.location.as_ref().map(|loc| self.source_location(loc));
+        let value = self.evaluate_expression(model, entity_id, &field_name, &computed.expression, anchors)?;
+        model.add_field_with_location(entity_id, &field_name, value, location)?;
         Ok(())
     }
 
+    /// Build the file named by an `include` directive, relative to the including file, and
+    /// merge its model into the entity holding the directive
+    fn process_include(&self, model: &mut ConfigModel, entity_id: usize, include: &Include) -> Result<(), String> {
+        let location = include.location.as_ref().map(|loc| self.source_location(loc));
+        let at = Self::at(location.as_ref());
+        let path = self.file_path.parent().unwrap_or(Path::new("")).join(Self::unquote(include.as_ref()));
+        let included = path
+            .canonicalize()
+            .map_err(|e| format!("Cannot include '{}'{}: {}", path.display(), at, e))?;
+
+        // Including a file that is already being built would never end
+        let mut chain = self.include_chain.clone();
+        if let Ok(current) = self.file_path.canonicalize() {
+            chain.push(current);
+        }
+        if let Some(start) = chain.iter().position(|file| *file == included) {
+            let cycle: Vec<String> = chain[start..]
+                .iter()
+                .chain(std::iter::once(&included))
+                .map(|file| file.display().to_string())
+                .collect();
+            return Err(format!("Include cycle{}: {}", at, cycle.join(" -> ")));
+        }
+
+        let source = fs::read_to_string(&path)
+            .map_err(|e| format!("Cannot include '{}'{}: {}", path.display(), at, e))?;
+        let cola = ColaParser::new()
+            .parse(&source)
+            .map_err(|e| format!("Failed to parse included file '{}': {}", path.display(), e))?;
+        let builder = ModelBuilder {
+            file_path: path,
+            include_chain: chain,
+            ..self.clone()
+        };
+        let mut included_model = builder.build(&cola)?;
+        builder.attach_doc_comments(&mut included_model, &source);
+        model.merge_into(entity_id, &included_model)
+    }
+
+    /// Add a field declared with a type only, holding the default value of that type, with the
+    /// `?` or `!` marker written after its name
+    fn add_declared_field_to_entity(
+        &self,
+        model: &mut ConfigModel,
+        entity_id: usize,
+        declaration: &FieldDeclaration,
+        marker: FieldMarker,
+    ) -> Result<(), String> {
+        let field_name = declaration.identifier.as_ref().trim().to_string();
+        let type_name = declaration.type_name.as_ref().trim().to_string();
+        let location = declaration.location.as_ref().map(|loc| self.source_location(loc));
+        let value = match type_name.as_str() {
+            "i64" | "i32" | "i16" | "i8" | "u32" | "u16" | "u8" | "Duration" => ConfigValue::Integer(0),
+            "f64" => ConfigValue::Float(0.0),
+            "bool" => ConfigValue::Boolean(false),
+            "String" => ConfigValue::String(String::new()),
+            _ => {
+                return Err(format!(
+                    "Field '{}' has unsupported type annotation '{}'{}",
+                    field_name,
+                    type_name,
+                    Self::at(location.as_ref())
+                ));
+            }
+        };
+        model.add_field_with_location(entity_id, &field_name, value, location)?;
+        model.set_field_type(entity_id, &field_name, &type_name)?;
+        match marker {
+            FieldMarker::Optional => model.set_field_optional(entity_id, &field_name)?,
+            FieldMarker::Required => model.set_field_required(entity_id, &field_name)?,
+            FieldMarker::None => {}
+        }
+        model.set_field_declared(entity_id, &field_name)
+    }
+
+    /// Evaluate an arithmetic expression over number literals and sibling numeric fields.
+    /// Integers stay integers until a float is involved; overflow and division by zero are
+    /// errors.
+    fn evaluate_expression(
+        &self,
+        model: &ConfigModel,
+        entity_id: usize,
+        field_name: &str,
+        expression: &Expression,
+        anchors: &Anchors,
+    ) -> Result<ConfigValue, String> {
+        let at = |location: &Option<rustemo::Location>| {
+            location
+                .as_ref()
+                .map(|l| format!(" at {}", self.source_location(l)))
+                .unwrap_or_default()
+        };
+        let (op, left, right, location) = match expression {
+            Expression::Number(n) => {
+                return Self::convert_number(n.as_ref().trim()).map_err(|e| format!("{}{}", e, at(&n.location)));
+            }
+            Expression::Identifier(reference) => {
+                let name = reference.as_ref().trim();
+                return match model.get_field_value(entity_id, name) {
+                    Some(value @ (ConfigValue::Integer(_) | ConfigValue::Float(_))) => Ok(value),
+                    Some(value) => Err(format!(
+                        "Computed field '{}' refers to '{}', which is not a number but {}{}",
+                        field_name,
+                        name,
+                        value,
+                        at(&reference.location)
+                    )),
+                    None => Err(format!(
+                        "Computed field '{}' refers to unknown field '{}'{}",
+                        field_name,
+                        name,
+                        at(&reference.location)
+                    )),
+                };
+            }
+            // A reference alone copies the anchored value, whatever its type
+            Expression::AnchorReference(reference) => return self.resolve_anchor(reference, anchors),
+            Expression::Group(inner) => {
+                return self.evaluate_expression(model, entity_id, field_name, inner, anchors);
+            }
+            Expression::Add(e) => ('+', &e.left, &e.right, &e.location),
+            Expression::Sub(e) => ('-', &e.left, &e.right, &e.location),
+            Expression::Mul(e) => ('*', &e.left, &e.right, &e.location),
+            Expression::Div(e) => ('/', &e.left, &e.right, &e.location),
+        };
+        let numeric = |value: ConfigValue| match value {
+            ConfigValue::Integer(_) | ConfigValue::Float(_) => Ok(value),
+            value => Err(format!(
+                "Computed field '{}' uses {}, which is not a number, in arithmetic{}",
+                field_name,
+                value,
+                at(location)
+            )),
+        };
+        let left = numeric(self.evaluate_expression(model, entity_id, field_name, left, anchors)?)?;
+        let right = numeric(self.evaluate_expression(model, entity_id, field_name, right, anchors)?)?;
+
+        if op == '/' && Self::as_f64(&right) == 0.0 {
+            return Err(format!(
+                "Computed field '{}' divides by zero{}",
+                field_name,
+                at(location)
+            ));
+        }
+        match (left, right) {
+            (ConfigValue::Integer(a), ConfigValue::Integer(b)) => match op {
+                '+' => a.checked_add(b),
+                '-' => a.checked_sub(b),
+                '*' => a.checked_mul(b),
+                _ => a.checked_div(b),
+            }
+            .map(ConfigValue::Integer)
+            .ok_or_else(|| format!("Computed field '{}' overflows{}", field_name, at(location))),
+            (a, b) => {
+                let (a, b) = (Self::as_f64(&a), Self::as_f64(&b));
+                Ok(ConfigValue::Float(match op {
+                    '+' => a + b,
+                    '-' => a - b,
+                    '*' => a * b,
+                    _ => a / b,
+                }))
+            }
+        }
+    }
+
+    /// Widen a numeric value for float arithmetic
+    fn as_f64(value: &ConfigValue) -> f64 {
+        match value {
+            ConfigValue::Integer(i) => *i as f64,
+            ConfigValue::Float(f) => *f,
+            _ => 0.0,
+        }
+    }
+
+    /// Convert a rustemo location to a SourceLocation in the input file
+    fn source_location(&self, loc: &rustemo::Location) -> SourceLocation {
+        let (start_line, start_column) = match &loc.start {
+            rustemo::Position::LineBased(lc) => (lc.line, lc.column),
+            rustemo::Position::Position(_) => (1, 0), // Fallback for byte offset position
+        };
+        let (end_line, end_column) = match &loc.end {
+            Some(rustemo::Position::LineBased(lc)) => (lc.line, lc.column),
+            _ => (start_line, start_column),
+        };
+        SourceLocation {
+            file_path: self.file_path.clone(),
+            start_line: start_line as u32,
+            start_column: start_column as u32,
+            end_line: end_line as u32,
+            end_column: end_column as u32,
+        }
+    }
+
     /// Add a field to an entity in the model
     fn add_field_to_entity(
+        &self,
         model: &mut ConfigModel,
         entity_id: usize,
-        field: &crate::cola_actions::Field,
+        field: &Field,
+        marker: FieldMarker,
+        anchors: &Anchors,
     ) -> Result<(), String> {
         // Extract field name from identifier
-        let id = &field.identifier;
-        let field_name = match id.as_ref() {
-            s => s.trim().to_string(),
+        let field_name = field.identifier.as_ref().trim().to_string();
+        let location = field.location.as_ref().map(|loc| self.source_location(loc));
+
+        // Pass field_value to be converted
+        let field_value = self.convert_field_value(&field.field_value, anchors)?;
+
+        // Enforce the declared type annotation, if any, instead of guessing from the literal. A
+        // duration literal declares its own type.
+        let type_name = field
+            .type_annotation_opt
+            .as_ref()
+            .map(|t| t.as_ref().trim().to_string())
+            .or_else(|| self.is_duration_literal(&field.field_value).then(|| "Duration".to_string()));
+        let field_value = match &type_name {
+            Some(type_name) => {
+                Self::apply_type_annotation(&field_name, type_name, field_value, location.as_ref())?
+            }
+            None => field_value,
         };
-        
-        // Extract source location from the field
-        let location = field.location.as_ref().map(|loc| {
-            // Convert rustemo Location to our SourceLocation
-            // Extract start position (line, column)
-            let (start_line, start_column) = match &loc.start {
-                rustemo::Position::LineBased(lc) => (lc.line, lc.column),
-                rustemo::Position::Position(_) => (1, 0), // Fallback for byte offset position
-            };
-            
-            // Extract end position (line, column) if available
-            let (end_line, end_column) = if let Some(end) = &loc.end {
-                match end {
-                    rustemo::Position::LineBased(lc) => (lc.line, lc.column),
-                    rustemo::Position::Position(_) => (start_line, start_column), // Fallback
+
+        // Fold a repeated field into an array of all its occurrences when enabled, on the field
+        // node of its first occurrence. The array could not hold the declared scalar type.
+        if self.repeatable_fields
+            && let Some(existing) = model.get_field_value(entity_id, &field_name)
+        {
+            if let Some(type_name) = type_name.or_else(|| model.get_field_type(entity_id, &field_name)) {
+                return Err(format!(
+                    "Field '{}' is declared as {} and cannot be repeated{}",
+                    field_name,
+                    type_name,
+                    Self::at(location.as_ref())
+                ));
+            }
+            let field_value = match existing {
+                ConfigValue::Array(mut items) => {
+                    items.push(field_value);
+                    ConfigValue::Array(items)
                 }
-            } else {
-                (start_line, start_column) // Default to start position if end is not available
+                single => ConfigValue::Array(vec![single, field_value]),
             };
-            
-            SourceLocation {
-                file_path: PathBuf::new(), // We may not have a file path in the Location
-                start_line: start_line as u32,
-                start_column: start_column as u32,
-                end_line: end_line as u32,
-                end_column: end_column as u32,
+            model.set_field_value(entity_id, &field_name, field_value)?;
+        } else {
+            // Add field with source location to the entity
+            model.add_field_with_location(entity_id, &field_name, field_value, location)?;
+            if let Some(type_name) = type_name {
+                model.set_field_type(entity_id, &field_name, &type_name)?;
             }
-        });
-        
-        // Pass field_value to be converted
-        let field_value = Self::convert_field_value(&field.field_value)?;
-        
-        // Add field with source location to the entity
-        model.add_field_with_location(entity_id, &field_name, field_value, location)?;
-        
+        }
+
+        match marker {
+            FieldMarker::Optional => model.set_field_optional(entity_id, &field_name)?,
+            FieldMarker::Required => model.set_field_required(entity_id, &field_name)?,
+            FieldMarker::None => {}
+        }
+
+        Ok(())
+    }
+
+    /// Check a converted value against its declared type, coercing where it is lossless.
+    /// An `i64` field rejects a float literal, while an `f64` field accepts an integer one.
+    fn apply_type_annotation(
+        field_name: &str,
+        type_name: &str,
+        value: ConfigValue,
+        location: Option<&SourceLocation>,
+    ) -> Result<ConfigValue, String> {
+        let at = location.map(|l| format!(" at {}", l)).unwrap_or_default();
+        match (type_name, value) {
+            // Narrower integers are range-checked by the generated code, which reports the path
+            ("i64" | "i32" | "i16" | "i8" | "u32" | "u16" | "u8", value @ ConfigValue::Integer(_)) => Ok(value),
+            // Durations are stored in milliseconds
+            ("Duration", value @ ConfigValue::Integer(_)) => Ok(value),
+            ("f64", value @ ConfigValue::Float(_)) => Ok(value),
+            ("f64", ConfigValue::Integer(i)) => Ok(ConfigValue::Float(i as f64)),
+            ("bool", value @ ConfigValue::Boolean(_)) => Ok(value),
+            (_, ConfigValue::Null) if Self::is_supported_type(type_name) => Ok(ConfigValue::Null),
+            ("String", value @ ConfigValue::String(_)) => Ok(value),
+            (_, value) if Self::is_supported_type(type_name) => Err(format!(
+                "Field '{}' is declared as {} but has value {}{}",
+                field_name, type_name, value, at
+            )),
+            (_, _) => Err(format!(
+                "Field '{}' has unsupported type annotation '{}'{}",
+                field_name, type_name, at
+            )),
+        }
+    }
+
+    /// Whether a type annotation names a type the generator can map a field to
+    fn is_supported_type(type_name: &str) -> bool {
+        matches!(
+            type_name,
+            "i64" | "i32" | "i16" | "i8" | "u32" | "u16" | "u8" | "f64" | "bool" | "String" | "Duration"
+        )
+    }
+
+    /// Define an anchor, recording the error when errors are collected; the anchor is then
+    /// left undefined
+    fn define_anchor_recovering(
+        &self,
+        anchor: &AnchorDefinition,
+        anchors: &mut Anchors,
+        errors: &mut ErrorSink,
+    ) -> Result<(), String> {
+        let location = anchor.location.as_ref().map(|loc| self.source_location(loc));
+        errors.recover(self.define_anchor(anchor, anchors), location.as_ref())?;
+        Ok(())
+    }
+
+    /// Name the value of an anchor definition, replacing an earlier anchor of the same name
+    fn define_anchor(&self, anchor: &AnchorDefinition, anchors: &mut Anchors) -> Result<(), String> {
+        let value = self.convert_field_value(&anchor.field_value, anchors)?;
+        anchors.insert(anchor.identifier.as_ref().trim().to_string(), value);
         Ok(())
     }
 
-    /// Convert a FieldValue from the AST to a ConfigValue for the model
-    fn convert_field_value(field_value: &FieldValue) -> Result<ConfigValue, String> {
+    /// Look up the value of an `*name` reference among the anchors defined before it
+    fn resolve_anchor(&self, reference: &AnchorReference, anchors: &Anchors) -> Result<ConfigValue, String> {
+        let name = reference.as_ref().trim();
+        anchors.get(name).cloned().ok_or_else(|| {
+            format!(
+                "Reference to undefined anchor '{}'{}",
+                name,
+                Self::at(reference.location.as_ref().map(|loc| self.source_location(loc)).as_ref())
+            )
+        })
+    }
+
+    /// Remove the surrounding quotes of a quoted string. Strings spanning lines of a file with
+    /// CRLF endings get plain `\n` line breaks, so values do not depend on the editor used.
+    fn unquote(quoted: &str) -> String {
+        let quoted = quoted.trim();
+        quoted[1..quoted.len() - 1].replace("\r\n", "\n")
+    }
+
+    /// Remove the `"""` delimiters of a triple-quoted string, keeping its line breaks. A line
+    /// break right after the opening delimiter is dropped, so the text can start on its own line.
+    fn unquote_triple(quoted: &str) -> String {
+        let quoted = quoted.trim();
+        let text = quoted[3..quoted.len() - 3].replace("\r\n", "\n");
+        match text.strip_prefix('\n') {
+            Some(rest) => rest.to_string(),
+            None => text,
+        }
+    }
+
+    /// Convert a FieldValue from the AST to a ConfigValue for the model, expanding anchor
+    /// references
+    fn convert_field_value(&self, field_value: &FieldValue, anchors: &Anchors) -> Result<ConfigValue, String> {
         match field_value {
-            FieldValue::QuotedStringDouble(s) => {
-                // Extract string and remove surrounding quotes
-                let s_val = match s.as_ref() {
-                    s => s.trim(),
-                };
-                let content = s_val[1..s_val.len() - 1].to_string();
-                Ok(ConfigValue::String(content))
+            FieldValue::QuotedStringTriple(s) => Ok(ConfigValue::String(Self::unquote_triple(s.as_ref()))),
+            FieldValue::QuotedStringDouble(s) => Ok(ConfigValue::String(Self::unquote(s.as_ref()))),
+            FieldValue::QuotedStringSingle(s) => Ok(ConfigValue::String(Self::unquote(s.as_ref()))),
+            FieldValue::Number(n) => self.convert_number_literal(n),
+            FieldValue::BooleanTrue(_) => Ok(ConfigValue::Boolean(true)),
+            FieldValue::BooleanFalse(_) => Ok(ConfigValue::Boolean(false)),
+            FieldValue::Null(_) => Ok(ConfigValue::Null),
+            FieldValue::AnchorReference(reference) => self.resolve_anchor(reference, anchors),
+            FieldValue::ArrayValue(array_items) => {
+                let mut items = Vec::new();
+                let mut next = array_items.as_ref();
+                while let Some(array_items) = next {
+                    next = match array_items {
+                        ArrayItems::FieldValue1(value) | ArrayItems::FieldValue2(value) => {
+                            items.push(self.convert_field_value(value, anchors)?);
+                            None
+                        }
+                        ArrayItems::C3(array_items_c3) => {
+                            items.push(self.convert_field_value(&array_items_c3.field_value, anchors)?);
+                            Some(array_items_c3.array_items.as_ref())
+                        }
+                    };
+                }
+                Ok(ConfigValue::Array(items))
             }
-            FieldValue::QuotedStringSingle(s) => {
-                // Extract string and remove surrounding quotes
-                let s_val = match s.as_ref() {
-                    s => s.trim(),
-                };
-                let content = s_val[1..s_val.len() - 1].to_string();
-                Ok(ConfigValue::String(content))
+        }
+    }
+
+    /// Convert the number literal of a field value. With `unit_suffixes`, a number with a unit
+    /// becomes an Integer count of milliseconds or bytes.
+    fn convert_number_literal(&self, number: &Number) -> Result<ConfigValue, String> {
+        let literal = number.as_ref().trim();
+        let at = Self::at(number.location.as_ref().map(|loc| self.source_location(loc)).as_ref());
+        let Some((digits, per_unit, is_duration)) = Self::split_unit(literal) else {
+            return Self::convert_number(literal).map_err(|e| format!("{}{}", e, at));
+        };
+        if !self.unit_suffixes {
+            return Err(format!("Number '{}' has a unit suffix, but unit suffixes are not enabled{}", literal, at));
+        }
+        let scaled = match Self::convert_number(digits).map_err(|e| format!("{}{}", e, at))? {
+            ConfigValue::Integer(i) => i.checked_mul(per_unit),
+            ConfigValue::Float(f) => {
+                let scaled = f * per_unit as f64;
+                (scaled.fract() == 0.0 && scaled.abs() < i64::MAX as f64).then_some(scaled as i64)
             }
-            FieldValue::Number(n) => {
-                let n_str = match n.as_ref() {
-                    s => s.trim(),
-                };
-                if n_str.contains('.') {
-                    // Float value
-                    match n_str.parse::<f64>() {
-                        Ok(f) => Ok(ConfigValue::Float(f)),
-                        Err(_) => Err(format!("Failed to parse float: {}", n_str)),
-                    }
-                } else {
-                    // Integer value
-                    match n_str.parse::<i64>() {
-                        Ok(i) => Ok(ConfigValue::Integer(i)),
-                        Err(_) => Err(format!("Failed to parse integer: {}", n_str)),
-                    }
-                }
+            _ => None,
+        };
+        scaled.map(ConfigValue::Integer).ok_or_else(|| {
+            let unit = if is_duration { "milliseconds" } else { "bytes" };
+            format!("Number '{}' is not a whole number of {} that fits an i64{}", literal, unit, at)
+        })
+    }
+
+    /// Split a decimal number literal into its number and unit suffix, with the size of one
+    /// unit in milliseconds or bytes and whether the unit is a duration
+    fn split_unit(literal: &str) -> Option<(&str, i64, bool)> {
+        let digits = literal.trim_start_matches(['+', '-']);
+        // Prefixed literals end in letters too, as in `0xff`
+        if matches!(digits.get(..2).map(str::to_ascii_lowercase).as_deref(), Some("0x" | "0o" | "0b")) {
+            return None;
+        }
+        let durations = DURATION_UNITS.iter().map(|&(unit, per_unit)| (unit, per_unit, true));
+        let sizes = BYTE_UNITS.iter().map(|&(unit, per_unit)| (unit, per_unit, false));
+        durations.chain(sizes).find_map(|(unit, per_unit, is_duration)| {
+            let number = literal.strip_suffix(unit)?;
+            number.ends_with(|c: char| c.is_ascii_digit()).then_some((number, per_unit, is_duration))
+        })
+    }
+
+    /// Whether a field value is a number with a duration suffix that `unit_suffixes` reads
+    fn is_duration_literal(&self, value: &FieldValue) -> bool {
+        self.unit_suffixes
+            && matches!(value, FieldValue::Number(n) if Self::split_unit(n.as_ref().trim()).is_some_and(|(_, _, is_duration)| is_duration))
+    }
+
+    /// Convert a number literal to an Integer, or a Float if it has a fractional part
+    fn convert_number(n_str: &str) -> Result<ConfigValue, String> {
+        let (sign, digits) = match n_str.strip_prefix(['+', '-']) {
+            Some(rest) => (&n_str[..1], rest),
+            None => ("", n_str),
+        };
+        let radix = match digits.get(..2).map(|prefix| prefix.to_ascii_lowercase()).as_deref() {
+            Some("0x") => Some((16, "hexadecimal")),
+            Some("0o") => Some((8, "octal")),
+            Some("0b") => Some((2, "binary")),
+            _ => None,
+        };
+        if let Some((radix, kind)) = radix {
+            // Prefixed literals are always integers, so a `.` or an out-of-radix digit is an error
+            let body = digits[2..].replace('_', "");
+            let valid = !body.is_empty() && body.chars().all(|c| c.is_ascii_alphanumeric());
+            return valid
+                .then(|| i64::from_str_radix(&format!("{}{}", sign, body), radix).ok())
+                .flatten()
+                .map(ConfigValue::Integer)
+                .ok_or_else(|| format!("Invalid {} integer literal: {}", kind, n_str));
+        }
+
+        // Underscores only group digits
+        let n_str = n_str.replace('_', "");
+        if n_str.contains('.') {
+            // Float value
+            match n_str.parse::<f64>() {
+                Ok(f) => Ok(ConfigValue::Float(f)),
+                Err(_) => Err(format!("Failed to parse float: {}", n_str)),
+            }
+        } else {
+            // Integer value
+            match n_str.parse::<i64>() {
+                Ok(i) => Ok(ConfigValue::Integer(i)),
+                Err(_) => Err(format!("Failed to parse integer: {}", n_str)),
             }
-            FieldValue::BooleanTrue => Ok(ConfigValue::Boolean(true)),
-            FieldValue::BooleanFalse => Ok(ConfigValue::Boolean(false)),
         }
     }
 }
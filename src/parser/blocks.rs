@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: Apache-2.0
+//! Line-level checks on the code blocks of a markdown source
+//!
+//! The grammar sees an unclosed ```` ```cola ```` block only as an unexpected end of input,
+//! so these helpers recover the context needed for a readable error message.
+
+/// Return the 1-based line of a cola code block that is opened but never closed
+pub fn unterminated_cola_block(source: &str) -> Option<usize> {
+    // Line of the open block's fence, and whether it is a cola block
+    let mut open: Option<(usize, bool)> = None;
+    for (index, line) in source.lines().enumerate() {
+        let Some(info) = line.trim_start().strip_prefix("```") else {
+            continue;
+        };
+        open = match open {
+            Some(_) => None,
+            None => Some((index + 1, info.trim() == "cola")),
+        };
+    }
+    match open {
+        Some((line, true)) => Some(line),
+        _ => None,
+    }
+}
+
+/// Describe a parse failure, naming the unterminated cola block if that is the cause
+pub fn describe_parse_error(source: &str, error: impl std::fmt::Display) -> String {
+    match unterminated_cola_block(source) {
+        Some(line) => format!("unterminated cola code block starting at line {}", line),
+        None => format!("Failed to parse input: {}", error),
+    }
+}
@@ -7,6 +7,14 @@ pub type Input = str;
 pub type Ctx<'i> = Context<'i, Input>;
 #[allow(dead_code)]
 pub type Token<'i> = RustemoToken<'i, Input, TokenKind>;
+pub type BooleanTrue = ValLoc<String>;
+pub fn boolean_true(_ctx: &Ctx, token: Token) -> BooleanTrue {
+    BooleanTrue::new(token.value.into(), Some(_ctx.location()))
+}
+pub type BooleanFalse = ValLoc<String>;
+pub fn boolean_false(_ctx: &Ctx, token: Token) -> BooleanFalse {
+    BooleanFalse::new(token.value.into(), Some(_ctx.location()))
+}
 pub type ColaCodeStart = ValLoc<String>;
 pub fn cola_code_start(_ctx: &Ctx, token: Token) -> ColaCodeStart {
     ColaCodeStart::new(token.value.into(), Some(_ctx.location()))
@@ -23,6 +31,10 @@ pub type Identifier = ValLoc<String>;
 pub fn identifier(_ctx: &Ctx, token: Token) -> Identifier {
     Identifier::new(token.value.into(), Some(_ctx.location()))
 }
+pub type Null = ValLoc<String>;
+pub fn null(_ctx: &Ctx, token: Token) -> Null {
+    Null::new(token.value.into(), Some(_ctx.location()))
+}
 pub type Number = ValLoc<String>;
 pub fn number(_ctx: &Ctx, token: Token) -> Number {
     Number::new(token.value.into(), Some(_ctx.location()))
@@ -35,6 +47,10 @@ pub type QuotedStringDouble = ValLoc<String>;
 pub fn quoted_string_double(_ctx: &Ctx, token: Token) -> QuotedStringDouble {
     QuotedStringDouble::new(token.value.into(), Some(_ctx.location()))
 }
+pub type QuotedStringTriple = ValLoc<String>;
+pub fn quoted_string_triple(_ctx: &Ctx, token: Token) -> QuotedStringTriple {
+    QuotedStringTriple::new(token.value.into(), Some(_ctx.location()))
+}
 pub type QuotedStringSingle = ValLoc<String>;
 pub fn quoted_string_single(_ctx: &Ctx, token: Token) -> QuotedStringSingle {
     QuotedStringSingle::new(token.value.into(), Some(_ctx.location()))
@@ -144,29 +160,56 @@ pub fn cola_code_block_c1(
         Some(_ctx.location()),
     )
 }
-pub type ColaSyntax = Entity0;
-pub fn cola_syntax_entity0(_ctx: &Ctx, entity0: Entity0) -> ColaSyntax {
-    entity0
+pub type ColaSyntax = ColaItem0;
+pub fn cola_syntax_cola_item0(_ctx: &Ctx, cola_item0: ColaItem0) -> ColaSyntax {
+    cola_item0
 }
-pub type Entity1 = Vec<Entity>;
-pub fn entity1_c1(_ctx: &Ctx, mut entity1: Entity1, entity: Entity) -> Entity1 {
-    entity1.push(entity);
-    entity1
+pub type ColaItem1 = Vec<ColaItem>;
+pub fn cola_item1_c1(
+    _ctx: &Ctx,
+    mut cola_item1: ColaItem1,
+    cola_item: ColaItem,
+) -> ColaItem1 {
+    cola_item1.push(cola_item);
+    cola_item1
 }
-pub fn entity1_entity(_ctx: &Ctx, entity: Entity) -> Entity1 {
-    vec![entity]
+pub fn cola_item1_cola_item(_ctx: &Ctx, cola_item: ColaItem) -> ColaItem1 {
+    vec![cola_item]
 }
-pub type Entity0 = Option<Entity1>;
-pub fn entity0_entity1(_ctx: &Ctx, entity1: Entity1) -> Entity0 {
-    Some(entity1)
+pub type ColaItem0 = Option<ColaItem1>;
+pub fn cola_item0_cola_item1(_ctx: &Ctx, cola_item1: ColaItem1) -> ColaItem0 {
+    Some(cola_item1)
 }
-pub fn entity0_empty(_ctx: &Ctx) -> Entity0 {
+pub fn cola_item0_empty(_ctx: &Ctx) -> ColaItem0 {
     None
 }
 #[derive(Debug, Clone)]
+pub enum ColaItem {
+    Entity(Entity),
+    FieldList(FieldList),
+    AnchorDefinition(AnchorDefinition),
+    Include(Include),
+}
+pub fn cola_item_entity(_ctx: &Ctx, entity: Entity) -> ColaItem {
+    ColaItem::Entity(entity)
+}
+pub fn cola_item_field_list(_ctx: &Ctx, field_list: FieldList) -> ColaItem {
+    ColaItem::FieldList(field_list)
+}
+pub fn cola_item_anchor_definition(
+    _ctx: &Ctx,
+    anchor_definition: AnchorDefinition,
+) -> ColaItem {
+    ColaItem::AnchorDefinition(anchor_definition)
+}
+pub fn cola_item_include(_ctx: &Ctx, include: Include) -> ColaItem {
+    ColaItem::Include(include)
+}
+#[derive(Debug, Clone)]
 pub enum Entity {
     PluralEntity(PluralEntity),
     SingularEntity(SingularEntity),
+    InlinePluralEntity(InlinePluralEntity),
 }
 pub fn entity_plural_entity(_ctx: &Ctx, plural_entity: PluralEntity) -> Entity {
     Entity::PluralEntity(plural_entity)
@@ -174,10 +217,18 @@ pub fn entity_plural_entity(_ctx: &Ctx, plural_entity: PluralEntity) -> Entity {
 pub fn entity_singular_entity(_ctx: &Ctx, singular_entity: SingularEntity) -> Entity {
     Entity::SingularEntity(singular_entity)
 }
+pub fn entity_inline_plural_entity(
+    _ctx: &Ctx,
+    inline_plural_entity: InlinePluralEntity,
+) -> Entity {
+    Entity::InlinePluralEntity(inline_plural_entity)
+}
 #[derive(Debug, Clone)]
 pub struct PluralEntityBase {
     pub identifier_1: Identifier,
     pub identifier_3: Identifier,
+    pub plural_key_opt: PluralKeyOpt,
+    pub plural_enum_opt: PluralEnumOpt,
     pub entity_definition: EntityDefinition,
 }
 pub type PluralEntity = ValLoc<PluralEntityBase>;
@@ -185,17 +236,38 @@ pub fn plural_entity_c1(
     _ctx: &Ctx,
     identifier_1: Identifier,
     identifier_3: Identifier,
+    plural_key_opt: PluralKeyOpt,
+    plural_enum_opt: PluralEnumOpt,
     entity_definition: EntityDefinition,
 ) -> PluralEntity {
     PluralEntity::new(
         PluralEntityBase {
             identifier_1,
             identifier_3,
+            plural_key_opt,
+            plural_enum_opt,
             entity_definition,
         },
         Some(_ctx.location()),
     )
 }
+pub type PluralKeyOpt = Option<PluralKey>;
+pub fn plural_key_opt_plural_key(_ctx: &Ctx, plural_key: PluralKey) -> PluralKeyOpt {
+    Some(plural_key)
+}
+pub fn plural_key_opt_empty(_ctx: &Ctx) -> PluralKeyOpt {
+    None
+}
+pub type PluralEnumOpt = Option<PluralEnum>;
+pub fn plural_enum_opt_plural_enum(
+    _ctx: &Ctx,
+    plural_enum: PluralEnum,
+) -> PluralEnumOpt {
+    Some(plural_enum)
+}
+pub fn plural_enum_opt_empty(_ctx: &Ctx) -> PluralEnumOpt {
+    None
+}
 #[derive(Debug, Clone)]
 pub struct SingularEntityBase {
     pub identifier: Identifier,
@@ -215,12 +287,101 @@ pub fn singular_entity_c1(
         Some(_ctx.location()),
     )
 }
-pub type EntityDefinition = NestedBlock0;
-pub fn entity_definition_nested_block0(
+#[derive(Debug, Clone)]
+pub struct InlinePluralEntityBase {
+    pub identifier: Identifier,
+    pub inline_instances_opt: InlineInstancesOpt,
+}
+pub type InlinePluralEntity = ValLoc<InlinePluralEntityBase>;
+pub fn inline_plural_entity_c1(
+    _ctx: &Ctx,
+    identifier: Identifier,
+    inline_instances_opt: InlineInstancesOpt,
+) -> InlinePluralEntity {
+    InlinePluralEntity::new(
+        InlinePluralEntityBase {
+            identifier,
+            inline_instances_opt,
+        },
+        Some(_ctx.location()),
+    )
+}
+pub type InlineInstancesOpt = Option<InlineInstances>;
+pub fn inline_instances_opt_inline_instances(
+    _ctx: &Ctx,
+    inline_instances: InlineInstances,
+) -> InlineInstancesOpt {
+    Some(inline_instances)
+}
+pub fn inline_instances_opt_empty(_ctx: &Ctx) -> InlineInstancesOpt {
+    None
+}
+#[derive(Debug, Clone)]
+pub struct InlineInstancesC3Base {
+    pub identifier: Identifier,
+    pub inline_instances: Box<InlineInstances>,
+}
+pub type InlineInstancesC3 = ValLoc<InlineInstancesC3Base>;
+#[derive(Debug, Clone)]
+pub enum InlineInstances {
+    Identifier1(Identifier),
+    Identifier2(Identifier),
+    C3(InlineInstancesC3),
+}
+pub fn inline_instances_identifier1(
+    _ctx: &Ctx,
+    identifier: Identifier,
+) -> InlineInstances {
+    InlineInstances::Identifier1(identifier)
+}
+pub fn inline_instances_identifier2(
+    _ctx: &Ctx,
+    identifier: Identifier,
+) -> InlineInstances {
+    InlineInstances::Identifier2(identifier)
+}
+pub fn inline_instances_c3(
+    _ctx: &Ctx,
+    identifier: Identifier,
+    inline_instances: InlineInstances,
+) -> InlineInstances {
+    InlineInstances::C3(
+        InlineInstancesC3::new(
+            InlineInstancesC3Base {
+                identifier,
+                inline_instances: Box::new(inline_instances),
+            },
+            Some(_ctx.location()),
+        ),
+    )
+}
+pub type PluralKey = Identifier;
+pub fn plural_key_identifier(_ctx: &Ctx, identifier: Identifier) -> PluralKey {
+    identifier
+}
+#[derive(Debug, Clone)]
+pub enum PluralEnum {
+    C1,
+}
+pub fn plural_enum_c1(_ctx: &Ctx) -> PluralEnum {
+    PluralEnum::C1
+}
+#[derive(Debug, Clone)]
+pub enum EntityDefinition {
+    Plain(NestedBlock0),
+    Braced(NestedBlock0),
+}
+pub fn entity_definition_plain(
+    _ctx: &Ctx,
+    nested_block0: NestedBlock0,
+) -> EntityDefinition {
+    EntityDefinition::Plain(nested_block0)
+}
+pub fn entity_definition_braced(
     _ctx: &Ctx,
     nested_block0: NestedBlock0,
 ) -> EntityDefinition {
-    nested_block0
+    EntityDefinition::Braced(nested_block0)
 }
 pub type NestedBlock1 = Vec<NestedBlock>;
 pub fn nested_block1_c1(
@@ -251,6 +412,8 @@ pub fn nested_block0_empty(_ctx: &Ctx) -> NestedBlock0 {
 pub enum NestedBlock {
     FieldList(FieldList),
     Entity(Box<Entity>),
+    AnchorDefinition(AnchorDefinition),
+    Include(Include),
 }
 pub fn nested_block_field_list(_ctx: &Ctx, field_list: FieldList) -> NestedBlock {
     NestedBlock::FieldList(field_list)
@@ -258,53 +421,397 @@ pub fn nested_block_field_list(_ctx: &Ctx, field_list: FieldList) -> NestedBlock
 pub fn nested_block_entity(_ctx: &Ctx, entity: Entity) -> NestedBlock {
     NestedBlock::Entity(Box::new(entity))
 }
+pub fn nested_block_anchor_definition(
+    _ctx: &Ctx,
+    anchor_definition: AnchorDefinition,
+) -> NestedBlock {
+    NestedBlock::AnchorDefinition(anchor_definition)
+}
+pub fn nested_block_include(_ctx: &Ctx, include: Include) -> NestedBlock {
+    NestedBlock::Include(include)
+}
+pub type Include = QuotedStringDouble;
+pub fn include_quoted_string_double(
+    _ctx: &Ctx,
+    quoted_string_double: QuotedStringDouble,
+) -> Include {
+    quoted_string_double
+}
+#[derive(Debug, Clone)]
+pub struct AnchorDefinitionBase {
+    pub identifier: Identifier,
+    pub field_value: FieldValue,
+}
+pub type AnchorDefinition = ValLoc<AnchorDefinitionBase>;
+pub fn anchor_definition_c1(
+    _ctx: &Ctx,
+    identifier: Identifier,
+    field_value: FieldValue,
+) -> AnchorDefinition {
+    AnchorDefinition::new(
+        AnchorDefinitionBase {
+            identifier,
+            field_value,
+        },
+        Some(_ctx.location()),
+    )
+}
+pub type AnchorReference = Identifier;
+pub fn anchor_reference_identifier(
+    _ctx: &Ctx,
+    identifier: Identifier,
+) -> AnchorReference {
+    identifier
+}
 #[derive(Debug, Clone)]
 pub struct FieldListC2Base {
     pub field_list: Box<FieldList>,
-    pub field: Field,
+    pub field_entry: FieldEntry,
 }
 pub type FieldListC2 = ValLoc<FieldListC2Base>;
 #[derive(Debug, Clone)]
 pub enum FieldList {
-    Field(Field),
+    FieldEntry(FieldEntry),
     C2(FieldListC2),
 }
-pub fn field_list_field(_ctx: &Ctx, field: Field) -> FieldList {
-    FieldList::Field(field)
+pub fn field_list_field_entry(_ctx: &Ctx, field_entry: FieldEntry) -> FieldList {
+    FieldList::FieldEntry(field_entry)
 }
-pub fn field_list_c2(_ctx: &Ctx, field_list: FieldList, field: Field) -> FieldList {
+pub fn field_list_c2(
+    _ctx: &Ctx,
+    field_list: FieldList,
+    field_entry: FieldEntry,
+) -> FieldList {
     FieldList::C2(
         FieldListC2::new(
             FieldListC2Base {
                 field_list: Box::new(field_list),
-                field,
+                field_entry,
             },
             Some(_ctx.location()),
         ),
     )
 }
 #[derive(Debug, Clone)]
+pub enum FieldEntry {
+    Field(Field),
+    OptionalField(OptionalField),
+    RequiredField(RequiredField),
+    ComputedField(ComputedField),
+    FieldDeclaration(FieldDeclaration),
+    OptionalDeclaration(OptionalDeclaration),
+    RequiredDeclaration(RequiredDeclaration),
+}
+pub fn field_entry_field(_ctx: &Ctx, field: Field) -> FieldEntry {
+    FieldEntry::Field(field)
+}
+pub fn field_entry_optional_field(
+    _ctx: &Ctx,
+    optional_field: OptionalField,
+) -> FieldEntry {
+    FieldEntry::OptionalField(optional_field)
+}
+pub fn field_entry_required_field(
+    _ctx: &Ctx,
+    required_field: RequiredField,
+) -> FieldEntry {
+    FieldEntry::RequiredField(required_field)
+}
+pub fn field_entry_computed_field(
+    _ctx: &Ctx,
+    computed_field: ComputedField,
+) -> FieldEntry {
+    FieldEntry::ComputedField(computed_field)
+}
+pub fn field_entry_field_declaration(
+    _ctx: &Ctx,
+    field_declaration: FieldDeclaration,
+) -> FieldEntry {
+    FieldEntry::FieldDeclaration(field_declaration)
+}
+pub fn field_entry_optional_declaration(
+    _ctx: &Ctx,
+    optional_declaration: OptionalDeclaration,
+) -> FieldEntry {
+    FieldEntry::OptionalDeclaration(optional_declaration)
+}
+pub fn field_entry_required_declaration(
+    _ctx: &Ctx,
+    required_declaration: RequiredDeclaration,
+) -> FieldEntry {
+    FieldEntry::RequiredDeclaration(required_declaration)
+}
+#[derive(Debug, Clone)]
 pub struct FieldBase {
     pub identifier: Identifier,
+    pub type_annotation_opt: TypeAnnotationOpt,
     pub field_value: FieldValue,
 }
 pub type Field = ValLoc<FieldBase>;
-pub fn field_c1(_ctx: &Ctx, identifier: Identifier, field_value: FieldValue) -> Field {
+pub fn field_c1(
+    _ctx: &Ctx,
+    identifier: Identifier,
+    type_annotation_opt: TypeAnnotationOpt,
+    field_value: FieldValue,
+) -> Field {
     Field::new(
         FieldBase {
             identifier,
+            type_annotation_opt,
+            field_value,
+        },
+        Some(_ctx.location()),
+    )
+}
+pub type TypeAnnotationOpt = Option<TypeAnnotation>;
+pub fn type_annotation_opt_type_annotation(
+    _ctx: &Ctx,
+    type_annotation: TypeAnnotation,
+) -> TypeAnnotationOpt {
+    Some(type_annotation)
+}
+pub fn type_annotation_opt_empty(_ctx: &Ctx) -> TypeAnnotationOpt {
+    None
+}
+#[derive(Debug, Clone)]
+pub struct OptionalFieldBase {
+    pub identifier: Identifier,
+    pub type_annotation_opt: TypeAnnotationOpt,
+    pub field_value: FieldValue,
+}
+pub type OptionalField = ValLoc<OptionalFieldBase>;
+pub fn optional_field_c1(
+    _ctx: &Ctx,
+    identifier: Identifier,
+    type_annotation_opt: TypeAnnotationOpt,
+    field_value: FieldValue,
+) -> OptionalField {
+    OptionalField::new(
+        OptionalFieldBase {
+            identifier,
+            type_annotation_opt,
+            field_value,
+        },
+        Some(_ctx.location()),
+    )
+}
+#[derive(Debug, Clone)]
+pub struct RequiredFieldBase {
+    pub identifier: Identifier,
+    pub type_annotation_opt: TypeAnnotationOpt,
+    pub field_value: FieldValue,
+}
+pub type RequiredField = ValLoc<RequiredFieldBase>;
+pub fn required_field_c1(
+    _ctx: &Ctx,
+    identifier: Identifier,
+    type_annotation_opt: TypeAnnotationOpt,
+    field_value: FieldValue,
+) -> RequiredField {
+    RequiredField::new(
+        RequiredFieldBase {
+            identifier,
+            type_annotation_opt,
             field_value,
         },
         Some(_ctx.location()),
     )
 }
 #[derive(Debug, Clone)]
+pub struct FieldDeclarationBase {
+    pub identifier: Identifier,
+    pub type_name: TypeName,
+}
+pub type FieldDeclaration = ValLoc<FieldDeclarationBase>;
+pub fn field_declaration_c1(
+    _ctx: &Ctx,
+    identifier: Identifier,
+    type_name: TypeName,
+) -> FieldDeclaration {
+    FieldDeclaration::new(
+        FieldDeclarationBase {
+            identifier,
+            type_name,
+        },
+        Some(_ctx.location()),
+    )
+}
+#[derive(Debug, Clone)]
+pub struct OptionalDeclarationBase {
+    pub identifier: Identifier,
+    pub type_name: TypeName,
+}
+pub type OptionalDeclaration = ValLoc<OptionalDeclarationBase>;
+pub fn optional_declaration_c1(
+    _ctx: &Ctx,
+    identifier: Identifier,
+    type_name: TypeName,
+) -> OptionalDeclaration {
+    OptionalDeclaration::new(
+        OptionalDeclarationBase {
+            identifier,
+            type_name,
+        },
+        Some(_ctx.location()),
+    )
+}
+#[derive(Debug, Clone)]
+pub struct RequiredDeclarationBase {
+    pub identifier: Identifier,
+    pub type_name: TypeName,
+}
+pub type RequiredDeclaration = ValLoc<RequiredDeclarationBase>;
+pub fn required_declaration_c1(
+    _ctx: &Ctx,
+    identifier: Identifier,
+    type_name: TypeName,
+) -> RequiredDeclaration {
+    RequiredDeclaration::new(
+        RequiredDeclarationBase {
+            identifier,
+            type_name,
+        },
+        Some(_ctx.location()),
+    )
+}
+pub type TypeName = Identifier;
+pub fn type_name_identifier(_ctx: &Ctx, identifier: Identifier) -> TypeName {
+    identifier
+}
+#[derive(Debug, Clone)]
+pub struct ComputedFieldBase {
+    pub identifier: Identifier,
+    pub expression: Expression,
+}
+pub type ComputedField = ValLoc<ComputedFieldBase>;
+pub fn computed_field_c1(
+    _ctx: &Ctx,
+    identifier: Identifier,
+    expression: Expression,
+) -> ComputedField {
+    ComputedField::new(
+        ComputedFieldBase {
+            identifier,
+            expression,
+        },
+        Some(_ctx.location()),
+    )
+}
+#[derive(Debug, Clone)]
+pub struct AddBase {
+    pub left: Box<Expression>,
+    pub right: Box<Expression>,
+}
+pub type Add = ValLoc<AddBase>;
+#[derive(Debug, Clone)]
+pub struct SubBase {
+    pub left: Box<Expression>,
+    pub right: Box<Expression>,
+}
+pub type Sub = ValLoc<SubBase>;
+#[derive(Debug, Clone)]
+pub struct MulBase {
+    pub left: Box<Expression>,
+    pub right: Box<Expression>,
+}
+pub type Mul = ValLoc<MulBase>;
+#[derive(Debug, Clone)]
+pub struct DivBase {
+    pub left: Box<Expression>,
+    pub right: Box<Expression>,
+}
+pub type Div = ValLoc<DivBase>;
+#[derive(Debug, Clone)]
+pub enum Expression {
+    Add(Add),
+    Sub(Sub),
+    Mul(Mul),
+    Div(Div),
+    Group(Box<Expression>),
+    Number(Number),
+    Identifier(Identifier),
+    AnchorReference(AnchorReference),
+}
+pub fn expression_add(_ctx: &Ctx, left: Expression, right: Expression) -> Expression {
+    Expression::Add(
+        Add::new(
+            AddBase {
+                left: Box::new(left),
+                right: Box::new(right),
+            },
+            Some(_ctx.location()),
+        ),
+    )
+}
+pub fn expression_sub(_ctx: &Ctx, left: Expression, right: Expression) -> Expression {
+    Expression::Sub(
+        Sub::new(
+            SubBase {
+                left: Box::new(left),
+                right: Box::new(right),
+            },
+            Some(_ctx.location()),
+        ),
+    )
+}
+pub fn expression_mul(_ctx: &Ctx, left: Expression, right: Expression) -> Expression {
+    Expression::Mul(
+        Mul::new(
+            MulBase {
+                left: Box::new(left),
+                right: Box::new(right),
+            },
+            Some(_ctx.location()),
+        ),
+    )
+}
+pub fn expression_div(_ctx: &Ctx, left: Expression, right: Expression) -> Expression {
+    Expression::Div(
+        Div::new(
+            DivBase {
+                left: Box::new(left),
+                right: Box::new(right),
+            },
+            Some(_ctx.location()),
+        ),
+    )
+}
+pub fn expression_group(_ctx: &Ctx, expression: Expression) -> Expression {
+    Expression::Group(Box::new(expression))
+}
+pub fn expression_number(_ctx: &Ctx, number: Number) -> Expression {
+    Expression::Number(number)
+}
+pub fn expression_identifier(_ctx: &Ctx, identifier: Identifier) -> Expression {
+    Expression::Identifier(identifier)
+}
+pub fn expression_anchor_reference(
+    _ctx: &Ctx,
+    anchor_reference: AnchorReference,
+) -> Expression {
+    Expression::AnchorReference(anchor_reference)
+}
+pub type TypeAnnotation = Identifier;
+pub fn type_annotation_identifier(_ctx: &Ctx, identifier: Identifier) -> TypeAnnotation {
+    identifier
+}
+#[derive(Debug, Clone)]
 pub enum FieldValue {
+    QuotedStringTriple(QuotedStringTriple),
     QuotedStringDouble(QuotedStringDouble),
     QuotedStringSingle(QuotedStringSingle),
     Number(Number),
-    BooleanTrue,
-    BooleanFalse,
+    BooleanTrue(BooleanTrue),
+    BooleanFalse(BooleanFalse),
+    Null(Null),
+    ArrayValue(ArrayValue),
+    AnchorReference(AnchorReference),
+}
+pub fn field_value_quoted_string_triple(
+    _ctx: &Ctx,
+    quoted_string_triple: QuotedStringTriple,
+) -> FieldValue {
+    FieldValue::QuotedStringTriple(quoted_string_triple)
 }
 pub fn field_value_quoted_string_double(
     _ctx: &Ctx,
@@ -321,11 +828,73 @@ pub fn field_value_quoted_string_single(
 pub fn field_value_number(_ctx: &Ctx, number: Number) -> FieldValue {
     FieldValue::Number(number)
 }
-pub fn field_value_boolean_true(_ctx: &Ctx) -> FieldValue {
-    FieldValue::BooleanTrue
+pub fn field_value_boolean_true(_ctx: &Ctx, boolean_true: BooleanTrue) -> FieldValue {
+    FieldValue::BooleanTrue(boolean_true)
+}
+pub fn field_value_boolean_false(_ctx: &Ctx, boolean_false: BooleanFalse) -> FieldValue {
+    FieldValue::BooleanFalse(boolean_false)
+}
+pub fn field_value_null(_ctx: &Ctx, null: Null) -> FieldValue {
+    FieldValue::Null(null)
 }
-pub fn field_value_boolean_false(_ctx: &Ctx) -> FieldValue {
-    FieldValue::BooleanFalse
+pub fn field_value_array_value(_ctx: &Ctx, array_value: ArrayValue) -> FieldValue {
+    FieldValue::ArrayValue(array_value)
+}
+pub fn field_value_anchor_reference(
+    _ctx: &Ctx,
+    anchor_reference: AnchorReference,
+) -> FieldValue {
+    FieldValue::AnchorReference(anchor_reference)
+}
+pub type ArrayValue = ArrayItemsOpt;
+pub fn array_value_array_items_opt(
+    _ctx: &Ctx,
+    array_items_opt: ArrayItemsOpt,
+) -> ArrayValue {
+    array_items_opt
+}
+pub type ArrayItemsOpt = Option<ArrayItems>;
+pub fn array_items_opt_array_items(
+    _ctx: &Ctx,
+    array_items: ArrayItems,
+) -> ArrayItemsOpt {
+    Some(array_items)
+}
+pub fn array_items_opt_empty(_ctx: &Ctx) -> ArrayItemsOpt {
+    None
+}
+#[derive(Debug, Clone)]
+pub struct ArrayItemsC3Base {
+    pub field_value: Box<FieldValue>,
+    pub array_items: Box<ArrayItems>,
+}
+pub type ArrayItemsC3 = ValLoc<ArrayItemsC3Base>;
+#[derive(Debug, Clone)]
+pub enum ArrayItems {
+    FieldValue1(Box<FieldValue>),
+    FieldValue2(Box<FieldValue>),
+    C3(ArrayItemsC3),
+}
+pub fn array_items_field_value1(_ctx: &Ctx, field_value: FieldValue) -> ArrayItems {
+    ArrayItems::FieldValue1(Box::new(field_value))
+}
+pub fn array_items_field_value2(_ctx: &Ctx, field_value: FieldValue) -> ArrayItems {
+    ArrayItems::FieldValue2(Box::new(field_value))
+}
+pub fn array_items_c3(
+    _ctx: &Ctx,
+    field_value: FieldValue,
+    array_items: ArrayItems,
+) -> ArrayItems {
+    ArrayItems::C3(
+        ArrayItemsC3::new(
+            ArrayItemsC3Base {
+                field_value: Box::new(field_value),
+                array_items: Box::new(array_items),
+            },
+            Some(_ctx.location()),
+        ),
+    )
 }
 #[derive(Debug, Clone)]
 pub struct RegularCodeBlockBase {
@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: Apache-2.0
+//! Decoding of configuration input that is embedded in an encoded form
+use std::io::Read;
+
+use base64::Engine;
+use flate2::read::GzDecoder;
+
+use crate::model::config_model::ConfigModel;
+use crate::model::model_builder::ModelBuilder;
+
+/// Encoding of configuration input that has to be decoded before parsing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InputEncoding {
+    /// Standard base64; whitespace such as line wrapping is ignored
+    Base64,
+    /// A gzip stream
+    Gzip,
+}
+
+impl std::str::FromStr for InputEncoding {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "base64" => Ok(InputEncoding::Base64),
+            "gzip" => Ok(InputEncoding::Gzip),
+            other => Err(format!("Unknown input encoding '{}', expected base64 or gzip", other)),
+        }
+    }
+}
+
+/// Decode encoded input bytes into Cola (or Markdown) source text
+pub fn decode_input(input: &[u8], encoding: InputEncoding) -> Result<String, String> {
+    let bytes = match encoding {
+        InputEncoding::Base64 => {
+            let compact: Vec<u8> = input
+                .iter()
+                .copied()
+                .filter(|b| !b.is_ascii_whitespace())
+                .collect();
+            base64::engine::general_purpose::STANDARD
+                .decode(compact)
+                .map_err(|e| format!("Invalid base64 input: {}", e))?
+        }
+        InputEncoding::Gzip => {
+            let mut decoded = Vec::new();
+            GzDecoder::new(input)
+                .read_to_end(&mut decoded)
+                .map_err(|e| format!("Invalid gzip input: {}", e))?;
+            decoded
+        }
+    };
+    String::from_utf8(bytes).map_err(|e| format!("Decoded input is not UTF-8: {}", e))
+}
+
+/// Decode, parse and build a configuration model from encoded input
+pub fn parse_config_encoded(input: &[u8], encoding: InputEncoding) -> Result<ConfigModel, String> {
+    ModelBuilder::new().build_from_str(&decode_input(input, encoding)?)
+}
@@ -0,0 +1,255 @@
+// SPDX-License-Identifier: Apache-2.0
+//! Lenient parsing that recovers from syntax errors, for editor integration
+//!
+//! The generated parser stops at the first error, so recovery happens on the source text: the
+//! top-level item of the cola block holding the error is blanked out, keeping its line breaks so
+//! that later locations stay put, and the source is parsed again. Every blanked item is reported
+//! as a [`Diagnostic`] and the model is built from the items that remain.
+
+use std::fmt;
+use std::ops::Range;
+
+use rustemo::{Lexer, Parser};
+
+use crate::model::config_model::ConfigModel;
+use crate::model::model_builder::ModelBuilder;
+use crate::model::source_location::SourceLocation;
+use crate::parser::cola::{ColaParser, Context, State, TokenKind};
+use crate::parser::snippet::error_position;
+
+/// A problem found while parsing leniently
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// Where the problem was found, when it can be pinned to the source
+    pub location: Option<SourceLocation>,
+    pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "{}: {}", location, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl<'i, L, B> ColaParser<'i, str, L, B>
+where
+    L: Lexer<'i, Context<'i, str>, State, TokenKind, Input = str>,
+{
+    /// Parse `source`, skipping the top-level items that fail to parse instead of stopping at the
+    /// first error
+    ///
+    /// Returns the model built from the remaining items, or `None` when an error cannot be
+    /// attributed to a single item, together with a diagnostic for every error found. Each
+    /// attempt runs on a fresh default parser, as the source changes between attempts.
+    pub fn parse_lenient(&self, source: &str) -> (Option<ConfigModel>, Vec<Diagnostic>) {
+        let items = item_spans(source);
+        let mut text = source.to_string();
+        let mut diagnostics = Vec::new();
+        // Each round blanks one item, so the loop ends after at most one round per item
+        loop {
+            let error = match ColaParser::new().parse(&text) {
+                Ok(cola) => {
+                    let builder = ModelBuilder::new();
+                    return match builder.build(&cola) {
+                        Ok(mut model) => {
+                            builder.attach_doc_comments(&mut model, &text);
+                            (Some(model), diagnostics)
+                        }
+                        Err(message) => {
+                            diagnostics.push(Diagnostic { location: None, message });
+                            (None, diagnostics)
+                        }
+                    };
+                }
+                Err(error) => error,
+            };
+            let position = error_position(&error);
+            let message = match error {
+                rustemo::Error::Error { message, .. } => message,
+                other => other.to_string(),
+            };
+            diagnostics.push(Diagnostic {
+                location: position.map(|(line, column)| SourceLocation {
+                    file_path: Default::default(),
+                    start_line: line as u32,
+                    start_column: column as u32,
+                    end_line: line as u32,
+                    end_column: column as u32,
+                }),
+                message,
+            });
+
+            let offset = position.map(|(line, column)| byte_offset(&text, line, column));
+            let Some(item) = offset.and_then(|offset| failing_item(&items, offset)) else {
+                return (None, diagnostics);
+            };
+            if text[item.clone()].trim().is_empty() {
+                // Already blanked, so the error lies outside the items
+                return (None, diagnostics);
+            }
+            let blank: String = text[item.clone()]
+                .chars()
+                .map(|c| if c == '\n' { '\n' } else { ' ' })
+                .collect();
+            text.replace_range(item, &blank);
+        }
+    }
+}
+
+/// Byte offset of the 1-based `line` and 0-based `column` in `text`
+fn byte_offset(text: &str, line: usize, column: usize) -> usize {
+    let line_start: usize = text
+        .split_inclusive('\n')
+        .take(line.saturating_sub(1))
+        .map(str::len)
+        .sum();
+    let line_text = text[line_start..].split('\n').next().unwrap_or("");
+    line_start
+        + line_text
+            .char_indices()
+            .nth(column)
+            .map_or(line_text.len(), |(index, _)| index)
+}
+
+/// The item holding `offset`, or the last one before it when the error is only detected at the
+/// end of its block
+fn failing_item(items: &[Range<usize>], offset: usize) -> Option<Range<usize>> {
+    items.iter().rfind(|item| item.start <= offset).cloned()
+}
+
+/// Byte ranges of the top-level items of every cola code block of `source`
+fn item_spans(source: &str) -> Vec<Range<usize>> {
+    let mut spans = Vec::new();
+    let mut block_start = None;
+    let mut offset = 0;
+    for line in source.split_inclusive('\n') {
+        if let Some(info) = line.trim_start().strip_prefix("```") {
+            match block_start.take() {
+                Some(start) => spans.extend(block_items(&source[start..offset], start)),
+                None if info.trim() == "cola" => block_start = Some(offset + line.len()),
+                None => {}
+            }
+        }
+        offset += line.len();
+    }
+    if let Some(start) = block_start {
+        spans.extend(block_items(&source[start..], start));
+    }
+    spans
+}
+
+/// Split the body of a cola block into its top-level items, offset by `base`
+///
+/// An item ends at the `;` closing its outermost entity. A `:` opens an entity unless a value
+/// or a type annotation follows it, which is enough to track nesting without a full parse.
+fn block_items(block: &str, base: usize) -> Vec<Range<usize>> {
+    let bytes = block.as_bytes();
+    let mut items = Vec::new();
+    let mut start = None;
+    let mut depth = 0usize;
+    let mut i = 0;
+    while i < bytes.len() {
+        let c = bytes[i];
+        if c.is_ascii_whitespace() {
+            i += 1;
+            continue;
+        }
+        if c == b'#' {
+            i = line_end(bytes, i);
+            continue;
+        }
+        let token_start = i;
+        start.get_or_insert(token_start);
+        match c {
+            b'"' | b'\'' => i = string_end(bytes, i),
+            b':' => {
+                if !value_follows(block, i + 1) {
+                    depth += 1;
+                }
+                i += 1;
+            }
+            b';' => {
+                depth = depth.saturating_sub(1);
+                i += 1;
+                if depth == 0
+                    && let Some(item_start) = start.take()
+                {
+                    items.push(base + item_start..base + i);
+                }
+            }
+            _ => i += 1,
+        }
+    }
+    if let Some(item_start) = start {
+        items.push(base + item_start..base + bytes.len());
+    }
+    items
+}
+
+/// Whether the text after a `:` at `from` starts a field value rather than an entity body
+fn value_follows(block: &str, from: usize) -> bool {
+    let bytes = block.as_bytes();
+    let mut i = from;
+    loop {
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+        if i < bytes.len() && bytes[i] == b'#' {
+            i = line_end(bytes, i);
+        } else {
+            break;
+        }
+    }
+    let rest = &block[i..];
+    match rest.bytes().next() {
+        Some(b'"' | b'\'' | b'[' | b'*' | b'+' | b'-' | b'0'..=b'9') => true,
+        Some(c) if c.is_ascii_alphabetic() || c == b'_' => {
+            let word_len = rest
+                .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            let word = &rest[..word_len];
+            // A type annotation reads `name: type = value`, a declaration `name: type`
+            matches!(word, "true" | "false" | "null") || rest[word_len..].trim_start().starts_with(['=', ',', ';'])
+        }
+        _ => false,
+    }
+}
+
+/// Index of the newline ending the line at `i`
+fn line_end(bytes: &[u8], i: usize) -> usize {
+    bytes[i..].iter().position(|&b| b == b'\n').map_or(bytes.len(), |n| i + n)
+}
+
+/// Index just past the string literal starting at `i`, triple-quoted or not
+fn string_end(bytes: &[u8], i: usize) -> usize {
+    let quote = bytes[i];
+    if bytes[i..].starts_with(&[quote; 3]) {
+        return bytes[i + 3..]
+            .windows(3)
+            .position(|window| window == [quote; 3])
+            .map_or(bytes.len(), |n| i + 3 + n + 3);
+    }
+    let mut j = i + 1;
+    while j < bytes.len() && bytes[j] != quote && bytes[j] != b'\n' {
+        j += if bytes[j] == b'\\' { 2 } else { 1 };
+    }
+    (j + 1).min(bytes.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_block_items_split_top_level_entities() {
+        let source = "```cola\na:\n    x: 1,\n    b:\n        y: \"; #\"\n    ;\n;\nc: z: true;\n```\n";
+        let spans = item_spans(source);
+        let items: Vec<&str> = spans.iter().map(|span| &source[span.clone()]).collect();
+        assert_eq!(items.len(), 2);
+        assert!(items[0].starts_with("a:") && items[0].ends_with(';'));
+        assert_eq!(items[1], "c: z: true;");
+    }
+}
@@ -0,0 +1,137 @@
+// SPDX-License-Identifier: Apache-2.0
+//! Removal of markdown containers around code blocks, run on the source text before parsing
+//!
+//! The grammar only sees a fence at the start of a line, so a block quoted with `>` or written
+//! inside a list item reads as paragraph text. For every fence opened behind such a prefix, the
+//! prefix is removed from the fence and from each line of the block up to its closing fence:
+//!
+//! ```text
+//! > ```cola          ```cola
+//! > app:             app:
+//! >     port: 80, => port: 80,
+//! > ;                ;
+//! > ```              ```
+//! ```
+//!
+//! A list marker on the opening line is matched by its width in spaces on the lines that follow.
+//! Text outside the blocks is left alone and no line is added or removed, so line numbers in
+//! parse errors still point into the original file.
+
+/// Remove the blockquote markers and list indentation in front of every fenced code block of
+/// `source`
+pub fn strip_containers(source: &str) -> String {
+    let mut out = String::with_capacity(source.len());
+    // Continuation prefix of the block being read, if any
+    let mut open: Option<String> = None;
+    for line in source.split_inclusive('\n') {
+        match &open {
+            Some(prefix) => {
+                let body = strip_prefix(line, prefix);
+                if body.trim_start().starts_with("```") {
+                    open = None;
+                }
+                out.push_str(body);
+            }
+            None => {
+                let (prefix_len, continuation) = container_prefix(line);
+                if line[prefix_len..].starts_with("```") {
+                    out.push_str(&line[prefix_len..]);
+                    open = Some(continuation);
+                } else {
+                    out.push_str(line);
+                }
+            }
+        }
+    }
+    out
+}
+
+/// Length of the container markers at the start of `line`, and the prefix the lines continuing
+/// the same containers start with
+fn container_prefix(line: &str) -> (usize, String) {
+    let bytes = line.as_bytes();
+    let mut continuation = String::new();
+    let mut i = 0;
+    loop {
+        while i < bytes.len() && (bytes[i] == b' ' || bytes[i] == b'\t') {
+            continuation.push(bytes[i] as char);
+            i += 1;
+        }
+        if i < bytes.len() && bytes[i] == b'>' {
+            continuation.push('>');
+            i += 1;
+        } else if let Some(len) = list_marker(&line[i..]) {
+            continuation.push_str(&" ".repeat(len));
+            i += len;
+        } else {
+            return (i, continuation);
+        }
+    }
+}
+
+/// Length of the list item marker at the start of `text`, including the space after it
+fn list_marker(text: &str) -> Option<usize> {
+    let digits = text.bytes().take_while(u8::is_ascii_digit).count();
+    let marker = match text.as_bytes().get(digits) {
+        Some(b'.' | b')') if digits > 0 => digits + 1,
+        Some(b'-' | b'*' | b'+') if digits == 0 => 1,
+        _ => return None,
+    };
+    (text.as_bytes().get(marker) == Some(&b' ')).then_some(marker + 1)
+}
+
+/// `line` without as much of the continuation `prefix` as it starts with
+///
+/// A `>` must be present for the line to stay in the block quote, while whitespace may be
+/// missing, as on blank quoted lines written as a bare `>`.
+fn strip_prefix<'a>(line: &'a str, prefix: &str) -> &'a str {
+    let bytes = line.as_bytes();
+    let mut i = 0;
+    for expected in prefix.bytes() {
+        match bytes.get(i) {
+            Some(&b) if b == expected => i += 1,
+            Some(b' ' | b'\t') if expected == b'>' => {
+                // Quote markers may be indented differently from the opening line
+                while matches!(bytes.get(i), Some(b' ' | b'\t')) {
+                    i += 1;
+                }
+                if bytes.get(i) != Some(&b'>') {
+                    break;
+                }
+                i += 1;
+            }
+            _ if expected == b'>' => break,
+            _ => {}
+        }
+    }
+    &line[i..]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_containers_unquotes_a_block() {
+        let source = "Intro\n> ```cola\n> app:\n>     port: 80,\n>\n> ;\n> ```\n> After\n";
+        assert_eq!(
+            strip_containers(source),
+            "Intro\n```cola\napp:\n    port: 80,\n\n;\n```\n> After\n"
+        );
+    }
+
+    #[test]
+    fn test_strip_containers_outdents_list_items() {
+        let source = "1. Step\n   ```cola\n   a: x: 1;\n   ```\n- ```cola\n  b: y: 2;\n  ```\n";
+        assert_eq!(
+            strip_containers(source),
+            "1. Step\n```cola\na: x: 1;\n```\n```cola\nb: y: 2;\n```\n"
+        );
+    }
+
+    #[test]
+    fn test_strip_containers_keeps_plain_blocks() {
+        let source = "# Title\n```cola\na:\n    - x: 1;\n;\n```\n";
+        assert_eq!(strip_containers(source), source);
+    }
+}
@@ -1,3 +1,12 @@
 // SPDX-License-Identifier: Apache-2.0
+// The parser modules are regenerated by build.rs from the grammar on every build,
+// so the lints the generated parser trips are allowed here rather than in the file itself.
+#[allow(clippy::large_enum_variant, clippy::new_without_default)]
 pub mod cola;
 pub mod cola_actions;
+pub mod blocks;
+pub mod encoding;
+pub mod lenient;
+pub mod markdown;
+pub mod repeat;
+pub mod snippet;
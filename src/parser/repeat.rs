@@ -0,0 +1,89 @@
+// SPDX-License-Identifier: Apache-2.0
+//! Expansion of `repeat` directives, run on the source text before parsing
+//!
+//! A line starting with `repeat <name> {` opens a directive whose name holds a numeric range,
+//! e.g. `repeat gpt-{1..4} { max_tokens: 1000 }`. It expands into one entity per number of the
+//! inclusive range, `gpt-1` to `gpt-4`, with the body between the braces as the entity body and
+//! every `{i}` in it replaced by the number.
+
+/// Expand every `repeat` directive of `source` into the entities it stands for
+pub fn expand_repeats(source: &str) -> Result<String, String> {
+    let mut out = String::with_capacity(source.len());
+    let mut rest = source;
+    let mut line_start = true;
+    while !rest.is_empty() {
+        if line_start
+            && let Some((expanded, consumed)) = expand_directive(rest)?
+        {
+            out.push_str(&expanded);
+            rest = &rest[consumed..];
+            line_start = false;
+            continue;
+        }
+        let end = rest.find('\n').map_or(rest.len(), |index| index + 1);
+        out.push_str(&rest[..end]);
+        rest = &rest[end..];
+        line_start = true;
+    }
+    Ok(out)
+}
+
+/// Expand the directive at the start of `text`, returning the expansion and the length of the
+/// directive, or `None` when the line is not a directive
+fn expand_directive(text: &str) -> Result<Option<(String, usize)>, String> {
+    let indent = text.len() - text.trim_start_matches([' ', '\t']).len();
+    let Some(header) = text[indent..].strip_prefix("repeat") else {
+        return Ok(None);
+    };
+    let after_name = header.trim_start_matches([' ', '\t']);
+    if after_name.len() == header.len() {
+        return Ok(None);
+    }
+    let name_len = after_name.find(char::is_whitespace).unwrap_or(after_name.len());
+    let name = &after_name[..name_len];
+    let Some(body) = after_name[name_len..].trim_start().strip_prefix('{') else {
+        return Ok(None);
+    };
+
+    let (prefix, (start, end), suffix) = split_range(name)?;
+    let body_len = matching_brace(body).ok_or_else(|| format!("Unterminated repeat block for '{}'", name))?;
+    let template = &body[..body_len];
+
+    let indent = &text[..indent];
+    let copies: Vec<String> = (start..=end)
+        .map(|i| {
+            let index = i.to_string();
+            format!("{}{}{}{}:{}{};", indent, prefix, index, suffix, template.replace("{i}", &index), indent)
+        })
+        .collect();
+    let consumed = text.len() - body.len() + body_len + 1;
+    Ok(Some((copies.join("\n"), consumed)))
+}
+
+/// Split a directive name around its `{start..end}` range
+fn split_range(name: &str) -> Result<(&str, (u64, u64), &str), String> {
+    let invalid = || format!("Repeat name '{}' needs a range such as {{1..4}}", name);
+    let open = name.find('{').ok_or_else(invalid)?;
+    let close = open + name[open..].find('}').ok_or_else(invalid)?;
+    let (start, end) = name[open + 1..close].split_once("..").ok_or_else(invalid)?;
+    let start: u64 = start.trim().parse().map_err(|_| invalid())?;
+    let end: u64 = end.trim().parse().map_err(|_| invalid())?;
+    if start > end {
+        return Err(format!("Repeat range {{{}..{}}} of '{}' is empty", start, end, name));
+    }
+    Ok((&name[..open], (start, end), &name[close + 1..]))
+}
+
+/// The offset of the `}` closing a body whose `{` was just consumed; `{i}` placeholders nest
+fn matching_brace(body: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    for (index, c) in body.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' if depth == 0 => return Some(index),
+            '}' => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
@@ -0,0 +1,97 @@
+// SPDX-License-Identifier: Apache-2.0
+//! Source snippets pointing at the position of a parse error
+//!
+//! Renders the offending line with a `^` under the error column and up to two lines of context
+//! on either side, in the style of compiler diagnostics:
+//!
+//! ```text
+//!   9 | cache:
+//!  10 |     ttl 300
+//!     |         ^
+//!  11 | ;
+//! ```
+
+/// Lines of context shown before and after the offending line
+const CONTEXT_LINES: usize = 2;
+
+/// Width of a tab stop when expanding tabs
+const TAB_WIDTH: usize = 4;
+
+/// The 1-based line and 0-based column of a parse error, when the parser reported one
+pub fn error_position(error: &rustemo::Error) -> Option<(usize, usize)> {
+    match error {
+        rustemo::Error::Error {
+            location: Some(location),
+            ..
+        } => match location.start {
+            rustemo::Position::LineBased(lc) => Some((lc.line, lc.column)),
+            rustemo::Position::Position(_) => None,
+        },
+        _ => None,
+    }
+}
+
+/// Render the 1-based `line` of `source` with a caret under the 0-based `column`
+///
+/// Tabs are expanded to the next multiple of four columns so the caret lines up with the text
+/// as most terminals show it. A column past the end of the line points just after its last
+/// character, where errors at the end of input are reported.
+pub fn caret_snippet(source: &str, line: usize, column: usize) -> String {
+    let lines: Vec<&str> = source.lines().collect();
+    let index = line.saturating_sub(1).min(lines.len().saturating_sub(1));
+    let first = index.saturating_sub(CONTEXT_LINES);
+    let last = (index + CONTEXT_LINES).min(lines.len().saturating_sub(1));
+    let gutter = (last + 1).to_string().len();
+
+    let mut out = String::new();
+    if lines.is_empty() {
+        out.push_str(&format!("{:>gutter$} | \n{:>gutter$} | ^\n", 1, ""));
+        return out;
+    }
+    for (number, text) in lines.iter().enumerate().take(last + 1).skip(first) {
+        let expanded = expand_tabs(text);
+        out.push_str(&format!("{:>gutter$} | {}\n", number + 1, expanded.trim_end()));
+        if number == index {
+            let prefix: String = text.chars().take(column).collect();
+            let offset = expand_tabs(&prefix).chars().count();
+            out.push_str(&format!("{:>gutter$} | {}^\n", "", " ".repeat(offset)));
+        }
+    }
+    out
+}
+
+/// Replace every tab of `text` with spaces up to the next tab stop
+fn expand_tabs(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut width = 0;
+    for c in text.chars() {
+        if c == '\t' {
+            let spaces = TAB_WIDTH - width % TAB_WIDTH;
+            out.push_str(&" ".repeat(spaces));
+            width += spaces;
+        } else {
+            out.push(c);
+            width += 1;
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_caret_snippet_points_at_the_column() {
+        let source = "a:\n    x: 1,\n    y 2\n;\nb:\n;\n";
+        let snippet = caret_snippet(source, 3, 6);
+        assert_eq!(snippet, "1 | a:\n2 |     x: 1,\n3 |     y 2\n  |       ^\n4 | ;\n5 | b:\n");
+    }
+
+    #[test]
+    fn test_caret_snippet_expands_tabs() {
+        let source = "a:\n\tx\t2\n;";
+        let snippet = caret_snippet(source, 2, 3);
+        assert_eq!(snippet, "1 | a:\n2 |     x   2\n  |         ^\n3 | ;\n");
+    }
+}
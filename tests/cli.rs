@@ -0,0 +1,154 @@
+// SPDX-License-Identifier: Apache-2.0
+//! Integration tests for the colap command line
+use std::process::Command;
+
+/// Run the colap binary with arguments and return its standard output
+fn run_colap(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_colap"))
+        .args(args)
+        .output()
+        .expect("Failed to run colap");
+    assert!(
+        output.status.success(),
+        "colap {:?} failed: {}",
+        args,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8(output.stdout).expect("colap printed invalid UTF-8")
+}
+
+#[test]
+fn test_list_entity_paths() {
+    let stdout = run_colap(&["list", "tests/data/test_extras.md"]);
+    assert_eq!(stdout, "model\nmodel/gpt-4\nmodel/o1\n");
+}
+
+#[test]
+fn test_list_fields() {
+    let stdout = run_colap(&["list", "--fields", "tests/data/test_service.md"]);
+    assert_eq!(
+        stdout,
+        "service.debug = false\n\
+         service.name = \"gateway\"\n\
+         service.port = 8080\n\
+         service/database.pool_size = 10\n\
+         service/database.url = \"postgres://localhost/app\"\n"
+    );
+}
+
+#[test]
+fn test_list_expands_repeat_directives() {
+    let stdout = run_colap(&["list", "tests/data/test_repeat.md"]);
+    assert_eq!(stdout, "model\nmodel/gpt-1\nmodel/gpt-2\nmodel/gpt-3\nmodel/gpt-4\n");
+}
+
+#[test]
+fn test_import_rust_structs() {
+    let stdout = run_colap(&["import", "tests/data/test_import.rs"]);
+    assert!(stdout.starts_with("```cola\nserver:\n"));
+    assert!(stdout.contains("database plural databases:\n"));
+}
+
+#[test]
+fn test_unterminated_cola_block_names_its_line() {
+    let output = Command::new(env!("CARGO_BIN_EXE_colap"))
+        .args(["list", "tests/data/test_unterminated.md"])
+        .output()
+        .expect("Failed to run colap");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("unterminated cola code block starting at line 11"),
+        "unexpected error: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_parse_error_points_at_the_offending_column() {
+    let output = Command::new(env!("CARGO_BIN_EXE_colap"))
+        .args(["list", "tests/data/test_bare_minus.md"])
+        .output()
+        .expect("Failed to run colap");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("test_bare_minus.md:5:12: parse error\n3 | ```cola\n4 | tuning:\n5 |     delta: -\n  |            ^\n6 | ;\n"),
+        "unexpected error: {}",
+        stderr
+    );
+}
+
+#[test]
+fn test_strict_rejects_fields_with_different_types_across_instances() {
+    let args = ["tests/data/test_inconsistent.md", "--dry-run"];
+    let output = Command::new(env!("CARGO_BIN_EXE_colap"))
+        .args(args.iter().chain(&["--strict"]))
+        .output()
+        .expect("Failed to run colap");
+    assert!(!output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("'timeout' of 'llm' is i64 in 'openai'") && stderr.contains("but String in 'anthropic'"),
+        "unexpected error: {}",
+        stderr
+    );
+
+    // Without --strict the conflict is only a warning
+    run_colap(&args);
+}
+
+#[test]
+fn test_dry_run_lists_files_without_writing() {
+    let output_dir = std::env::temp_dir().join(format!("colap-dry-run-{}", std::process::id()));
+    let output = output_dir.to_str().unwrap();
+    let stdout = run_colap(&["tests/data/test_service.md", "--output", output, "--dry-run"]);
+    assert!(!output_dir.exists());
+
+    let crate_dir = output_dir.join("test-service-config");
+    for file in ["Cargo.toml", "src/lib.rs", "tests/integration.rs", "README.md", "tests/data/config.md"] {
+        assert!(stdout.contains(&format!("  {} (", crate_dir.join(file).display())), "{} missing from {}", file, stdout);
+    }
+    let config_size = std::fs::metadata("tests/data/test_service.md").unwrap().len();
+    assert!(stdout.contains(&format!("config.md ({} bytes)", config_size)));
+}
+
+#[test]
+fn test_stdin_reads_piped_input() {
+    use std::io::Write;
+    use std::process::Stdio;
+
+    let output_dir = std::env::temp_dir().join(format!("colap-stdin-{}", std::process::id()));
+    let mut child = Command::new(env!("CARGO_BIN_EXE_colap"))
+        .args(["--stdin", "--output", output_dir.to_str().unwrap()])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .spawn()
+        .expect("Failed to run colap");
+    let source = std::fs::read_to_string("tests/data/test_service.md").unwrap();
+    child.stdin.take().unwrap().write_all(source.as_bytes()).unwrap();
+    assert!(child.wait().unwrap().success());
+
+    // Without a file name the crate gets a fixed default, and its tests read the model back
+    let crate_dir = output_dir.join("stdin-config");
+    let config = std::fs::read_to_string(crate_dir.join("tests/data/config.md"));
+    std::fs::remove_dir_all(&output_dir).unwrap();
+    let config = config.unwrap();
+    assert!(config.contains("pool_size: 10"), "{}", config);
+}
+
+#[test]
+fn test_warns_about_ignored_code_blocks() {
+    let output = Command::new(env!("CARGO_BIN_EXE_colap"))
+        .args(["tests/data/test_mixed_blocks.md", "--dry-run"])
+        .env("RUST_LOG", "warn")
+        .output()
+        .expect("Failed to run colap");
+    assert!(output.status.success());
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("ignored 3 non-cola code blocks (rust, bash, untagged)"),
+        "unexpected output: {}",
+        stderr
+    );
+}
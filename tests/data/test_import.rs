@@ -0,0 +1,17 @@
+// Sample hand-written configuration structs for `colap import`
+pub struct AppConfig {
+    pub server: Server,
+    pub databases: Vec<Database>,
+}
+
+pub struct Server {
+    pub host: String,
+    pub port: u16,
+    pub timeout: Option<u64>,
+    pub tags: Vec<String>,
+}
+
+pub struct Database {
+    pub url: String,
+    pub pool_size: u32,
+}
@@ -0,0 +1,1196 @@
+// SPDX-License-Identifier: Apache-2.0
+//! Integration tests for the code generator
+use rustemo::Parser;
+use colap::cola::ColaParser;
+use colap::config_model::{ConfigModel, ConfigValue};
+use colap::generator::rust_import::rust_to_cola;
+use colap::generator::type_map::TypeMap;
+use colap::generator::{CodeGenerator, ColapDependency, GenerationMode, GeneratorOptions};
+use colap::model_builder::ModelBuilder;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Parse a fixture and build its model
+fn build_model(path: &str) -> ConfigModel {
+    build_model_with(&ModelBuilder::new(), path)
+}
+
+/// Parse a fixture and build its model with a configured builder, resolving its includes
+/// relative to the fixture
+fn build_model_with(builder: &ModelBuilder, path: &str) -> ConfigModel {
+    let content = fs::read_to_string(path).expect("Failed to read test file");
+    let builder = ModelBuilder { file_path: PathBuf::from(path), ..builder.clone() };
+    builder.build_from_str(&content).expect("Failed to build model")
+}
+
+/// The output location under the system temp dir for a test
+fn output_path(test_name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("colap-{}-{}", test_name, std::process::id()))
+}
+
+/// The output location for a test, cleared of any previous run
+fn fresh_output_path(test_name: &str) -> PathBuf {
+    let dir = output_path(test_name);
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+/// Generator options for tests: the generated code is always checked to parse as Rust
+fn verified_options() -> GeneratorOptions {
+    GeneratorOptions {
+        verify_syntax: true,
+        ..GeneratorOptions::default()
+    }
+}
+
+/// Generate a module file for a fixture, letting the caller configure the generator
+fn generate_module_with(
+    path: &str,
+    test_name: &str,
+    configure: impl FnOnce(CodeGenerator) -> CodeGenerator,
+) -> String {
+    generate_model_module(build_model(path), path, test_name, configure)
+}
+
+/// Generate a module file for an already built model and return its content
+fn generate_model_module(
+    model: ConfigModel,
+    path: &str,
+    test_name: &str,
+    configure: impl FnOnce(CodeGenerator) -> CodeGenerator,
+) -> String {
+    let output_file = fresh_output_path(test_name).join("config.rs");
+    let generator = CodeGenerator::new(
+        model,
+        GenerationMode::Module {
+            output_file: output_file.clone(),
+        },
+        PathBuf::from(path),
+    )
+    .expect("Failed to create generator")
+    .with_options(verified_options());
+    configure(generator).generate().expect("Failed to generate code");
+    fs::read_to_string(output_file).expect("Failed to read generated module")
+}
+
+/// Generate a crate for a fixture, letting the caller configure the generator,
+/// and return the crate directory
+fn generate_crate_with(
+    path: &str,
+    test_name: &str,
+    configure: impl FnOnce(CodeGenerator) -> CodeGenerator,
+) -> PathBuf {
+    let output_dir = fresh_output_path(test_name).join("config");
+    let generator = CodeGenerator::new(
+        build_model(path),
+        GenerationMode::Crate {
+            output_dir: output_dir.clone(),
+            crate_name: "test-config".to_string(),
+        },
+        PathBuf::from(path),
+    )
+    .expect("Failed to create generator")
+    .with_options(verified_options());
+    configure(generator).generate().expect("Failed to generate crate");
+    output_dir
+}
+
+/// Run the tests of a generated crate against this checkout of colap, offline and in a target
+/// directory shared by every generated crate
+fn cargo_test_generated(crate_dir: &Path) {
+    let output = std::process::Command::new(env!("CARGO"))
+        .args(["test", "--offline", "--quiet"])
+        .current_dir(crate_dir)
+        .env("CARGO_TARGET_DIR", Path::new(env!("CARGO_TARGET_TMPDIR")).join("generated"))
+        .output()
+        .expect("Failed to run cargo");
+    assert!(
+        output.status.success(),
+        "Tests of {} failed:\n{}{}",
+        crate_dir.display(),
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+/// The colap dependency of a generated crate that is built in a test
+fn this_colap() -> ColapDependency {
+    ColapDependency::Path(env!("CARGO_MANIFEST_DIR").to_string())
+}
+
+/// Read a generated file relative to a crate directory
+fn read_generated(crate_dir: &Path, file: &str) -> String {
+    fs::read_to_string(crate_dir.join(file)).expect("Failed to read generated file")
+}
+
+#[test]
+fn test_post_processor_applied_to_output() {
+    let content = generate_module_with("tests/data/test_genite.md", "post-processor", |g| {
+        g.with_post_processor(Box::new(|code| format!("{}// processed by test\n", code)))
+    });
+    assert!(content.ends_with("// processed by test\n"));
+    assert!(content.contains("pub struct Root"));
+}
+
+#[test]
+fn test_duration_fields_generate_std_durations() {
+    let builder = ModelBuilder {
+        unit_suffixes: true,
+        ..ModelBuilder::new()
+    };
+    let model = build_model_with(&builder, "tests/data/test_units.md");
+    let options = GeneratorOptions {
+        unit_suffixes: true,
+        ..verified_options()
+    };
+    let content = generate_model_module(model, "tests/data/test_units.md", "units", |g| g.with_options(options));
+    assert!(content.contains("pub timeout: std::time::Duration,"));
+    assert!(content.contains("pub fn timeout(&self) -> std::time::Duration {"));
+    assert!(content.contains("result.timeout = std::time::Duration::from_millis(millis);"));
+    assert!(content.contains("pub every: std::time::Duration,"));
+    // Byte sizes stay plain integers
+    assert!(content.contains("pub cache_size: i64,"));
+    // The generated tests read the configuration with unit suffixes too
+    assert!(content.contains("let builder = ModelBuilder { unit_suffixes: true, ..ModelBuilder::new() };"));
+}
+
+#[test]
+fn test_type_map_overrides_field_types() {
+    let type_map = TypeMap::load(Path::new("tests/data/test_type_map.toml")).expect("Failed to load type map");
+    let content = generate_module_with("tests/data/test_type_map.md", "type-map", |g| {
+        g.with_type_map(type_map.clone()).expect("Type map does not fit")
+    });
+    assert!(content.contains("use url::Url;"));
+    assert!(content.contains("    pub api_url: Url,\n"));
+    // Name patterns also match the fields of plural instances
+    assert!(content.contains("    pub callback_url: Url,\n"));
+    assert!(content.contains("    pub port: u16,\n"));
+    assert!(content.contains("if let Ok(v) = s.parse::<Url>() {"));
+    assert!(content.contains("check_parsed::<Url>(ent.fields.get(\"api_url\"), \"Url\", true"));
+    assert!(content.contains("ConfigValue::String(self.api_url.to_string())"));
+    assert!(content.contains("(\"service.api_url\", \"Url\"),"));
+
+    let output_dir = generate_crate_with("tests/data/test_type_map.md", "type-map-crate", |g| {
+        g.with_type_map(type_map).expect("Type map does not fit")
+    });
+    let cargo_toml = fs::read_to_string(output_dir.join("Cargo.toml")).unwrap();
+    assert!(cargo_toml.contains("\nurl = \"2\"\n"));
+}
+
+#[test]
+fn test_type_map_rejects_unknown_types_and_mismatched_values() {
+    let error = TypeMap::from_toml_str("[types]\n\"*_url\" = \"Url\"\n").unwrap_err();
+    assert!(error.to_string().contains("add its `use` path to `imports`"), "{}", error);
+
+    let type_map = TypeMap::from_toml_str("[types]\n\"*_url\" = \"u16\"\n").unwrap();
+    let generator = CodeGenerator::new(
+        build_model("tests/data/test_type_map.md"),
+        GenerationMode::Module { output_file: output_path("type-map-mismatch").join("config.rs") },
+        PathBuf::from("tests/data/test_type_map.md"),
+    )
+    .unwrap();
+    let error = generator.with_type_map(type_map).err().expect("A string field was mapped to u16");
+    assert!(error.to_string().contains("maps field 'service/api_url' to u16"), "{}", error);
+}
+
+#[test]
+fn test_repeated_field_generates_slice_accessor() {
+    let mut builder = ModelBuilder::new();
+    builder.repeatable_fields = true;
+    let model = build_model_with(&builder, "tests/data/test_repeated.md");
+    let content = generate_model_module(model, "tests/data/test_repeated.md", "repeated", |g| g);
+    assert!(content.contains("pub tag: Vec<String>,"));
+    assert!(content.contains("pub fn tag(&self) -> &[String] {"));
+    assert!(content.contains("ConfigValue::Array(items)"));
+}
+
+#[test]
+fn test_emit_clap_generates_args_and_overlay_test() {
+    let options = GeneratorOptions {
+        emit_clap: true,
+        ..verified_options()
+    };
+    let crate_dir =
+        generate_crate_with("tests/data/test_service.md", "emit-clap", |g| g.with_options(options));
+
+    let lib = read_generated(&crate_dir, "src/lib.rs");
+    assert!(lib.contains("pub struct RootArgs"));
+    assert!(lib.contains("#[arg(long = \"service-database-url\")]"));
+    assert!(lib.contains("root.service.database.url = value.clone();"));
+
+    let cargo_toml = read_generated(&crate_dir, "Cargo.toml");
+    assert!(cargo_toml.contains("clap = { version = \"4\", features = [\"derive\"] }"));
+
+    let tests = read_generated(&crate_dir, "tests/integration.rs");
+    assert!(tests.contains("fn test_clap_args_overlay()"));
+    assert!(tests.contains("args.apply(&mut config);"));
+}
+
+#[test]
+fn test_clap_args_not_emitted_by_default() {
+    let crate_dir = generate_crate_with("tests/data/test_service.md", "no-clap", |g| g);
+    assert!(!read_generated(&crate_dir, "src/lib.rs").contains("RootArgs"));
+    assert!(!read_generated(&crate_dir, "Cargo.toml").contains("clap"));
+}
+
+#[test]
+fn test_derive_serde_adds_derives_renames_and_json_test() {
+    let options = GeneratorOptions {
+        derive_serde: true,
+        ..verified_options()
+    };
+    let crate_dir =
+        generate_crate_with("tests/data/test_genite.md", "derive-serde", |g| g.with_options(options));
+
+    let lib = read_generated(&crate_dir, "src/lib.rs");
+    assert!(lib.contains("#[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]\npub struct Root"));
+    assert!(lib.contains("#[serde(from = \"HashMap<String, Llm>\", into = \"HashMap<String, Llm>\")]\npub struct Llms"));
+    assert!(lib.contains("    #[serde(rename = \"type\")]\n    pub type_: Option<String>,"));
+    assert!(!lib.contains("#[derive(Debug, Clone, Default)]"));
+
+    let cargo_toml = read_generated(&crate_dir, "Cargo.toml");
+    assert!(cargo_toml.contains("serde = { version = \"1\", features = [\"derive\"] }"));
+    assert!(cargo_toml.contains("serde_json = \"1\""));
+
+    let tests = read_generated(&crate_dir, "tests/integration.rs");
+    assert!(tests.contains("fn test_serde_json_round_trip()"));
+
+    let crate_dir = generate_crate_with("tests/data/test_genite.md", "no-serde", |g| g);
+    assert!(!read_generated(&crate_dir, "src/lib.rs").contains("serde"));
+    assert!(!read_generated(&crate_dir, "Cargo.toml").contains("serde"));
+}
+
+#[test]
+fn test_module_tests_embed_relative_source_path() {
+    let content = generate_module_with("tests/data/test_service.md", "relative-path", |g| g);
+
+    let marker = "include_str!(\"";
+    let start = content.find(marker).expect("module tests should embed the config") + marker.len();
+    let embedded = &content[start..start + content[start..].find('"').unwrap()];
+
+    assert!(!Path::new(embedded).is_absolute(), "embedded path is absolute: {}", embedded);
+    assert!(!embedded.contains('\\'), "embedded path is not forward-slashed: {}", embedded);
+
+    // The path resolves from the generated module's directory back to the fixture
+    let module_dir = output_path("relative-path");
+    let resolved = fs::canonicalize(module_dir.join(embedded)).unwrap();
+    assert_eq!(resolved, fs::canonicalize("tests/data/test_service.md").unwrap());
+}
+
+#[test]
+fn test_keep_extras_captures_unknown_fields() {
+    let options = GeneratorOptions {
+        keep_extras: true,
+        ..verified_options()
+    };
+    let crate_dir =
+        generate_crate_with("tests/data/test_extras.md", "keep-extras", |g| g.with_options(options));
+
+    // The singular struct is modelled on the first instance, so `reasoning_effort` is an extra
+    let lib = read_generated(&crate_dir, "src/lib.rs");
+    assert!(lib.contains("pub extras: HashMap<String, colap::model::config_model::ConfigValue>,"));
+    assert!(lib.contains("pub fn get_extra(&self, name: &str)"));
+    assert!(lib.contains("let known: &[&str] = &[\"name\"];"));
+    assert!(!lib.contains("pub reasoning_effort"));
+    assert!(!lib.contains("fn reasoning_effort"));
+
+    let tests = read_generated(&crate_dir, "tests/integration.rs");
+    assert!(tests.contains("config.get_extra(\"unknown_field\")"));
+}
+
+#[test]
+fn test_config_error_and_fallible_loaders_emitted() {
+    let crate_dir = generate_crate_with("tests/data/test_service.md", "config-error", |g| g);
+
+    let lib = read_generated(&crate_dir, "src/lib.rs");
+    assert!(lib.contains("pub enum ConfigError {"));
+    assert!(lib.contains("TypeMismatch { path: String, expected: &'static str },"));
+    assert!(lib.contains("impl std::error::Error for ConfigError {}"));
+    assert!(lib.contains("pub fn load_from_str(content: &str) -> Result<Self, ConfigError>"));
+    assert!(lib.contains("model.find_child_entity_by_name(id, \"database\")"));
+    assert!(lib.contains("check_field(ent.fields.get(\"port\"), \"i64\", true, config_path(path, \"port\"))?;"));
+
+    let tests = read_generated(&crate_dir, "tests/integration.rs");
+    assert!(tests.contains("use test_config::{ConfigError, Root, Visitor, CONFIG_SCHEMA_HASH, PATHS, TYPES};"));
+    assert!(tests.contains("Err(ConfigError::MissingField { path })"));
+}
+
+#[test]
+fn test_load_from_file_emitted_in_crate_mode_only() {
+    let crate_dir = generate_crate_with("tests/data/test_service.md", "load-from-file", |g| g);
+
+    let lib = read_generated(&crate_dir, "src/lib.rs");
+    assert!(lib.contains("pub fn load_from_file(path: &std::path::Path) -> anyhow::Result<Self>"));
+    assert!(read_generated(&crate_dir, "Cargo.toml").contains("anyhow = \"1\"\n"));
+    assert!(read_generated(&crate_dir, "tests/integration.rs").contains("fn test_load_from_file()"));
+
+    let content = generate_module_with("tests/data/test_service.md", "load-from-file-module", |g| g);
+    assert!(!content.contains("load_from_file"));
+    assert!(content.contains("pub fn load_from_str(content: &str) -> Result<Self, ConfigError>"));
+}
+
+#[test]
+fn test_generated_loader_expands_repeats() {
+    let crate_dir =
+        generate_crate_with("tests/data/test_repeat.md", "repeat-crate", |g| g.with_colap_dependency(this_colap()));
+    assert!(read_generated(&crate_dir, "src/lib.rs").contains(".build_from_str(content)"));
+    cargo_test_generated(&crate_dir);
+}
+
+#[test]
+fn test_generated_loader_collects_repeated_fields() {
+    let builder = ModelBuilder {
+        repeatable_fields: true,
+        ..ModelBuilder::new()
+    };
+    let output_dir = fresh_output_path("repeated-crate").join("config");
+    let options = GeneratorOptions {
+        repeatable_fields: true,
+        ..verified_options()
+    };
+    CodeGenerator::new(
+        build_model_with(&builder, "tests/data/test_repeated.md"),
+        GenerationMode::Crate {
+            output_dir: output_dir.clone(),
+            crate_name: "test-config".to_string(),
+        },
+        PathBuf::from("tests/data/test_repeated.md"),
+    )
+    .expect("Failed to create generator")
+    .with_options(options)
+    .with_colap_dependency(this_colap())
+    .generate()
+    .expect("Failed to generate crate");
+    assert!(read_generated(&output_dir, "src/lib.rs").contains("            repeatable_fields: true,\n"));
+    cargo_test_generated(&output_dir);
+}
+
+#[test]
+fn test_generated_crate_reads_included_files() {
+    let crate_dir =
+        generate_crate_with("tests/data/test_include.md", "include-crate", |g| g.with_colap_dependency(this_colap()));
+    let config = read_generated(&crate_dir, "tests/data/config.md");
+    assert!(!config.contains("include "));
+    assert!(config.contains("max_connections: 64"));
+    cargo_test_generated(&crate_dir);
+}
+
+#[test]
+fn test_from_path_emitted_for_entity_structs() {
+    let content = generate_module_with("tests/data/test_genite.md", "from-path", |g| g);
+
+    let from_path = "pub fn from_path(model: &colap::model::config_model::ConfigModel, path: &str) -> Option<Self>";
+    for struct_name in ["Root", "Llm", "Api", "Model"] {
+        let header = format!("impl {} {{\n", struct_name);
+        let has_from_path = content.match_indices(&header).any(|(start, _)| {
+            let end = content[start..].find("\n}\n").map_or(content.len(), |n| start + n);
+            content[start..end].contains(from_path)
+        });
+        assert!(has_from_path, "{}", struct_name);
+    }
+    assert!(content.contains("assert!(Root::from_path(&model, \"no/such/entity\").is_none());"));
+}
+
+#[test]
+fn test_try_from_entity_emitted_for_every_struct() {
+    let content = generate_module_with("tests/data/test_genite.md", "try-from-entity", |g| g);
+
+    let try_from_entity = "pub fn try_from_entity(model: &colap::model::config_model::ConfigModel, id: usize) -> Result<Self, ConfigError>";
+    assert_eq!(
+        content.matches(try_from_entity).count(),
+        content.matches("pub fn from_entity(").count()
+    );
+    assert!(content.contains("MissingEntity { id: usize },"));
+    assert!(content.contains("WrongNodeType { id: usize },"));
+    assert!(content.contains("fn test_try_from_entity()"));
+}
+
+#[test]
+fn test_flatten_singletons_types_single_instances_as_their_struct() {
+    let collections = generate_module_with("tests/data/test_singletons.md", "singletons", |g| g);
+    let options = GeneratorOptions { flatten_singletons: true, ..Default::default() };
+    let flattened =
+        generate_module_with("tests/data/test_singletons.md", "singletons-flat", |g| g.with_options(options));
+
+    // The only database is typed as its instance, so its collection struct is gone
+    assert!(collections.contains("pub databases: Databases,"));
+    assert!(collections.contains("pub struct Databases {"));
+    assert!(flattened.contains("pub database: Database,"));
+    assert!(!flattened.contains("pub struct Databases {"));
+    assert!(flattened.contains("first_instance(model, child_id)).map(|instance_id| Database::from_entity(model, instance_id))"));
+    assert!(flattened.contains("self.database.to_model_node(model, plural_id, \"primary\");"));
+    assert!(!collections.contains("fn first_instance("));
+
+    // Several workers, and a keyed region, stay collections either way
+    for content in [&collections, &flattened] {
+        assert!(content.contains("pub workers: Workers,"));
+        assert!(content.contains("pub regions: Regions,"));
+    }
+}
+
+#[test]
+fn test_identical_singular_and_plural_names_are_disambiguated() {
+    let content = generate_module_with("tests/data/test_same_plural.md", "same-plural", |g| g);
+
+    // The collection keeps the shared name and the entry struct gets an `Item` suffix
+    assert_eq!(content.matches("pub struct Servers {").count(), 1);
+    assert!(content.contains("pub struct ServersItem {"));
+    assert!(content.contains("map: HashMap<String, ServersItem>,"));
+    assert!(content.contains("pub servers: Servers,"));
+}
+
+#[test]
+fn test_inline_plural_generates_a_collection() {
+    let content = generate_module_with("tests/data/test_inline_plural.md", "inline-plural", |g| g);
+
+    assert!(content.contains("pub models: Models,"));
+    assert!(content.contains("map: HashMap<String, ModelsItem>,"));
+    assert!(content.contains("pub regions: Regions,"));
+}
+
+#[test]
+fn test_names_that_are_not_snake_case_are_looked_up_exactly() {
+    let content = generate_module_with("tests/data/test_mixed_case.md", "mixed-case", |g| g);
+
+    assert!(content.contains("pub max_tokens: i64,"));
+    assert!(content.contains("ent.fields.get(\"Max-Tokens\")"));
+    assert!(content.contains("ent.fields.get(\"topP\")"));
+    assert!(content.contains("result.open_ai = model.find_child_entity_by_name(id, \"open-ai\")"));
+    assert!(!content.contains("\"open_ai\""));
+}
+
+#[test]
+fn test_schema_hash_constant_embeds_model_fingerprint() {
+    let fingerprint = build_model("tests/data/test_service.md").fingerprint();
+    let crate_dir = generate_crate_with("tests/data/test_service.md", "schema-hash", |g| g);
+
+    let lib = read_generated(&crate_dir, "src/lib.rs");
+    assert!(lib.contains(&format!("pub const CONFIG_SCHEMA_HASH: u64 = {};", fingerprint)));
+    assert!(lib.contains("pub fn assert_compatible(model_hash: u64) -> Result<(), ConfigError>"));
+
+    let tests = read_generated(&crate_dir, "tests/integration.rs");
+    assert!(tests.contains("assert_eq!(model.fingerprint(), CONFIG_SCHEMA_HASH);"));
+    assert!(tests.contains("Err(ConfigError::SchemaMismatch { expected, found })"));
+}
+
+#[test]
+fn test_emit_index_generates_handle_and_index_test() {
+    let options = GeneratorOptions {
+        emit_index: true,
+        ..verified_options()
+    };
+    let crate_dir =
+        generate_crate_with("tests/data/test_genite.md", "emit-index", |g| g.with_options(options));
+
+    let lib = read_generated(&crate_dir, "src/lib.rs");
+    assert!(lib.contains("pub enum ConfigHandle {"));
+    assert!(lib.contains("impl std::ops::Index<&str> for Root {"));
+    assert_eq!(lib.matches("config_handle: ConfigHandle,").count(), 1);
+
+    let tests = read_generated(&crate_dir, "tests/integration.rs");
+    assert!(tests.contains("&config[\"llm\"][\"openai\"][\"api\"][\"base_url\"];"));
+    assert!(tests.contains("Some(\"\\\"some_base_url\\\"\".to_string())"));
+}
+
+#[test]
+fn test_from_model_conversion_emitted() {
+    let content = generate_module_with("tests/data/test_service.md", "from-model", |g| g);
+    assert!(content.contains("impl From<&colap::model::config_model::ConfigModel> for Root {"));
+    assert!(content.contains("let config: Root = (&model).into();"));
+
+    let crate_dir = generate_crate_with("tests/data/test_service.md", "from-model", |g| g);
+    let lib = read_generated(&crate_dir, "src/lib.rs");
+    assert_eq!(lib.matches("impl From<&colap::model::config_model::ConfigModel> for").count(), 1);
+    let tests = read_generated(&crate_dir, "tests/integration.rs");
+    assert!(tests.contains("let config: Root = (&model).into();"));
+}
+
+#[test]
+fn test_index_not_emitted_by_default() {
+    let content = generate_module_with("tests/data/test_genite.md", "no-index", |g| g);
+    assert!(!content.contains("ConfigHandle"));
+}
+
+#[test]
+fn test_to_model_conversion_emitted() {
+    let crate_dir = generate_crate_with("tests/data/test_genite.md", "to-model", |g| g);
+
+    let lib = read_generated(&crate_dir, "src/lib.rs");
+    assert!(lib.contains("impl From<&Root> for colap::model::config_model::ConfigModel {"));
+    assert!(lib.contains("pub fn to_model(&self) -> colap::model::config_model::ConfigModel {"));
+    assert!(lib.contains("self.llms.to_model_node(model, id, \"llm\", \"llms\");"));
+    assert!(lib.contains("self.models.to_model_node(model, id, \"model\", \"models\");"));
+
+    // Plural fields load from the entries of the plural entity, not from its parent
+    assert!(lib.contains(
+        "result.llms = model.find_child_entity_by_name(id, \"llm\").map(|child_id| Llms::from_children(model, child_id)).unwrap_or_default();"
+    ));
+
+    let tests = read_generated(&crate_dir, "tests/integration.rs");
+    assert!(tests.contains("let reparsed = parse_model_str(&model.to_cola());"));
+}
+
+#[test]
+fn test_declared_optional_field_is_wrapped_in_option() {
+    let content = generate_module_with("tests/data/test_optional.md", "optional-declared", |g| g);
+
+    assert!(content.contains("pub timeout: Option<i64>,"));
+    assert!(content.contains("pub fn timeout(&self) -> Option<i64> {"));
+    assert!(content.contains("result.timeout = Some(*i);"));
+    assert!(content.contains("pub name: String,"));
+    // Entity fields stay required regardless of their name
+    assert!(content.contains("pub database: Database,"));
+    assert!(content.contains("pub backends: Backends,"));
+}
+
+#[test]
+fn test_field_absent_from_a_plural_instance_is_optional() {
+    let content = generate_module_with("tests/data/test_optional.md", "optional-plural", |g| g);
+
+    assert!(content.contains("pub weight: Option<i64>,"));
+    assert!(content.contains("pub host: String,"));
+}
+
+#[test]
+fn test_array_literal_fields_map_to_vec() {
+    let content = generate_module_with("tests/data/test_arrays.md", "arrays", |g| g);
+
+    assert!(content.contains("pub stop_sequences: Vec<String>,"));
+    assert!(content.contains("pub retry_delays: Vec<i64>,"));
+    assert!(content.contains("pub weights: Vec<f64>,"));
+    // Empty arrays have no element to infer from
+    assert!(content.contains("pub tags: Vec<String>,"));
+}
+
+#[test]
+fn test_null_field_generates_optional_string() {
+    let content = generate_module_with("tests/data/test_null.md", "null", |g| g);
+
+    assert!(content.contains("pub proxy: Option<String>,"));
+    assert!(content.contains("pub user_agent: Option<String>,"));
+    assert!(content.contains("pub nullable_port: i64,"));
+}
+
+#[test]
+fn test_keyed_plural_collection_uses_key_field() {
+    let content = generate_module_with("tests/data/test_keyed.md", "keyed", |g| g);
+
+    assert!(content.contains("/// Get the entry whose `id` field is `key`"));
+    assert!(content.contains("let name = match _entry.fields.get(\"id\") {"));
+
+    // Without a key the collection stays keyed by entity name
+    let content = generate_module_with("tests/data/test_genite.md", "unkeyed", |g| g);
+    assert!(!content.contains("_entry.fields.get("));
+}
+
+#[test]
+fn test_required_fields_generate_validate_checks() {
+    let content = generate_module_with("tests/data/test_required.md", "required", |g| g);
+
+    assert!(content.contains("pub fn validate(&self) -> Result<(), Vec<String>> {"));
+    assert!(content.contains("if self.owner.is_empty() {"));
+    assert!(content.contains("if self.model.is_empty() {"));
+    assert!(!content.contains("if self.port"));
+    assert!(content.contains("(\"key\", &self.key, true),"));
+    assert!(content.contains("(\"base_url\", &self.base_url, false),"));
+    assert!(content.contains("self.llms.missing_required_into(&value_path(path, \"llm\"), missing);"));
+    assert!(content.contains("self.api.missing_required_into(&value_path(path, \"api\"), missing);"));
+}
+
+#[test]
+fn test_missing_required_lists_paths_without_failing() {
+    let crate_dir = generate_crate_with("tests/data/test_required.md", "missing-required", |g| g);
+    let lib = read_generated(&crate_dir, "src/lib.rs");
+    assert!(lib.contains("pub fn missing_required(&self) -> Vec<String> {"));
+    assert!(lib.contains("            missing.push(value_path(path, \"owner\"));\n"));
+
+    // The generated test checks a required field left empty in the fixture
+    let tests = read_generated(&crate_dir, "tests/integration.rs");
+    assert!(tests.contains("assert!(missing.contains(&\"service.owner\".to_string())"));
+}
+
+#[test]
+fn test_dry_run_renders_without_writing() {
+    let output_dir = fresh_output_path("dry-run").join("config");
+    let mut generator = CodeGenerator::new(
+        build_model("tests/data/test_genite.md"),
+        GenerationMode::Crate {
+            output_dir: output_dir.clone(),
+            crate_name: "test-config".to_string(),
+        },
+        PathBuf::from("tests/data/test_genite.md"),
+    )
+    .expect("Failed to create generator")
+    .with_options(verified_options())
+    .with_dry_run(true);
+    generator.generate().expect("Failed to generate crate");
+    assert!(!output_dir.exists());
+
+    let files: Vec<PathBuf> = generator.written_files().iter().map(|(path, _)| path.clone()).collect();
+    for file in ["Cargo.toml", "src/lib.rs", "tests/data/config.md", "tests/integration.rs", "README.md"] {
+        assert!(files.contains(&output_dir.join(file)), "{} not listed", file);
+    }
+    assert!(generator.written_files().iter().all(|(_, size)| *size > 0));
+}
+
+#[test]
+fn test_cow_getter_style() {
+    let cow = |g: CodeGenerator| {
+        g.with_options(GeneratorOptions {
+            cow_getters: true,
+            ..verified_options()
+        })
+    };
+    let content = generate_module_with("tests/data/test_optional.md", "cow-getters", cow);
+    assert!(content.contains("pub fn name(&self) -> std::borrow::Cow<'_, str> {\n        std::borrow::Cow::Borrowed(&*self.name)"));
+    assert!(content.contains("pub fn timeout(&self) -> Option<std::borrow::Cow<'_, i64>> {\n        self.timeout.as_ref().map(std::borrow::Cow::Borrowed)"));
+    // Entity getters still borrow the child struct
+    assert!(content.contains("pub fn database(&self) -> &Database {"));
+    assert!(content.contains("fn test_cow_getters_borrow_from_the_config()"));
+    assert!(content.contains("let value = config.service().name();"));
+
+    let content = generate_module_with("tests/data/test_arrays.md", "cow-getters-arrays", cow);
+    assert!(content.contains("pub fn retry_delays(&self) -> std::borrow::Cow<'_, [i64]> {"));
+
+    let content = generate_module_with("tests/data/test_optional.md", "borrow-getters", |g| g);
+    assert!(content.contains("pub fn name(&self) -> &str {"));
+    assert!(!content.contains("std::borrow::Cow"));
+}
+
+#[test]
+fn test_serde_defaults_provide_configured_values() {
+    let options = GeneratorOptions {
+        derive_serde: true,
+        serde_defaults: true,
+        ..verified_options()
+    };
+    let crate_dir = generate_crate_with("tests/data/test_optional.md", "serde-defaults", |g| g.with_options(options));
+    let content = read_generated(&crate_dir, "src/lib.rs");
+    assert!(content.contains("    #[serde(default = \"Service::serde_default_name\")]\n    pub name: String,"));
+    assert!(content.contains("    fn serde_default_name() -> String {\n        \"gateway\".to_string()\n    }"));
+    assert!(content.contains("    fn serde_default_url() -> String {"));
+    // Optional fields already deserialize to `None` when missing
+    assert!(!content.contains("serde_default_timeout"));
+    // Instances of plural entities share a struct, so no single value is the default
+    assert!(!content.contains("serde_default_host"));
+    let tests = read_generated(&crate_dir, "tests/integration.rs");
+    assert!(tests.contains("fn test_serde_default_applies_the_configured_value()"));
+
+    // Without the serde derives there is nothing to attach the defaults to
+    let options = GeneratorOptions {
+        serde_defaults: true,
+        ..verified_options()
+    };
+    let content = generate_module_with("tests/data/test_optional.md", "serde-defaults-no-serde", |g| g.with_options(options));
+    assert!(!content.contains("serde_default_"));
+}
+
+#[test]
+fn test_config_eq_is_generated_for_every_struct() {
+    let content = generate_module_with("tests/data/test_optional.md", "config-eq", |g| g);
+
+    assert!(content.contains("        self.name.split_whitespace().eq(other.name.split_whitespace())\n"));
+    assert!(content.contains(
+        "self.timeout.as_ref().zip(other.timeout.as_ref()).map_or(self.timeout.is_none() && other.timeout.is_none(), |(a, b)| a == b)"
+    ));
+    assert!(content.contains("            && self.database.config_eq(&other.database)\n"));
+    // Collections compare their entries by key, whatever their order
+    assert!(content.contains("other.map.get(key).is_some_and(|other| entry.config_eq(other))"));
+    assert!(content.contains("fn test_config_eq_ignores_field_order()"));
+}
+
+#[test]
+fn test_derive_partialeq() {
+    let options = GeneratorOptions {
+        derive_partialeq: true,
+        emit_index: true,
+        ..verified_options()
+    };
+    let content = generate_module_with("tests/data/test_service.md", "derive-partialeq", |g| g.with_options(options));
+    assert!(content.contains("#[derive(Debug, Clone, Default, PartialEq)]\npub struct Root {"));
+    assert!(content.contains("#[derive(Debug, Clone, PartialEq)]\npub enum ConfigHandle {"));
+    assert!(!content.contains(", Eq"));
+    assert!(content.contains("assert_eq!(Root::from_model(&model), Root::from_model(&model));"));
+
+    let content = generate_module_with("tests/data/test_service.md", "no-partialeq", |g| g);
+    assert!(!content.contains("PartialEq)]\npub struct"));
+}
+
+#[test]
+fn test_generated_tests_round_trip_the_config() {
+    let content = generate_module_with("tests/data/test_keyed.md", "round-trip", |g| g.with_options(verified_options()));
+    assert!(content.contains("fn test_config_round_trips_through_cola()"));
+    assert!(content.contains("let reloaded = parse_config_str(&cola);"));
+    assert!(content.contains("assert!(config.config_eq(&reloaded)"));
+    // Presence is checked for the plural entities of the config, not a fixed placeholder
+    assert!(content.contains("assert!(config.models().count() > 0"));
+    assert!(!content.contains("Llms"));
+}
+
+#[test]
+fn test_impl_display() {
+    let options = GeneratorOptions {
+        impl_display: true,
+        ..verified_options()
+    };
+    let content = generate_module_with("tests/data/test_genite.md", "impl-display", |g| g.with_options(options));
+    assert!(content.contains("impl std::fmt::Display for Root {"));
+    assert!(content.contains("impl std::fmt::Display for Llms {"));
+    assert!(content.contains("impl std::fmt::Display for Api {"));
+    assert!(content.contains("fn tree_line("));
+    // Collections label their node with the number of entries
+    assert!(content.contains("format!(\"{} ({})\", name, self.map.len())"));
+    assert!(content.contains("self.llms.fmt_tree_node(f, prefix, true, \"llms\")?;"));
+    assert!(content.contains("fn test_display_renders_a_tree()"));
+
+    let content = generate_module_with("tests/data/test_genite.md", "no-display", |g| g);
+    assert!(!content.contains("impl std::fmt::Display for Root"));
+    assert!(!content.contains("fn tree_line("));
+}
+
+#[test]
+fn test_schema_only_fields_are_typed_by_their_declaration() {
+    let content = generate_module_with("tests/data/test_schema_only.md", "schema-only", |g| g.with_options(verified_options()));
+    assert!(content.contains("    pub max_tokens: i64,\n"));
+    assert!(content.contains("    pub temperature: f64,\n"));
+    assert!(content.contains("    pub model: String,\n"));
+    assert!(content.contains("    pub stream: bool,\n"));
+    assert!(content.contains("    pub timeout: u16,\n"));
+}
+
+#[test]
+fn test_heterogeneous_arrays_use_the_scalar_enum() {
+    let heterogeneous = |g: CodeGenerator| {
+        g.with_options(GeneratorOptions {
+            heterogeneous_arrays: true,
+            ..verified_options()
+        })
+    };
+    let content = generate_module_with("tests/data/test_mixed_arrays.md", "heterogeneous-arrays", heterogeneous);
+    assert!(content.contains("pub enum ConfigScalar {"));
+    assert!(content.contains("    pub steps: Vec<ConfigScalar>,\n"));
+    assert!(content.contains("result.steps = items.iter().filter_map(ConfigScalar::from_value).collect();"));
+    // Uniform arrays keep their element type
+    assert!(content.contains("    pub labels: Vec<String>,\n"));
+    assert!(content.contains("    pub limits: Vec<i64>,\n"));
+
+    // Without the option mixed arrays keep their strings, and no enum is emitted
+    let content = generate_module_with("tests/data/test_mixed_arrays.md", "mixed-arrays", |g| g);
+    assert!(content.contains("    pub steps: Vec<String>,\n"));
+    assert!(!content.contains("ConfigScalar"));
+    let content = generate_module_with("tests/data/test_arrays.md", "uniform-arrays", heterogeneous);
+    assert!(!content.contains("ConfigScalar"));
+}
+
+#[test]
+fn test_colap_dependency_is_a_path_or_a_version() {
+    let crate_dir = generate_crate_with("tests/data/test_service.md", "colap-default", |g| g);
+    assert!(read_generated(&crate_dir, "Cargo.toml").contains("colap = { path = \"../colap\" }\n"));
+
+    let crate_dir = generate_crate_with("tests/data/test_service.md", "colap-path", |g| {
+        g.with_colap_dependency(ColapDependency::Path("/opt/src/colap".to_string()))
+    });
+    assert!(read_generated(&crate_dir, "Cargo.toml").contains("colap = { path = \"/opt/src/colap\" }\n"));
+
+    let crate_dir = generate_crate_with("tests/data/test_service.md", "colap-version", |g| {
+        g.with_colap_dependency(ColapDependency::Version("0.1".to_string()))
+    });
+    let cargo_toml = read_generated(&crate_dir, "Cargo.toml");
+    assert!(cargo_toml.contains("colap = \"0.1\"\n"));
+    assert!(!cargo_toml.contains("path ="));
+}
+
+#[test]
+fn test_paths_and_types_list_every_field() {
+    let content = generate_module_with("tests/data/test_service.md", "paths", |g| g);
+    assert!(content.contains(
+        "pub const PATHS: &[&str] = &[\n    \"service.debug\",\n    \"service.name\",\n    \"service.port\",\n    \
+         \"service/database.pool_size\",\n    \"service/database.url\",\n];\n"
+    ));
+    assert!(content.contains("    (\"service/database.pool_size\", \"i64\"),\n"));
+
+    // Optional fields, and fields missing from another instance, are typed as their struct fields
+    let content = generate_module_with("tests/data/test_optional.md", "paths-optional", |g| g);
+    assert!(content.contains("    (\"service.timeout\", \"Option<i64>\"),\n"));
+    assert!(content.contains("    (\"service/backend/primary.weight\", \"Option<i64>\"),\n"));
+    assert!(content.contains("    (\"service/backend/primary.host\", \"String\"),\n"));
+
+    // The generated test compares the paths with the flattened model
+    let crate_dir = generate_crate_with("tests/data/test_service.md", "paths-test", |g| g);
+    let tests = read_generated(&crate_dir, "tests/integration.rs");
+    assert!(tests.contains("    assert!(PATHS.contains(&\"service.debug\"));\n"));
+}
+
+/// Dump a fixture's model as YAML and return the text
+fn generate_yaml(path: &str, test_name: &str) -> String {
+    let output_file = fresh_output_path(test_name).join("config.yaml");
+    CodeGenerator::new(
+        build_model(path),
+        GenerationMode::Yaml {
+            output_file: output_file.clone(),
+        },
+        PathBuf::from(path),
+    )
+    .expect("Failed to create generator")
+    .generate()
+    .expect("Failed to write YAML");
+    fs::read_to_string(output_file).expect("Failed to read YAML")
+}
+
+#[test]
+fn test_yaml_mode_nests_entities() {
+    let yaml = generate_yaml("tests/data/test_service.md", "yaml-service");
+    assert_eq!(
+        yaml,
+        "service:\n  debug: false\n  name: \"gateway\"\n  port: 8080\n  \
+         database:\n    pool_size: 10\n    url: \"postgres://localhost/app\"\n"
+    );
+}
+
+#[test]
+fn test_yaml_mode_lists_plural_instances() {
+    let yaml = generate_yaml("tests/data/test_keyed.md", "yaml-keyed");
+    assert_eq!(
+        yaml,
+        "models:\n  - gpt:\n      id: \"gpt-4o\"\n      max_tokens: 4096\n  \
+         - claude:\n      id: \"claude-3-7-sonnet\"\n      max_tokens: 8192\n"
+    );
+
+    let yaml = generate_yaml("tests/data/test_typed.md", "yaml-typed");
+    assert!(yaml.contains("  temperature: 1.0\n"), "floats keep their fraction: {}", yaml);
+}
+
+/// Dump a fixture's model as TOML and return the text
+fn generate_toml(path: &str, test_name: &str) -> String {
+    let output_file = fresh_output_path(test_name).join("config.toml");
+    CodeGenerator::new(
+        build_model(path),
+        GenerationMode::Toml {
+            output_file: output_file.clone(),
+        },
+        PathBuf::from(path),
+    )
+    .expect("Failed to create generator")
+    .generate()
+    .expect("Failed to write TOML");
+    fs::read_to_string(output_file).expect("Failed to read TOML")
+}
+
+#[test]
+fn test_toml_mode_writes_tables_before_subtables() {
+    let text = generate_toml("tests/data/test_service.md", "toml-service");
+    assert_eq!(
+        text,
+        "[service]\ndebug = false\nname = \"gateway\"\nport = 8080\n\n\
+         [service.database]\npool_size = 10\nurl = \"postgres://localhost/app\"\n"
+    );
+
+    let text = generate_toml("tests/data/test_keyed.md", "toml-keyed");
+    assert!(text.starts_with("[[models]]\n[models.gpt]\nid = \"gpt-4o\"\n"), "{}", text);
+}
+
+#[test]
+fn test_toml_mode_reparses_with_the_toml_crate() {
+    for fixture in ["test_genite", "test_service", "test_keyed", "test_typed", "test_arrays", "test_null"] {
+        let path = format!("tests/data/{}.md", fixture);
+        let text = generate_toml(&path, &format!("toml-{}", fixture));
+        if let Err(e) = text.parse::<toml::Table>() {
+            panic!("{} is not valid TOML: {}\n{}", fixture, e, text);
+        }
+    }
+
+    let table: toml::Table = generate_toml("tests/data/test_genite.md", "toml-genite-values").parse().unwrap();
+    let models = &table["llms"][0]["openai"]["models"];
+    assert_eq!(models[0]["gpt-4.1"]["max_output_tokens"].as_integer(), Some(32768));
+    assert_eq!(models[0]["gpt-4.1"]["input_price"].as_float(), Some(2.0));
+    assert!(models.as_array().unwrap().len() > 1);
+}
+
+#[test]
+fn test_verify_syntax_rejects_invalid_generated_code() {
+    let output_file = fresh_output_path("verify-syntax").join("config.rs");
+    let path = "tests/data/test_keyword_field.md";
+    let mut generator = CodeGenerator::new(
+        build_model(path),
+        GenerationMode::Module {
+            output_file: output_file.clone(),
+        },
+        PathBuf::from(path),
+    )
+    .expect("Failed to create generator")
+    .with_options(verified_options());
+
+    let err = generator.generate().expect_err("Expected a keyword field name to fail verification");
+    let message = err.to_string();
+    assert!(message.starts_with("Generated code is not valid Rust"), "unexpected error: {}", message);
+    assert!(message.contains("pub fn: String"), "error should quote the offending line: {}", message);
+    assert!(!output_file.exists(), "nothing should be written when verification fails");
+}
+
+#[test]
+fn test_schema_doc_tabulates_entity_fields() {
+    let crate_dir = generate_crate_with("tests/data/test_keyed.md", "schema-doc", |g| {
+        g.with_options(GeneratorOptions {
+            emit_schema_doc: true,
+            ..verified_options()
+        })
+    });
+    let schema = read_generated(&crate_dir, "SCHEMA.md");
+    assert!(schema.contains("## `model` (plural `models`)\n\nNo fields.\n"));
+    assert!(schema.contains(
+        "## `model/gpt`\n\n| Field | Type | Default |\n|-------|------|---------|\n\
+         | `id` | `String` | `\"gpt-4o\"` |\n| `max_tokens` | `i64` | `4096` |\n"
+    ));
+
+    // The document is opt-in
+    let crate_dir = generate_crate_with("tests/data/test_keyed.md", "schema-doc-off", |g| g);
+    assert!(!crate_dir.join("SCHEMA.md").exists());
+}
+
+#[test]
+fn test_builder_api_hides_fields_behind_builders() {
+    let content = generate_module_with("tests/data/test_service.md", "builder-api", |g| {
+        g.with_options(GeneratorOptions {
+            builder_api: true,
+            ..verified_options()
+        })
+    });
+    assert!(content.contains("\n    name: String,\n"));
+    assert!(content.contains("\n    port: i64,\n"));
+    assert!(!content.contains("pub port: i64"));
+    assert!(content.contains("pub struct ServiceBuilder {\n    inner: Service,\n}"));
+    assert!(content.contains(
+        "    pub fn with_name(mut self, value: impl Into<String>) -> Self {\n        self.inner.name = value.into();"
+    ));
+    assert!(content.contains("    pub fn with_port(mut self, value: i64) -> Self {"));
+    assert!(content.contains("    pub fn with_database(mut self, value: Database) -> Self {"));
+    assert!(content.contains("    pub fn builder() -> ServiceBuilder {"));
+    assert!(content.contains("        .with_service(config.service().clone())"));
+
+    // Without the option fields stay public and no builders are emitted
+    let content = generate_module_with("tests/data/test_service.md", "builder-api-off", |g| g);
+    assert!(content.contains("    pub port: i64,"));
+    assert!(!content.contains("ServiceBuilder"));
+}
+
+#[test]
+fn test_only_generates_the_subtree() {
+    let content = generate_module_with("tests/data/test_service.md", "only-subtree", |g| {
+        g.with_only("service/database").expect("Failed to select subtree")
+    });
+    assert!(content.contains("pub struct Root {"));
+    assert!(content.contains("    /// config path: url\n    #[inline]\n    pub fn url(&self)"));
+    assert!(content.contains("    pub fn pool_size(&self)"));
+    assert!(!content.contains("pub struct Service"));
+    assert!(!content.contains("pub struct Database"));
+    assert!(!content.contains("pub fn port(&self)"));
+    assert!(content.contains("model.find_entity_by_path(\"service/database\")"));
+
+    let generator = CodeGenerator::new(
+        build_model("tests/data/test_service.md"),
+        GenerationMode::Module {
+            output_file: fresh_output_path("only-missing").join("config.rs"),
+        },
+        PathBuf::from("tests/data/test_service.md"),
+    )
+    .expect("Failed to create generator");
+    let err = generator.with_only("service/cache").err().expect("Expected a missing path error");
+    assert_eq!(err.to_string(), "Entity path 'service/cache' not found in the configuration");
+}
+
+#[test]
+fn test_collections_expose_entries_in_source_order() {
+    let crate_dir = generate_crate_with("tests/data/test_keyed.md", "plural-order", |g| g);
+    let lib = read_generated(&crate_dir, "src/lib.rs");
+    assert!(lib.contains("    order: Vec<String>,\n"));
+    assert!(lib.contains("    pub fn first(&self) -> Option<&Model> {"));
+    assert!(lib.contains("    pub fn last(&self) -> Option<&Model> {"));
+    assert!(lib.contains("    pub fn nth(&self, index: usize) -> Option<&Model> {"));
+
+    // The generated test checks the accessors against the keyed instances in document order
+    let tests = read_generated(&crate_dir, "tests/integration.rs");
+    assert!(tests.contains("    let entries = config.models();\n"));
+    assert!(tests.contains("entries.first().expect(\"Missing first entry\"), entry(\"gpt-4o\")"));
+    assert!(tests.contains("entries.last().expect(\"Missing last entry\"), entry(\"claude-3-7-sonnet\")"));
+    assert!(tests.contains("assert!(entries.nth(2).is_none());"));
+}
+
+#[test]
+fn test_fixed_arrays_store_known_collections_in_arrays() {
+    let fixed = |g: CodeGenerator| {
+        g.with_options(GeneratorOptions {
+            fixed_arrays: true,
+            ..verified_options()
+        })
+    };
+    let crate_dir = generate_crate_with("tests/data/test_extras.md", "fixed-arrays", fixed);
+    let lib = read_generated(&crate_dir, "src/lib.rs");
+    assert!(lib.contains("    entries: [Model; 2],
+"));
+    assert!(lib.contains("    pub const KEYS: [&'static str; 2] = [\"gpt-4\", \"o1\"];\n"));
+    assert!(!lib.contains("map: HashMap<String, Model>"));
+
+    // The generated test checks the array length at compile time
+    let tests = read_generated(&crate_dir, "tests/integration.rs");
+    assert!(tests.contains("    let fixed: &[_; 2] = entries.entries();\n"));
+
+    // The `models` collections of the genite llms hold different entries, so they stay maps
+    let content = generate_module_with("tests/data/test_genite.md", "fixed-arrays-mixed", fixed);
+    assert!(content.contains("    entries: [Llm; 4],\n"));
+    assert!(content.contains("    map: HashMap<String, Model>,\n"));
+}
+
+#[test]
+fn test_plural_enum_generates_an_enum_of_variants() {
+    // The generated module is parsed with `syn`, so it must be valid Rust
+    let content = generate_module_with("tests/data/test_variants.md", "plural-enum", |g| g);
+    assert!(content.contains("pub enum Providers {\n    Openai(Openai),\n    Anthropic(Anthropic),\n}"));
+    assert!(content.contains("            \"openai\" => Self::Openai(Openai::from_entity(model, id)),\n"));
+    assert!(content.contains("expected: \"openai | anthropic\","));
+    assert!(!content.contains("pub struct Provider {"));
+
+    // Each variant struct only has the fields of its own instances, so none become optional
+    assert!(content.contains("    pub model: String,\n"));
+    assert!(content.contains("    pub max_tokens: i64,\n"));
+
+    // Without the annotation the instances share a collection entry struct
+    let model = build_model("tests/data/test_variants.md");
+    let cola = model.to_cola().replace(" as enum", "");
+    let ast = ColaParser::new().parse(&cola).expect("Failed to parse exported Cola");
+    let plain = ModelBuilder::build_config_model(&ast).expect("Failed to build model");
+    let content = generate_model_module(plain, "tests/data/test_variants.md", "plural-enum-absent", |g| g);
+    assert!(content.contains("pub struct Providers {"));
+    assert!(!content.contains("pub enum Providers"));
+}
+
+#[test]
+fn test_derive_builder_annotates_structs_and_adds_dependency() {
+    let crate_dir = generate_crate_with("tests/data/test_optional.md", "derive-builder", |g| {
+        g.with_options(GeneratorOptions {
+            derive_builder: true,
+            ..verified_options()
+        })
+    });
+    let lib = read_generated(&crate_dir, "src/lib.rs");
+    assert!(lib.contains(", derive_builder::Builder)]\n#[builder(default)]\npub struct Root {"));
+    assert!(lib.contains("    #[builder(setter(into))]\n    pub name: String,\n"));
+    assert!(lib.contains("    #[builder(setter(into, strip_option))]\n    pub timeout: Option<i64>,\n"));
+    assert!(!lib.contains("pub struct RootBuilder"));
+
+    let cargo_toml = read_generated(&crate_dir, "Cargo.toml");
+    assert!(cargo_toml.contains("derive_builder = \"0.20\"\n"));
+
+    // The generated test rebuilds the configuration with the derived builder
+    let tests = read_generated(&crate_dir, "tests/integration.rs");
+    assert!(tests.contains("    let rebuilt = RootBuilder::default()\n"));
+    assert!(tests.contains("        .build()\n        .expect(\"Failed to build configuration\");\n"));
+}
+
+#[test]
+fn test_string_getters_borrow_and_scalars_return_by_value() {
+    let content = generate_module_with("tests/data/test_service.md", "borrowing-getters", |g| g);
+    assert!(content.contains("pub fn url(&self) -> &str {\n        &self.url\n    }"));
+    assert!(content.contains("pub fn port(&self) -> i64 {\n        self.port\n    }"));
+    assert!(content.contains("pub fn debug(&self) -> bool {\n        self.debug\n    }"));
+    assert!(content.contains("pub fn database(&self) -> &Database {\n        &self.database\n    }"));
+
+    // Structs of plural instances get the same getters
+    let content = generate_module_with("tests/data/test_keyed.md", "borrowing-instance-getters", |g| g);
+    assert!(content.contains(
+        "    /// config path: model/*/id\n    #[inline]\n    pub fn id(&self) -> &str {\n        &self.id\n    }"
+    ));
+    assert!(content.contains("pub fn max_tokens(&self) -> i64 {\n        self.max_tokens\n    }"));
+    assert!(!content.contains(".clone()\n    }"));
+}
+
+#[test]
+fn test_getters_are_inline_and_document_config_path() {
+    let content = generate_module_with("tests/data/test_service.md", "getter-docs", |g| g);
+    assert!(content.contains(
+        "    /// config path: service/database/url\n    #[inline]\n    pub fn url(&self) -> &str {"
+    ));
+    assert!(content.contains("    /// config path: service/database\n    #[inline]\n    pub fn database(&self)"));
+
+    // Structs below plural instances are shared by every instance
+    let mut model = ConfigModel::new();
+    model.create_entity_at_path("", "llm", Some("llms"), None).unwrap();
+    model.create_entity_at_path("llm", "openai", None, None).unwrap();
+    let limits = model.create_entity_at_path("llm/openai", "limits", None, None).unwrap();
+    model.add_field_to_entity(limits, "rpm", ConfigValue::Integer(60)).unwrap();
+    let content = generate_model_module(model, "tests/data/test_service.md", "getter-docs-plural", |g| g);
+    assert!(content.contains("    /// config path: llm/*/limits/rpm\n    #[inline]\n    pub fn rpm(&self)"));
+}
+
+#[test]
+fn test_structs_accept_visitors() {
+    let content = generate_module_with("tests/data/test_genite.md", "visitor", |g| g);
+
+    assert!(content.contains("pub trait Visitor {"));
+    let accepts = content.matches("    pub fn accept(&self, visitor: &mut impl Visitor) {").count();
+    assert_eq!(accepts, content.matches("    pub fn accept_at(").count());
+    assert!(content.contains("self.llms.accept_at(&value_path(path, \"llm\"), visitor);"));
+    assert!(content.contains("self.map[key].accept_at(&value_path(path, key), visitor);"));
+    assert!(content.contains("fn test_visitor_counts_entities_and_fields()"));
+}
+
+#[test]
+fn test_narrow_integer_fields_use_try_from() {
+    let content = generate_module_with("tests/data/test_narrow.md", "narrow", |g| g);
+
+    assert!(content.contains("pub max_retries: u8,"));
+    assert!(content.contains("pub port: u16,"));
+    assert!(content.contains("if let Ok(v) = u8::try_from(*i) {"));
+    assert!(content.contains("if let Ok(v) = i16::try_from(*i) {"));
+    assert!(content.contains("ConfigValue::Integer(i64::from(self.port))"));
+    assert!(content.contains("fn test_out_of_range_integer_rejected()"));
+    assert!(content.contains("ConfigValue::Integer(256)"));
+    assert!(content.contains("assert_eq!(path, \"server/max_retries\");"));
+}
+
+#[test]
+fn test_rust_import_output_reparses() {
+    let source = fs::read_to_string("tests/data/test_import.rs").expect("Failed to read fixture");
+    let cola = rust_to_cola(&source, None).expect("Failed to import Rust structs");
+
+    let ast = ColaParser::new().parse(&cola).expect("Imported Cola should parse");
+    let model = ModelBuilder::new().build(&ast).expect("Imported Cola should build");
+
+    let server = model.find_entity_by_path("server").expect("server entity");
+    assert!(model.is_field_optional(server, "timeout"));
+    assert!(!model.is_field_optional(server, "host"));
+    let fields = model.as_flat_map();
+    assert_eq!(fields.get("server.port").map(|v| v.to_string()), Some("0".to_string()));
+    assert!(model.find_entity_by_path("database/example").is_some());
+}
+
+#[test]
+fn test_rust_import_unknown_root() {
+    let source = fs::read_to_string("tests/data/test_import.rs").expect("Failed to read fixture");
+    let err = rust_to_cola(&source, Some("Missing")).unwrap_err();
+    assert!(err.contains("Missing"));
+}
+
+#[test]
+fn test_cola_comments_become_doc_comments() {
+    let path = "tests/data/test_comments.md";
+    let mut model = build_model(path);
+    let source = fs::read_to_string(path).expect("Failed to read fixture");
+    ModelBuilder::new().attach_doc_comments(&mut model, &source);
+    let content = generate_model_module(model, path, "doc-comments", |g| g);
+    assert!(content.contains("/// Comment before the first entity\n#[derive(Debug, Clone, Default)]\npub struct Server {\n"));
+    assert!(content.contains("    /// Comment between fields\n    pub host: String,\n"));
+    assert!(content.contains("    /// Comment between entities\n    pub llms: Llms,\n"));
+    // Trailing comments on the same line as a value are not docs
+    assert!(!content.contains("development host"));
+}
+
+#[test]
+fn test_doc_comments_are_escaped() {
+    let path = "tests/data/test_comments.md";
+    let mut model = build_model(path);
+    let server = model.find_entities_by_name("server")[0];
+    model.set_doc(server, "Ends a block comment */ and opens `code").unwrap();
+    // The generated module is parsed with `syn`, so an unescaped doc would fail here
+    let content = generate_model_module(model, path, "doc-comments-escaped", |g| g);
+    assert!(content.contains("/// Ends a block comment *\\/ and opens \\`code\n"));
+}
@@ -1,8 +1,8 @@
 // SPDX-License-Identifier: Apache-2.0
 use rustemo::Parser;
 use colap::cola::ColaParser;
-use colap::config_model::ConfigValue;
-use colap::model_builder::ModelBuilder;
+use colap::config_model::{ConfigNode, ConfigValue};
+use colap::model_builder::{BuildError, ModelBuilder};
 use std::fs;
 use std::path::Path;
 
@@ -120,3 +120,873 @@ fn test_model_builder_with_test_genite() {
     // Print the model structure for debugging
     println!("ConfigModel structure:\n{}", model);
 }
+
+/// Parse a fixture and build its model, returning the builder's result
+fn build_model_from_file(path: &str) -> Result<colap::config_model::ConfigModel, String> {
+    build_model_with(&ModelBuilder::new(), path)
+}
+
+/// Parse a fixture and build its model with a configured builder
+fn build_model_with(
+    builder: &ModelBuilder,
+    path: &str,
+) -> Result<colap::config_model::ConfigModel, String> {
+    let content = fs::read_to_string(path).expect("Failed to read test file");
+    let parser = ColaParser::new();
+    let ast = parser.parse(&content).expect("Failed to parse test file");
+    builder.build(&ast)
+}
+
+#[test]
+fn test_type_annotation_coerces_integer_to_float() {
+    let model = build_model_from_file("tests/data/test_typed.md").expect("Failed to build model");
+    let openai_id = model.find_entity_by_path("openai").unwrap();
+
+    match model.get_field_value(openai_id, "temperature") {
+        Some(ConfigValue::Float(value)) => assert_eq!(value, 1.0),
+        other => panic!("Expected coerced Float for temperature, got {:?}", other),
+    }
+    match model.get_field_value(openai_id, "max_tokens") {
+        Some(ConfigValue::Integer(value)) => assert_eq!(value, 1000),
+        other => panic!("Expected Integer for max_tokens, got {:?}", other),
+    }
+    assert_eq!(model.get_field_type(openai_id, "temperature").as_deref(), Some("f64"));
+    assert_eq!(model.get_field_type(openai_id, "top_p"), None);
+}
+
+#[test]
+fn test_type_annotation_rejects_float_for_integer() {
+    let err = build_model_from_file("tests/data/test_typed_mismatch.md")
+        .expect_err("Expected a float literal to be rejected for an i64 field");
+    assert!(err.contains("'max_tokens' is declared as i64"), "unexpected error: {}", err);
+    assert!(err.contains("[(5,4)"), "error should carry the field location: {}", err);
+}
+
+#[test]
+fn test_repeated_fields_accumulate_when_enabled() {
+    let mut builder = ModelBuilder::new();
+    builder.repeatable_fields = true;
+    let model = build_model_with(&builder, "tests/data/test_repeated.md").unwrap();
+    let post_id = model.find_entity_by_path("post").unwrap();
+
+    match model.get_field_value(post_id, "tag") {
+        Some(ConfigValue::Array(items)) => {
+            let tags: Vec<String> = items.iter().map(|v| v.to_string()).collect();
+            assert_eq!(tags, vec!["\"rust\"", "\"config\"", "\"parser\""]);
+        }
+        other => panic!("Expected an Array for the repeated tag field, got {:?}", other),
+    }
+    assert!(matches!(model.get_field_value(post_id, "title"), Some(ConfigValue::String(_))));
+}
+
+#[test]
+fn test_repeated_field_keeps_one_field_node() {
+    let mut builder = ModelBuilder::new();
+    builder.repeatable_fields = true;
+    let model = build_model_with(&builder, "tests/data/test_repeated.md").unwrap();
+    let post_id = model.find_entity_by_path("post").unwrap();
+
+    let node = model.get_node(post_id).unwrap();
+    let tag_nodes: Vec<ConfigValue> = match &*node.borrow() {
+        colap::config_model::ConfigNode::Entity(entity) => entity
+            .children
+            .iter()
+            .filter_map(|&child| match &*model.get_node(child).unwrap().borrow() {
+                colap::config_model::ConfigNode::Field(field) if field.name == "tag" => Some(field.value.clone()),
+                _ => None,
+            })
+            .collect(),
+        _ => panic!("post is not an entity"),
+    };
+    assert_eq!(tag_nodes.len(), 1);
+    assert_eq!(Some(&tag_nodes[0]), model.get_field_value(post_id, "tag").as_ref());
+}
+
+#[test]
+fn test_repeated_field_rejects_type_annotation() {
+    let mut builder = ModelBuilder::new();
+    builder.repeatable_fields = true;
+    let source = "```cola\npost:\n    tag: String = \"rust\",\n    tag: \"config\"\n;\n```\n";
+    let err = builder.build_from_str(source).unwrap_err();
+    assert!(err.contains("Field 'tag' is declared as String and cannot be repeated at"), "{}", err);
+
+    let source = "```cola\npost:\n    tag: \"rust\",\n    tag: String = \"config\"\n;\n```\n";
+    let err = builder.build_from_str(source).unwrap_err();
+    assert!(err.contains("Field 'tag' is declared as String and cannot be repeated at"), "{}", err);
+}
+
+#[test]
+fn test_repeated_fields_keep_last_value_by_default() {
+    let model = build_model_from_file("tests/data/test_repeated.md").unwrap();
+    let post_id = model.find_entity_by_path("post").unwrap();
+
+    match model.get_field_value(post_id, "tag") {
+        Some(ConfigValue::String(value)) => assert_eq!(value, "parser"),
+        other => panic!("Expected the last tag value, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_node_count_estimate_matches_built_model() {
+    for path in ["tests/data/test_genite.md", "tests/data/test_service.md", "tests/data/test_repeated.md"] {
+        let content = fs::read_to_string(path).expect("Failed to read test file");
+        let ast = ColaParser::new().parse(&content).expect("Failed to parse test file");
+        let model = ModelBuilder::build_config_model(&ast).expect("Failed to build model");
+        assert_eq!(ModelBuilder::estimate_node_count(&ast), model.node_count(), "{}", path);
+    }
+}
+
+#[test]
+fn test_to_cola_round_trips() {
+    let fixtures = [
+        "tests/data/test_genite.md",
+        "tests/data/test_service.md",
+        "tests/data/test_typed.md",
+        "tests/data/test_optional.md",
+        "tests/data/test_arrays.md",
+        "tests/data/test_null.md",
+        "tests/data/test_keyed.md",
+        "tests/data/test_required.md",
+        "tests/data/test_narrow.md",
+        "tests/data/test_anchors.md",
+        "tests/data/test_int_literals.md",
+        "tests/data/test_multiline.md",
+        "tests/data/test_negative.md",
+        "tests/data/test_mixed_arrays.md",
+    ];
+    for path in fixtures {
+        let model = build_model_from_file(path).expect("Failed to build model");
+        let cola = model.to_cola();
+        let ast = ColaParser::new()
+            .parse(&cola)
+            .unwrap_or_else(|e| panic!("Exported Cola for {} does not parse: {}\n{}", path, e, cola));
+        let reparsed = ModelBuilder::build_config_model(&ast).expect("Failed to rebuild model");
+
+        let flat = |m: &colap::config_model::ConfigModel| -> Vec<(String, String)> {
+            m.as_flat_map().into_iter().map(|(k, v)| (k, v.to_string())).collect()
+        };
+        assert_eq!(flat(&model), flat(&reparsed), "{}", path);
+        assert_eq!(model.fingerprint(), reparsed.fingerprint(), "{}", path);
+    }
+}
+
+#[test]
+fn test_to_cola_round_trips_root_fields_and_mixed_quotes() {
+    let source = r#"```cola
+version: 2,
+motto: """
+it's "done""""
+
+server:
+    host: 'say "hi"',
+    banner: """
+'single' and "double"
+"""
+;
+```
+"#;
+    let model = ModelBuilder::new().build_from_str(source).expect("Failed to build model");
+    assert_eq!(model.get_field_value_by_path("version"), Some(ConfigValue::Integer(2)));
+    assert_eq!(model.get_field_value_by_path("motto"), Some(ConfigValue::String("it's \"done\"".to_string())));
+
+    let cola = model.to_cola();
+    assert!(cola.starts_with("```cola\nversion: 2,\n"), "{}", cola);
+    let reparsed = ModelBuilder::new()
+        .build_from_str(&cola)
+        .unwrap_or_else(|e| panic!("Exported Cola does not parse: {}\n{}", e, cola));
+    for path in ["version", "motto", "server/host", "server/banner"] {
+        assert_eq!(reparsed.get_field_value_by_path(path), model.get_field_value_by_path(path), "{}", path);
+    }
+    assert_eq!(model.fingerprint(), reparsed.fingerprint());
+}
+
+#[test]
+fn test_to_cola_round_trips_marked_declarations() {
+    let source = "```cola\nserver:\n    host!: String,\n    timeout?: i64,\n    port: u16\n;\n```\n";
+    let ast = ColaParser::new().parse(source).expect("Failed to parse declarations");
+    let model = ModelBuilder::build_config_model(&ast).expect("Failed to build model");
+    let cola = model.to_cola();
+    assert!(cola.contains("timeout?: i64"), "{}", cola);
+
+    let ast = ColaParser::new()
+        .parse(&cola)
+        .unwrap_or_else(|e| panic!("Exported Cola does not parse: {}\n{}", e, cola));
+    let reparsed = ModelBuilder::build_config_model(&ast).expect("Failed to rebuild model");
+    let server = reparsed.find_entity_by_path("server").expect("Missing server");
+    assert!(reparsed.is_field_optional(server, "timeout"));
+    assert!(reparsed.is_field_required(server, "host"));
+    for field in ["host", "timeout", "port"] {
+        assert!(reparsed.is_field_declared(server, field), "{}", field);
+    }
+    assert!(!reparsed.is_field_optional(server, "port"));
+    assert_eq!(model.fingerprint(), reparsed.fingerprint());
+}
+
+#[test]
+fn test_entity_path_round_trips_through_find_entity_by_path() {
+    for path in ["tests/data/test_genite.md", "tests/data/test_service.md", "tests/data/test_keyed.md"] {
+        let model = build_model_from_file(path).expect("Failed to build model");
+        assert_eq!(model.get_entity_path(model.root_id()).as_deref(), Some(""));
+        assert_eq!(model.find_entity_by_path(""), Some(model.root_id()));
+        for (id, _, _) in model.iter_entities() {
+            let entity_path = model.get_entity_path(id).expect("Missing entity path");
+            assert_eq!(model.find_entity_by_path(&entity_path), Some(id), "{}: {}", path, entity_path);
+        }
+        assert_eq!(model.get_entity_path(model.node_count()), None);
+    }
+}
+
+#[test]
+fn test_skipped_code_blocks_lists_non_cola_tags() {
+    let content = fs::read_to_string("tests/data/test_mixed_blocks.md").expect("Failed to read test file");
+    let ast = ColaParser::new().parse(&content).expect("Failed to parse test file");
+    assert_eq!(
+        ModelBuilder::skipped_code_blocks(&ast),
+        vec![Some("rust".to_string()), Some("bash".to_string()), None]
+    );
+
+    // The cola blocks around them still build
+    let model = ModelBuilder::build_config_model(&ast).expect("Failed to build model");
+    assert!(model.find_entity_by_path("worker").is_some());
+    assert!(model.find_entity_by_path("limits").is_some());
+}
+
+#[test]
+fn test_get_field_location_points_at_the_field() {
+    let model = build_model_from_file("tests/data/test_service.md").expect("Failed to build model");
+    let service = model.find_entity_by_path("service").expect("Missing service");
+    let database = model.find_entity_by_path("service/database").expect("Missing database");
+
+    let port = model.get_field_location(service, "port").expect("Missing location");
+    assert_eq!((port.start_line, port.start_column), (6, 4));
+    let pool_size = model.get_field_location(database, "pool_size").expect("Missing location");
+    assert_eq!((pool_size.start_line, pool_size.start_column), (11, 8));
+
+    assert!(model.get_field_location(service, "missing").is_none());
+    assert!(model.get_field_location(model.node_count(), "port").is_none());
+}
+
+#[test]
+fn test_to_cola_keeps_value_types_and_repeats() {
+    let mut builder = ModelBuilder::new();
+    builder.repeatable_fields = true;
+    let model = build_model_with(&builder, "tests/data/test_repeated.md").expect("Failed to build model");
+    let cola = model.to_cola();
+    assert!(cola.starts_with("```cola\n"));
+    assert!(cola.contains(r#"tag: ["rust", "config", "parser"]"#), "{}", cola);
+
+    let typed = build_model_from_file("tests/data/test_typed.md").unwrap().to_cola();
+    assert!(typed.contains("temperature: f64 = 1.0"), "{}", typed);
+}
+
+#[test]
+fn test_field_count_limit_warns_for_oversized_entity() {
+    let mut builder = ModelBuilder::new();
+    builder.max_fields_per_entity = 2;
+    let model = build_model_with(&builder, "tests/data/test_service.md").expect("Limit is only a warning");
+
+    let warnings = builder.field_count_warnings(&model);
+    assert_eq!(warnings.len(), 1, "only service exceeds the limit: {:?}", warnings);
+    assert!(warnings[0].contains("'service'"), "unexpected warning: {}", warnings[0]);
+    assert!(warnings[0].contains("has 3 fields, more than the limit of 2"), "unexpected warning: {}", warnings[0]);
+    assert!(warnings[0].contains("at line 4"), "warning should carry the entity location: {}", warnings[0]);
+
+    assert!(ModelBuilder::new().field_count_warnings(&model).is_empty());
+}
+
+#[test]
+fn test_field_count_limit_errors_when_strict() {
+    let mut builder = ModelBuilder::new();
+    builder.max_fields_per_entity = 2;
+    builder.strict = true;
+    let err = build_model_with(&builder, "tests/data/test_service.md")
+        .expect_err("Expected the over-limit entity to fail a strict build");
+    assert!(err.contains("Entity 'service'"), "unexpected error: {}", err);
+}
+
+#[test]
+fn test_duplicate_field_kept_last_when_lenient() {
+    let model = build_model_from_file("tests/data/test_duplicate_field.md").expect("Failed to build model");
+    let service = model.find_entity_by_path("service").unwrap();
+    match model.get_field_value(service, "port") {
+        Some(ConfigValue::Integer(value)) => assert_eq!(value, 9090),
+        other => panic!("Expected the last port to win, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_duplicate_field_errors_when_strict() {
+    let builder = ModelBuilder { strict: true, ..ModelBuilder::new() };
+    let err = build_model_with(&builder, "tests/data/test_duplicate_field.md")
+        .expect_err("Expected a repeated field to fail a strict build");
+    assert!(err.contains("Duplicate field 'port' in entity 'service'"), "unexpected error: {}", err);
+    assert!(err.contains("at : [(9,4)"), "error should carry the duplicate location: {}", err);
+    assert!(err.contains("first defined at : [(7,4)"), "error should carry the original location: {}", err);
+}
+
+#[test]
+fn test_duplicate_entity_errors_when_strict() {
+    assert!(build_model_from_file("tests/data/test_duplicate_entity.md").is_ok());
+
+    let builder = ModelBuilder { strict: true, ..ModelBuilder::new() };
+    let err = build_model_with(&builder, "tests/data/test_duplicate_entity.md")
+        .expect_err("Expected a repeated entity to fail a strict build");
+    assert!(err.contains("Duplicate entity 'database' in entity 'root'"), "unexpected error: {}", err);
+    assert!(err.contains("at : [(10,0)"), "error should carry the duplicate location: {}", err);
+    assert!(err.contains("first defined at : [(6,0)"), "error should carry the original location: {}", err);
+}
+
+#[test]
+fn test_duplicate_entities_keep_their_own_children_when_lenient() {
+    let model = build_model_from_file("tests/data/test_duplicate_entity.md").expect("Failed to build model");
+    let databases = model.find_entities_by_name("database");
+    assert_eq!(databases.len(), 2);
+    assert_eq!(model.find_entity_by_path("database"), Some(databases[0]));
+    assert_eq!(model.get_field_value(databases[0], "host"), Some(ConfigValue::String("primary".to_string())));
+    assert_eq!(model.get_field_value(databases[1], "host"), Some(ConfigValue::String("replica".to_string())));
+
+    // The nested entity stays under the instance it is written in, not the first of the name
+    let children = |id| match &*model.get_node(id).unwrap().borrow() {
+        ConfigNode::Entity(entity) => entity.children.clone(),
+        _ => panic!("Expected an entity"),
+    };
+    let pools = model.find_entities_by_name("pool");
+    assert_eq!(pools.len(), 1);
+    assert!(!children(databases[0]).contains(&pools[0]));
+    assert!(children(databases[1]).contains(&pools[0]));
+}
+
+#[test]
+fn test_build_collecting_reports_every_error() {
+    let content = fs::read_to_string("tests/data/test_build_errors.md").expect("Failed to read test file");
+    let ast = ColaParser::new().parse(&content).expect("Failed to parse test file");
+
+    let errors = ModelBuilder::build_config_model_collecting(&ast).expect_err("Expected the build to fail");
+    let messages: Vec<&str> = errors.iter().map(|error| error.message.as_str()).collect();
+    assert_eq!(errors.len(), 4, "unexpected errors: {:?}", messages);
+    assert!(messages[0].starts_with("Field 'max_tokens' is declared as i64 but has value 1.5"));
+    assert!(messages[1].starts_with("Computed field 'total' refers to unknown field 'minutes'"));
+    assert!(messages[2].starts_with("Reference to undefined anchor 'missing_key'"));
+    assert!(messages[3].starts_with("Instance 'claude' of plural entity 'model' has no key field 'id'"));
+    // Each error carries the location of the field or entity it was found in
+    let lines: Vec<u32> = errors.iter().map(|error| error.location.as_ref().unwrap().start_line).collect();
+    assert_eq!(lines, [7, 9, 13, 17]);
+
+    // A plain build stops at the first
+    let err = ModelBuilder::build_config_model(&ast).expect_err("Expected the build to fail");
+    assert_eq!(err, errors[0].message);
+}
+
+#[test]
+fn test_build_collecting_skips_duplicates_when_strict() {
+    let builder = ModelBuilder { strict: true, ..ModelBuilder::new() };
+    let content = fs::read_to_string("tests/data/test_duplicate_entity.md").expect("Failed to read test file");
+    let ast = ColaParser::new().parse(&content).expect("Failed to parse test file");
+    let errors = builder.build_collecting(&ast).expect_err("Expected a repeated entity to fail a strict build");
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].message.starts_with("Duplicate entity 'database' in entity 'root'"));
+    assert!(errors[0].to_string().starts_with("Duplicate entity"), "{}", errors[0]);
+}
+
+#[test]
+fn test_anchor_references_expand_to_values() {
+    let model = build_model_from_file("tests/data/test_anchors.md").expect("Failed to build model");
+    let fields: Vec<(String, String)> = model
+        .as_flat_map()
+        .into_iter()
+        .map(|(key, value)| (key, value.to_string()))
+        .collect();
+    let get = |key: &str| fields.iter().find(|(k, _)| k == key).map(|(_, v)| v.as_str());
+
+    assert_eq!(get("llm/openai.key"), Some("\"sk-shared\""));
+    assert_eq!(get("llm/openai.max_tokens"), Some("4096"));
+    assert_eq!(get("llm/anthropic.key"), Some("\"sk-shared\""));
+    assert_eq!(get("llm/anthropic.max_tokens"), Some("8192"));
+    assert_eq!(get("llm/anthropic.models"), Some("[\"claude-3-7-sonnet\", \"claude-3-5-haiku\"]"));
+    // Anchors name values without adding fields or nodes of their own
+    assert_eq!(model.field_count(), 5);
+}
+
+#[test]
+fn test_undefined_anchor_reference_errors() {
+    let err = build_model_from_file("tests/data/test_anchors_undefined.md")
+        .expect_err("Expected an undefined anchor to be rejected");
+    assert!(err.contains("undefined anchor 'missing_key'"), "unexpected error: {}", err);
+    assert!(err.contains("[(5,10)"), "error should carry the reference location: {}", err);
+}
+
+#[test]
+fn test_prefixed_and_grouped_integer_literals() {
+    let model = build_model_from_file("tests/data/test_int_literals.md").expect("Failed to build model");
+    let limits = model.find_entity_by_path("limits").unwrap();
+    let expected = [
+        ("mask", 0xFF),
+        ("perms", 0o755),
+        ("flags", 0b1010_0101),
+        ("max_tokens", 1_000_000),
+        ("offset", -0x10),
+        ("doubled", 32),
+    ];
+    for (name, value) in expected {
+        match model.get_field_value(limits, name) {
+            Some(ConfigValue::Integer(actual)) => assert_eq!(actual, value, "{}", name),
+            other => panic!("Expected Integer for {}, got {:?}", name, other),
+        }
+    }
+    match model.get_field_value(limits, "ratio") {
+        Some(ConfigValue::Float(value)) => assert_eq!(value, 1000.5),
+        other => panic!("Expected Float for ratio, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_malformed_hex_literal_is_not_a_float() {
+    let err = build_model_from_file("tests/data/test_int_literals_invalid.md")
+        .expect_err("Expected a hex literal with a fraction to be rejected");
+    assert_eq!(err, "Invalid hexadecimal integer literal: 0xFF.5 at : [(5,10)-(5,16)]");
+}
+
+#[test]
+fn test_build_error_names_its_location_once() {
+    let content = fs::read_to_string("tests/data/test_int_literals_invalid.md").expect("Failed to read test file");
+    let ast = ColaParser::new().parse(&content).expect("Failed to parse test file");
+    let errors = ModelBuilder::build_config_model_collecting(&ast).expect_err("Expected the build to fail");
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].located);
+    assert_eq!(errors[0].to_string(), errors[0].message);
+
+    let unlocated = BuildError { located: false, ..errors[0].clone() };
+    let location = errors[0].location.as_ref().expect("Expected a location");
+    assert_eq!(unlocated.to_string(), format!("{}: {}", location, unlocated.message));
+}
+
+#[test]
+fn test_unit_suffixes_scale_to_milliseconds_and_bytes() {
+    let builder = ModelBuilder {
+        unit_suffixes: true,
+        ..ModelBuilder::new()
+    };
+    let model = build_model_with(&builder, "tests/data/test_units.md").expect("Failed to build model");
+    let server = model.find_entity_by_path("server").unwrap();
+    let expected = [
+        ("retry_delay", 250, Some("Duration")),
+        ("timeout", 30_000, Some("Duration")),
+        ("idle", 300_000, Some("Duration")),
+        ("session", 5_400_000, Some("Duration")),
+        ("buffer", 64 * 1024, None),
+        ("cache_size", 256 * 1024 * 1024, None),
+        ("upload_limit", 2 * 1024 * 1024 * 1024, None),
+        ("port", 8080, None),
+    ];
+    for (name, value, field_type) in expected {
+        match model.get_field_value(server, name) {
+            Some(ConfigValue::Integer(actual)) => assert_eq!(actual, value, "{}", name),
+            other => panic!("Expected Integer for {}, got {:?}", name, other),
+        }
+        assert_eq!(model.get_field_type(server, name).as_deref(), field_type, "{}", name);
+    }
+}
+
+#[test]
+fn test_unit_suffixes_are_rejected_unless_enabled() {
+    let err = build_model_from_file("tests/data/test_units.md").expect_err("Expected a unit suffix to be rejected");
+    assert!(err.starts_with("Number '30s' has a unit suffix, but unit suffixes are not enabled"), "{}", err);
+}
+
+#[test]
+fn test_unit_suffix_rejects_a_fraction_of_the_smallest_unit() {
+    let builder = ModelBuilder {
+        unit_suffixes: true,
+        ..ModelBuilder::new()
+    };
+    let err = build_model_with(&builder, "tests/data/test_units_invalid.md").expect_err("Expected 0.5ms to be rejected");
+    assert!(err.starts_with("Number '0.5ms' is not a whole number of milliseconds"), "{}", err);
+}
+
+#[test]
+fn test_triple_quoted_strings_keep_line_breaks() {
+    let model = build_model_from_file("tests/data/test_multiline.md").expect("Failed to build model");
+    let assistant = model.find_entity_by_path("assistant").unwrap();
+    let expected = [
+        ("prompt", "You are a helpful assistant.\nAnswer in \"plain\" English.\n"),
+        ("footer", "Thanks!"),
+        ("name", "helper"),
+    ];
+    for (name, value) in expected {
+        match model.get_field_value(assistant, name) {
+            Some(ConfigValue::String(actual)) => assert_eq!(actual, value, "{}", name),
+            other => panic!("Expected String for {}, got {:?}", name, other),
+        }
+    }
+}
+
+#[test]
+fn test_negative_numbers_keep_their_sign() {
+    let model = build_model_from_file("tests/data/test_negative.md").expect("Failed to build model");
+    let tuning = model.find_entity_by_path("tuning").unwrap();
+    let integers = [("delta", -42), ("zero", 0), ("floor", -1000), ("mask", -16), ("shifted", -50)];
+    for (name, expected) in integers {
+        match model.get_field_value(tuning, name) {
+            Some(ConfigValue::Integer(actual)) => assert_eq!(actual, expected, "{}", name),
+            other => panic!("Expected Integer for {}, got {:?}", name, other),
+        }
+    }
+    match model.get_field_value(tuning, "temperature_offset") {
+        Some(ConfigValue::Float(actual)) => assert_eq!(actual, -0.3),
+        other => panic!("Expected Float for temperature_offset, got {:?}", other),
+    }
+    match model.get_field_value(tuning, "bias") {
+        Some(ConfigValue::Array(items)) => {
+            assert!(matches!(items[..], [ConfigValue::Integer(-1), ConfigValue::Float(f), ConfigValue::Integer(3)] if f == -2.5));
+        }
+        other => panic!("Expected Array for bias, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_comments_do_not_affect_the_model() {
+    let model = build_model_from_file("tests/data/test_comments.md").expect("Failed to build model");
+    let server = model.find_entity_by_path("server").unwrap();
+    match model.get_field_value(server, "host") {
+        Some(ConfigValue::String(host)) => assert_eq!(host, "localhost"),
+        other => panic!("Expected String for host, got {:?}", other),
+    }
+    assert!(matches!(model.get_field_value(server, "port"), Some(ConfigValue::Integer(8080))));
+
+    let openai = model.find_entity_by_path("llm/openai").unwrap();
+    match model.get_field_value(openai, "api_key") {
+        Some(ConfigValue::String(key)) => assert_eq!(key, "sk-1#2"),
+        other => panic!("Expected String for api_key, got {:?}", other),
+    }
+    assert_eq!(model.iter_fields(openai).count(), 2);
+}
+
+#[test]
+fn test_comment_lines_attach_as_docs() {
+    let content = fs::read_to_string("tests/data/test_comments.md").expect("Failed to read test file");
+    let mut model = build_model_from_file("tests/data/test_comments.md").expect("Failed to build model");
+    ModelBuilder::new().attach_doc_comments(&mut model, &content);
+
+    let server = model.find_entity_by_path("server").unwrap();
+    assert_eq!(model.get_entity_doc(server).as_deref(), Some("Comment before the first entity"));
+    assert_eq!(model.get_field_doc(server, "host").as_deref(), Some("Comment between fields"));
+    // An end-of-line comment belongs to the line it ends, not the next field
+    assert_eq!(model.get_field_doc(server, "port"), None);
+    let openai = model.find_entity_by_path("llm/openai").unwrap();
+    assert_eq!(model.get_entity_doc(openai), None);
+}
+
+#[test]
+fn test_crlf_line_endings_leave_no_carriage_returns() {
+    let content = fs::read_to_string("tests/data/test_crlf.md").expect("Failed to read test file");
+    assert!(content.contains("\r\n"), "fixture must keep its CRLF line endings");
+
+    let model = build_model_from_file("tests/data/test_crlf.md").expect("Failed to build model");
+    let flat = model.as_flat_map();
+    let keys: Vec<&str> = flat.keys().map(String::as_str).collect();
+    assert_eq!(
+        keys,
+        [
+            "service.motd",
+            "service.name",
+            "service.port",
+            "service/database.tags",
+            "service/database.url",
+        ]
+    );
+    for (key, value) in &flat {
+        assert!(!value.to_string().contains('\r'), "carriage return in {}", key);
+    }
+    match &flat["service.motd"] {
+        ConfigValue::String(motd) => assert_eq!(motd, "line one\nline two"),
+        other => panic!("Expected String for motd, got {:?}", other),
+    }
+    match &flat["service/database.url"] {
+        ConfigValue::String(url) => assert_eq!(url, "postgres://localhost/app"),
+        other => panic!("Expected String for url, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_group_plural_instances_by_field() {
+    let model = build_model_from_file("tests/data/test_grouped.md").expect("Failed to build model");
+    let models = model.find_entity_by_path("model").unwrap();
+    let names = |ids: &Vec<usize>| -> Vec<String> {
+        ids.iter()
+            .map(|&id| model.get_node(id).unwrap().borrow().name().to_string())
+            .collect()
+    };
+
+    let groups = model.group_by_field(models, "type");
+    assert_eq!(groups.len(), 2);
+    assert_eq!(names(&groups["chat"]), vec!["gpt-4o", "claude-3-7-sonnet"]);
+    assert_eq!(names(&groups["embedding"]), vec!["text-embedding-3"]);
+
+    // Non-string values are grouped by their Cola form
+    let by_window = model.group_by_field(models, "context_window");
+    assert_eq!(names(&by_window["0"]), vec!["whisper"]);
+    assert!(model.group_by_field(models, "missing").is_empty());
+}
+
+#[test]
+fn test_computed_fields_evaluate_against_siblings() {
+    let model = build_model_from_file("tests/data/test_computed.md").expect("Failed to build model");
+    let limits_id = model.find_entity_by_path("limits").unwrap();
+
+    match model.get_field_value(limits_id, "rpm_per_hour") {
+        Some(ConfigValue::Integer(value)) => assert_eq!(value, 6000),
+        other => panic!("Expected Integer for rpm_per_hour, got {:?}", other),
+    }
+    match model.get_field_value(limits_id, "peak") {
+        Some(ConfigValue::Float(value)) => assert_eq!(value, 180.0),
+        other => panic!("Expected Float for peak, got {:?}", other),
+    }
+    match model.get_field_value(limits_id, "per_second") {
+        Some(ConfigValue::Integer(value)) => assert_eq!(value, 1),
+        other => panic!("Expected integer division for per_second, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_computed_field_rejects_unknown_reference() {
+    let err = build_model_from_file("tests/data/test_computed_unknown.md")
+        .expect_err("Expected an unknown reference to be rejected");
+    assert!(err.contains("refers to unknown field 'minutes'"), "unexpected error: {}", err);
+    assert!(err.contains("[(6,18)"), "error should carry the reference location: {}", err);
+}
+
+#[test]
+fn test_to_env_quotes_and_prefixes_fields() {
+    let service = build_model_from_file("tests/data/test_service.md").expect("Failed to build model");
+    assert_eq!(
+        service.to_env("APP"),
+        "APP_SERVICE_DEBUG=false\n\
+         APP_SERVICE_NAME=\"gateway\"\n\
+         APP_SERVICE_PORT=8080\n\
+         APP_SERVICE_DATABASE_POOL_SIZE=10\n\
+         APP_SERVICE_DATABASE_URL=\"postgres://localhost/app\"\n"
+    );
+
+    let post = build_model_from_file("tests/data/test_repeated.md").expect("Failed to build model");
+    let env = post.to_env("");
+    assert!(env.contains("POST_TITLE=\"Release notes\"\n"), "{}", env);
+}
+
+#[test]
+fn test_optional_marker_is_recorded_and_exported() {
+    let model = build_model_from_file("tests/data/test_optional.md").expect("Failed to build model");
+    let service_id = model.find_entity_by_path("service").unwrap();
+    assert!(model.is_field_optional(service_id, "timeout"));
+    assert!(!model.is_field_optional(service_id, "name"));
+    assert!(model.to_cola().contains("timeout?: 30"));
+}
+
+#[test]
+fn test_array_literals_build_arrays() {
+    let model = build_model_from_file("tests/data/test_arrays.md").expect("Failed to build model");
+    let completion_id = model.find_entity_by_path("completion").unwrap();
+
+    let rendered = |name: &str| model.get_field_value(completion_id, name).map(|v| v.to_string());
+    assert_eq!(rendered("stop_sequences").as_deref(), Some(r#"["\n", "END"]"#));
+    assert_eq!(rendered("retry_delays").as_deref(), Some("[1, 2, 4]"));
+    assert_eq!(rendered("weights").as_deref(), Some("[0.5, 1.5]"));
+    assert_eq!(rendered("tags").as_deref(), Some("[]"));
+}
+
+#[test]
+fn test_null_keyword_builds_null_value() {
+    let model = build_model_from_file("tests/data/test_null.md").expect("Failed to build model");
+    let http_id = model.find_entity_by_path("http").unwrap();
+
+    assert!(matches!(model.get_field_value(http_id, "proxy"), Some(ConfigValue::Null)));
+    assert!(matches!(model.get_field_value(http_id, "user_agent"), Some(ConfigValue::Null)));
+    // Identifiers may still start with `null`
+    assert!(matches!(model.get_field_value(http_id, "nullable_port"), Some(ConfigValue::Integer(8080))));
+    assert_eq!(ConfigValue::Null.to_string(), "null");
+    assert!(model.pretty_display().contains("proxy: null"), "{}", model.pretty_display());
+}
+
+#[test]
+fn test_plural_key_field_is_recorded() {
+    let model = build_model_from_file("tests/data/test_keyed.md").expect("Failed to build model");
+    let models_id = model.find_entity_by_path("model").unwrap();
+    let node = model.get_node(models_id).unwrap();
+    let key_field = match &*node.borrow() {
+        colap::config_model::ConfigNode::Entity(entity) => entity.key_field.clone(),
+        _ => None,
+    };
+    assert_eq!(key_field.as_deref(), Some("id"));
+    assert!(model.to_cola().contains("model plural models key id:\n"));
+}
+
+#[test]
+fn test_inline_plural_expands_into_instances() {
+    let model = build_model_from_file("tests/data/test_inline_plural.md").expect("Failed to build model");
+    let models_id = model.find_entity_by_path("llm/models").unwrap();
+    let instances: Vec<(String, Option<String>)> = model
+        .iter_entities()
+        .filter(|(id, _, _)| model.get_entity_path(*id).is_some_and(|path| path.starts_with("llm/models/")))
+        .map(|(_, name, plural)| (name, plural))
+        .collect();
+    assert_eq!(
+        instances,
+        vec![("gpt-4".to_string(), None), ("gpt-3.5".to_string(), None), ("o1-mini".to_string(), None)]
+    );
+    let gpt4 = model.find_entity_by_path("llm/models/gpt-4").unwrap();
+    assert_eq!(model.iter_fields(gpt4).count(), 0);
+    let plural_name = match &*model.get_node(models_id).unwrap().borrow() {
+        colap::config_model::ConfigNode::Entity(entity) => entity.plural_name.clone(),
+        _ => None,
+    };
+    assert_eq!(plural_name.as_deref(), Some("models"));
+    assert!(model.find_entity_by_path("regions/us-east").is_some());
+}
+
+#[test]
+fn test_plural_key_field_required_on_every_instance() {
+    let err = build_model_from_file("tests/data/test_keyed_missing.md").unwrap_err();
+    assert!(err.contains("Instance 'claude' of plural entity 'model' has no key field 'id'"), "{}", err);
+}
+
+#[test]
+fn test_plural_key_values_are_unique() {
+    let path = Path::new("tests/data/test_keyed_duplicate.md");
+    let content = fs::read_to_string(path).expect("Failed to read test file");
+    let ast = ColaParser::new().parse(&content).expect("Failed to parse test file");
+    let err = ModelBuilder::build_config_model_with_path(&ast, path).unwrap_err();
+    assert!(
+        err.contains("Instances 'gpt' at tests/data/test_keyed_duplicate.md: [(6,8)"),
+        "the first instance should carry its key location: {}",
+        err
+    );
+    assert!(err.contains("and 'gpt_mini' at tests/data/test_keyed_duplicate.md: [(9,8)"), "{}", err);
+    assert!(err.ends_with("of plural entity 'model' have the same key id = \"gpt-4o\""), "{}", err);
+}
+
+#[test]
+fn test_plural_enum_is_recorded() {
+    let model = build_model_from_file("tests/data/test_variants.md").expect("Failed to build model");
+    let providers_id = model.find_entity_by_path("provider").unwrap();
+    let node = model.get_node(providers_id).unwrap();
+    let is_enum = match &*node.borrow() {
+        colap::config_model::ConfigNode::Entity(entity) => entity.is_enum,
+        _ => false,
+    };
+    assert!(is_enum);
+    assert!(model.to_cola().contains("provider plural providers as enum:\n"));
+}
+
+#[test]
+fn test_plural_enum_requires_a_type_on_every_instance() {
+    let err = build_model_from_file("tests/data/test_variants_missing.md").unwrap_err();
+    assert!(err.contains("Instance 'fallback' of enum entity 'provider' has no string 'type' field"), "{}", err);
+}
+
+#[test]
+fn test_summary_describes_model_size() {
+    let model = build_model_from_file("tests/data/test_service.md").expect("Failed to build model");
+    assert_eq!(model.summary(), "1 top-level entities, 2 entities total, 5 fields, max depth 2");
+}
+
+#[test]
+fn test_source_locations_record_input_path() {
+    let path = Path::new("tests/data/test_service.md");
+    let content = fs::read_to_string(path).expect("Failed to read test file");
+    let ast = ColaParser::new().parse(&content).expect("Failed to parse test file");
+    let model = ModelBuilder::build_config_model_with_path(&ast, path).expect("Failed to build model");
+
+    let service_id = model.find_entity_by_path("service").unwrap();
+    let node = model.get_node(service_id).unwrap();
+    let (entity_location, field_location) = match &*node.borrow() {
+        colap::config_model::ConfigNode::Entity(entity) => {
+            let field_location = entity.children.iter().find_map(|&child| {
+                match &*model.get_node(child).unwrap().borrow() {
+                    colap::config_model::ConfigNode::Field(field) => field.location.clone(),
+                    _ => None,
+                }
+            });
+            (entity.location.clone().unwrap(), field_location.unwrap())
+        }
+        _ => panic!("service is not an entity"),
+    };
+    assert_eq!(entity_location.file_path, path);
+    assert_eq!(field_location.file_path, path);
+    assert!(field_location.to_string().starts_with("tests/data/test_service.md: [("));
+
+    // Without a path the locations stay relative to nothing, as before
+    let model = ModelBuilder::build_config_model(&ast).expect("Failed to build model");
+    let node = model.get_node(model.find_entity_by_path("service").unwrap()).unwrap();
+    if let colap::config_model::ConfigNode::Entity(entity) = &*node.borrow() {
+        assert!(entity.location.as_ref().unwrap().file_path.as_os_str().is_empty());
+    }
+}
+
+#[test]
+fn test_required_marker_is_recorded_and_exported() {
+    let model = build_model_from_file("tests/data/test_required.md").expect("Failed to build model");
+    let service_id = model.find_entity_by_path("service").unwrap();
+    assert!(model.is_field_required(service_id, "owner"));
+    assert!(!model.is_field_required(service_id, "port"));
+    assert!(!model.is_field_optional(service_id, "owner"));
+    assert!(model.to_cola().contains("    owner!: \"\""));
+}
+
+#[test]
+fn test_type_only_fields_hold_type_defaults() {
+    let model = build_model_from_file("tests/data/test_schema_only.md").expect("Failed to build model");
+    let llm = model.find_entity_by_path("llm").unwrap();
+    assert!(matches!(model.get_field_value(llm, "max_tokens"), Some(ConfigValue::Integer(0))));
+    assert!(matches!(model.get_field_value(llm, "temperature"), Some(ConfigValue::Float(f)) if f == 0.0));
+    assert!(matches!(model.get_field_value(llm, "model"), Some(ConfigValue::String(s)) if s.is_empty()));
+    assert!(matches!(model.get_field_value(llm, "stream"), Some(ConfigValue::Boolean(false))));
+    assert_eq!(model.get_field_type(llm, "max_tokens").as_deref(), Some("i64"));
+    assert!(model.is_field_declared(llm, "max_tokens"));
+    assert!(!model.is_field_declared(llm, "retries"));
+
+    let endpoint = model.find_entity_by_path("llm/endpoint").unwrap();
+    assert_eq!(model.get_field_type(endpoint, "timeout").as_deref(), Some("u16"));
+
+    // Declarations are exported as declarations, not as their default values
+    let cola = model.to_cola();
+    assert!(cola.contains("    max_tokens: i64,\n"), "{}", cola);
+    assert!(cola.contains("    retries: 3"), "{}", cola);
+
+    let unsupported = "```cola\nllm:\n    max_tokens: int\n;\n```\n";
+    let cola = ColaParser::new().parse(unsupported).expect("Failed to parse");
+    let error = ModelBuilder::build_config_model(&cola).unwrap_err();
+    assert!(error.contains("unsupported type annotation 'int'"), "{}", error);
+}
+
+#[test]
+fn test_include_merges_files_relative_to_the_including_one() {
+    let builder = ModelBuilder {
+        file_path: "tests/data/test_include.md".into(),
+        ..ModelBuilder::new()
+    };
+    let mut model = build_model_with(&builder, "tests/data/test_include.md").expect("Failed to build model");
+    let content = fs::read_to_string("tests/data/test_include.md").expect("Failed to read test file");
+    builder.attach_doc_comments(&mut model, &content);
+
+    let logging = model.find_entity_by_path("logging").unwrap();
+    assert!(matches!(model.get_field_value(logging, "level"), Some(ConfigValue::String(s)) if s == "info"));
+    let app = model.find_entity_by_path("app").unwrap();
+    assert!(matches!(model.get_field_value(app, "name"), Some(ConfigValue::String(s)) if s == "demo"));
+    let limits = model.find_entity_by_path("app/limits").unwrap();
+    assert!(matches!(model.get_field_value(limits, "max_connections"), Some(ConfigValue::Integer(64))));
+    assert!(matches!(model.get_field_value(limits, "timeout"), Some(ConfigValue::Integer(30))));
+
+    // Comments of included files come from those files, not from the lines of the including one
+    assert_eq!(model.get_field_doc(logging, "retention_days").as_deref(), Some("Keep a week of logs"));
+    assert_eq!(model.get_field_doc(limits, "timeout"), None);
+}
+
+#[test]
+fn test_include_cycle_is_an_error() {
+    let builder = ModelBuilder {
+        file_path: "tests/data/test_include_self.md".into(),
+        ..ModelBuilder::new()
+    };
+    let error = build_model_with(&builder, "tests/data/test_include_self.md").unwrap_err();
+    assert!(error.contains("Include cycle"), "{}", error);
+    assert!(error.contains("test_include_self.md -> "), "{}", error);
+}
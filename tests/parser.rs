@@ -8,14 +8,12 @@ use std::path::Path;
 
 /// Helper to parse a file and assert success
 fn parse_cola_file<P: AsRef<Path>>(path: P) -> Cola {
-    let input = fs::read_to_string(&path).expect(&format!(
-        "Failed to read test file: {}",
-        path.as_ref().display()
-    ));
+    let input = fs::read_to_string(&path)
+        .unwrap_or_else(|_| panic!("Failed to read test file: {}", path.as_ref().display()));
     let parser = ColaParser::new();
     parser
         .parse(&input)
-        .expect(&format!("Parse failed for {}", path.as_ref().display()))
+        .unwrap_or_else(|_| panic!("Parse failed for {}", path.as_ref().display()))
 }
 
 #[test]
@@ -43,6 +41,21 @@ fn test_nested_oneline_md() {
     parse_cola_file("tests/data/test_nested_oneline.md");
 }
 
+#[test]
+fn test_braced_oneline_md() {
+    use colap::model_builder::ModelBuilder;
+
+    let cola = parse_cola_file("tests/data/test_braced_oneline.md");
+    let model = ModelBuilder::build_config_model(&cola).expect("Failed to build model");
+    for (braced, plain) in [("llm/openai", "llm_plain/openai"), ("server", "server_plain")] {
+        let braced = model.find_entity_by_path(braced).expect("Missing braced entity");
+        let plain = model.find_entity_by_path(plain).expect("Missing plain entity");
+        let fields: Vec<_> = model.iter_fields(braced).collect();
+        assert_eq!(fields.len(), 3);
+        assert_eq!(fields, model.iter_fields(plain).collect::<Vec<_>>());
+    }
+}
+
 #[test]
 fn test_no_indent_md() {
     parse_cola_file("tests/data/test_no_indent.md");
@@ -67,3 +80,205 @@ fn test_simple_cola_md() {
 fn test_genite_md() {
     parse_cola_file("tests/data/test_genite.md");
 }
+
+#[test]
+fn test_typed_md() {
+    parse_cola_file("tests/data/test_typed.md");
+}
+
+#[test]
+fn test_repeated_md() {
+    parse_cola_file("tests/data/test_repeated.md");
+}
+
+#[test]
+fn test_service_md() {
+    parse_cola_file("tests/data/test_service.md");
+}
+
+#[test]
+fn test_extras_md() {
+    parse_cola_file("tests/data/test_extras.md");
+}
+
+#[test]
+fn test_multiline_md() {
+    parse_cola_file("tests/data/test_multiline.md");
+}
+
+#[test]
+fn test_variants_md() {
+    parse_cola_file("tests/data/test_variants.md");
+}
+
+#[test]
+fn test_negative_md() {
+    parse_cola_file("tests/data/test_negative.md");
+}
+
+#[test]
+fn test_mixed_arrays_md() {
+    parse_cola_file("tests/data/test_mixed_arrays.md");
+}
+
+#[test]
+fn test_schema_only_md() {
+    parse_cola_file("tests/data/test_schema_only.md");
+}
+
+#[test]
+fn test_inline_plural_md() {
+    parse_cola_file("tests/data/test_inline_plural.md");
+}
+
+#[test]
+fn test_include_md() {
+    parse_cola_file("tests/data/test_include.md");
+    parse_cola_file("tests/data/test_include_self.md");
+}
+
+#[test]
+fn test_bare_minus_is_a_parse_error() {
+    let input = fs::read_to_string("tests/data/test_bare_minus.md").expect("Failed to read test file");
+    assert!(ColaParser::new().parse(&input).is_err());
+}
+
+#[test]
+fn test_comments_md() {
+    parse_cola_file("tests/data/test_comments.md");
+}
+
+#[test]
+fn test_value_keywords_do_not_split_identifiers() {
+    use colap::config_model::ConfigValue;
+    use colap::model_builder::ModelBuilder;
+
+    let input = "```cola\nhttp:\n    nullable_port: 8080,\n    true_color: false,\n    proxy: null\n;\nenabled: true\n```\n";
+    let cola = ColaParser::new().parse(input).expect("Failed to parse keyword-prefixed names");
+    let model = ModelBuilder::build_config_model(&cola).expect("Failed to build model");
+    assert_eq!(model.get_field_value_by_path("http/nullable_port"), Some(ConfigValue::Integer(8080)));
+    assert_eq!(model.get_field_value_by_path("http/true_color"), Some(ConfigValue::Boolean(false)));
+    assert_eq!(model.get_field_value_by_path("http/proxy"), Some(ConfigValue::Null));
+    assert_eq!(model.get_field_value_by_path("enabled"), Some(ConfigValue::Boolean(true)));
+}
+
+#[test]
+fn test_unterminated_cola_block_reports_its_start_line() {
+    use colap::parser::blocks::unterminated_cola_block;
+
+    let input = fs::read_to_string("tests/data/test_unterminated.md").expect("Failed to read test file");
+    assert!(ColaParser::new().parse(&input).is_err());
+    assert_eq!(unterminated_cola_block(&input), Some(11));
+
+    let closed = fs::read_to_string("tests/data/test_service.md").expect("Failed to read test file");
+    assert_eq!(unterminated_cola_block(&closed), None);
+}
+
+#[test]
+fn test_repeat_expands_a_range_into_entities() {
+    use colap::model_builder::ModelBuilder;
+    use colap::parser::repeat::expand_repeats;
+
+    let input = fs::read_to_string("tests/data/test_repeat.md").expect("Failed to read test file");
+    let expanded = expand_repeats(&input).expect("Failed to expand repeat");
+    assert!(!expanded.contains("repeat gpt"));
+    let cola = ColaParser::new().parse(&expanded).expect("Failed to parse expanded input");
+    let model = ModelBuilder::build_config_model(&cola).expect("Failed to build model");
+
+    let models: Vec<String> = model
+        .iter_entities()
+        .filter(|(_, name, _)| name.starts_with("gpt-"))
+        .map(|(_, name, _)| name)
+        .collect();
+    assert_eq!(models, ["gpt-1", "gpt-2", "gpt-3", "gpt-4"]);
+    let gpt_3 = model.find_entity_by_path("model/gpt-3").expect("Missing model/gpt-3");
+    assert_eq!(model.get_field_value(gpt_3, "id").map(|value| value.to_string()), Some("\"gpt-3\"".to_string()));
+
+    assert!(expand_repeats("repeat gpt {\n}").is_err());
+    assert!(expand_repeats("repeat gpt-{4..1} {\n}").is_err());
+    assert!(expand_repeats("repeat gpt-{1..2} {\n").is_err());
+    assert_eq!(expand_repeats("repeat: 3").unwrap(), "repeat: 3");
+}
+
+#[test]
+fn test_strip_containers_reads_blocks_in_quotes_and_lists() {
+    use colap::model_builder::ModelBuilder;
+    use colap::parser::markdown::strip_containers;
+
+    let input = fs::read_to_string("tests/data/test_blockquote.md").expect("Failed to read test file");
+    // Without stripping, the quoted block is paragraph text
+    let cola = ColaParser::new().parse(&input).expect("Failed to parse quoted input");
+    let model = ModelBuilder::build_config_model(&cola).expect("Failed to build model");
+    assert!(model.find_entity_by_path("service").is_none());
+
+    let stripped = strip_containers(&input);
+    assert_eq!(stripped.lines().count(), input.lines().count());
+    let cola = ColaParser::new().parse(&stripped).expect("Failed to parse stripped input");
+    let model = ModelBuilder::build_config_model(&cola).expect("Failed to build model");
+    let service = model.find_entity_by_path("service").expect("Missing service");
+    assert_eq!(model.get_field_value(service, "port").map(|value| value.to_string()), Some("8080".to_string()));
+    let cache = model.find_entity_by_path("cache").expect("Missing cache");
+    assert_eq!(model.get_field_value(cache, "ttl").map(|value| value.to_string()), Some("300".to_string()));
+}
+
+#[test]
+fn test_parse_config_encoded_base64() {
+    use base64::Engine;
+    use colap::parser::encoding::{InputEncoding, parse_config_encoded};
+
+    let source = fs::read_to_string("tests/data/test_service.md").expect("Failed to read test file");
+    let encoded = base64::engine::general_purpose::STANDARD.encode(source);
+    // Embedded base64 is often line-wrapped
+    let wrapped: Vec<u8> = encoded
+        .as_bytes()
+        .chunks(76)
+        .flat_map(|line| line.iter().copied().chain(std::iter::once(b'\n')))
+        .collect();
+
+    let model = parse_config_encoded(&wrapped, InputEncoding::Base64).expect("Failed to build model");
+    let database_id = model.find_entity_by_path("service/database").expect("Expected service/database");
+    assert!(model.get_field_value(database_id, "pool_size").is_some());
+
+    assert!(parse_config_encoded(b"not base64!", InputEncoding::Base64).is_err());
+}
+
+#[test]
+fn test_parse_config_encoded_gzip() {
+    use colap::parser::encoding::{InputEncoding, parse_config_encoded};
+    use flate2::{Compression, write::GzEncoder};
+    use std::io::Write;
+
+    let source = fs::read_to_string("tests/data/test_service.md").expect("Failed to read test file");
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(source.as_bytes()).unwrap();
+    let compressed = encoder.finish().unwrap();
+
+    let model = parse_config_encoded(&compressed, InputEncoding::Gzip).expect("Failed to build model");
+    assert!(model.find_entity_by_path("service").is_some());
+}
+
+#[test]
+fn test_parse_lenient_reports_every_error_and_keeps_valid_entities() {
+    let input = fs::read_to_string("tests/data/test_lenient.md").expect("Failed to read test file");
+    assert!(ColaParser::new().parse(&input).is_err());
+
+    let (model, diagnostics) = ColaParser::new().parse_lenient(&input);
+    let lines: Vec<u32> = diagnostics
+        .iter()
+        .map(|diagnostic| diagnostic.location.as_ref().expect("Expected a location").start_line)
+        .collect();
+    assert_eq!(lines, [11, 18], "{:?}", diagnostics);
+
+    let model = model.expect("Expected a partial model");
+    for path in ["server", "database", "metrics"] {
+        assert!(model.find_entity_by_path(path).is_some(), "Missing {}", path);
+    }
+    assert!(model.find_entity_by_path("cache").is_none());
+    assert!(model.find_entity_by_path("logging").is_none());
+    let database = model.find_entity_by_path("database").unwrap();
+    assert_eq!(model.get_field_value(database, "pool").map(|value| value.to_string()), Some("5".to_string()));
+
+    let valid = fs::read_to_string("tests/data/test_service.md").expect("Failed to read test file");
+    let (model, diagnostics) = ColaParser::new().parse_lenient(&valid);
+    assert!(model.is_some() && diagnostics.is_empty());
+}